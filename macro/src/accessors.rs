@@ -0,0 +1,344 @@
+use darling::FromMeta;
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{
+    parse_macro_input, parse_quote, parse_str, Data, DeriveInput, Fields, Ident, Meta, NestedMeta,
+    Type,
+};
+
+pub(super) fn derive(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let derive_input = parse_macro_input!(input as DeriveInput);
+    match expand(&derive_input) {
+        Ok(tokens) => tokens.into(),
+        Err(error) => error.write_errors().into(),
+    }
+}
+
+fn expand(derive_input: &DeriveInput) -> darling::Result<TokenStream> {
+    let ident = &derive_input.ident;
+    let (impl_generics, type_generics, where_clause) = derive_input.generics.split_for_impl();
+    let mut errors = Vec::new();
+    let mut accessors = Vec::new();
+    let mut options = Options::default();
+    for attr in derive_input.attrs.iter() {
+        if attr.path.is_ident("accessors") {
+            match list(attr).and_then(|nested| Options::from_list(&nested)) {
+                Ok(parsed) => {
+                    for (name, field) in &parsed.fields.0 {
+                        match Accessor::new(name, field) {
+                            Ok(accessor) => accessors.push(accessor),
+                            Err(error) => errors.push(error.with_span(attr)),
+                        }
+                    }
+                    options = parsed;
+                }
+                Err(error) => errors.push(error),
+            }
+        }
+    }
+    if !errors.is_empty() {
+        return Err(darling::Error::multiple(errors));
+    }
+    let deserialize = if options.deserialize {
+        Some(deserialize(
+            derive_input,
+            &accessors,
+            options.size.as_deref(),
+        )?)
+    } else {
+        None
+    };
+
+    let methods = accessors.iter().map(Accessor::method);
+    let names = accessors.iter().map(|accessor| accessor.name.to_string());
+    let calls = accessors.iter().map(|accessor| &accessor.name);
+    let serialized = accessors
+        .iter()
+        .filter(|accessor| !accessor.skip_serializing)
+        .collect::<Vec<_>>();
+    let serialized_count = serialized.len();
+    let serialized_names = serialized.iter().map(|accessor| accessor.name.to_string());
+    let serialized_calls = serialized.iter().map(|accessor| &accessor.name);
+    Ok(quote! {
+        impl #impl_generics #ident #type_generics #where_clause {
+            #(#methods)*
+        }
+
+        impl #impl_generics std::fmt::Debug for #ident #type_generics #where_clause {
+            fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+                f.debug_struct(stringify!(#ident))
+                    #(.field(#names, &self.#calls()))*
+                    .finish()
+            }
+        }
+
+        #[cfg(feature = "serde")]
+        impl #impl_generics ::serde::Serialize for #ident #type_generics #where_clause {
+            fn serialize<S: ::serde::Serializer>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> {
+                use ::serde::ser::SerializeStruct;
+
+                let mut state = serializer.serialize_struct(stringify!(#ident), #serialized_count)?;
+                #(state.serialize_field(#serialized_names, &self.#serialized_calls())?;)*
+                state.end()
+            }
+        }
+
+        #deserialize
+    })
+}
+
+/// The `Deserialize` that fills the accessed fields of a zeroed structure and
+/// sets its size field, if any, to the size of the structure.
+fn deserialize(
+    derive_input: &DeriveInput,
+    accessors: &[Accessor],
+    size: Option<&str>,
+) -> darling::Result<TokenStream> {
+    let ident = &derive_input.ident;
+    let ident_name = ident.to_string();
+    let inner = match &derive_input.data {
+        Data::Struct(data) => match &data.fields {
+            Fields::Unnamed(fields) if fields.unnamed.len() == 1 => &fields.unnamed[0].ty,
+            _ => {
+                return Err(darling::Error::unsupported_shape(
+                    "non-single-field tuple structure",
+                ))
+            }
+        },
+        _ => return Err(darling::Error::unsupported_shape("non-structure")),
+    };
+    let deserialized = accessors
+        .iter()
+        .filter(|accessor| !accessor.skip_serializing)
+        .collect::<Vec<_>>();
+    let names = deserialized.iter().map(|accessor| &accessor.name);
+    let types = deserialized.iter().map(|accessor| &accessor.r#type);
+    let assignments = deserialized
+        .iter()
+        .map(|accessor| accessor.assignment())
+        .collect::<darling::Result<Vec<_>>>()?;
+    let size = size
+        .map(|size| {
+            let size = parse::<Ident>(size)?;
+            Ok(quote!(inner.#size = std::mem::size_of::<#inner>() as _;))
+        })
+        .transpose()?;
+    Ok(quote! {
+        #[cfg(feature = "serde")]
+        impl<'de> ::serde::Deserialize<'de> for #ident {
+            fn deserialize<D: ::serde::Deserializer<'de>>(deserializer: D) -> std::result::Result<Self, D::Error> {
+                #[derive(::serde::Deserialize)]
+                #[serde(rename = #ident_name)]
+                struct Fields {
+                    #(#names: #types,)*
+                }
+
+                let fields = <Fields as ::serde::Deserialize>::deserialize(deserializer)?;
+                let mut inner: #inner = unsafe { std::mem::zeroed() };
+                #(#assignments)*
+                #size
+                Ok(Self(inner))
+            }
+        }
+    })
+}
+
+/// The accessor of a field of the wrapped structure.
+struct Accessor {
+    name: Ident,
+    from: Vec<Ident>,
+    r#type: Type,
+    convert: Convert,
+    skip_serializing: bool,
+}
+
+impl Accessor {
+    fn new(name: &Ident, field: &Field) -> darling::Result<Self> {
+        let convert = match &field.convert {
+            Some(convert) => Convert::from_string(convert)?,
+            None => Convert::None,
+        };
+        let r#type = match (&field.ty, convert) {
+            (Some(r#type), _) => parse(r#type)?,
+            (None, Convert::WideCStr) => parse_quote!(String),
+            (None, Convert::WideCStrPath) => parse_quote!(std::path::PathBuf),
+            (None, Convert::Bool) => parse_quote!(bool),
+            (None, _) => return Err(darling::Error::missing_field("ty")),
+        };
+        let name = name.clone();
+        let from = match &field.from {
+            Some(from) => from.split('.').map(parse).collect::<darling::Result<_>>()?,
+            None => vec![name.clone()],
+        };
+        Ok(Self {
+            name,
+            from,
+            r#type,
+            convert,
+            skip_serializing: field.skip_serializing,
+        })
+    }
+
+    fn method(&self) -> TokenStream {
+        let Self {
+            name, from, r#type, ..
+        } = self;
+        let value = quote!(self.0.#(#from).*);
+        let body = match self.convert {
+            Convert::None => value,
+            Convert::Cast => quote!(#value as _),
+            Convert::From => quote!(<#r#type>::from(#value)),
+            Convert::Bits => quote!(<#r#type>::from_bits_truncate(#value)),
+            Convert::Bool => quote!(#value != 0),
+            Convert::Handle => quote! {
+                unsafe { <#r#type as crate::utils::FromStrictRawHandle>::from_strict_raw_handle(#value) }
+            },
+            Convert::WideCStr => quote! {
+                ::widestring::U16CStr::from_slice_with_nul(&#value)
+                    .unwrap()
+                    .to_string_lossy()
+            },
+            Convert::WideCStrPath => quote! {
+                ::widestring::U16CStr::from_slice_with_nul(&#value)
+                    .unwrap()
+                    .to_os_string()
+                    .into()
+            },
+        };
+        quote! {
+            #[inline]
+            pub fn #name(&self) -> #r#type {
+                #body
+            }
+        }
+    }
+
+    /// The assignment of the deserialized value to the field, the inverse of
+    /// the accessor.
+    fn assignment(&self) -> darling::Result<TokenStream> {
+        let Self { name, from, .. } = self;
+        let field = quote!(inner.#(#from).*);
+        let value = quote!(fields.#name);
+        let wide = |string: TokenStream| {
+            quote! {
+                let wide = #string.map_err(<D::Error as ::serde::de::Error>::custom)?;
+                let wide = wide.as_slice_with_nul();
+                if wide.len() > #field.len() {
+                    return Err(<D::Error as ::serde::de::Error>::custom(
+                        concat!(stringify!(#name), " is too long"),
+                    ));
+                }
+                #field[..wide.len()].copy_from_slice(wide);
+            }
+        };
+        Ok(match self.convert {
+            Convert::None => quote!(#field = #value;),
+            Convert::Cast | Convert::Bool => quote!(#field = #value as _;),
+            Convert::From => quote!(#field = #value.into();),
+            Convert::Bits => quote!(#field = #value.bits();),
+            Convert::Handle => {
+                return Err(darling::Error::custom(format!(
+                    "handle `{}` can't be deserialized, skip its serializing",
+                    name,
+                )))
+            }
+            Convert::WideCStr => {
+                let assignment = wide(quote!(::widestring::U16CString::from_str(&#value)));
+                quote!({ #assignment })
+            }
+            Convert::WideCStrPath => {
+                let assignment = wide(quote!(::widestring::U16CString::from_os_str(&#value)));
+                quote!({ #assignment })
+            }
+        })
+    }
+}
+
+/// The conversion of the field value to the accessor type.
+#[derive(Clone, Copy, Debug)]
+enum Convert {
+    None,
+    Cast,
+    From,
+    Bits,
+    Bool,
+    Handle,
+    WideCStr,
+    WideCStrPath,
+}
+
+impl Convert {
+    fn from_string(value: &str) -> darling::Result<Self> {
+        Ok(match value {
+            "cast" => Self::Cast,
+            "from" => Self::From,
+            "bits" => Self::Bits,
+            "bool" => Self::Bool,
+            "handle" => Self::Handle,
+            "wide_cstr" => Self::WideCStr,
+            "wide_cstr_path" => Self::WideCStrPath,
+            _ => return Err(darling::Error::unknown_value(value)),
+        })
+    }
+}
+
+/// Field of the options.
+#[derive(Debug, FromMeta)]
+struct Field {
+    #[darling(default)]
+    from: Option<String>,
+    #[darling(default)]
+    ty: Option<String>,
+    #[darling(default)]
+    convert: Option<String>,
+    #[darling(default)]
+    skip_serializing: bool,
+}
+
+/// Options attribute.
+#[derive(Debug, Default, FromMeta)]
+struct Options {
+    #[darling(default)]
+    deserialize: bool,
+    /// The field that holds the size of the structure, as `dwSize`.
+    #[darling(default)]
+    size: Option<String>,
+    #[darling(default)]
+    fields: NamedFields,
+}
+
+/// Fields of the options, each list is named after its accessor, so the
+/// options of different fields never repeat the same attribute path.
+#[derive(Debug, Default)]
+struct NamedFields(Vec<(Ident, Field)>);
+
+impl FromMeta for NamedFields {
+    fn from_list(items: &[NestedMeta]) -> darling::Result<Self> {
+        items
+            .iter()
+            .map(|item| match item {
+                NestedMeta::Meta(Meta::List(list)) => {
+                    let name = list.path.get_ident().cloned().ok_or_else(|| {
+                        darling::Error::unsupported_format("non-ident").with_span(&list.path)
+                    })?;
+                    let nested = list.nested.iter().cloned().collect::<Vec<_>>();
+                    Ok((name, Field::from_list(&nested)?))
+                }
+                _ => Err(darling::Error::unsupported_format("non-list").with_span(item)),
+            })
+            .collect::<darling::Result<_>>()
+            .map(Self)
+    }
+}
+
+fn list(attr: &syn::Attribute) -> darling::Result<Vec<NestedMeta>> {
+    match attr.parse_meta() {
+        Ok(Meta::List(list)) => Ok(list.nested.into_iter().collect()),
+        Ok(meta) => Err(darling::Error::unsupported_format("non-list").with_span(&meta)),
+        Err(error) => Err(darling::Error::custom(error).with_span(attr)),
+    }
+}
+
+fn parse<T: syn::parse::Parse>(value: &str) -> darling::Result<T> {
+    parse_str(value).map_err(darling::Error::custom)
+}
@@ -5,8 +5,8 @@ use syn::{
     parse::{Parse, ParseStream},
     parse_macro_input, parse_quote,
     token::Comma,
-    AngleBracketedGenericArguments, Data, DataStruct, DeriveInput, Expr, Field, GenericArgument,
-    Ident,
+    AngleBracketedGenericArguments, Data, DataStruct, DeriveInput, Expr, Field, Fields,
+    GenericArgument, Ident, WherePredicate,
 };
 
 pub(super) fn derive(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
@@ -18,13 +18,31 @@ pub(super) fn derive(input: proc_macro::TokenStream) -> proc_macro::TokenStream
         Span::call_site(),
     );
     let (impl_generics, type_generics, where_clause) = derive_input.generics.split_for_impl();
+    let call_predicate: WherePredicate = parse_quote!(#ident #type_generics: crate::utils::Call);
+    let mut where_clause = where_clause.cloned().unwrap_or_else(|| parse_quote!(where));
+    where_clause.predicates.push(call_predicate);
+    let fields = match &derive_input.data {
+        Data::Struct(DataStruct { fields, .. }) => fields,
+        _ => unimplemented!(),
+    };
+    let call_tokens = quote! {
+        #[cfg(feature = "nightly")]
+        impl #impl_generics std::ops::FnOnce<()> for #ident #type_generics #where_clause {
+            type Output = <Self as crate::utils::Call>::Output;
+
+            #[inline]
+            extern "rust-call" fn call_once(self, _args: ()) -> Self::Output {
+                crate::utils::Call::call(self)
+            }
+        }
+    };
+    // A unit struct has no builder.
+    if let Fields::Unit = fields {
+        return call_tokens.into();
+    }
     let (generic_arguments, fields_argumet) = {
         let mut generic_arguments: Vec<GenericArgument> = Vec::new();
         let mut fields_tuples: Vec<GenericArgument> = Vec::new();
-        let fields = match derive_input.data {
-            Data::Struct(DataStruct { fields, .. }) => fields,
-            _ => unimplemented!(),
-        };
         'fields: for Field {
             attrs, ident, ty, ..
         } in fields
@@ -38,9 +56,9 @@ pub(super) fn derive(input: proc_macro::TokenStream) -> proc_macro::TokenStream
                         continue 'fields;
                     }
                     if attributes.default {
-                        let ident = ident.as_ref().map(|ident| {
-                            Ident::new(&format!("__{}", ident.to_string()), ident.span())
-                        });
+                        let ident = ident
+                            .as_ref()
+                            .map(|ident| Ident::new(&format!("__{}", ident), ident.span()));
                         generic_arguments.push(parse_quote!(#ident: #builder_optional_ident<#ty>));
                         fields_tuples.push(parse_quote!(#ident));
                         continue 'fields;
@@ -67,13 +85,25 @@ pub(super) fn derive(input: proc_macro::TokenStream) -> proc_macro::TokenStream
     }
     type_generic_arguments.args.insert(0, fields_argumet);
     let tokens = quote! {
+        #call_tokens
+
+        #[allow(non_camel_case_types)]
+        impl #impl_generics_arguments #builder_ident #type_generic_arguments #where_clause {
+            /// Builds and calls.
+            #[inline]
+            pub fn call(self) -> <#ident #type_generics as crate::utils::Call>::Output {
+                crate::utils::Call::call(self.build())
+            }
+        }
+
+        #[cfg(feature = "nightly")]
         #[allow(non_camel_case_types)]
         impl #impl_generics_arguments std::ops::FnOnce<()> for #builder_ident #type_generic_arguments #where_clause {
-            type Output = <#ident #type_generics as std::ops::FnOnce<()>>::Output;
+            type Output = <#ident #type_generics as crate::utils::Call>::Output;
 
             #[inline]
             extern "rust-call" fn call_once(self, _args: ()) -> Self::Output {
-                self.build()()
+                self.call()
             }
         }
     };
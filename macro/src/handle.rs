@@ -1,7 +1,8 @@
 use darling::{
     ast::{Data, Style},
-    FromDeriveInput,
+    FromDeriveInput, FromMeta,
 };
+use proc_macro2::TokenStream;
 use quote::quote;
 use syn::{parse_macro_input, DeriveInput, Field, Ident};
 
@@ -16,7 +17,12 @@ pub(super) fn derive(input: proc_macro::TokenStream) -> proc_macro::TokenStream
         "Handle derive supports only tuple structures with a single field",
     );
     let r#type = &fields.fields[0].ty;
+    let invalid = attribute.invalid.condition();
+    let close = attribute.close.close(ident);
+    // The `std::os::windows` traits only exist on Windows, the raw handle is
+    // spelled out as the `*mut c_void` it is there.
     let tokens = quote! {
+        #[cfg(windows)]
         impl std::os::windows::io::AsRawHandle for #ident {
             fn as_raw_handle(&self) -> std::os::windows::io::RawHandle {
                 self.0 as _
@@ -29,6 +35,7 @@ pub(super) fn derive(input: proc_macro::TokenStream) -> proc_macro::TokenStream
             }
         }
 
+        #[cfg(windows)]
         impl std::os::windows::io::FromRawHandle for #ident {
             unsafe fn from_raw_handle(handle: std::os::windows::io::RawHandle) -> Self {
                 Self(handle as _)
@@ -43,21 +50,45 @@ pub(super) fn derive(input: proc_macro::TokenStream) -> proc_macro::TokenStream
             }
         }
 
+        #[cfg(windows)]
         impl std::os::windows::io::IntoRawHandle for #ident {
             fn into_raw_handle(self) -> std::os::windows::io::RawHandle {
-                let handle = self.0;
-                std::mem::forget(self);
-                handle as _
+                std::mem::ManuallyDrop::new(self).0 as _
             }
         }
 
         impl crate::utils::IntoStrictRawHandle for #ident {
             fn into_strict_raw_handle(self) -> Self::StrictRawHandle {
-                let handle = self.0;
-                std::mem::forget(self);
-                handle
+                std::mem::ManuallyDrop::new(self).0
             }
         }
+
+        impl std::convert::TryFrom<*mut std::ffi::c_void> for #ident {
+            type Error = crate::Error;
+
+            fn try_from(handle: *mut std::ffi::c_void) -> std::result::Result<Self, Self::Error> {
+                if #invalid {
+                    return Err(crate::Error::Win32(::winapi::shared::winerror::ERROR_INVALID_HANDLE));
+                }
+                Ok(Self(handle as _))
+            }
+        }
+
+        impl std::fmt::Debug for #ident {
+            fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+                f.debug_tuple(stringify!(#ident))
+                    .field(&format_args!("{:#p}", self.0))
+                    .finish()
+            }
+        }
+
+        impl std::fmt::Display for #ident {
+            fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+                write!(f, "{:#p}", self.0)
+            }
+        }
+
+        #close
     };
     tokens.into()
 }
@@ -67,4 +98,90 @@ pub(super) fn derive(input: proc_macro::TokenStream) -> proc_macro::TokenStream
 struct Attribute {
     ident: Ident,
     data: Data<(), Field>,
+    #[darling(default)]
+    invalid: Validity,
+    #[darling(default)]
+    close: Release,
+}
+
+/// The raw values that are not a handle.
+#[derive(Clone, Copy, Debug, Default, FromMeta)]
+enum Validity {
+    #[darling(rename = "null")]
+    #[default]
+    Null,
+    #[darling(rename = "INVALID_HANDLE_VALUE")]
+    InvalidHandleValue,
+    #[darling(rename = "both")]
+    Both,
+}
+
+impl Validity {
+    fn condition(self) -> TokenStream {
+        let null = quote!(handle.is_null());
+        let invalid_handle_value = quote! {
+            handle == ::winapi::um::handleapi::INVALID_HANDLE_VALUE as *mut std::ffi::c_void
+        };
+        match self {
+            Self::Null => null,
+            Self::InvalidHandleValue => invalid_handle_value,
+            Self::Both => quote!(#null || #invalid_handle_value),
+        }
+    }
+}
+
+/// The function that closes the handle on drop.
+#[derive(Clone, Copy, Debug, Default, FromMeta)]
+enum Release {
+    #[darling(rename = "CloseHandle")]
+    CloseHandle,
+    #[darling(rename = "RegCloseKey")]
+    RegCloseKey,
+    #[darling(rename = "none")]
+    #[default]
+    None,
+}
+
+impl Release {
+    /// The `Drop` that ignores the error and the `try_close` that returns it.
+    fn close(self, ident: &Ident) -> Option<TokenStream> {
+        let (close, check) = match self {
+            Self::CloseHandle => (
+                quote!(::winapi::um::handleapi::CloseHandle(handle as _)),
+                quote! {
+                    if r#return == ::winapi::shared::minwindef::FALSE {
+                        return Err(crate::Error::last_os_error());
+                    }
+                },
+            ),
+            Self::RegCloseKey => (
+                quote!(::winapi::um::winreg::RegCloseKey(handle as _)),
+                quote! {
+                    if r#return != ::winapi::shared::winerror::ERROR_SUCCESS as _ {
+                        return Err(crate::Error::Win32(r#return as _));
+                    }
+                },
+            ),
+            Self::None => return None,
+        };
+        Some(quote! {
+            impl #ident {
+                /// Closes the handle, unlike the drop returns the error.
+                pub fn try_close(self) -> crate::Result<()> {
+                    let handle = self.0;
+                    std::mem::forget(self);
+                    let r#return = unsafe { #close };
+                    #check
+                    Ok(())
+                }
+            }
+
+            impl Drop for #ident {
+                fn drop(&mut self) {
+                    let handle = self.0;
+                    unsafe { #close };
+                }
+            }
+        })
+    }
 }
@@ -1,7 +1,21 @@
-#![feature(bindings_after_at)]
-
 use proc_macro::TokenStream;
 
+/// Accessors derive macro.
+///
+/// Generates the accessors of a `#[repr(transparent)]` wrapper over a winapi
+/// structure, its `Debug` and, with the `serde` feature, its `Serialize`. The
+/// accessors are listed by name in a single attribute: `#[accessors(fields(
+/// process_id(from = "th32ProcessID", ty = "u32"), ...))]`, `convert` is one of
+/// `cast`, `from`, `bits`, `bool`, `handle`, `wide_cstr` and `wide_cstr_path`,
+/// `from` may be a path like `SampleDesc.Count`. With `deserialize` in the list
+/// it also generates the `Deserialize` that fills the fields of a zeroed
+/// structure, `size = "dwSize"` also sets the size field to the size of the
+/// structure.
+#[proc_macro_derive(Accessors, attributes(accessors))]
+pub fn accessors(input: TokenStream) -> TokenStream {
+    accessors::derive(input)
+}
+
 /// FnOnce derive macro.
 #[proc_macro_derive(FnOnce, attributes(fn_once))]
 pub fn fn_once(input: TokenStream) -> TokenStream {
@@ -9,6 +23,10 @@ pub fn fn_once(input: TokenStream) -> TokenStream {
 }
 
 /// Handle derive macro.
+///
+/// `#[handle(invalid = "null" | "INVALID_HANDLE_VALUE" | "both", close = "CloseHandle" |
+/// "RegCloseKey" | "none")]` selects the values `TryFrom<RawHandle>` rejects (`null` by default)
+/// and the function `Drop` and `try_close` close the handle with (`none` by default).
 #[proc_macro_derive(Handle, attributes(handle))]
 pub fn handle(input: TokenStream) -> TokenStream {
     handle::derive(input)
@@ -20,6 +38,17 @@ pub fn transparent(input: TokenStream) -> TokenStream {
     transparent::derive(input)
 }
 
+/// Win32 attribute macro.
+///
+/// Generates the structure builder and the `Call` implementation of a
+/// wrapper: `#[win32(fn = "GetExitCodeThread", fail = "FALSE", out = "exit_code")]`.
+#[proc_macro_attribute]
+pub fn win32(attribute: TokenStream, item: TokenStream) -> TokenStream {
+    win32::expand(attribute, item)
+}
+
+mod accessors;
 mod fn_once;
 mod handle;
 mod transparent;
+mod win32;
@@ -0,0 +1,232 @@
+use darling::{FromField, FromMeta};
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{
+    parse::Parse, parse_macro_input, parse_quote, parse_str, AttributeArgs, Expr, Fields, Ident,
+    ItemStruct, Path, Type,
+};
+
+pub(super) fn expand(
+    attribute: proc_macro::TokenStream,
+    item: proc_macro::TokenStream,
+) -> proc_macro::TokenStream {
+    let attribute_args = parse_macro_input!(attribute as AttributeArgs);
+    let item_struct = parse_macro_input!(item as ItemStruct);
+    match expand_struct(&attribute_args, item_struct) {
+        Ok(tokens) => tokens.into(),
+        Err(error) => error.write_errors().into(),
+    }
+}
+
+fn expand_struct(
+    attribute_args: &AttributeArgs,
+    mut item_struct: ItemStruct,
+) -> darling::Result<TokenStream> {
+    let arguments = Arguments::from_list(attribute_args)?;
+    let outs = match &arguments.out {
+        Some(out) => out
+            .split(',')
+            .map(|out| parse::<Ident>(out.trim()))
+            .collect::<darling::Result<Vec<_>>>()?,
+        None => Vec::new(),
+    };
+    let fields = match &mut item_struct.fields {
+        Fields::Named(fields) => &mut fields.named,
+        fields => {
+            return Err(darling::Error::custom("expected named fields").with_span(fields));
+        }
+    };
+    let mut parameters = Vec::new();
+    let mut arguments_tokens = Vec::new();
+    let mut out_types = Vec::new();
+    for field in fields.iter_mut() {
+        let attributes = FieldAttributes::from_field(field)?;
+        field.attrs.retain(|attr| !attr.path.is_ident("win32"));
+        let ident = attributes.ident.expect("named field");
+        let name = match &attributes.name {
+            Some(name) => parse::<Ident>(name)?,
+            None => ident.clone(),
+        };
+        let value = if outs.contains(&ident) {
+            if !field.attrs.iter().any(|attr| attr.path.is_ident("builder")) {
+                field
+                    .attrs
+                    .push(parse_quote!(#[builder(default, setter(skip))]));
+            }
+            out_types.push(attributes.ty.clone());
+            quote!(&mut self.#ident)
+        } else if let Some(arg) = &attributes.arg {
+            let arg = parse::<Expr>(arg)?;
+            quote!(#arg)
+        } else {
+            argument(&ident, &attributes.ty)
+        };
+        parameters.push(name.clone());
+        arguments_tokens.push(quote!(let #name = #value;));
+    }
+    if out_types.len() != outs.len() {
+        return Err(darling::Error::custom("out field is not found").with_span(&item_struct.ident));
+    }
+
+    let function = &arguments.function;
+    let import = arguments
+        .module
+        .as_ref()
+        .map(|module| quote!(use #module::#function;));
+    let call = quote!(#function(#(#parameters),*));
+    let check = match &arguments.fail {
+        Some(fail) => Some(match fail.as_str() {
+            "NULL" => {
+                quote!(crate::error::ensure!(!r#return.is_null(), crate::Error::last_os_error());)
+            }
+            _ => {
+                let fail = failure(fail)?;
+                quote!(crate::error::ensure!(r#return != #fail, crate::Error::last_os_error());)
+            }
+        }),
+        None => None,
+    };
+    let output_type = match &arguments.output {
+        Some(output) => Some(parse::<Type>(output)?),
+        None => None,
+    };
+    let (r#type, value) = if !outs.is_empty() {
+        match (&outs[..], &out_types[..]) {
+            ([out], [r#type]) => (quote!(#r#type), quote!(self.#out)),
+            (outs, types) => (quote!((#(#types),*)), quote!((#(self.#outs),*))),
+        }
+    } else if let Some(r#type) = &output_type {
+        (quote!(#r#type), output(r#type))
+    } else {
+        (quote!(()), quote!(()))
+    };
+    let uses_return = check.is_some() || (outs.is_empty() && output_type.is_some());
+    let invoke = if uses_return {
+        quote!(let r#return = #call;)
+    } else {
+        quote!(#call;)
+    };
+    let (output, value) = if check.is_some() {
+        (quote!(crate::Result<#r#type>), quote!(Ok(#value)))
+    } else {
+        (r#type, value)
+    };
+    let receiver = if outs.is_empty() {
+        quote!(self)
+    } else {
+        quote!(mut self)
+    };
+
+    let ident = &item_struct.ident;
+    let (impl_generics, type_generics, where_clause) = item_struct.generics.split_for_impl();
+    Ok(quote! {
+        #[derive(crate::r#macro::FnOnce, ::typed_builder::TypedBuilder)]
+        #item_struct
+
+        impl #impl_generics crate::utils::Call for #ident #type_generics #where_clause {
+            type Output = #output;
+
+            fn call(#receiver) -> Self::Output {
+                #import
+
+                #[allow(non_snake_case)]
+                unsafe {
+                    #(#arguments_tokens)*
+                    #invoke
+                    #check
+                    #value
+                }
+            }
+        }
+    })
+}
+
+/// The argument of a field: handles are passed raw, primitives are cast.
+fn argument(ident: &Ident, r#type: &Type) -> TokenStream {
+    if is_handle(r#type) {
+        quote!({
+            use crate::utils::AsStrictRawHandle;
+            self.#ident.as_strict_raw_handle()
+        })
+    } else if is_primitive(r#type) {
+        quote!(self.#ident as _)
+    } else {
+        quote!(self.#ident)
+    }
+}
+
+/// The value of the return: handles are wrapped, `BOOL` becomes `bool`.
+fn output(r#type: &Type) -> TokenStream {
+    if is_handle(r#type) {
+        quote!(crate::utils::FromStrictRawHandle::from_strict_raw_handle(
+            r#return
+        ))
+    } else if *r#type == parse_quote!(bool) {
+        quote!(r#return != 0)
+    } else {
+        quote!(r#return)
+    }
+}
+
+fn failure(fail: &str) -> darling::Result<Expr> {
+    Ok(match fail {
+        "FALSE" => parse_quote!(::winapi::shared::minwindef::FALSE),
+        "INVALID_HANDLE_VALUE" => parse_quote!(::winapi::um::handleapi::INVALID_HANDLE_VALUE),
+        _ => parse(fail)?,
+    })
+}
+
+fn parse<T: Parse>(value: &str) -> darling::Result<T> {
+    parse_str(value).map_err(darling::Error::custom)
+}
+
+fn is_handle(r#type: &Type) -> bool {
+    match r#type {
+        Type::Reference(reference) => is_handle(&reference.elem),
+        Type::Path(path) => path
+            .path
+            .segments
+            .last()
+            .is_some_and(|segment| segment.ident.to_string().ends_with("Handle")),
+        _ => false,
+    }
+}
+
+fn is_primitive(r#type: &Type) -> bool {
+    const PRIMITIVES: &[&str] = &[
+        "bool", "i8", "i16", "i32", "i64", "isize", "u8", "u16", "u32", "u64", "usize",
+    ];
+    match r#type {
+        Type::Path(path) => PRIMITIVES
+            .iter()
+            .any(|primitive| path.path.is_ident(primitive)),
+        _ => false,
+    }
+}
+
+/// Arguments.
+#[derive(Debug, FromMeta)]
+struct Arguments {
+    #[darling(rename = "fn")]
+    function: Ident,
+    #[darling(default)]
+    module: Option<Path>,
+    #[darling(default)]
+    fail: Option<String>,
+    #[darling(default)]
+    out: Option<String>,
+    #[darling(default)]
+    output: Option<String>,
+}
+
+/// Field attributes.
+#[derive(Debug, FromField)]
+#[darling(attributes(win32))]
+struct FieldAttributes {
+    ident: Option<Ident>,
+    ty: Type,
+    #[darling(default)]
+    name: Option<String>,
+    #[darling(default)]
+    arg: Option<String>,
+}
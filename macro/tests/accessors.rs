@@ -0,0 +1,163 @@
+use rustapi_macro::Accessors;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+
+#[allow(non_snake_case)]
+pub struct ENTRY {
+    dwSize: u32,
+    th32ProcessID: u32,
+    modBaseAddr: *mut u8,
+    bInherit: i32,
+    dwState: u32,
+    dwFlags: u32,
+    hModule: *mut u8,
+    szModule: [u16; 4],
+    szExePath: [u16; 8],
+}
+
+#[derive(Accessors)]
+#[accessors(
+    deserialize,
+    size = "dwSize",
+    fields(
+        size(from = "dwSize", ty = "u32"),
+        process_id(from = "th32ProcessID", ty = "u32"),
+        base_address(from = "modBaseAddr", ty = "usize", convert = "cast"),
+        inherit(from = "bInherit", convert = "bool"),
+        state(from = "dwState", ty = "State", convert = "from"),
+        flags(from = "dwFlags", ty = "Flags", convert = "bits"),
+        handle(from = "hModule", ty = "Handle", convert = "handle", skip_serializing),
+        name(from = "szModule", convert = "wide_cstr"),
+        path(from = "szExePath", convert = "wide_cstr_path")
+    )
+)]
+#[repr(transparent)]
+pub struct Entry(ENTRY);
+
+#[derive(Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
+pub enum State {
+    Running,
+    Stopped,
+}
+
+impl From<u32> for State {
+    fn from(from: u32) -> Self {
+        match from {
+            0 => State::Running,
+            _ => State::Stopped,
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl From<State> for u32 {
+    fn from(from: State) -> Self {
+        match from {
+            State::Running => 0,
+            State::Stopped => 1,
+        }
+    }
+}
+
+#[derive(Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
+pub struct Flags(u32);
+
+impl Flags {
+    fn from_bits_truncate(bits: u32) -> Self {
+        Flags(bits & 0b11)
+    }
+
+    #[cfg(feature = "serde")]
+    fn bits(&self) -> u32 {
+        self.0
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Handle(*mut u8);
+
+impl utils::FromStrictRawHandle for Handle {
+    type StrictRawHandle = *mut u8;
+
+    unsafe fn from_strict_raw_handle(handle: Self::StrictRawHandle) -> Self {
+        Handle(handle)
+    }
+}
+
+fn wide<const N: usize>(value: &str) -> [u16; N] {
+    let mut wide = [0; N];
+    for (index, unit) in value.encode_utf16().enumerate() {
+        wide[index] = unit;
+    }
+    wide
+}
+
+fn entry() -> Entry {
+    Entry(ENTRY {
+        dwSize: 48,
+        th32ProcessID: 4,
+        modBaseAddr: 0x1000 as _,
+        bInherit: 1,
+        dwState: 1,
+        dwFlags: 0b111,
+        hModule: 0x2000 as _,
+        szModule: wide("a.d"),
+        szExePath: wide(r"C:\a.d"),
+    })
+}
+
+#[test]
+fn accessors() {
+    let entry = entry();
+    assert_eq!(48, entry.size());
+    assert_eq!(4, entry.process_id());
+    assert_eq!(0x1000, entry.base_address());
+    assert!(entry.inherit());
+    assert_eq!(State::Stopped, entry.state());
+    assert_eq!(Flags(0b11), entry.flags());
+    assert_eq!(Handle(0x2000 as _), entry.handle());
+    assert_eq!("a.d", entry.name());
+    assert_eq!(PathBuf::from(r"C:\a.d"), entry.path());
+}
+
+#[test]
+fn debug() {
+    assert_eq!(
+        r#"Entry { size: 48, process_id: 4, base_address: 4096, inherit: true, state: Stopped, flags: Flags(3), handle: Handle(0x2000), name: "a.d", path: "C:\\a.d" }"#,
+        format!("{:?}", entry()),
+    );
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn serde() {
+    let json = serde_json::to_string(&entry()).unwrap();
+    assert_eq!(
+        r#"{"size":48,"process_id":4,"base_address":4096,"inherit":true,"state":"Stopped","flags":3,"name":"a.d","path":"C:\\a.d"}"#,
+        json,
+    );
+    let entry: Entry = serde_json::from_str(&json).unwrap();
+    assert_eq!(std::mem::size_of::<ENTRY>() as u32, entry.size());
+    assert_eq!(4, entry.process_id());
+    assert_eq!(0x1000, entry.base_address());
+    assert!(entry.inherit());
+    assert_eq!(State::Stopped, entry.state());
+    assert_eq!(Flags(0b11), entry.flags());
+    assert_eq!(Handle(0 as _), entry.handle());
+    assert_eq!("a.d", entry.name());
+    assert_eq!(PathBuf::from(r"C:\a.d"), entry.path());
+    let long = json.replace(r#""a.d""#, r#""a.dll""#);
+    assert!(serde_json::from_str::<Entry>(&long).is_err());
+}
+
+mod utils {
+    /// From strict raw handle.
+    pub trait FromStrictRawHandle {
+        type StrictRawHandle;
+
+        unsafe fn from_strict_raw_handle(handle: Self::StrictRawHandle) -> Self;
+    }
+}
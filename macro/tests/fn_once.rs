@@ -0,0 +1,74 @@
+#![cfg_attr(feature = "nightly", feature(fn_traits, unboxed_closures))]
+
+use rustapi_macro::FnOnce;
+use typed_builder::TypedBuilder;
+use utils::Call;
+
+#[derive(FnOnce, TypedBuilder)]
+pub struct Add {
+    left: u32,
+    #[builder(default = 1)]
+    right: u32,
+}
+
+impl Call for Add {
+    type Output = u32;
+
+    fn call(self) -> Self::Output {
+        self.left + self.right
+    }
+}
+
+#[derive(FnOnce, TypedBuilder)]
+pub struct Count<'a, T> {
+    items: &'a [T],
+    #[builder(default, setter(skip))]
+    count: usize,
+}
+
+impl<T> Call for Count<'_, T> {
+    type Output = usize;
+
+    fn call(mut self) -> Self::Output {
+        self.count = self.items.len();
+        self.count
+    }
+}
+
+#[derive(FnOnce)]
+pub struct Answer;
+
+impl Call for Answer {
+    type Output = u32;
+
+    fn call(self) -> Self::Output {
+        42
+    }
+}
+
+#[test]
+fn call() {
+    assert_eq!(3, Add::builder().left(2).call());
+    assert_eq!(5, Add::builder().left(2).right(3).call());
+    assert_eq!(5, Add::builder().left(2).right(3).build().call());
+    assert_eq!(2, Count::builder().items(&[1, 2]).call());
+    assert_eq!(42, Answer.call());
+}
+
+#[cfg(feature = "nightly")]
+#[test]
+fn fn_once() {
+    assert_eq!(3, Add::builder().left(2)());
+    assert_eq!(5, Add::builder().left(2).right(3).build()());
+    assert_eq!(2, Count::builder().items(&[1, 2])());
+    assert_eq!(42, Answer());
+}
+
+mod utils {
+    /// Call.
+    pub trait Call {
+        type Output;
+
+        fn call(self) -> Self::Output;
+    }
+}
@@ -1,4 +1,17 @@
+//! The Linux friendly subset of `winapi` is declared in this crate.
+
+// The stand-ins are only reached through the generated code.
+#![allow(dead_code)]
+
+extern crate self as winapi;
+
 use rustapi_macro::Handle;
+use std::{
+    convert::TryFrom,
+    sync::atomic::{AtomicUsize, Ordering},
+};
+
+type RawHandle = *mut std::ffi::c_void;
 
 #[test]
 fn test() {
@@ -6,6 +19,109 @@ fn test() {
     pub struct Temp(*mut u8);
 }
 
+#[test]
+fn invalid() {
+    #[derive(Handle)]
+    pub struct Null(RawHandle);
+
+    #[derive(Handle)]
+    #[handle(invalid = "INVALID_HANDLE_VALUE")]
+    pub struct InvalidHandleValue(RawHandle);
+
+    #[derive(Handle)]
+    #[handle(invalid = "both")]
+    pub struct Both(RawHandle);
+
+    let null = 0 as RawHandle;
+    let invalid_handle_value = -1isize as RawHandle;
+    let valid = 4 as RawHandle;
+    assert_eq!(Null::try_from(null).unwrap_err(), Error::Win32(6));
+    assert!(Null::try_from(invalid_handle_value).is_ok());
+    assert!(InvalidHandleValue::try_from(null).is_ok());
+    assert!(InvalidHandleValue::try_from(invalid_handle_value).is_err());
+    assert!(Both::try_from(null).is_err());
+    assert!(Both::try_from(invalid_handle_value).is_err());
+    assert!(Both::try_from(valid).is_ok());
+}
+
+#[test]
+fn close() {
+    #[derive(Handle)]
+    #[handle(close = "CloseHandle")]
+    pub struct Closed(RawHandle);
+
+    #[derive(Handle)]
+    #[handle(close = "none")]
+    pub struct Leaked(RawHandle);
+
+    Leaked::try_from(4 as RawHandle).unwrap();
+    assert_eq!(CLOSED.load(Ordering::SeqCst), 0);
+    drop(Closed::try_from(4 as RawHandle).unwrap());
+    assert_eq!(CLOSED.load(Ordering::SeqCst), 1);
+    assert_eq!(
+        Closed::try_from(4 as RawHandle).unwrap().try_close(),
+        Ok(())
+    );
+    assert_eq!(CLOSED.load(Ordering::SeqCst), 2);
+    assert_eq!(
+        Closed::try_from(5 as RawHandle).unwrap().try_close(),
+        Err(Error::Win32(0))
+    );
+}
+
+#[test]
+fn format() {
+    #[derive(Handle)]
+    pub struct Formatted(RawHandle);
+
+    let formatted = Formatted::try_from(0x20 as RawHandle).unwrap();
+    assert_eq!(format!("{:?}", formatted), "Formatted(0x0000000000000020)");
+    assert_eq!(formatted.to_string(), "0x0000000000000020");
+}
+
+static CLOSED: AtomicUsize = AtomicUsize::new(0);
+
+#[derive(Debug, PartialEq)]
+pub enum Error {
+    Win32(u32),
+}
+
+impl Error {
+    fn last_os_error() -> Self {
+        Error::Win32(0)
+    }
+}
+
+pub type Result<T, E = Error> = std::result::Result<T, E>;
+
+mod shared {
+    pub mod minwindef {
+        pub const FALSE: i32 = 0;
+    }
+
+    pub mod winerror {
+        pub const ERROR_INVALID_HANDLE: u32 = 6;
+    }
+}
+
+mod um {
+    pub mod handleapi {
+        use crate::RawHandle;
+        use std::sync::atomic::Ordering;
+
+        pub const INVALID_HANDLE_VALUE: RawHandle = -1isize as RawHandle;
+
+        #[allow(non_snake_case)]
+        pub unsafe fn CloseHandle(hObject: RawHandle) -> i32 {
+            if hObject as usize & 0b11 != 0 {
+                return 0;
+            }
+            crate::CLOSED.fetch_add(1, Ordering::SeqCst);
+            1
+        }
+    }
+}
+
 mod utils {
     /// As strict raw handle.
     pub trait AsStrictRawHandle: FromStrictRawHandle {
@@ -0,0 +1,6 @@
+#[test]
+fn win32() {
+    let test_cases = trybuild::TestCases::new();
+    test_cases.pass("tests/win32/pass/*.rs");
+    test_cases.compile_fail("tests/win32/fail/*.rs");
+}
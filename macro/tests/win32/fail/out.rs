@@ -0,0 +1,8 @@
+use rustapi_macro::win32;
+
+#[win32(fn = "GetExitCodeThread", fail = "FALSE", out = "exit_code")]
+pub struct GetExitCodeThread {
+    code: u32,
+}
+
+fn main() {}
@@ -0,0 +1,6 @@
+use rustapi_macro::win32;
+
+#[win32(fn = "GetTickCount")]
+pub struct GetTickCount(u32);
+
+fn main() {}
@@ -0,0 +1,224 @@
+#![cfg_attr(feature = "nightly", feature(fn_traits, unboxed_closures))]
+
+//! The Linux friendly subset of `winapi` is declared in this crate.
+
+extern crate self as winapi;
+
+use self::{
+    r#macro::win32,
+    utils::{Call, Handle},
+};
+
+#[win32(
+    fn = "GetExitCodeThread",
+    module = "crate::fake",
+    fail = "FALSE",
+    out = "exit_code"
+)]
+pub struct GetExitCodeThread<'a> {
+    #[win32(name = "hThread")]
+    thread: &'a Handle,
+    #[win32(name = "lpExitCode")]
+    exit_code: u32,
+}
+
+#[win32(fn = "GetProcessId", module = "crate::fake", output = "u32")]
+pub struct GetProcessId<'a> {
+    #[win32(name = "Process")]
+    process: &'a Handle,
+}
+
+#[win32(
+    fn = "IsWow64Process",
+    module = "crate::fake",
+    fail = "FALSE",
+    out = "wow64_process"
+)]
+pub struct IsWow64Process<'a> {
+    #[win32(name = "hProcess")]
+    process: &'a Handle,
+    #[win32(name = "Wow64Process")]
+    wow64_process: i32,
+}
+
+#[win32(
+    fn = "OpenThread",
+    module = "crate::fake",
+    fail = "NULL",
+    output = "Handle"
+)]
+pub struct OpenThread {
+    #[win32(name = "dwDesiredAccess")]
+    desired_access: u32,
+    #[win32(name = "bInheritHandle")]
+    inherit_handle: bool,
+    #[win32(name = "dwThreadId")]
+    thread_id: u32,
+}
+
+fn main() {
+    let thread = OpenThread::builder()
+        .desired_access(1)
+        .inherit_handle(false)
+        .thread_id(2)
+        .build()
+        .call()
+        .unwrap();
+    assert_eq!(thread.0, 3 as _);
+    assert_eq!(GetExitCodeThread::builder().thread(&thread).call(), Ok(259));
+    assert_eq!(GetProcessId::builder().process(&thread).call(), 3);
+    assert_eq!(IsWow64Process::builder().process(&thread).call(), Ok(1));
+    assert_eq!(
+        GetExitCodeThread::builder().thread(&Handle(0 as _)).call(),
+        Err(Error(6))
+    );
+    assert!(OpenThread::builder()
+        .desired_access(1)
+        .inherit_handle(false)
+        .thread_id(0)
+        .call()
+        .is_err());
+    assert_eq!(CreateFile::builder().share_mode(None).call(), Err(Error(6)));
+    assert_eq!(
+        CreateFile::builder().share_mode(Some(7)).call().unwrap().0,
+        7 as _
+    );
+}
+
+#[allow(non_snake_case)]
+mod fake {
+    use crate::{
+        shared::minwindef::{BOOL, DWORD, FALSE, LPDWORD, PBOOL, TRUE},
+        um::{handleapi::INVALID_HANDLE_VALUE, winnt::HANDLE},
+    };
+
+    pub unsafe fn GetExitCodeThread(hThread: HANDLE, lpExitCode: LPDWORD) -> BOOL {
+        if hThread.is_null() {
+            return FALSE;
+        }
+        *lpExitCode = 259;
+        TRUE
+    }
+
+    pub unsafe fn GetProcessId(Process: HANDLE) -> DWORD {
+        Process as _
+    }
+
+    pub unsafe fn IsWow64Process(hProcess: HANDLE, Wow64Process: PBOOL) -> BOOL {
+        *Wow64Process = (!hProcess.is_null()) as _;
+        TRUE
+    }
+
+    pub unsafe fn CreateFile(dwShareMode: DWORD) -> HANDLE {
+        match dwShareMode {
+            0 => INVALID_HANDLE_VALUE,
+            share_mode => share_mode as _,
+        }
+    }
+
+    pub unsafe fn OpenThread(
+        dwDesiredAccess: DWORD,
+        bInheritHandle: BOOL,
+        dwThreadId: DWORD,
+    ) -> HANDLE {
+        match dwThreadId {
+            0 => std::ptr::null_mut(),
+            _ => (dwDesiredAccess + bInheritHandle as DWORD + dwThreadId) as usize as _,
+        }
+    }
+}
+
+#[win32(
+    fn = "CreateFile",
+    module = "crate::fake",
+    fail = "INVALID_HANDLE_VALUE",
+    output = "Handle"
+)]
+pub struct CreateFile {
+    #[win32(name = "dwShareMode", arg = "self.share_mode.unwrap_or_default()")]
+    share_mode: Option<u32>,
+}
+
+mod shared {
+    pub mod minwindef {
+        pub type BOOL = i32;
+        pub type DWORD = u32;
+        pub type LPDWORD = *mut DWORD;
+        pub type PBOOL = *mut BOOL;
+
+        pub const FALSE: BOOL = 0;
+        pub const TRUE: BOOL = 1;
+    }
+}
+
+mod um {
+    pub mod handleapi {
+        use super::winnt::HANDLE;
+
+        pub const INVALID_HANDLE_VALUE: HANDLE = -1isize as HANDLE;
+    }
+
+    pub mod winnt {
+        pub type HANDLE = *mut std::ffi::c_void;
+    }
+}
+
+mod error {
+    macro_rules! ensure {
+        ($condition:expr, $error:expr) => {
+            if !$condition {
+                return Err($error.into());
+            }
+        };
+    }
+
+    pub(crate) use ensure;
+}
+
+mod r#macro {
+    pub use rustapi_macro::{win32, FnOnce};
+}
+
+mod utils {
+    use crate::um::winnt::HANDLE;
+
+    pub trait Call {
+        type Output;
+
+        fn call(self) -> Self::Output;
+    }
+
+    pub trait AsStrictRawHandle {
+        fn as_strict_raw_handle(&self) -> HANDLE;
+    }
+
+    pub trait FromStrictRawHandle {
+        unsafe fn from_strict_raw_handle(handle: HANDLE) -> Self;
+    }
+
+    #[derive(Debug, PartialEq)]
+    pub struct Handle(pub HANDLE);
+
+    impl AsStrictRawHandle for Handle {
+        fn as_strict_raw_handle(&self) -> HANDLE {
+            self.0
+        }
+    }
+
+    impl FromStrictRawHandle for Handle {
+        unsafe fn from_strict_raw_handle(handle: HANDLE) -> Self {
+            Handle(handle)
+        }
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Error(u32);
+
+impl Error {
+    fn last_os_error() -> Self {
+        Error(6)
+    }
+}
+
+pub type Result<T, E = Error> = std::result::Result<T, E>;
@@ -0,0 +1,139 @@
+//! Win32 values.
+//!
+//! The error codes, the memory states, types and protections and the service
+//! states and accepted controls the backends use, as `winapi` only has them on
+//! Windows.
+
+pub const ERROR_ACCESS_DENIED: u32 = 5;
+pub const ERROR_INVALID_HANDLE: u32 = 6;
+pub const ERROR_NO_MORE_FILES: u32 = 18;
+pub const ERROR_INVALID_PARAMETER: u32 = 87;
+pub const ERROR_PARTIAL_COPY: u32 = 299;
+pub const ERROR_INVALID_ADDRESS: u32 = 487;
+pub const ERROR_NOACCESS: u32 = 998;
+
+pub const MEM_COMMIT: u32 = 0x1000;
+pub const MEM_RESERVE: u32 = 0x2000;
+pub const MEM_FREE: u32 = 0x10000;
+pub const MEM_PRIVATE: u32 = 0x20000;
+pub const MEM_MAPPED: u32 = 0x40000;
+pub const MEM_IMAGE: u32 = 0x1000000;
+
+pub const PAGE_NOACCESS: u32 = 0x01;
+pub const PAGE_READONLY: u32 = 0x02;
+pub const PAGE_READWRITE: u32 = 0x04;
+pub const PAGE_WRITECOPY: u32 = 0x08;
+pub const PAGE_EXECUTE: u32 = 0x10;
+pub const PAGE_EXECUTE_READ: u32 = 0x20;
+pub const PAGE_EXECUTE_READWRITE: u32 = 0x40;
+pub const PAGE_EXECUTE_WRITECOPY: u32 = 0x80;
+pub const PAGE_GUARD: u32 = 0x100;
+
+pub const SERVICE_STOPPED: u32 = 1;
+pub const SERVICE_START_PENDING: u32 = 2;
+pub const SERVICE_STOP_PENDING: u32 = 3;
+pub const SERVICE_RUNNING: u32 = 4;
+pub const SERVICE_CONTINUE_PENDING: u32 = 5;
+pub const SERVICE_PAUSE_PENDING: u32 = 6;
+pub const SERVICE_PAUSED: u32 = 7;
+
+pub const SERVICE_ACCEPT_STOP: u32 = 0x001;
+pub const SERVICE_ACCEPT_PAUSE_CONTINUE: u32 = 0x002;
+pub const SERVICE_ACCEPT_SHUTDOWN: u32 = 0x004;
+pub const SERVICE_ACCEPT_PARAMCHANGE: u32 = 0x008;
+pub const SERVICE_ACCEPT_NETBINDCHANGE: u32 = 0x010;
+pub const SERVICE_ACCEPT_HARDWAREPROFILECHANGE: u32 = 0x020;
+pub const SERVICE_ACCEPT_POWEREVENT: u32 = 0x040;
+pub const SERVICE_ACCEPT_SESSIONCHANGE: u32 = 0x080;
+pub const SERVICE_ACCEPT_PRESHUTDOWN: u32 = 0x100;
+pub const SERVICE_ACCEPT_TIMECHANGE: u32 = 0x200;
+pub const SERVICE_ACCEPT_TRIGGEREVENT: u32 = 0x400;
+pub const SERVICE_ACCEPT_USERMODEREBOOT: u32 = 0x800;
+
+#[cfg(all(test, windows))]
+mod tests {
+    use winapi::{shared::winerror, um::winnt, um::winsvc};
+
+    #[test]
+    fn winapi() {
+        assert_eq!(winerror::ERROR_ACCESS_DENIED, super::ERROR_ACCESS_DENIED);
+        assert_eq!(winerror::ERROR_INVALID_HANDLE, super::ERROR_INVALID_HANDLE);
+        assert_eq!(winerror::ERROR_NO_MORE_FILES, super::ERROR_NO_MORE_FILES);
+        assert_eq!(
+            winerror::ERROR_INVALID_PARAMETER,
+            super::ERROR_INVALID_PARAMETER
+        );
+        assert_eq!(winerror::ERROR_PARTIAL_COPY, super::ERROR_PARTIAL_COPY);
+        assert_eq!(
+            winerror::ERROR_INVALID_ADDRESS,
+            super::ERROR_INVALID_ADDRESS
+        );
+        assert_eq!(winerror::ERROR_NOACCESS, super::ERROR_NOACCESS);
+        assert_eq!(winnt::MEM_COMMIT, super::MEM_COMMIT);
+        assert_eq!(winnt::MEM_RESERVE, super::MEM_RESERVE);
+        assert_eq!(winnt::MEM_FREE, super::MEM_FREE);
+        assert_eq!(winnt::MEM_PRIVATE, super::MEM_PRIVATE);
+        assert_eq!(winnt::MEM_MAPPED, super::MEM_MAPPED);
+        assert_eq!(winnt::MEM_IMAGE, super::MEM_IMAGE);
+        assert_eq!(winnt::PAGE_NOACCESS, super::PAGE_NOACCESS);
+        assert_eq!(winnt::PAGE_READONLY, super::PAGE_READONLY);
+        assert_eq!(winnt::PAGE_READWRITE, super::PAGE_READWRITE);
+        assert_eq!(winnt::PAGE_WRITECOPY, super::PAGE_WRITECOPY);
+        assert_eq!(winnt::PAGE_EXECUTE, super::PAGE_EXECUTE);
+        assert_eq!(winnt::PAGE_EXECUTE_READ, super::PAGE_EXECUTE_READ);
+        assert_eq!(winnt::PAGE_EXECUTE_READWRITE, super::PAGE_EXECUTE_READWRITE);
+        assert_eq!(winnt::PAGE_EXECUTE_WRITECOPY, super::PAGE_EXECUTE_WRITECOPY);
+        assert_eq!(winnt::PAGE_GUARD, super::PAGE_GUARD);
+        assert_eq!(winsvc::SERVICE_STOPPED, super::SERVICE_STOPPED);
+        assert_eq!(winsvc::SERVICE_START_PENDING, super::SERVICE_START_PENDING);
+        assert_eq!(winsvc::SERVICE_STOP_PENDING, super::SERVICE_STOP_PENDING);
+        assert_eq!(winsvc::SERVICE_RUNNING, super::SERVICE_RUNNING);
+        assert_eq!(
+            winsvc::SERVICE_CONTINUE_PENDING,
+            super::SERVICE_CONTINUE_PENDING
+        );
+        assert_eq!(winsvc::SERVICE_PAUSE_PENDING, super::SERVICE_PAUSE_PENDING);
+        assert_eq!(winsvc::SERVICE_PAUSED, super::SERVICE_PAUSED);
+        assert_eq!(winsvc::SERVICE_ACCEPT_STOP, super::SERVICE_ACCEPT_STOP);
+        assert_eq!(
+            winsvc::SERVICE_ACCEPT_PAUSE_CONTINUE,
+            super::SERVICE_ACCEPT_PAUSE_CONTINUE
+        );
+        assert_eq!(
+            winsvc::SERVICE_ACCEPT_SHUTDOWN,
+            super::SERVICE_ACCEPT_SHUTDOWN
+        );
+        assert_eq!(
+            winsvc::SERVICE_ACCEPT_PARAMCHANGE,
+            super::SERVICE_ACCEPT_PARAMCHANGE
+        );
+        assert_eq!(
+            winsvc::SERVICE_ACCEPT_NETBINDCHANGE,
+            super::SERVICE_ACCEPT_NETBINDCHANGE
+        );
+        assert_eq!(
+            winsvc::SERVICE_ACCEPT_HARDWAREPROFILECHANGE,
+            super::SERVICE_ACCEPT_HARDWAREPROFILECHANGE
+        );
+        assert_eq!(
+            winsvc::SERVICE_ACCEPT_POWEREVENT,
+            super::SERVICE_ACCEPT_POWEREVENT
+        );
+        assert_eq!(
+            winsvc::SERVICE_ACCEPT_SESSIONCHANGE,
+            super::SERVICE_ACCEPT_SESSIONCHANGE
+        );
+        assert_eq!(
+            winsvc::SERVICE_ACCEPT_PRESHUTDOWN,
+            super::SERVICE_ACCEPT_PRESHUTDOWN
+        );
+        assert_eq!(
+            winsvc::SERVICE_ACCEPT_TIMECHANGE,
+            super::SERVICE_ACCEPT_TIMECHANGE
+        );
+        assert_eq!(
+            winsvc::SERVICE_ACCEPT_TRIGGEREVENT,
+            super::SERVICE_ACCEPT_TRIGGEREVENT
+        );
+    }
+}
@@ -0,0 +1,664 @@
+//! Fake backend.
+//!
+//! An in-memory system scripted with processes, their threads, modules and
+//! memory regions, and with services that record the statuses they are set.
+
+use super::{
+    consts::{
+        ERROR_INVALID_ADDRESS, ERROR_INVALID_HANDLE, ERROR_INVALID_PARAMETER, ERROR_NOACCESS,
+        ERROR_PARTIAL_COPY, MEM_COMMIT, MEM_FREE, MEM_PRIVATE, MEM_RESERVE, PAGE_EXECUTE_READ,
+        PAGE_EXECUTE_READWRITE, PAGE_EXECUTE_WRITECOPY, PAGE_GUARD, PAGE_READONLY, PAGE_READWRITE,
+        PAGE_WRITECOPY,
+    },
+    MemoryApi, MemoryRegion, ModuleEntry, ProcessApi, ProcessEntry, ServiceApi, ServiceStatus,
+    SnapshotApi, ThreadEntry,
+};
+use crate::{error::ensure, Error, Result};
+use std::{
+    path::PathBuf,
+    sync::{Mutex, MutexGuard},
+};
+
+const ALLOCATION_GRANULARITY: usize = 0x10000;
+const PAGE_SIZE: usize = 0x1000;
+
+const READABLE: u32 = PAGE_READONLY
+    | PAGE_READWRITE
+    | PAGE_WRITECOPY
+    | PAGE_EXECUTE_READ
+    | PAGE_EXECUTE_READWRITE
+    | PAGE_EXECUTE_WRITECOPY;
+const WRITABLE: u32 =
+    PAGE_READWRITE | PAGE_WRITECOPY | PAGE_EXECUTE_READWRITE | PAGE_EXECUTE_WRITECOPY;
+
+/// Fake backend.
+///
+/// Processes are opened by id, the `Process` of the fake is the process id,
+/// the `Service` is the index of the service. Past the last region of a
+/// process the memory queries fail, as past the maximum application address.
+#[derive(Debug, Default)]
+pub struct Fake {
+    system: Mutex<System>,
+}
+
+impl Fake {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds the `process`.
+    pub fn process(self, process: Process) -> Self {
+        self.insert_process(process);
+        self
+    }
+
+    /// Adds the `process`, replaces the one with the same id.
+    pub fn insert_process(&self, process: Process) {
+        let mut system = self.system.lock().unwrap();
+        system.processes.retain(|other| other.id != process.id);
+        system.processes.push(process);
+    }
+
+    /// Removes the process of the `id`, returns whether it existed.
+    pub fn remove_process(&self, id: u32) -> bool {
+        let mut system = self.system.lock().unwrap();
+        let len = system.processes.len();
+        system.processes.retain(|process| process.id != id);
+        system.processes.len() != len
+    }
+
+    /// Adds a service, returns its index.
+    pub fn service(&self) -> usize {
+        let mut system = self.system.lock().unwrap();
+        system.services.push(Vec::new());
+        system.services.len() - 1
+    }
+
+    /// The statuses set for the `service`, from the first one.
+    pub fn statuses(&self, service: usize) -> Vec<ServiceStatus> {
+        let system = self.system.lock().unwrap();
+        system.services.get(service).cloned().unwrap_or_default()
+    }
+
+    /// The state of the last status set for the `service`.
+    pub fn current_state(&self, service: usize) -> Option<u32> {
+        let system = self.system.lock().unwrap();
+        system
+            .services
+            .get(service)?
+            .last()
+            .map(|status| status.current_state)
+    }
+
+    /// Fails the next call with the `error`.
+    pub fn fail_next(&self, error: Error) {
+        self.system.lock().unwrap().failure = Some(error);
+    }
+
+    /// Locks the system, unless the next call has to fail.
+    fn system(&self) -> Result<MutexGuard<'_, System>> {
+        let mut system = self.system.lock().unwrap();
+        match system.failure.take() {
+            Some(error) => Err(error),
+            None => Ok(system),
+        }
+    }
+}
+
+impl ProcessApi for Fake {
+    type Process = u32;
+
+    fn open_process(&self, id: u32, _: u32) -> Result<Self::Process> {
+        let system = self.system()?;
+        system.process(id)?;
+        Ok(id)
+    }
+
+    fn process_id(&self, process: &Self::Process) -> Result<u32> {
+        let system = self.system()?;
+        Ok(system.process(*process)?.id)
+    }
+}
+
+impl MemoryApi for Fake {
+    fn query(&self, process: &Self::Process, address: usize) -> Result<MemoryRegion> {
+        let system = self.system()?;
+        let regions = &system.process(*process)?.regions;
+        let index = regions.iter().position(|region| address < region.end());
+        let region = match index.map(|index| &regions[index]) {
+            Some(region) if region.base_address <= address => region,
+            Some(next) => {
+                return Ok(MemoryRegion {
+                    base_address: address,
+                    size: next.base_address - address,
+                    state: MEM_FREE,
+                    ..MemoryRegion::default()
+                })
+            }
+            None => return Err(Error::Win32(ERROR_INVALID_PARAMETER)),
+        };
+        let protect = match region.state {
+            MEM_COMMIT => region.protect,
+            _ => 0,
+        };
+        Ok(MemoryRegion {
+            base_address: address,
+            allocation_base_address: region.base_address,
+            allocation_protect: region.protect,
+            size: region.end() - address,
+            state: region.state,
+            protect,
+            r#type: region.r#type,
+        })
+    }
+
+    fn read(&self, process: &Self::Process, address: usize, buffer: &mut [u8]) -> Result<usize> {
+        let system = self.system()?;
+        let region = system
+            .process(*process)?
+            .find_region(address, buffer.len())
+            .filter(|region| region.is_readable())
+            .ok_or(Error::Win32(ERROR_PARTIAL_COPY))?;
+        let offset = address - region.base_address;
+        buffer.copy_from_slice(&region.data[offset..offset + buffer.len()]);
+        Ok(buffer.len())
+    }
+
+    fn write(&self, process: &Self::Process, address: usize, buffer: &[u8]) -> Result<usize> {
+        let mut system = self.system()?;
+        let region = system
+            .process_mut(*process)?
+            .find_region_mut(address, buffer.len())
+            .ok_or(Error::Win32(ERROR_PARTIAL_COPY))?;
+        if !region.is_writable() {
+            return Err(Error::Win32(ERROR_NOACCESS));
+        }
+        let offset = address - region.base_address;
+        region.data[offset..offset + buffer.len()].copy_from_slice(buffer);
+        Ok(buffer.len())
+    }
+
+    fn allocate(
+        &self,
+        process: &Self::Process,
+        address: usize,
+        size: usize,
+        allocation_type: u32,
+        protect: u32,
+    ) -> Result<usize> {
+        ensure!(size != 0, Error::Win32(ERROR_INVALID_PARAMETER));
+        let mut system = self.system()?;
+        let regions = &mut system.process_mut(*process)?.regions;
+        let base_address = match address {
+            0 => {
+                let end = regions.last().map_or(0, Region::end);
+                align_up(end.max(ALLOCATION_GRANULARITY), ALLOCATION_GRANULARITY)
+            }
+            _ => Some(address - address % ALLOCATION_GRANULARITY),
+        };
+        // The region covers the pages from the rounded down base address to
+        // the end of the requested range.
+        let end = base_address
+            .and_then(|base_address| address.max(base_address).checked_add(size))
+            .and_then(|end| align_up(end, PAGE_SIZE));
+        let (base_address, end) = match (base_address, end) {
+            (Some(base_address), Some(end)) => (base_address, end),
+            _ => return Err(Error::Win32(ERROR_INVALID_ADDRESS)),
+        };
+        let overlaps = regions
+            .iter()
+            .any(|region| region.base_address < end && base_address < region.end());
+        ensure!(!overlaps, Error::Win32(ERROR_INVALID_ADDRESS));
+        let size = end - base_address;
+        let mut region = Region::new(base_address, vec![0; size]).protect(protect);
+        if allocation_type & MEM_COMMIT == 0 {
+            region.state = MEM_RESERVE;
+        }
+        regions.push(region);
+        regions.sort_by_key(|region| region.base_address);
+        Ok(base_address)
+    }
+}
+
+impl SnapshotApi for Fake {
+    fn processes(&self) -> Result<Vec<ProcessEntry>> {
+        let system = self.system()?;
+        Ok(system.processes.iter().map(Process::entry).collect())
+    }
+
+    fn modules(&self, process_id: u32) -> Result<Vec<ModuleEntry>> {
+        let system = self.system()?;
+        let process = system.process(process_id)?;
+        Ok(process
+            .modules
+            .iter()
+            .map(|module| module.entry(process_id))
+            .collect())
+    }
+
+    fn threads(&self, process_id: u32) -> Result<Vec<ThreadEntry>> {
+        let system = self.system()?;
+        Ok(system
+            .processes
+            .iter()
+            .filter(|process| process.id == process_id)
+            .flat_map(|process| {
+                process
+                    .threads
+                    .iter()
+                    .map(move |&id| process.thread_entry(id))
+            })
+            .collect())
+    }
+}
+
+impl ServiceApi for Fake {
+    type Service = usize;
+
+    fn set_service_status(&self, service: &Self::Service, status: ServiceStatus) -> Result<()> {
+        let mut system = self.system()?;
+        let statuses = system
+            .services
+            .get_mut(*service)
+            .ok_or(Error::Win32(ERROR_INVALID_HANDLE))?;
+        statuses.push(status);
+        Ok(())
+    }
+}
+
+/// Fake process.
+#[derive(Clone, Debug)]
+pub struct Process {
+    id: u32,
+    parent_id: u32,
+    name: String,
+    base_priority: i32,
+    threads: Vec<u32>,
+    modules: Vec<Module>,
+    regions: Vec<Region>,
+}
+
+impl Process {
+    pub fn new(id: u32, name: &str) -> Self {
+        Self {
+            id,
+            parent_id: 0,
+            name: name.to_owned(),
+            base_priority: 8,
+            threads: Vec::new(),
+            modules: Vec::new(),
+            regions: Vec::new(),
+        }
+    }
+
+    pub fn parent(mut self, parent_id: u32) -> Self {
+        self.parent_id = parent_id;
+        self
+    }
+
+    pub fn base_priority(mut self, base_priority: i32) -> Self {
+        self.base_priority = base_priority;
+        self
+    }
+
+    pub fn thread(mut self, id: u32) -> Self {
+        self.threads.push(id);
+        self
+    }
+
+    pub fn module(mut self, module: Module) -> Self {
+        self.modules.push(module);
+        self
+    }
+
+    /// Adds the `region`, the regions must not overlap.
+    pub fn region(mut self, region: Region) -> Self {
+        self.regions.push(region);
+        self.regions.sort_by_key(|region| region.base_address);
+        self
+    }
+
+    fn entry(&self) -> ProcessEntry {
+        ProcessEntry {
+            process_id: self.id,
+            parent_process_id: self.parent_id,
+            threads_count: self.threads.len() as _,
+            base_priority: self.base_priority,
+            name: self.name.clone(),
+        }
+    }
+
+    fn thread_entry(&self, id: u32) -> ThreadEntry {
+        ThreadEntry {
+            thread_id: id,
+            process_id: self.id,
+            base_priority: self.base_priority,
+        }
+    }
+
+    /// The region containing the `size` bytes from the `address`.
+    fn find_region(&self, address: usize, size: usize) -> Option<&Region> {
+        self.regions
+            .iter()
+            .find(|region| region.contains(address, size))
+    }
+
+    fn find_region_mut(&mut self, address: usize, size: usize) -> Option<&mut Region> {
+        self.regions
+            .iter_mut()
+            .find(|region| region.contains(address, size))
+    }
+}
+
+/// Fake module.
+#[derive(Clone, Debug)]
+pub struct Module {
+    name: String,
+    path: PathBuf,
+    base_address: usize,
+    size: u32,
+}
+
+impl Module {
+    /// The module named after the file name of the `path`, also split on `\`
+    /// on the other platforms.
+    pub fn new(path: impl Into<PathBuf>, base_address: usize, size: u32) -> Self {
+        let path = path.into();
+        let name = path
+            .to_string_lossy()
+            .rsplit(&['\\', '/'][..])
+            .next()
+            .unwrap_or_default()
+            .to_owned();
+        Self {
+            name,
+            path,
+            base_address,
+            size,
+        }
+    }
+
+    fn entry(&self, process_id: u32) -> ModuleEntry {
+        ModuleEntry {
+            process_id,
+            base_address: self.base_address,
+            base_size: self.size,
+            name: self.name.clone(),
+            path: self.path.clone(),
+        }
+    }
+}
+
+/// Fake region.
+///
+/// The state, the protect and the type are the Win32 values, see `consts`.
+#[derive(Clone, Debug)]
+pub struct Region {
+    base_address: usize,
+    state: u32,
+    protect: u32,
+    r#type: u32,
+    data: Vec<u8>,
+}
+
+impl Region {
+    /// The committed private read/write region of the `data`.
+    pub fn new(base_address: usize, data: Vec<u8>) -> Self {
+        Self {
+            base_address,
+            state: MEM_COMMIT,
+            protect: PAGE_READWRITE,
+            r#type: MEM_PRIVATE,
+            data,
+        }
+    }
+
+    /// The reserved private region of the `size`.
+    pub fn reserved(base_address: usize, size: usize) -> Self {
+        Self {
+            state: MEM_RESERVE,
+            ..Self::new(base_address, vec![0; size])
+        }
+    }
+
+    pub fn protect(mut self, protect: u32) -> Self {
+        self.protect = protect;
+        self
+    }
+
+    pub fn r#type(mut self, r#type: u32) -> Self {
+        self.r#type = r#type;
+        self
+    }
+
+    #[inline]
+    fn end(&self) -> usize {
+        self.base_address + self.data.len()
+    }
+
+    fn contains(&self, address: usize, size: usize) -> bool {
+        match address.checked_add(size) {
+            Some(end) => self.base_address <= address && end <= self.end(),
+            None => false,
+        }
+    }
+
+    fn is_committed(&self) -> bool {
+        self.state == MEM_COMMIT && self.protect & PAGE_GUARD == 0
+    }
+
+    fn is_readable(&self) -> bool {
+        self.is_committed() && self.protect & READABLE != 0
+    }
+
+    fn is_writable(&self) -> bool {
+        self.is_committed() && self.protect & WRITABLE != 0
+    }
+}
+
+#[derive(Debug, Default)]
+struct System {
+    processes: Vec<Process>,
+    services: Vec<Vec<ServiceStatus>>,
+    failure: Option<Error>,
+}
+
+impl System {
+    fn process(&self, id: u32) -> Result<&Process> {
+        self.processes
+            .iter()
+            .find(|process| process.id == id)
+            .ok_or(Error::Win32(ERROR_INVALID_PARAMETER))
+    }
+
+    fn process_mut(&mut self, id: u32) -> Result<&mut Process> {
+        self.processes
+            .iter_mut()
+            .find(|process| process.id == id)
+            .ok_or(Error::Win32(ERROR_INVALID_PARAMETER))
+    }
+}
+
+/// Rounds the `value` up to the `alignment`, `None` on overflow.
+fn align_up(value: usize, alignment: usize) -> Option<usize> {
+    let value = value.checked_add(alignment - 1)?;
+    Some(value - value % alignment)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Fake, Module, Process, Region};
+    use crate::{
+        backend::{
+            consts::{
+                ERROR_ACCESS_DENIED, ERROR_INVALID_ADDRESS, ERROR_NOACCESS, ERROR_PARTIAL_COPY,
+                MEM_COMMIT, MEM_FREE, MEM_RESERVE, PAGE_READONLY, PAGE_READWRITE, SERVICE_RUNNING,
+            },
+            MemoryApi, ProcessApi, ServiceApi, ServiceStatus, SnapshotApi,
+        },
+        Error,
+    };
+    use std::path::PathBuf;
+
+    fn fake() -> Fake {
+        Fake::new().process(
+            Process::new(4, "a.exe")
+                .parent(1)
+                .thread(8)
+                .thread(12)
+                .module(Module::new(r"C:\a\a.exe", 0x40_0000, 0x2000))
+                .region(Region::new(0x1_0000, b"abcd".to_vec()))
+                .region(Region::reserved(0x2_0000, 0x1000))
+                .region(Region::new(0x3_0000, vec![0; 4]).protect(PAGE_READONLY)),
+        )
+    }
+
+    #[test]
+    fn snapshot() {
+        let fake = fake();
+        let processes = fake.processes().unwrap();
+        assert_eq!(1, processes.len());
+        assert_eq!(4, processes[0].process_id);
+        assert_eq!(1, processes[0].parent_process_id);
+        assert_eq!(2, processes[0].threads_count);
+        assert_eq!("a.exe", processes[0].name);
+        let modules = fake.modules(4).unwrap();
+        assert_eq!("a.exe", modules[0].name);
+        assert_eq!(PathBuf::from(r"C:\a\a.exe"), modules[0].path);
+        assert_eq!(0x40_0000, modules[0].base_address);
+        let threads = fake.threads(4).unwrap();
+        assert_eq!(
+            vec![8, 12],
+            threads
+                .iter()
+                .map(|thread| thread.thread_id)
+                .collect::<Vec<_>>()
+        );
+        assert!(fake.threads(5).unwrap().is_empty());
+        assert!(fake.modules(5).is_err());
+    }
+
+    #[test]
+    fn memory() {
+        let fake = fake();
+        let process = fake.open_process(4, 0).unwrap();
+        let mut buffer = [0; 2];
+        assert_eq!(Ok(2), fake.read(&process, 0x1_0001, &mut buffer));
+        assert_eq!(b"bc", &buffer);
+        assert_eq!(Ok(2), fake.write(&process, 0x1_0002, b"yz"));
+        let mut buffer = [0; 4];
+        fake.read(&process, 0x1_0000, &mut buffer).unwrap();
+        assert_eq!(b"abyz", &buffer);
+        assert_eq!(
+            Err(Error::Win32(ERROR_PARTIAL_COPY)),
+            fake.read(&process, 0x1_0002, &mut buffer),
+        );
+        assert_eq!(
+            Err(Error::Win32(ERROR_PARTIAL_COPY)),
+            fake.read(&process, 0x2_0000, &mut buffer),
+        );
+        assert_eq!(
+            Err(Error::Win32(ERROR_NOACCESS)),
+            fake.write(&process, 0x3_0000, b"a"),
+        );
+        assert!(fake.open_process(5, 0).is_err());
+    }
+
+    #[test]
+    fn regions() {
+        let fake = fake();
+        let process = fake.open_process(4, 0).unwrap();
+        let regions = fake.regions(&process);
+        assert_eq!(
+            vec![
+                (0x0_0000, 0x1_0000),
+                (0x1_0000, 0x4),
+                (0x1_0004, 0xFFFC),
+                (0x2_0000, 0x1000),
+                (0x2_1000, 0xF000),
+                (0x3_0000, 0x4),
+            ],
+            regions
+                .iter()
+                .map(|region| (region.base_address, region.size))
+                .collect::<Vec<_>>(),
+        );
+        assert_eq!(MEM_FREE, regions[0].state);
+        assert_eq!(0, regions[3].protect);
+        assert_eq!(PAGE_READONLY, regions[5].protect);
+        let region = fake.query(&process, 0x1_0002).unwrap();
+        assert_eq!(0x2, region.size);
+        assert_eq!(0x1_0000, region.allocation_base_address);
+    }
+
+    #[test]
+    fn allocate() {
+        let fake = fake();
+        let process = fake.open_process(4, 0).unwrap();
+        let address = fake
+            .allocate(&process, 0, 10, MEM_COMMIT | MEM_RESERVE, PAGE_READWRITE)
+            .unwrap();
+        assert_eq!(0x4_0000, address);
+        assert_eq!(Ok(2), fake.write(&process, address + 0xFFE, b"ab"));
+        assert_eq!(
+            Err(Error::Win32(ERROR_PARTIAL_COPY)),
+            fake.write(&process, address + 0xFFF, b"ab"),
+        );
+        let reserved = fake
+            .allocate(&process, 0x8_1234, 0x1000, MEM_RESERVE, PAGE_READONLY)
+            .unwrap();
+        assert_eq!(0x8_0000, reserved);
+        assert_eq!(MEM_RESERVE, fake.query(&process, reserved).unwrap().state);
+        assert!(fake
+            .allocate(&process, 0x1_0000, 0x1000, MEM_COMMIT, PAGE_READONLY)
+            .is_err());
+        let unaligned = fake
+            .allocate(&process, 0x5_F000, 0x2000, MEM_COMMIT, PAGE_READWRITE)
+            .unwrap();
+        assert_eq!(0x5_0000, unaligned);
+        assert_eq!(0x1_1000, fake.query(&process, unaligned).unwrap().size);
+        assert_eq!(Ok(2), fake.write(&process, 0x6_0FFE, b"ab"));
+    }
+
+    #[test]
+    fn overflow() {
+        let fake = fake();
+        let process = fake.open_process(4, 0).unwrap();
+        let address = usize::MAX - 1;
+        let mut buffer = [0; 4];
+        assert_eq!(
+            Err(Error::Win32(ERROR_PARTIAL_COPY)),
+            fake.read(&process, address, &mut buffer),
+        );
+        assert_eq!(
+            Err(Error::Win32(ERROR_PARTIAL_COPY)),
+            fake.write(&process, address, &buffer),
+        );
+        assert_eq!(
+            Err(Error::Win32(ERROR_INVALID_ADDRESS)),
+            fake.allocate(&process, address, 0x1000, MEM_COMMIT, PAGE_READWRITE),
+        );
+        assert!(fake.query(&process, address).is_err());
+    }
+
+    #[test]
+    fn service() {
+        let fake = Fake::new();
+        let service = fake.service();
+        assert_eq!(None, fake.current_state(service));
+        let status = ServiceStatus {
+            current_state: SERVICE_RUNNING,
+            ..ServiceStatus::default()
+        };
+        fake.set_service_status(&service, status).unwrap();
+        assert_eq!(Some(SERVICE_RUNNING), fake.current_state(service));
+        assert_eq!(1, fake.statuses(service).len());
+        fake.fail_next(Error::Win32(ERROR_ACCESS_DENIED));
+        assert_eq!(
+            Err(Error::Win32(ERROR_ACCESS_DENIED)),
+            fake.set_service_status(&service, status),
+        );
+        assert_eq!(1, fake.statuses(service).len());
+        assert!(fake.set_service_status(&1, status).is_err());
+    }
+}
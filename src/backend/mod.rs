@@ -0,0 +1,197 @@
+//! Backends.
+//!
+//! The process, memory, toolhelp and service wrappers behind traits, so that
+//! the code built on them runs either against the system with `Win32` or
+//! against a scripted in-memory `Fake`.
+//!
+//! The traits and their types do not depend on `winapi`, which is empty on the
+//! other platforms, so the `Fake` and the tests built on it run everywhere.
+//! The flags and the states keep their Win32 values, see `consts`.
+
+pub use self::fake::Fake;
+#[cfg(windows)]
+pub use self::win32::Win32;
+
+use crate::Result;
+use std::path::PathBuf;
+
+pub mod consts;
+pub mod fake;
+
+#[cfg(windows)]
+mod win32;
+
+/// Process API.
+pub trait ProcessApi {
+    /// The handle of an opened process.
+    type Process;
+
+    fn open_process(&self, id: u32, desired_access: u32) -> Result<Self::Process>;
+
+    fn process_id(&self, process: &Self::Process) -> Result<u32>;
+}
+
+/// Memory API.
+pub trait MemoryApi: ProcessApi {
+    /// Queries the region from the `address` to the end of its region.
+    fn query(&self, process: &Self::Process, address: usize) -> Result<MemoryRegion>;
+
+    fn read(&self, process: &Self::Process, address: usize, buffer: &mut [u8]) -> Result<usize>;
+
+    fn write(&self, process: &Self::Process, address: usize, buffer: &[u8]) -> Result<usize>;
+
+    /// Allocates the region, returns its base address.
+    fn allocate(
+        &self,
+        process: &Self::Process,
+        address: usize,
+        size: usize,
+        allocation_type: u32,
+        protect: u32,
+    ) -> Result<usize>;
+
+    /// Queries the regions from the zero address until a query fails.
+    fn regions(&self, process: &Self::Process) -> Vec<MemoryRegion> {
+        let mut regions = Vec::new();
+        let mut address = 0;
+        while let Ok(region) = self.query(process, address) {
+            match address.checked_add(region.size) {
+                Some(next) if next > address => address = next,
+                _ => break,
+            }
+            regions.push(region);
+        }
+        regions
+    }
+}
+
+/// Snapshot API.
+pub trait SnapshotApi {
+    fn processes(&self) -> Result<Vec<ProcessEntry>>;
+
+    fn modules(&self, process_id: u32) -> Result<Vec<ModuleEntry>>;
+
+    fn threads(&self, process_id: u32) -> Result<Vec<ThreadEntry>>;
+}
+
+/// Service API.
+pub trait ServiceApi {
+    /// The status handle of a service.
+    type Service;
+
+    fn set_service_status(&self, service: &Self::Service, status: ServiceStatus) -> Result<()>;
+}
+
+/// Memory region, as queried from an address.
+///
+/// The allocation fields and the type are zero in a free region, the protect
+/// is zero in a free or reserved one.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct MemoryRegion {
+    pub base_address: usize,
+    pub allocation_base_address: usize,
+    pub allocation_protect: u32,
+    pub size: usize,
+    pub state: u32,
+    pub protect: u32,
+    pub r#type: u32,
+}
+
+/// Process entry of a snapshot.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct ProcessEntry {
+    pub process_id: u32,
+    pub parent_process_id: u32,
+    pub threads_count: u32,
+    pub base_priority: i32,
+    pub name: String,
+}
+
+/// Module entry of a snapshot.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct ModuleEntry {
+    pub process_id: u32,
+    pub base_address: usize,
+    pub base_size: u32,
+    pub name: String,
+    pub path: PathBuf,
+}
+
+/// Thread entry of a snapshot.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct ThreadEntry {
+    pub thread_id: u32,
+    pub process_id: u32,
+    pub base_priority: i32,
+}
+
+/// Service status.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct ServiceStatus {
+    pub service_type: u32,
+    pub current_state: u32,
+    pub controls_accepted: u32,
+    pub win32_exit_code: u32,
+    pub service_specific_exit_code: u32,
+    pub check_point: u32,
+    pub wait_hint: u32,
+}
+
+impl<T: ProcessApi + ?Sized> ProcessApi for &T {
+    type Process = T::Process;
+
+    fn open_process(&self, id: u32, desired_access: u32) -> Result<Self::Process> {
+        (**self).open_process(id, desired_access)
+    }
+
+    fn process_id(&self, process: &Self::Process) -> Result<u32> {
+        (**self).process_id(process)
+    }
+}
+
+impl<T: MemoryApi + ?Sized> MemoryApi for &T {
+    fn query(&self, process: &Self::Process, address: usize) -> Result<MemoryRegion> {
+        (**self).query(process, address)
+    }
+
+    fn read(&self, process: &Self::Process, address: usize, buffer: &mut [u8]) -> Result<usize> {
+        (**self).read(process, address, buffer)
+    }
+
+    fn write(&self, process: &Self::Process, address: usize, buffer: &[u8]) -> Result<usize> {
+        (**self).write(process, address, buffer)
+    }
+
+    fn allocate(
+        &self,
+        process: &Self::Process,
+        address: usize,
+        size: usize,
+        allocation_type: u32,
+        protect: u32,
+    ) -> Result<usize> {
+        (**self).allocate(process, address, size, allocation_type, protect)
+    }
+}
+
+impl<T: SnapshotApi + ?Sized> SnapshotApi for &T {
+    fn processes(&self) -> Result<Vec<ProcessEntry>> {
+        (**self).processes()
+    }
+
+    fn modules(&self, process_id: u32) -> Result<Vec<ModuleEntry>> {
+        (**self).modules(process_id)
+    }
+
+    fn threads(&self, process_id: u32) -> Result<Vec<ThreadEntry>> {
+        (**self).threads(process_id)
+    }
+}
+
+impl<T: ServiceApi + ?Sized> ServiceApi for &T {
+    type Service = T::Service;
+
+    fn set_service_status(&self, service: &Self::Service, status: ServiceStatus) -> Result<()> {
+        (**self).set_service_status(service, status)
+    }
+}
@@ -0,0 +1,217 @@
+use super::{
+    MemoryApi, MemoryRegion, ModuleEntry, ProcessApi, ProcessEntry, ServiceApi, ServiceStatus,
+    SnapshotApi, ThreadEntry,
+};
+use crate::{
+    shared::ntdef::OwnedHandle,
+    um::{
+        memoryapi::{read_process_memory, virtual_alloc, virtual_query, write_process_memory},
+        processthreadsapi::{get_process_id, open_process},
+        tlhelp32::{
+            self, create_toolhelp32_snapshot, module_first, module_next, process_first,
+            process_next, thread_first, thread_next,
+        },
+        winnt::MemoryBasicInformation,
+        winsvc::{set_service_status, ServiceStatusHandle, Status},
+    },
+    Error, Result,
+};
+use winapi::{
+    shared::winerror::ERROR_NO_MORE_FILES,
+    um::{
+        tlhelp32::{TH32CS_SNAPMODULE, TH32CS_SNAPMODULE32, TH32CS_SNAPPROCESS, TH32CS_SNAPTHREAD},
+        winsvc::SERVICE_STATUS,
+    },
+};
+
+/// Win32 backend.
+///
+/// Calls the system through the `um` wrappers.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Win32;
+
+impl ProcessApi for Win32 {
+    type Process = OwnedHandle;
+
+    fn open_process(&self, id: u32, desired_access: u32) -> Result<Self::Process> {
+        open_process().desired_access(desired_access).id(id).call()
+    }
+
+    fn process_id(&self, process: &Self::Process) -> Result<u32> {
+        get_process_id().process(process).call()
+    }
+}
+
+impl MemoryApi for Win32 {
+    fn query(&self, process: &Self::Process, address: usize) -> Result<MemoryRegion> {
+        let information = virtual_query().process(process).address(address).call()?;
+        Ok(information.into())
+    }
+
+    fn read(&self, process: &Self::Process, address: usize, buffer: &mut [u8]) -> Result<usize> {
+        read_process_memory()
+            .process(process)
+            .base_address(&address)
+            .buffer(buffer)
+            .call()
+    }
+
+    fn write(&self, process: &Self::Process, address: usize, buffer: &[u8]) -> Result<usize> {
+        write_process_memory()
+            .process(process)
+            .base_address(address)
+            .buffer(buffer)
+            .call()
+    }
+
+    fn allocate(
+        &self,
+        process: &Self::Process,
+        address: usize,
+        size: usize,
+        allocation_type: u32,
+        protect: u32,
+    ) -> Result<usize> {
+        let address = virtual_alloc()
+            .process(process)
+            .address(address)
+            .size(size)
+            .allocation_type(allocation_type)
+            .protect(protect)
+            .call()?;
+        Ok(address.as_ptr() as _)
+    }
+}
+
+impl SnapshotApi for Win32 {
+    fn processes(&self) -> Result<Vec<ProcessEntry>> {
+        let snapshot = create_toolhelp32_snapshot()
+            .flags(TH32CS_SNAPPROCESS)
+            .call()?;
+        entries(process_first().snapshot(&snapshot).call(), || {
+            process_next().snapshot(&snapshot).call()
+        })
+    }
+
+    fn modules(&self, process_id: u32) -> Result<Vec<ModuleEntry>> {
+        let snapshot = create_toolhelp32_snapshot()
+            .flags(TH32CS_SNAPMODULE | TH32CS_SNAPMODULE32)
+            .process_id(process_id)
+            .call()?;
+        entries(module_first().snapshot(&snapshot).call(), || {
+            module_next().snapshot(&snapshot).call()
+        })
+    }
+
+    fn threads(&self, process_id: u32) -> Result<Vec<ThreadEntry>> {
+        let snapshot = create_toolhelp32_snapshot()
+            .flags(TH32CS_SNAPTHREAD)
+            .call()?;
+        let mut threads = entries(thread_first().snapshot(&snapshot).call(), || {
+            thread_next().snapshot(&snapshot).call()
+        })?;
+        threads.retain(|thread: &ThreadEntry| thread.process_id == process_id);
+        Ok(threads)
+    }
+}
+
+impl ServiceApi for Win32 {
+    type Service = ServiceStatusHandle;
+
+    fn set_service_status(&self, service: &Self::Service, status: ServiceStatus) -> Result<()> {
+        set_service_status()
+            .handle(*service)
+            .status(status.into())
+            .call()
+    }
+}
+
+impl From<MemoryBasicInformation> for MemoryRegion {
+    fn from(from: MemoryBasicInformation) -> Self {
+        Self {
+            base_address: from.base_address(),
+            allocation_base_address: from.allocation_base_address().unwrap_or_default(),
+            allocation_protect: from.allocation_protect().map_or(0, Into::into),
+            size: from.size(),
+            state: from.state().into(),
+            protect: from.protect().map_or(0, Into::into),
+            r#type: from.r#type().map_or(0, Into::into),
+        }
+    }
+}
+
+impl From<tlhelp32::ProcessEntry> for ProcessEntry {
+    fn from(from: tlhelp32::ProcessEntry) -> Self {
+        Self {
+            process_id: from.process_id(),
+            parent_process_id: from.parent_process_id(),
+            threads_count: from.threads_count(),
+            base_priority: from.base_priority(),
+            name: from.name(),
+        }
+    }
+}
+
+impl From<tlhelp32::ModuleEntry> for ModuleEntry {
+    fn from(from: tlhelp32::ModuleEntry) -> Self {
+        Self {
+            process_id: from.process_id(),
+            base_address: from.base_address(),
+            base_size: from.base_size(),
+            name: from.name(),
+            path: from.path(),
+        }
+    }
+}
+
+impl From<tlhelp32::ThreadEntry> for ThreadEntry {
+    fn from(from: tlhelp32::ThreadEntry) -> Self {
+        Self {
+            thread_id: from.thread_id(),
+            process_id: from.process_id(),
+            base_priority: from.base_pri(),
+        }
+    }
+}
+
+impl From<Status> for ServiceStatus {
+    fn from(from: Status) -> Self {
+        Self {
+            service_type: from.dwServiceType,
+            current_state: from.dwCurrentState,
+            controls_accepted: from.dwControlsAccepted,
+            win32_exit_code: from.dwWin32ExitCode,
+            service_specific_exit_code: from.dwServiceSpecificExitCode,
+            check_point: from.dwCheckPoint,
+            wait_hint: from.dwWaitHint,
+        }
+    }
+}
+
+impl From<ServiceStatus> for Status {
+    fn from(from: ServiceStatus) -> Self {
+        Self::from(SERVICE_STATUS {
+            dwServiceType: from.service_type,
+            dwCurrentState: from.current_state,
+            dwControlsAccepted: from.controls_accepted,
+            dwWin32ExitCode: from.win32_exit_code,
+            dwServiceSpecificExitCode: from.service_specific_exit_code,
+            dwCheckPoint: from.check_point,
+            dwWaitHint: from.wait_hint,
+        })
+    }
+}
+
+/// Collects the entries of a snapshot until there are no more of them.
+fn entries<T, U: From<T>>(first: Result<T>, mut next: impl FnMut() -> Result<T>) -> Result<Vec<U>> {
+    let mut entries = Vec::new();
+    let mut entry = first;
+    loop {
+        match entry {
+            Ok(value) => entries.push(value.into()),
+            Err(Error::Win32(ERROR_NO_MORE_FILES)) => return Ok(entries),
+            Err(error) => return Err(error),
+        }
+        entry = next();
+    }
+}
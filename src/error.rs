@@ -0,0 +1,295 @@
+#[cfg(windows)]
+use crate::shared::{
+    ntstatus::NtStatus,
+    winerror::{win32_message, win32_name, HResult},
+};
+use crate::wrap::service::TransitionError;
+use std::{
+    error,
+    ffi::NulError,
+    fmt::{self, Display, Formatter},
+    io,
+    str::Utf8Error,
+    string::FromUtf16Error,
+};
+use widestring::MissingNulError;
+
+/// Result.
+pub type Result<T, E = Error> = std::result::Result<T, E>;
+
+/// Error.
+///
+/// The HRESULT and NTSTATUS variants are only on Windows, the other platforms
+/// build the `backend` and the logic of `wrap` alone. Matches the Win32 codes against the `winapi::shared::winerror` constants:
+///
+/// ```ignore
+/// match error {
+///     Error::Win32(ERROR_ACCESS_DENIED) => elevate(),
+///     Error::HResult(HResult::DXGI_ERROR_DEVICE_REMOVED) => recreate(),
+///     _ => return Err(error),
+/// }
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Error {
+    /// A Win32 error code, as returned by `GetLastError`.
+    Win32(u32),
+    #[cfg(windows)]
+    HResult(HResult),
+    #[cfg(windows)]
+    NtStatus(NtStatus),
+    /// A shader that fails to compile, with the messages of the compiler.
+    #[cfg(windows)]
+    Compile {
+        hresult: HResult,
+        message: String,
+    },
+    /// A service status that can't follow the reported one.
+    Transition(TransitionError),
+    /// A string that can not cross the API boundary.
+    InvalidString(String),
+    BufferTooSmall {
+        needed: usize,
+    },
+    /// A value that is not known to the wrapper.
+    UnexpectedValue {
+        name: &'static str,
+        value: i64,
+    },
+    /// A name that is not known to the wrapper.
+    UnknownName {
+        name: &'static str,
+        value: String,
+    },
+    /// A value that the wrapper does not accept.
+    InvalidValue {
+        name: &'static str,
+        value: String,
+    },
+}
+
+impl Error {
+    /// The error of the calling thread.
+    pub fn last_os_error() -> Self {
+        let code = io::Error::last_os_error()
+            .raw_os_error()
+            .unwrap_or_default();
+        Self::Win32(code as _)
+    }
+
+    pub fn unexpected_value(name: &'static str, value: impl Into<i64>) -> Self {
+        Self::UnexpectedValue {
+            name,
+            value: value.into(),
+        }
+    }
+
+    pub fn unknown_name(name: &'static str, value: impl Into<String>) -> Self {
+        Self::UnknownName {
+            name,
+            value: value.into(),
+        }
+    }
+
+    pub fn invalid_value(name: &'static str, value: impl Into<String>) -> Self {
+        Self::InvalidValue {
+            name,
+            value: value.into(),
+        }
+    }
+
+    /// The Win32 error code, including the one of a `HRESULT_FROM_WIN32`
+    /// result and the one a NTSTATUS maps to.
+    pub fn win32_code(&self) -> Option<u32> {
+        match *self {
+            Self::Win32(code) => Some(code),
+            #[cfg(windows)]
+            Self::HResult(result) => result.win32_code(),
+            #[cfg(windows)]
+            Self::Compile { hresult, .. } => hresult.win32_code(),
+            #[cfg(windows)]
+            Self::NtStatus(status) => status.to_win32(),
+            _ => None,
+        }
+    }
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            #[cfg(not(windows))]
+            Self::Win32(code) => write!(f, "Win32 error {}", code),
+            #[cfg(windows)]
+            Self::Win32(code) => {
+                match win32_message(*code) {
+                    Some(message) => write!(f, "{}", message)?,
+                    None => write!(f, "{}", io::Error::from_raw_os_error(*code as _))?,
+                }
+                match win32_name(*code) {
+                    Some(name) => write!(f, " ({}, os error {})", name, code),
+                    None => Ok(()),
+                }
+            }
+            #[cfg(windows)]
+            Self::HResult(result) => write!(f, "{}", result),
+            #[cfg(windows)]
+            Self::NtStatus(status) => write!(f, "{}", status),
+            #[cfg(windows)]
+            Self::Compile { hresult, message } => write!(f, "{}\n{}", hresult, message),
+            Self::Transition(error) => write!(f, "{}", error),
+            Self::InvalidString(message) => write!(f, "The string is invalid: {}.", message),
+            Self::BufferTooSmall { needed } => {
+                write!(
+                    f,
+                    "The buffer is too small, {} elements are needed.",
+                    needed
+                )
+            }
+            Self::UnexpectedValue { name, value } => {
+                write!(f, "The {} {} is unknown.", name, value)
+            }
+            Self::UnknownName { name, value } => {
+                write!(f, "The {} {:?} is unknown.", name, value)
+            }
+            Self::InvalidValue { name, value } => {
+                write!(f, "The {} {:?} is invalid.", name, value)
+            }
+        }
+    }
+}
+
+impl error::Error for Error {}
+
+#[cfg(windows)]
+impl From<HResult> for Error {
+    fn from(from: HResult) -> Self {
+        Self::HResult(from)
+    }
+}
+
+#[cfg(windows)]
+impl From<NtStatus> for Error {
+    fn from(from: NtStatus) -> Self {
+        Self::NtStatus(from)
+    }
+}
+
+impl From<TransitionError> for Error {
+    fn from(from: TransitionError) -> Self {
+        Self::Transition(from)
+    }
+}
+
+impl From<NulError> for Error {
+    fn from(from: NulError) -> Self {
+        Self::InvalidString(from.to_string())
+    }
+}
+
+impl From<widestring::NulError<u16>> for Error {
+    fn from(from: widestring::NulError<u16>) -> Self {
+        Self::InvalidString(from.to_string())
+    }
+}
+
+impl From<MissingNulError<u16>> for Error {
+    fn from(from: MissingNulError<u16>) -> Self {
+        Self::InvalidString(from.to_string())
+    }
+}
+
+impl From<FromUtf16Error> for Error {
+    fn from(from: FromUtf16Error) -> Self {
+        Self::InvalidString(from.to_string())
+    }
+}
+
+impl From<Utf8Error> for Error {
+    fn from(from: Utf8Error) -> Self {
+        Self::InvalidString(from.to_string())
+    }
+}
+
+impl From<Error> for io::Error {
+    fn from(from: Error) -> Self {
+        #[cfg(windows)]
+        if let Some(code) = from.win32_code() {
+            return io::Error::from_raw_os_error(code as _);
+        }
+        let kind = match from {
+            Error::InvalidString(_) | Error::UnexpectedValue { .. } | Error::UnknownName { .. } => {
+                io::ErrorKind::InvalidData
+            }
+            Error::InvalidValue { .. } => io::ErrorKind::InvalidInput,
+            _ => io::ErrorKind::Other,
+        };
+        io::Error::new(kind, from)
+    }
+}
+
+/// Returns early with an error if a condition is not satisfied.
+macro_rules! ensure {
+    ($condition:expr, $error:expr $(,)?) => {
+        if !$condition {
+            return Err($error.into());
+        }
+    };
+}
+
+pub(crate) use ensure;
+
+#[cfg(test)]
+mod tests {
+    use super::{Error, Result};
+    #[cfg(windows)]
+    use crate::shared::{ntstatus::NtStatus, winerror::HResult};
+    use std::{ffi::CString, io};
+
+    #[cfg(windows)]
+    #[test]
+    fn display() {
+        assert_eq!(
+            "Access is denied. (ERROR_ACCESS_DENIED, os error 5)",
+            Error::Win32(5).to_string(),
+        );
+        assert_eq!(
+            "The buffer is too small, 260 elements are needed.",
+            Error::BufferTooSmall { needed: 260 }.to_string(),
+        );
+        assert_eq!(
+            "The show command 42 is unknown.",
+            Error::unexpected_value("show command", 42).to_string(),
+        );
+        assert_eq!(
+            "The key name \"Hyper\" is unknown.",
+            Error::unknown_name("key name", "Hyper").to_string(),
+        );
+    }
+
+    #[test]
+    fn conversion() {
+        let error = Error::from(CString::new("a\0b").unwrap_err());
+        assert!(matches!(error, Error::InvalidString(_)));
+        assert_eq!(io::ErrorKind::InvalidData, io::Error::from(error).kind());
+    }
+
+    #[cfg(windows)]
+    #[test]
+    fn os_error() {
+        assert_eq!(Some(5), io::Error::from(Error::Win32(5)).raw_os_error());
+        let access_denied = Error::from(HResult::E_ACCESSDENIED);
+        assert_eq!(Some(5), io::Error::from(access_denied).raw_os_error());
+        let invalid_handle = Error::from(NtStatus::STATUS_INVALID_HANDLE);
+        assert_eq!(Some(6), io::Error::from(invalid_handle).raw_os_error());
+    }
+
+    #[test]
+    fn ensure() {
+        fn check(value: u32) -> Result<u32> {
+            ensure!(value != 0, Error::Win32(87));
+            Ok(value)
+        }
+
+        assert_eq!(Ok(1), check(1));
+        assert_eq!(Err(Error::Win32(87)), check(0));
+    }
+}
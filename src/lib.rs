@@ -7,8 +7,18 @@
 //! - Первая строка описания (это не заголовок, а именно строка описания) заканчивается точкой.
 //!
 //! `function_without_any_arguments()`
-//! `function_without_input_arguments()()`
-//! `function_with_input_arguments().arg0()...argn()()`
+//! `function_without_input_arguments().call()`
+//! `function_with_input_arguments().arg0()...argn().call()`
+//!
+//! С feature `nightly` вместо `.call()` можно писать `()`: `function_without_input_arguments()()`.
+//!
+//! Простую обертку можно объявить атрибутом `#[win32]`, который генерирует builder и реализацию
+//! `Call`: `#[win32(fn = "GetExitCodeThread", fail = "FALSE", out = "exit_code")]`.
+//!
+//! Процессы, память, снимки toolhelp и статусы служб доступны через трейты `backend`: `Win32`
+//! вызывает систему, а `Fake` - сценарная система в памяти для тестов. Вне Windows `winapi` пуст:
+//! собираются `backend` с `Fake` и логика `wrap` без FFI (`vt`, `chord`, `key`, `keyboard`,
+//! раскладка `input` и `service::StatusValidator`), FFI закрыта `#[cfg(windows)]`.
 //!
 //! [NTSTATUS to io::Error](https://github.com/rust-lang/rust/pull/41684)
 
@@ -31,32 +41,29 @@
 // Thread
 
 // #![feature(generic_associated_types)]
-#![allow(incomplete_features)]
-#![feature(associated_type_defaults)]
-#![feature(bool_to_option)]
-#![feature(fn_traits)]
-#![feature(maybe_uninit_ref)]
-#![feature(maybe_uninit_slice)]
-#![feature(maybe_uninit_uninit_array)]
-#![feature(min_const_generics)]
-#![feature(specialization)]
-#![feature(split_inclusive)]
-#![feature(trait_alias)]
-#![feature(unboxed_closures)]
+#![cfg_attr(feature = "nightly", feature(fn_traits, unboxed_closures))]
 
+pub use self::error::{Error, Result};
 pub use rustapi_macro as r#macro;
 
+mod error;
+
+pub mod backend;
+#[cfg(windows)]
 pub mod shared;
+#[cfg(windows)]
 pub mod um;
+#[cfg(windows)]
 pub mod utils;
 pub mod wrap;
 
+#[cfg(windows)]
 #[test]
 fn test() {
     use um::libloaderapi::disable_thread_library_calls;
     use winapi::shared::minwindef::HMODULE;
 
     // disable_thread_library_calls().handle(0 as HMODULE).build()().unwrap();
-    let t = disable_thread_library_calls().handle(0 as HMODULE)();
+    let t = disable_thread_library_calls().handle(0 as HMODULE).call();
     println!("t: {:?}", t);
 }
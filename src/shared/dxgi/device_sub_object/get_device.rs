@@ -1,11 +1,7 @@
-use crate::r#macro::FnOnce;
-use anyhow::{ensure, Result};
+use crate::{r#macro::FnOnce, shared::winerror::HResult, utils::Call, Result};
 use std::mem::MaybeUninit;
 use typed_builder::TypedBuilder;
-use winapi::{
-    shared::{dxgi::IDXGIDeviceSubObject, winerror::SUCCEEDED},
-    Interface,
-};
+use winapi::{shared::dxgi::IDXGIDeviceSubObject, Interface};
 use wio::com::ComPtr;
 
 /// Get device.
@@ -16,20 +12,16 @@ pub struct GetDevice<T: Interface> {
     device: MaybeUninit<ComPtr<T>>,
 }
 
-impl<T: Interface> FnOnce<()> for GetDevice<T> {
+impl<T: Interface> Call for GetDevice<T> {
     type Output = Result<ComPtr<T>>;
 
-    extern "rust-call" fn call_once(mut self, _args: ()) -> Self::Output {
+    fn call(mut self) -> Self::Output {
         #[allow(non_snake_case)]
         unsafe {
             let riid = &T::uuidof();
             let ppDevice = self.device.as_mut_ptr() as _;
             let r#return = self.device_sub_object.GetDevice(riid, ppDevice);
-            ensure!(
-                SUCCEEDED(r#return),
-                "The IDXGIDeviceSubObject::GetDevice call FAILED ({}).",
-                r#return,
-            );
+            HResult::from(r#return).ok()?;
             Ok(self.device.assume_init())
         }
     }
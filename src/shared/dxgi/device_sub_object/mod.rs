@@ -1,5 +1,5 @@
 use self::get_device::GetDevice;
-use anyhow::Result;
+use crate::Result;
 use winapi::{
     shared::dxgi::{IDXGIDeviceSubObject, IDXGISwapChain},
     Interface,
@@ -13,7 +13,7 @@ pub trait DeviceSubObject {
 
 impl DeviceSubObject for ComPtr<IDXGIDeviceSubObject> {
     fn device<T: Interface>(&self) -> Result<ComPtr<T>> {
-        GetDevice::builder().device_sub_object(self.clone()).build()()
+        GetDevice::builder().device_sub_object(self.clone()).call()
     }
 }
 
@@ -21,7 +21,7 @@ impl DeviceSubObject for ComPtr<IDXGISwapChain> {
     fn device<T: Interface>(&self) -> Result<ComPtr<T>> {
         GetDevice::builder()
             .device_sub_object(self.cast().unwrap())
-            .build()()
+            .call()
     }
 }
 
@@ -1,11 +1,7 @@
-use crate::r#macro::FnOnce;
-use anyhow::{ensure, Result};
+use crate::{r#macro::FnOnce, shared::winerror::HResult, utils::Call, Result};
 use std::mem::MaybeUninit;
 use typed_builder::TypedBuilder;
-use winapi::{
-    shared::{dxgi::IDXGISwapChain, winerror::SUCCEEDED},
-    Interface,
-};
+use winapi::{shared::dxgi::IDXGISwapChain, Interface};
 use wio::com::ComPtr;
 
 /// Get buffer.
@@ -17,21 +13,17 @@ pub struct GetBuffer<T: Interface> {
     surface: MaybeUninit<ComPtr<T>>,
 }
 
-impl<T: Interface> FnOnce<()> for GetBuffer<T> {
+impl<T: Interface> Call for GetBuffer<T> {
     type Output = Result<ComPtr<T>>;
 
-    extern "rust-call" fn call_once(mut self, _args: ()) -> Self::Output {
+    fn call(mut self) -> Self::Output {
         #[allow(non_snake_case)]
         unsafe {
             let Buffer = self.index;
             let riid = &T::uuidof();
             let ppSurface = self.surface.as_mut_ptr() as _;
             let r#return = self.swap_chain.GetBuffer(Buffer, riid, ppSurface);
-            ensure!(
-                SUCCEEDED(r#return),
-                "The IDXGISwapChain::GetBuffer call FAILED ({}).",
-                r#return
-            );
+            HResult::from(r#return).ok()?;
             Ok(self.surface.assume_init())
         }
     }
@@ -1,9 +1,8 @@
 use super::Description;
-use crate::r#macro::FnOnce;
-use anyhow::{ensure, Result};
+use crate::{r#macro::FnOnce, shared::winerror::HResult, utils::Call, Result};
 use std::mem::MaybeUninit;
 use typed_builder::TypedBuilder;
-use winapi::shared::{dxgi::IDXGISwapChain, winerror::SUCCEEDED};
+use winapi::shared::dxgi::IDXGISwapChain;
 use wio::com::ComPtr;
 
 /// Get description.
@@ -14,19 +13,15 @@ pub struct GetDesc {
     description: MaybeUninit<Description>,
 }
 
-impl FnOnce<()> for GetDesc {
+impl Call for GetDesc {
     type Output = Result<Description>;
 
-    extern "rust-call" fn call_once(mut self, _args: ()) -> Self::Output {
+    fn call(mut self) -> Self::Output {
         #[allow(non_snake_case)]
         unsafe {
             let pDesc = self.description.as_mut_ptr() as _;
             let r#return = self.swap_chain.GetDesc(pDesc);
-            ensure!(
-                SUCCEEDED(r#return),
-                "The IDXGISwapChain::GetDesc call FAILED ({}).",
-                r#return,
-            );
+            HResult::from(r#return).ok()?;
             Ok(self.description.assume_init())
         }
     }
@@ -2,7 +2,7 @@ use self::{
     get_buffer::{GetBuffer, GetBufferBuilder},
     get_desc::GetDesc,
 };
-use anyhow::Result;
+use crate::Result;
 use derive_more::{Deref, DerefMut, From, Into};
 use winapi::{
     shared::{
@@ -28,7 +28,7 @@ impl SwapChain for ComPtr<IDXGISwapChain> {
     }
 
     fn get_description(&self) -> Result<Description> {
-        GetDesc::builder().swap_chain(self.clone()).build()()
+        GetDesc::builder().swap_chain(self.clone()).call()
     }
 }
 
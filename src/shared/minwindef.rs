@@ -1,18 +1,13 @@
-use derive_more::{Deref, Display, From, Into};
+use derive_more::{Deref, From, Into};
 use rustapi_macro::Handle;
-use std::fmt::{self, Debug, Formatter};
-use winapi::shared::minwindef::HMODULE;
+use winapi::shared::minwindef::{HKL, HMODULE};
+
+/// Keyboard layout handle.
+#[derive(Clone, Copy, Deref, Eq, From, Handle, Into, PartialEq)]
+#[repr(transparent)]
+pub struct KeyboardLayoutHandle(HKL);
 
 /// Module handle.
-#[derive(Clone, Copy, Deref, Display, From, Handle, Into)]
-#[display(fmt = "{:#p}", _0)]
+#[derive(Clone, Copy, Deref, From, Handle, Into)]
 #[repr(transparent)]
 pub struct ModuleHandle(HMODULE);
-
-impl Debug for ModuleHandle {
-    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
-        f.debug_tuple("ModuleHandle")
-            .field(&format_args!("{:#p}", self.0))
-            .finish()
-    }
-}
@@ -10,3 +10,5 @@ pub mod minwindef;
 pub mod ntdef;
 #[cfg(feature = "windef")]
 pub mod windef;
+pub mod ntstatus;
+pub mod winerror;
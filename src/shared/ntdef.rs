@@ -1,52 +1,187 @@
-use crate::um::{
-    handleapi::{duplicate_handle, DuplicateHandle, DuplicateHandleBuilder},
-    processthreadsapi::GetCurrentProcess,
+use crate::{
+    um::{
+        handleapi::{duplicate_handle, DuplicateHandle, DuplicateHandleBuilder},
+        processthreadsapi::GetCurrentProcess,
+    },
+    utils::{AsStrictRawHandle, Call, FromStrictRawHandle},
+    Error, Result,
 };
-use anyhow::Result;
 use derive_more::{Deref, DerefMut, Display, From, Into};
 use rustapi_macro::Handle;
 use std::{
+    convert::TryFrom,
     fmt::{self, Debug, Formatter},
-    io,
-    os::windows::io::RawHandle,
-};
-use winapi::{
-    shared::{minwindef::FALSE, ntdef::LUID},
-    um::{handleapi::CloseHandle, winnt::DUPLICATE_SAME_ACCESS},
+    fs::File,
+    marker::PhantomData,
+    os::windows::io::{self, AsRawHandle, FromRawHandle, IntoRawHandle, RawHandle},
+    process::{Child, ChildStderr, ChildStdin, ChildStdout},
+    thread::JoinHandle,
 };
+use winapi::{shared::ntdef::LUID, um::winnt::DUPLICATE_SAME_ACCESS};
 
-/// Handle.
-#[derive(Clone, Debug, Display, Handle)]
-#[display(fmt = "{:#p}", _0)]
+/// Owned handle.
+///
+/// Closes the handle on drop, ignoring the error, `try_close` returns it.
+#[derive(Handle)]
+#[handle(invalid = "both", close = "CloseHandle")]
 #[repr(transparent)]
-pub struct Handle(RawHandle);
+pub struct OwnedHandle(RawHandle);
 
-impl Handle {
-    pub fn duplicate(&self) -> DuplicateHandleBuilder<((), (&Handle,), (), (), (), ())> {
+impl OwnedHandle {
+    pub fn duplicate(
+        &self,
+    ) -> DuplicateHandleBuilder<'_, ((), (BorrowedHandle<'_>,), (), (), (), ())> {
         duplicate_handle().source_handle(self)
     }
 
-    pub fn duplicate_for_current_process(&self) -> Result<Handle> {
-        let process = GetCurrentProcess();
+    pub fn duplicate_for_current_process(&self) -> Result<OwnedHandle> {
+        let process = GetCurrentProcess.call();
         DuplicateHandle::builder()
             .source_handle(self)
-            .source_process_handle(&process)
-            .target_process_handle(&process)
+            .source_process_handle(process)
+            .target_process_handle(process)
             .options(DUPLICATE_SAME_ACCESS)
-            .build()()
+            .call()
+    }
+}
+
+impl From<OwnedHandle> for io::OwnedHandle {
+    fn from(from: OwnedHandle) -> Self {
+        unsafe { io::OwnedHandle::from_raw_handle(from.into_raw_handle()) }
+    }
+}
+
+/// Fails for the pseudo handle of the current process, which the standard
+/// handle may hold, and closes it.
+impl TryFrom<io::OwnedHandle> for OwnedHandle {
+    type Error = Error;
+
+    fn try_from(from: io::OwnedHandle) -> Result<Self> {
+        let handle = Self::try_from(from.as_raw_handle())?;
+        let _ = from.into_raw_handle();
+        Ok(handle)
+    }
+}
+
+unsafe impl Send for OwnedHandle {}
+
+unsafe impl Sync for OwnedHandle {}
+
+/// Borrowed handle.
+///
+/// Never closes the handle, the pseudo handles are borrowed for `'static`.
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[repr(transparent)]
+pub struct BorrowedHandle<'a>(RawHandle, PhantomData<&'a OwnedHandle>);
+
+impl BorrowedHandle<'static> {
+    /// The pseudo handle of the current process.
+    pub const CURRENT_PROCESS: Self = Self(-1isize as _, PhantomData);
+    /// The pseudo handle of the current process token.
+    pub const CURRENT_PROCESS_TOKEN: Self = Self(-4isize as _, PhantomData);
+    /// The pseudo handle of the current thread.
+    pub const CURRENT_THREAD: Self = Self(-2isize as _, PhantomData);
+    /// The pseudo handle of the current thread effective token.
+    pub const CURRENT_THREAD_EFFECTIVE_TOKEN: Self = Self(-6isize as _, PhantomData);
+    /// The pseudo handle of the current thread token.
+    pub const CURRENT_THREAD_TOKEN: Self = Self(-5isize as _, PhantomData);
+}
+
+impl AsRawHandle for BorrowedHandle<'_> {
+    fn as_raw_handle(&self) -> RawHandle {
+        self.0
+    }
+}
+
+impl AsStrictRawHandle for BorrowedHandle<'_> {
+    fn as_strict_raw_handle(&self) -> Self::StrictRawHandle {
+        self.0
+    }
+}
+
+impl FromStrictRawHandle for BorrowedHandle<'_> {
+    type StrictRawHandle = RawHandle;
+
+    unsafe fn from_strict_raw_handle(handle: Self::StrictRawHandle) -> Self {
+        Self(handle, PhantomData)
     }
 }
 
-impl Drop for Handle {
-    fn drop(&mut self) {
-        let r#return = unsafe { CloseHandle(self.0) };
-        assert!(r#return != FALSE, io::Error::last_os_error());
+impl Debug for BorrowedHandle<'_> {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.debug_tuple("BorrowedHandle")
+            .field(&format_args!("{:#p}", self.0))
+            .finish()
     }
 }
 
-unsafe impl Send for Handle {}
+impl fmt::Display for BorrowedHandle<'_> {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "{:#p}", self.0)
+    }
+}
 
-unsafe impl Sync for Handle {}
+impl<'a, T: AsHandle + ?Sized> From<&'a T> for BorrowedHandle<'a> {
+    fn from(from: &'a T) -> Self {
+        from.as_handle()
+    }
+}
+
+impl<'a> From<BorrowedHandle<'a>> for io::BorrowedHandle<'a> {
+    fn from(from: BorrowedHandle<'a>) -> Self {
+        unsafe { io::BorrowedHandle::borrow_raw(from.0) }
+    }
+}
+
+impl<'a> From<io::BorrowedHandle<'a>> for BorrowedHandle<'a> {
+    fn from(from: io::BorrowedHandle<'a>) -> Self {
+        Self(from.as_raw_handle(), PhantomData)
+    }
+}
+
+unsafe impl Send for BorrowedHandle<'_> {}
+
+unsafe impl Sync for BorrowedHandle<'_> {}
+
+/// As handle.
+///
+/// The builders take `impl Into<BorrowedHandle>`, so anything that implements
+/// it can be passed by reference.
+pub trait AsHandle {
+    fn as_handle(&self) -> BorrowedHandle<'_>;
+}
+
+impl AsHandle for OwnedHandle {
+    fn as_handle(&self) -> BorrowedHandle<'_> {
+        BorrowedHandle(self.0, PhantomData)
+    }
+}
+
+impl AsHandle for BorrowedHandle<'_> {
+    fn as_handle(&self) -> BorrowedHandle<'_> {
+        *self
+    }
+}
+
+macro_rules! as_handle {
+    ($($type:ty),*) => {
+        $(
+            impl AsHandle for $type {
+                fn as_handle(&self) -> BorrowedHandle<'_> {
+                    BorrowedHandle(self.as_raw_handle(), PhantomData)
+                }
+            }
+        )*
+    };
+}
+
+as_handle!(Child, ChildStderr, ChildStdin, ChildStdout, File);
+
+impl<T> AsHandle for JoinHandle<T> {
+    fn as_handle(&self) -> BorrowedHandle<'_> {
+        BorrowedHandle(self.as_raw_handle(), PhantomData)
+    }
+}
 
 /// Locally unique identifier.
 #[derive(Clone, Copy, Deref, DerefMut, Display, From, Into)]
@@ -93,3 +228,71 @@ impl From<i64> for Luid {
         })
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{BorrowedHandle, OwnedHandle};
+    use crate::{
+        um::{
+            handleapi::DuplicateHandle,
+            processthreadsapi::{GetCurrentProcess, GetCurrentThread, GetProcessId},
+        },
+        utils::Call,
+    };
+    use std::{
+        convert::TryFrom,
+        os::windows::io::{self, AsRawHandle, FromRawHandle, RawHandle},
+        process,
+    };
+    use winapi::um::winnt::DUPLICATE_SAME_ACCESS;
+
+    #[test]
+    fn pseudo() {
+        assert!(BorrowedHandle::CURRENT_PROCESS == GetCurrentProcess.call());
+        assert!(BorrowedHandle::CURRENT_THREAD == GetCurrentThread.call());
+        let process_id = GetProcessId::builder()
+            .process(BorrowedHandle::CURRENT_PROCESS)
+            .call()
+            .unwrap();
+        assert_eq!(process::id(), process_id);
+    }
+
+    #[test]
+    fn owned() {
+        assert!(OwnedHandle::try_from(0 as RawHandle).is_err());
+        assert!(OwnedHandle::try_from(-1isize as RawHandle).is_err());
+        let process = BorrowedHandle::CURRENT_PROCESS;
+        let handle = DuplicateHandle::builder()
+            .source_process_handle(process)
+            .source_handle(process)
+            .target_process_handle(process)
+            .options(DUPLICATE_SAME_ACCESS)
+            .call()
+            .unwrap();
+        let duplicate = handle.duplicate_for_current_process().unwrap();
+        assert!(handle.try_close().is_ok());
+        drop(duplicate);
+    }
+
+    #[test]
+    fn std() {
+        let process = BorrowedHandle::CURRENT_PROCESS;
+        let borrowed = io::BorrowedHandle::from(process);
+        assert_eq!(process.as_raw_handle(), borrowed.as_raw_handle());
+        assert!(process == BorrowedHandle::from(borrowed));
+        let handle = DuplicateHandle::builder()
+            .source_process_handle(process)
+            .source_handle(process)
+            .target_process_handle(process)
+            .options(DUPLICATE_SAME_ACCESS)
+            .call()
+            .unwrap();
+        let raw_handle = handle.as_raw_handle();
+        let owned = io::OwnedHandle::from(handle);
+        assert_eq!(raw_handle, owned.as_raw_handle());
+        let handle = OwnedHandle::try_from(owned).unwrap();
+        assert_eq!(raw_handle, handle.as_raw_handle());
+        let pseudo = unsafe { io::OwnedHandle::from_raw_handle(-1isize as _) };
+        assert!(OwnedHandle::try_from(pseudo).is_err());
+    }
+}
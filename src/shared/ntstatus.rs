@@ -0,0 +1,277 @@
+use derive_more::{From, Into};
+use std::{
+    error::Error,
+    fmt::{self, Debug, Display, Formatter},
+};
+use winapi::shared::{
+    ntdef::NTSTATUS,
+    ntstatus::{
+        STATUS_ACCESS_DENIED, STATUS_ACCESS_VIOLATION, STATUS_BUFFER_OVERFLOW,
+        STATUS_BUFFER_TOO_SMALL, STATUS_CANCELLED, STATUS_INFO_LENGTH_MISMATCH,
+        STATUS_INSUFFICIENT_RESOURCES, STATUS_INVALID_CID, STATUS_INVALID_HANDLE,
+        STATUS_INVALID_INFO_CLASS, STATUS_INVALID_PARAMETER, STATUS_NOT_FOUND,
+        STATUS_NOT_IMPLEMENTED, STATUS_NOT_SUPPORTED, STATUS_NO_MEMORY, STATUS_NO_MORE_ENTRIES,
+        STATUS_OBJECT_NAME_COLLISION, STATUS_OBJECT_NAME_NOT_FOUND, STATUS_OBJECT_PATH_NOT_FOUND,
+        STATUS_OBJECT_TYPE_MISMATCH, STATUS_PARTIAL_COPY, STATUS_PENDING,
+        STATUS_PRIVILEGE_NOT_HELD, STATUS_PROCESS_IS_TERMINATING, STATUS_SUCCESS, STATUS_TIMEOUT,
+        STATUS_UNSUCCESSFUL,
+    },
+    winerror::{
+        ERROR_ACCESS_DENIED, ERROR_ALREADY_EXISTS, ERROR_BAD_LENGTH, ERROR_FILE_NOT_FOUND,
+        ERROR_GEN_FAILURE, ERROR_INSUFFICIENT_BUFFER, ERROR_INVALID_FUNCTION, ERROR_INVALID_HANDLE,
+        ERROR_INVALID_PARAMETER, ERROR_IO_PENDING, ERROR_MORE_DATA, ERROR_NOACCESS,
+        ERROR_NOT_ENOUGH_MEMORY, ERROR_NOT_FOUND, ERROR_NOT_SUPPORTED, ERROR_NO_MORE_ITEMS,
+        ERROR_NO_SYSTEM_RESOURCES, ERROR_OPERATION_ABORTED, ERROR_PARTIAL_COPY,
+        ERROR_PATH_NOT_FOUND, ERROR_PRIVILEGE_NOT_HELD, ERROR_SUCCESS, WAIT_TIMEOUT,
+    },
+};
+
+/// NTSTATUS.
+///
+/// Decodes the severity, facility and code of native API results, and names
+/// the common ones. Matches the named codes as constants:
+///
+/// ```ignore
+/// match error {
+///     NtStatus::STATUS_INFO_LENGTH_MISMATCH => grow(),
+///     _ => return Err(error),
+/// }
+/// ```
+#[derive(Clone, Copy, Eq, From, Hash, Into, PartialEq)]
+#[repr(transparent)]
+pub struct NtStatus(NTSTATUS);
+
+#[allow(non_upper_case_globals)]
+impl NtStatus {
+    pub const STATUS_ACCESS_DENIED: Self = Self(STATUS_ACCESS_DENIED);
+    pub const STATUS_ACCESS_VIOLATION: Self = Self(STATUS_ACCESS_VIOLATION);
+    pub const STATUS_BUFFER_OVERFLOW: Self = Self(STATUS_BUFFER_OVERFLOW);
+    pub const STATUS_BUFFER_TOO_SMALL: Self = Self(STATUS_BUFFER_TOO_SMALL);
+    pub const STATUS_CANCELLED: Self = Self(STATUS_CANCELLED);
+    pub const STATUS_INFO_LENGTH_MISMATCH: Self = Self(STATUS_INFO_LENGTH_MISMATCH);
+    pub const STATUS_INSUFFICIENT_RESOURCES: Self = Self(STATUS_INSUFFICIENT_RESOURCES);
+    pub const STATUS_INVALID_CID: Self = Self(STATUS_INVALID_CID);
+    pub const STATUS_INVALID_HANDLE: Self = Self(STATUS_INVALID_HANDLE);
+    pub const STATUS_INVALID_INFO_CLASS: Self = Self(STATUS_INVALID_INFO_CLASS);
+    pub const STATUS_INVALID_PARAMETER: Self = Self(STATUS_INVALID_PARAMETER);
+    pub const STATUS_NOT_FOUND: Self = Self(STATUS_NOT_FOUND);
+    pub const STATUS_NOT_IMPLEMENTED: Self = Self(STATUS_NOT_IMPLEMENTED);
+    pub const STATUS_NOT_SUPPORTED: Self = Self(STATUS_NOT_SUPPORTED);
+    pub const STATUS_NO_MEMORY: Self = Self(STATUS_NO_MEMORY);
+    pub const STATUS_NO_MORE_ENTRIES: Self = Self(STATUS_NO_MORE_ENTRIES);
+    pub const STATUS_OBJECT_NAME_COLLISION: Self = Self(STATUS_OBJECT_NAME_COLLISION);
+    pub const STATUS_OBJECT_NAME_NOT_FOUND: Self = Self(STATUS_OBJECT_NAME_NOT_FOUND);
+    pub const STATUS_OBJECT_PATH_NOT_FOUND: Self = Self(STATUS_OBJECT_PATH_NOT_FOUND);
+    pub const STATUS_OBJECT_TYPE_MISMATCH: Self = Self(STATUS_OBJECT_TYPE_MISMATCH);
+    pub const STATUS_PARTIAL_COPY: Self = Self(STATUS_PARTIAL_COPY);
+    pub const STATUS_PENDING: Self = Self(STATUS_PENDING);
+    pub const STATUS_PRIVILEGE_NOT_HELD: Self = Self(STATUS_PRIVILEGE_NOT_HELD);
+    pub const STATUS_PROCESS_IS_TERMINATING: Self = Self(STATUS_PROCESS_IS_TERMINATING);
+    pub const STATUS_SUCCESS: Self = Self(STATUS_SUCCESS);
+    pub const STATUS_TIMEOUT: Self = Self(STATUS_TIMEOUT);
+    pub const STATUS_UNSUCCESSFUL: Self = Self(STATUS_UNSUCCESSFUL);
+}
+
+/// The name, message and `RtlNtStatusToDosError` Win32 error code of the
+/// common codes.
+const CODES: &[(NtStatus, &str, &str, u32)] = &[
+    (NtStatus::STATUS_SUCCESS, "STATUS_SUCCESS", "The operation completed successfully.", ERROR_SUCCESS),
+    (NtStatus::STATUS_TIMEOUT, "STATUS_TIMEOUT", "The wait operation timed out.", WAIT_TIMEOUT),
+    (NtStatus::STATUS_PENDING, "STATUS_PENDING", "The operation that was requested is pending completion.", ERROR_IO_PENDING),
+    (NtStatus::STATUS_BUFFER_OVERFLOW, "STATUS_BUFFER_OVERFLOW", "The data was too large to fit into the specified buffer.", ERROR_MORE_DATA),
+    (NtStatus::STATUS_PARTIAL_COPY, "STATUS_PARTIAL_COPY", "Because of protection conflicts, not all the requested bytes could be copied.", ERROR_PARTIAL_COPY),
+    (NtStatus::STATUS_NO_MORE_ENTRIES, "STATUS_NO_MORE_ENTRIES", "No more entries are available from an enumeration operation.", ERROR_NO_MORE_ITEMS),
+    (NtStatus::STATUS_UNSUCCESSFUL, "STATUS_UNSUCCESSFUL", "The requested operation was unsuccessful.", ERROR_GEN_FAILURE),
+    (NtStatus::STATUS_NOT_IMPLEMENTED, "STATUS_NOT_IMPLEMENTED", "The requested operation is not implemented.", ERROR_INVALID_FUNCTION),
+    (NtStatus::STATUS_INVALID_INFO_CLASS, "STATUS_INVALID_INFO_CLASS", "The specified information class is not a valid information class for the specified object.", ERROR_INVALID_PARAMETER),
+    (NtStatus::STATUS_INFO_LENGTH_MISMATCH, "STATUS_INFO_LENGTH_MISMATCH", "The specified information record length does not match the length that is required for the specified information class.", ERROR_BAD_LENGTH),
+    (NtStatus::STATUS_ACCESS_VIOLATION, "STATUS_ACCESS_VIOLATION", "The instruction caused a memory access violation.", ERROR_NOACCESS),
+    (NtStatus::STATUS_INVALID_HANDLE, "STATUS_INVALID_HANDLE", "An invalid HANDLE was specified.", ERROR_INVALID_HANDLE),
+    (NtStatus::STATUS_INVALID_CID, "STATUS_INVALID_CID", "An invalid client ID was specified.", ERROR_INVALID_PARAMETER),
+    (NtStatus::STATUS_INVALID_PARAMETER, "STATUS_INVALID_PARAMETER", "An invalid parameter was passed to a service or function.", ERROR_INVALID_PARAMETER),
+    (NtStatus::STATUS_NO_MEMORY, "STATUS_NO_MEMORY", "Not enough virtual memory or paging file quota is available to complete the specified operation.", ERROR_NOT_ENOUGH_MEMORY),
+    (NtStatus::STATUS_ACCESS_DENIED, "STATUS_ACCESS_DENIED", "A process has requested access to an object but has not been granted those access rights.", ERROR_ACCESS_DENIED),
+    (NtStatus::STATUS_BUFFER_TOO_SMALL, "STATUS_BUFFER_TOO_SMALL", "The buffer is too small to contain the entry.", ERROR_INSUFFICIENT_BUFFER),
+    (NtStatus::STATUS_OBJECT_TYPE_MISMATCH, "STATUS_OBJECT_TYPE_MISMATCH", "There is a mismatch between the type of object that is required by the requested operation and the type of object that is specified in the request.", ERROR_INVALID_HANDLE),
+    (NtStatus::STATUS_OBJECT_NAME_NOT_FOUND, "STATUS_OBJECT_NAME_NOT_FOUND", "The object name is not found.", ERROR_FILE_NOT_FOUND),
+    (NtStatus::STATUS_OBJECT_NAME_COLLISION, "STATUS_OBJECT_NAME_COLLISION", "The object name already exists.", ERROR_ALREADY_EXISTS),
+    (NtStatus::STATUS_OBJECT_PATH_NOT_FOUND, "STATUS_OBJECT_PATH_NOT_FOUND", "The object path component was not found.", ERROR_PATH_NOT_FOUND),
+    (NtStatus::STATUS_PRIVILEGE_NOT_HELD, "STATUS_PRIVILEGE_NOT_HELD", "A required privilege is not held by the client.", ERROR_PRIVILEGE_NOT_HELD),
+    (NtStatus::STATUS_INSUFFICIENT_RESOURCES, "STATUS_INSUFFICIENT_RESOURCES", "Insufficient system resources exist to complete the API.", ERROR_NO_SYSTEM_RESOURCES),
+    (NtStatus::STATUS_NOT_SUPPORTED, "STATUS_NOT_SUPPORTED", "The request is not supported.", ERROR_NOT_SUPPORTED),
+    (NtStatus::STATUS_PROCESS_IS_TERMINATING, "STATUS_PROCESS_IS_TERMINATING", "An attempt was made to access an exiting process.", ERROR_ACCESS_DENIED),
+    (NtStatus::STATUS_CANCELLED, "STATUS_CANCELLED", "The I/O request was canceled.", ERROR_OPERATION_ABORTED),
+    (NtStatus::STATUS_NOT_FOUND, "STATUS_NOT_FOUND", "The object was not found.", ERROR_NOT_FOUND),
+];
+
+/// NTSTATUS severity.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Severity {
+    Success,
+    Informational,
+    Warning,
+    Error,
+}
+
+impl NtStatus {
+    /// Converts to `Ok` on success, including the informational codes.
+    #[inline]
+    pub fn ok(self) -> Result<(), Self> {
+        if self.is_success() {
+            Ok(())
+        } else {
+            Err(self)
+        }
+    }
+
+    /// `NT_SUCCESS`, true for the success and informational codes.
+    #[inline]
+    pub fn is_success(self) -> bool {
+        self.0 >= 0
+    }
+
+    #[inline]
+    pub fn is_error(self) -> bool {
+        self.severity() == Severity::Error
+    }
+
+    #[inline]
+    pub fn severity(self) -> Severity {
+        match self.0 as u32 >> 30 {
+            0 => Severity::Success,
+            1 => Severity::Informational,
+            2 => Severity::Warning,
+            _ => Severity::Error,
+        }
+    }
+
+    /// Whether the code is defined by a third party, rather than Microsoft.
+    #[inline]
+    pub fn is_customer(self) -> bool {
+        self.0 as u32 & 0x2000_0000 != 0
+    }
+
+    #[inline]
+    pub fn facility(self) -> u16 {
+        (self.0 as u32 >> 16) as u16 & 0xFFF
+    }
+
+    #[inline]
+    pub fn code(self) -> u16 {
+        self.0 as u16
+    }
+
+    pub fn name(self) -> Option<&'static str> {
+        CODES
+            .iter()
+            .find(|&&(status, ..)| status == self)
+            .map(|&(_, name, ..)| name)
+    }
+
+    pub fn message(self) -> Option<&'static str> {
+        CODES
+            .iter()
+            .find(|&&(status, ..)| status == self)
+            .map(|&(_, _, message, _)| message)
+    }
+
+    /// The Win32 error code, as `RtlNtStatusToDosError` maps it.
+    ///
+    /// Looks up the common codes first, and asks `ntdll` for the others when
+    /// the feature is enabled.
+    pub fn to_win32(self) -> Option<u32> {
+        CODES
+            .iter()
+            .find(|&&(status, ..)| status == self)
+            .map(|&(.., code)| code)
+            .or_else(|| self.system_win32())
+    }
+
+    #[cfg(all(windows, feature = "ntdll"))]
+    fn system_win32(self) -> Option<u32> {
+        use winapi::shared::winerror::ERROR_MR_MID_NOT_FOUND;
+
+        let code = crate::um::winternl::rtl_nt_status_to_dos_error()
+            .status(self)
+            .call();
+        (code != ERROR_MR_MID_NOT_FOUND).then_some(code)
+    }
+
+    #[cfg(not(all(windows, feature = "ntdll")))]
+    fn system_win32(self) -> Option<u32> {
+        None
+    }
+}
+
+impl Debug for NtStatus {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let mut debug_tuple = f.debug_tuple("NtStatus");
+        match self.name() {
+            Some(name) => debug_tuple.field(&format_args!("{}", name)),
+            None => debug_tuple.field(&format_args!("{:#010x}", self.0)),
+        };
+        debug_tuple.finish()
+    }
+}
+
+impl Display for NtStatus {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "{:#010x}", self.0)?;
+        if let Some(name) = self.name() {
+            write!(f, " {}", name)?;
+        }
+        if let Some(message) = self.message() {
+            write!(f, ": {}", message)?;
+        }
+        Ok(())
+    }
+}
+
+impl Error for NtStatus {}
+
+#[cfg(test)]
+mod tests {
+    use super::{NtStatus, Severity};
+
+    #[test]
+    fn decode() {
+        let access_denied = NtStatus::from(0xC0000022_u32 as i32);
+        assert_eq!(NtStatus::STATUS_ACCESS_DENIED, access_denied);
+        assert_eq!(Severity::Error, access_denied.severity());
+        assert!(access_denied.is_error());
+        assert!(!access_denied.is_customer());
+        assert_eq!(0, access_denied.facility());
+        assert_eq!(0x22, access_denied.code());
+        assert_eq!(Err(access_denied), access_denied.ok());
+        assert_eq!(Severity::Warning, NtStatus::STATUS_PARTIAL_COPY.severity());
+        assert!(!NtStatus::STATUS_PARTIAL_COPY.is_success());
+        assert_eq!(Ok(()), NtStatus::STATUS_PENDING.ok());
+        let customer = NtStatus::from(0xE0AB0001_u32 as i32);
+        assert!(customer.is_customer());
+        assert_eq!(0xAB, customer.facility());
+    }
+
+    #[test]
+    fn win32() {
+        assert_eq!(Some(5), NtStatus::STATUS_ACCESS_DENIED.to_win32());
+        assert_eq!(Some(24), NtStatus::STATUS_INFO_LENGTH_MISMATCH.to_win32());
+        assert_eq!(Some(258), NtStatus::STATUS_TIMEOUT.to_win32());
+        assert_eq!(Some(0), NtStatus::STATUS_SUCCESS.to_win32());
+    }
+
+    #[test]
+    fn format() {
+        assert_eq!(
+            "0xc0000008 STATUS_INVALID_HANDLE: An invalid HANDLE was specified.",
+            NtStatus::STATUS_INVALID_HANDLE.to_string(),
+        );
+        assert_eq!(
+            "NtStatus(STATUS_PARTIAL_COPY)",
+            format!("{:?}", NtStatus::STATUS_PARTIAL_COPY),
+        );
+        assert_eq!(
+            "NtStatus(0xc0001234)",
+            format!("{:?}", NtStatus::from(0xC0001234_u32 as i32)),
+        );
+    }
+}
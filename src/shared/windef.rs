@@ -1,18 +1,80 @@
-use derive_more::{Deref, Display, From, Into};
+use derive_more::{Deref, DerefMut, From, Into};
 use rustapi_macro::Handle;
 use std::fmt::{self, Debug, Formatter};
-use winapi::shared::windef::HWND;
+use winapi::shared::windef::{HHOOK, HWND, RECT};
 
-/// Window handle.
-#[derive(Clone, Copy, Deref, Display, From, Handle, Into)]
-#[display(fmt = "{:#p}", _0)]
+/// Hook handle.
+#[derive(Clone, Copy, Deref, From, Handle, Into)]
 #[repr(transparent)]
-pub struct WindowHandle(HWND);
+pub struct HookHandle(HHOOK);
+
+/// Rectangle.
+///
+/// The right and bottom edges are exclusive.
+#[derive(Clone, Copy, Deref, DerefMut, From, Into)]
+#[repr(transparent)]
+pub struct Rect(RECT);
 
-impl Debug for WindowHandle {
+impl Rect {
+    pub fn new(left: i32, top: i32, right: i32, bottom: i32) -> Self {
+        Rect(RECT {
+            left,
+            top,
+            right,
+            bottom,
+        })
+    }
+
+    #[inline]
+    pub fn width(&self) -> i32 {
+        self.0.right - self.0.left
+    }
+
+    #[inline]
+    pub fn height(&self) -> i32 {
+        self.0.bottom - self.0.top
+    }
+}
+
+impl Debug for Rect {
     fn fmt(&self, f: &mut Formatter) -> fmt::Result {
-        f.debug_tuple("WindowHandle")
-            .field(&format_args!("{:#p}", self.0))
+        f.debug_struct("Rect")
+            .field("left", &self.0.left)
+            .field("top", &self.0.top)
+            .field("right", &self.0.right)
+            .field("bottom", &self.0.bottom)
             .finish()
     }
 }
+
+impl Default for Rect {
+    fn default() -> Self {
+        Rect::new(0, 0, 0, 0)
+    }
+}
+
+impl PartialEq for Rect {
+    fn eq(&self, other: &Self) -> bool {
+        let rect = |Rect(rect): &Rect| (rect.left, rect.top, rect.right, rect.bottom);
+        rect(self) == rect(other)
+    }
+}
+
+/// Window handle.
+#[derive(Clone, Copy, Deref, Eq, From, Handle, Hash, Into, PartialEq)]
+#[repr(transparent)]
+pub struct WindowHandle(HWND);
+
+#[cfg(test)]
+mod tests {
+    use super::Rect;
+
+    #[test]
+    fn rect() {
+        let rect = Rect::new(-8, 10, 1912, 1090);
+        assert_eq!(1920, rect.width());
+        assert_eq!(1080, rect.height());
+        assert_eq!(Rect::new(-8, 10, 1912, 1090), rect);
+        assert_eq!(Rect::new(0, 0, 0, 0), Rect::default());
+    }
+}
@@ -0,0 +1,439 @@
+use derive_more::{From, Into};
+use std::{
+    error::Error,
+    fmt::{self, Debug, Display, Formatter},
+};
+use winapi::shared::winerror::{
+    D3D11_ERROR_DEFERRED_CONTEXT_MAP_WITHOUT_INITIAL_DISCARD, D3D11_ERROR_FILE_NOT_FOUND,
+    D3D11_ERROR_TOO_MANY_UNIQUE_STATE_OBJECTS, D3D11_ERROR_TOO_MANY_UNIQUE_VIEW_OBJECTS,
+    DXGI_ERROR_ACCESS_DENIED, DXGI_ERROR_ACCESS_LOST, DXGI_ERROR_DEVICE_HUNG,
+    DXGI_ERROR_DEVICE_REMOVED, DXGI_ERROR_DEVICE_RESET, DXGI_ERROR_DRIVER_INTERNAL_ERROR,
+    DXGI_ERROR_INVALID_CALL, DXGI_ERROR_MORE_DATA, DXGI_ERROR_NOT_CURRENTLY_AVAILABLE,
+    DXGI_ERROR_NOT_FOUND, DXGI_ERROR_SDK_COMPONENT_MISSING, DXGI_ERROR_UNSUPPORTED,
+    DXGI_ERROR_WAIT_TIMEOUT, DXGI_ERROR_WAS_STILL_DRAWING, DXGI_STATUS_OCCLUDED,
+    ERROR_ACCESS_DENIED, ERROR_ALREADY_EXISTS, ERROR_BAD_LENGTH, ERROR_BROKEN_PIPE,
+    ERROR_CANNOT_FIND_WND_CLASS, ERROR_CLASS_ALREADY_EXISTS, ERROR_CLASS_DOES_NOT_EXIST,
+    ERROR_ELEVATION_REQUIRED, ERROR_ENVVAR_NOT_FOUND, ERROR_FAILED_SERVICE_CONTROLLER_CONNECT,
+    ERROR_FILE_EXISTS, ERROR_FILE_NOT_FOUND, ERROR_HANDLE_EOF, ERROR_HOOK_NEEDS_HMOD,
+    ERROR_INSUFFICIENT_BUFFER, ERROR_INVALID_ADDRESS, ERROR_INVALID_DATA, ERROR_INVALID_FLAGS,
+    ERROR_INVALID_FUNCTION, ERROR_INVALID_HANDLE, ERROR_INVALID_HOOK_HANDLE, ERROR_INVALID_NAME,
+    ERROR_INVALID_PARAMETER, ERROR_INVALID_THREAD_ID, ERROR_INVALID_WINDOW_HANDLE,
+    ERROR_IO_INCOMPLETE, ERROR_IO_PENDING, ERROR_MOD_NOT_FOUND, ERROR_MORE_DATA, ERROR_NOACCESS,
+    ERROR_NOT_ALL_ASSIGNED, ERROR_NOT_ENOUGH_MEMORY, ERROR_NOT_FOUND, ERROR_NOT_READY,
+    ERROR_NOT_SUPPORTED, ERROR_NO_DATA, ERROR_NO_MORE_FILES, ERROR_NO_MORE_ITEMS,
+    ERROR_NO_SUCH_PRIVILEGE, ERROR_NO_TOKEN, ERROR_OPERATION_ABORTED, ERROR_OUTOFMEMORY,
+    ERROR_PARTIAL_COPY, ERROR_PATH_NOT_FOUND, ERROR_PIPE_BUSY, ERROR_PIPE_NOT_CONNECTED,
+    ERROR_PRIVILEGE_NOT_HELD, ERROR_PROC_NOT_FOUND, ERROR_SEM_TIMEOUT,
+    ERROR_SERVICE_ALREADY_RUNNING, ERROR_SERVICE_DOES_NOT_EXIST, ERROR_SERVICE_NOT_ACTIVE,
+    ERROR_SHARING_VIOLATION, ERROR_SUCCESS, ERROR_TIMEOUT, ERROR_TOO_MANY_OPEN_FILES, E_ABORT,
+    E_ACCESSDENIED, E_FAIL, E_HANDLE, E_INVALIDARG, E_NOINTERFACE, E_NOTIMPL, E_OUTOFMEMORY,
+    E_POINTER, E_UNEXPECTED, FACILITY_WIN32, HRESULT, S_FALSE, S_OK, WAIT_TIMEOUT,
+};
+
+/// HRESULT.
+///
+/// Decodes the severity, facility and code of COM results, and names the
+/// common ones. Matches the named codes as constants:
+///
+/// ```ignore
+/// match error {
+///     HResult::DXGI_ERROR_DEVICE_REMOVED | HResult::DXGI_ERROR_DEVICE_RESET => recreate(),
+///     _ => return Err(error),
+/// }
+/// ```
+#[derive(Clone, Copy, Eq, From, Hash, Into, PartialEq)]
+#[repr(transparent)]
+pub struct HResult(HRESULT);
+
+#[allow(non_upper_case_globals)]
+impl HResult {
+    pub const D3D11_ERROR_DEFERRED_CONTEXT_MAP_WITHOUT_INITIAL_DISCARD: Self =
+        Self(D3D11_ERROR_DEFERRED_CONTEXT_MAP_WITHOUT_INITIAL_DISCARD);
+    pub const D3D11_ERROR_FILE_NOT_FOUND: Self = Self(D3D11_ERROR_FILE_NOT_FOUND);
+    pub const D3D11_ERROR_TOO_MANY_UNIQUE_STATE_OBJECTS: Self =
+        Self(D3D11_ERROR_TOO_MANY_UNIQUE_STATE_OBJECTS);
+    pub const D3D11_ERROR_TOO_MANY_UNIQUE_VIEW_OBJECTS: Self =
+        Self(D3D11_ERROR_TOO_MANY_UNIQUE_VIEW_OBJECTS);
+    pub const DXGI_ERROR_ACCESS_DENIED: Self = Self(DXGI_ERROR_ACCESS_DENIED);
+    pub const DXGI_ERROR_ACCESS_LOST: Self = Self(DXGI_ERROR_ACCESS_LOST);
+    pub const DXGI_ERROR_DEVICE_HUNG: Self = Self(DXGI_ERROR_DEVICE_HUNG);
+    pub const DXGI_ERROR_DEVICE_REMOVED: Self = Self(DXGI_ERROR_DEVICE_REMOVED);
+    pub const DXGI_ERROR_DEVICE_RESET: Self = Self(DXGI_ERROR_DEVICE_RESET);
+    pub const DXGI_ERROR_DRIVER_INTERNAL_ERROR: Self = Self(DXGI_ERROR_DRIVER_INTERNAL_ERROR);
+    pub const DXGI_ERROR_INVALID_CALL: Self = Self(DXGI_ERROR_INVALID_CALL);
+    pub const DXGI_ERROR_MORE_DATA: Self = Self(DXGI_ERROR_MORE_DATA);
+    pub const DXGI_ERROR_NOT_CURRENTLY_AVAILABLE: Self = Self(DXGI_ERROR_NOT_CURRENTLY_AVAILABLE);
+    pub const DXGI_ERROR_NOT_FOUND: Self = Self(DXGI_ERROR_NOT_FOUND);
+    pub const DXGI_ERROR_SDK_COMPONENT_MISSING: Self = Self(DXGI_ERROR_SDK_COMPONENT_MISSING);
+    pub const DXGI_ERROR_UNSUPPORTED: Self = Self(DXGI_ERROR_UNSUPPORTED);
+    pub const DXGI_ERROR_WAIT_TIMEOUT: Self = Self(DXGI_ERROR_WAIT_TIMEOUT);
+    pub const DXGI_ERROR_WAS_STILL_DRAWING: Self = Self(DXGI_ERROR_WAS_STILL_DRAWING);
+    pub const DXGI_STATUS_OCCLUDED: Self = Self(DXGI_STATUS_OCCLUDED);
+    pub const E_ABORT: Self = Self(E_ABORT);
+    pub const E_ACCESSDENIED: Self = Self(E_ACCESSDENIED);
+    pub const E_FAIL: Self = Self(E_FAIL);
+    pub const E_HANDLE: Self = Self(E_HANDLE);
+    pub const E_INVALIDARG: Self = Self(E_INVALIDARG);
+    pub const E_NOINTERFACE: Self = Self(E_NOINTERFACE);
+    pub const E_NOTIMPL: Self = Self(E_NOTIMPL);
+    pub const E_OUTOFMEMORY: Self = Self(E_OUTOFMEMORY);
+    pub const E_POINTER: Self = Self(E_POINTER);
+    pub const E_UNEXPECTED: Self = Self(E_UNEXPECTED);
+    pub const S_FALSE: Self = Self(S_FALSE);
+    pub const S_OK: Self = Self(S_OK);
+}
+
+const CODES: &[(HResult, &str, &str)] = &[
+    (HResult::S_OK, "S_OK", "The operation succeeded."),
+    (
+        HResult::S_FALSE,
+        "S_FALSE",
+        "The operation succeeded with a false result.",
+    ),
+    (HResult::E_ABORT, "E_ABORT", "The operation was aborted."),
+    (
+        HResult::E_ACCESSDENIED,
+        "E_ACCESSDENIED",
+        "Access is denied.",
+    ),
+    (HResult::E_FAIL, "E_FAIL", "Unspecified error."),
+    (HResult::E_HANDLE, "E_HANDLE", "The handle is invalid."),
+    (
+        HResult::E_INVALIDARG,
+        "E_INVALIDARG",
+        "One or more arguments are invalid.",
+    ),
+    (
+        HResult::E_NOINTERFACE,
+        "E_NOINTERFACE",
+        "No such interface is supported.",
+    ),
+    (HResult::E_NOTIMPL, "E_NOTIMPL", "Not implemented."),
+    (
+        HResult::E_OUTOFMEMORY,
+        "E_OUTOFMEMORY",
+        "Not enough memory resources are available.",
+    ),
+    (HResult::E_POINTER, "E_POINTER", "The pointer is invalid."),
+    (
+        HResult::E_UNEXPECTED,
+        "E_UNEXPECTED",
+        "Catastrophic failure.",
+    ),
+    (
+        HResult::DXGI_STATUS_OCCLUDED,
+        "DXGI_STATUS_OCCLUDED",
+        "The window content is not visible.",
+    ),
+    (
+        HResult::DXGI_ERROR_ACCESS_DENIED,
+        "DXGI_ERROR_ACCESS_DENIED",
+        "Access to a shared resource is denied.",
+    ),
+    (
+        HResult::DXGI_ERROR_ACCESS_LOST,
+        "DXGI_ERROR_ACCESS_LOST",
+        "The desktop duplication interface is invalid.",
+    ),
+    (
+        HResult::DXGI_ERROR_DEVICE_HUNG,
+        "DXGI_ERROR_DEVICE_HUNG",
+        "The device failed due to a badly formed command.",
+    ),
+    (
+        HResult::DXGI_ERROR_DEVICE_REMOVED,
+        "DXGI_ERROR_DEVICE_REMOVED",
+        "The video card has been physically removed or a driver upgrade occurred.",
+    ),
+    (
+        HResult::DXGI_ERROR_DEVICE_RESET,
+        "DXGI_ERROR_DEVICE_RESET",
+        "The device failed due to a badly formed command.",
+    ),
+    (
+        HResult::DXGI_ERROR_DRIVER_INTERNAL_ERROR,
+        "DXGI_ERROR_DRIVER_INTERNAL_ERROR",
+        "The driver encountered a problem.",
+    ),
+    (
+        HResult::DXGI_ERROR_INVALID_CALL,
+        "DXGI_ERROR_INVALID_CALL",
+        "The application provided invalid parameter data.",
+    ),
+    (
+        HResult::DXGI_ERROR_MORE_DATA,
+        "DXGI_ERROR_MORE_DATA",
+        "The buffer is not big enough to hold the requested data.",
+    ),
+    (
+        HResult::DXGI_ERROR_NOT_CURRENTLY_AVAILABLE,
+        "DXGI_ERROR_NOT_CURRENTLY_AVAILABLE",
+        "The resource or request is not currently available.",
+    ),
+    (
+        HResult::DXGI_ERROR_NOT_FOUND,
+        "DXGI_ERROR_NOT_FOUND",
+        "The object was not found.",
+    ),
+    (
+        HResult::DXGI_ERROR_SDK_COMPONENT_MISSING,
+        "DXGI_ERROR_SDK_COMPONENT_MISSING",
+        "The operation depends on an SDK component that is missing.",
+    ),
+    (
+        HResult::DXGI_ERROR_UNSUPPORTED,
+        "DXGI_ERROR_UNSUPPORTED",
+        "The requested functionality is not supported by the device or the driver.",
+    ),
+    (
+        HResult::DXGI_ERROR_WAIT_TIMEOUT,
+        "DXGI_ERROR_WAIT_TIMEOUT",
+        "The time-out interval elapsed.",
+    ),
+    (
+        HResult::DXGI_ERROR_WAS_STILL_DRAWING,
+        "DXGI_ERROR_WAS_STILL_DRAWING",
+        "The GPU was busy drawing.",
+    ),
+    (
+        HResult::D3D11_ERROR_DEFERRED_CONTEXT_MAP_WITHOUT_INITIAL_DISCARD,
+        "D3D11_ERROR_DEFERRED_CONTEXT_MAP_WITHOUT_INITIAL_DISCARD",
+        "The first call to Map on a deferred context did not use D3D11_MAP_WRITE_DISCARD.",
+    ),
+    (
+        HResult::D3D11_ERROR_FILE_NOT_FOUND,
+        "D3D11_ERROR_FILE_NOT_FOUND",
+        "The file was not found.",
+    ),
+    (
+        HResult::D3D11_ERROR_TOO_MANY_UNIQUE_STATE_OBJECTS,
+        "D3D11_ERROR_TOO_MANY_UNIQUE_STATE_OBJECTS",
+        "There are too many unique instances of a particular type of state object.",
+    ),
+    (
+        HResult::D3D11_ERROR_TOO_MANY_UNIQUE_VIEW_OBJECTS,
+        "D3D11_ERROR_TOO_MANY_UNIQUE_VIEW_OBJECTS",
+        "There are too many unique instances of a particular type of view object.",
+    ),
+];
+
+const WIN32_CODES: &[(u32, &str, &str)] = &[
+    (ERROR_SUCCESS, "ERROR_SUCCESS", "The operation completed successfully."),
+    (ERROR_INVALID_FUNCTION, "ERROR_INVALID_FUNCTION", "Incorrect function."),
+    (ERROR_FILE_NOT_FOUND, "ERROR_FILE_NOT_FOUND", "The system cannot find the file specified."),
+    (ERROR_PATH_NOT_FOUND, "ERROR_PATH_NOT_FOUND", "The system cannot find the path specified."),
+    (ERROR_TOO_MANY_OPEN_FILES, "ERROR_TOO_MANY_OPEN_FILES", "The system cannot open the file."),
+    (ERROR_ACCESS_DENIED, "ERROR_ACCESS_DENIED", "Access is denied."),
+    (ERROR_INVALID_HANDLE, "ERROR_INVALID_HANDLE", "The handle is invalid."),
+    (ERROR_NOT_ENOUGH_MEMORY, "ERROR_NOT_ENOUGH_MEMORY", "Not enough memory resources are available to process this command."),
+    (ERROR_INVALID_DATA, "ERROR_INVALID_DATA", "The data is invalid."),
+    (ERROR_OUTOFMEMORY, "ERROR_OUTOFMEMORY", "Not enough memory resources are available to complete this operation."),
+    (ERROR_NO_MORE_FILES, "ERROR_NO_MORE_FILES", "There are no more files."),
+    (ERROR_NOT_READY, "ERROR_NOT_READY", "The device is not ready."),
+    (ERROR_BAD_LENGTH, "ERROR_BAD_LENGTH", "The program issued a command but the command length is incorrect."),
+    (ERROR_SHARING_VIOLATION, "ERROR_SHARING_VIOLATION", "The process cannot access the file because it is being used by another process."),
+    (ERROR_HANDLE_EOF, "ERROR_HANDLE_EOF", "Reached the end of the file."),
+    (ERROR_NOT_SUPPORTED, "ERROR_NOT_SUPPORTED", "The request is not supported."),
+    (ERROR_FILE_EXISTS, "ERROR_FILE_EXISTS", "The file exists."),
+    (ERROR_INVALID_PARAMETER, "ERROR_INVALID_PARAMETER", "The parameter is incorrect."),
+    (ERROR_BROKEN_PIPE, "ERROR_BROKEN_PIPE", "The pipe has been ended."),
+    (ERROR_SEM_TIMEOUT, "ERROR_SEM_TIMEOUT", "The semaphore timeout period has expired."),
+    (ERROR_INSUFFICIENT_BUFFER, "ERROR_INSUFFICIENT_BUFFER", "The data area passed to a system call is too small."),
+    (ERROR_INVALID_NAME, "ERROR_INVALID_NAME", "The filename, directory name, or volume label syntax is incorrect."),
+    (ERROR_MOD_NOT_FOUND, "ERROR_MOD_NOT_FOUND", "The specified module could not be found."),
+    (ERROR_PROC_NOT_FOUND, "ERROR_PROC_NOT_FOUND", "The specified procedure could not be found."),
+    (ERROR_ALREADY_EXISTS, "ERROR_ALREADY_EXISTS", "Cannot create a file when that file already exists."),
+    (ERROR_ENVVAR_NOT_FOUND, "ERROR_ENVVAR_NOT_FOUND", "The system could not find the environment option that was entered."),
+    (ERROR_PIPE_BUSY, "ERROR_PIPE_BUSY", "All pipe instances are busy."),
+    (ERROR_NO_DATA, "ERROR_NO_DATA", "The pipe is being closed."),
+    (ERROR_PIPE_NOT_CONNECTED, "ERROR_PIPE_NOT_CONNECTED", "No process is on the other end of the pipe."),
+    (ERROR_MORE_DATA, "ERROR_MORE_DATA", "More data is available."),
+    (WAIT_TIMEOUT, "WAIT_TIMEOUT", "The wait operation timed out."),
+    (ERROR_NO_MORE_ITEMS, "ERROR_NO_MORE_ITEMS", "No more data is available."),
+    (ERROR_PARTIAL_COPY, "ERROR_PARTIAL_COPY", "Only part of a ReadProcessMemory or WriteProcessMemory request was completed."),
+    (ERROR_INVALID_ADDRESS, "ERROR_INVALID_ADDRESS", "Attempt to access invalid address."),
+    (ERROR_ELEVATION_REQUIRED, "ERROR_ELEVATION_REQUIRED", "The requested operation requires elevation."),
+    (ERROR_OPERATION_ABORTED, "ERROR_OPERATION_ABORTED", "The I/O operation has been aborted because of either a thread exit or an application request."),
+    (ERROR_IO_INCOMPLETE, "ERROR_IO_INCOMPLETE", "Overlapped I/O event is not in a signaled state."),
+    (ERROR_IO_PENDING, "ERROR_IO_PENDING", "Overlapped I/O operation is in progress."),
+    (ERROR_NOACCESS, "ERROR_NOACCESS", "Invalid access to memory location."),
+    (ERROR_INVALID_FLAGS, "ERROR_INVALID_FLAGS", "Invalid flags."),
+    (ERROR_NO_TOKEN, "ERROR_NO_TOKEN", "An attempt was made to reference a token that does not exist."),
+    (ERROR_SERVICE_ALREADY_RUNNING, "ERROR_SERVICE_ALREADY_RUNNING", "An instance of the service is already running."),
+    (ERROR_SERVICE_DOES_NOT_EXIST, "ERROR_SERVICE_DOES_NOT_EXIST", "The specified service does not exist as an installed service."),
+    (ERROR_SERVICE_NOT_ACTIVE, "ERROR_SERVICE_NOT_ACTIVE", "The service has not been started."),
+    (ERROR_FAILED_SERVICE_CONTROLLER_CONNECT, "ERROR_FAILED_SERVICE_CONTROLLER_CONNECT", "The service process could not connect to the service controller."),
+    (ERROR_NOT_FOUND, "ERROR_NOT_FOUND", "Element not found."),
+    (ERROR_NOT_ALL_ASSIGNED, "ERROR_NOT_ALL_ASSIGNED", "Not all privileges or groups referenced are assigned to the caller."),
+    (ERROR_NO_SUCH_PRIVILEGE, "ERROR_NO_SUCH_PRIVILEGE", "A specified privilege does not exist."),
+    (ERROR_PRIVILEGE_NOT_HELD, "ERROR_PRIVILEGE_NOT_HELD", "A required privilege is not held by the client."),
+    (ERROR_INVALID_WINDOW_HANDLE, "ERROR_INVALID_WINDOW_HANDLE", "Invalid window handle."),
+    (ERROR_INVALID_HOOK_HANDLE, "ERROR_INVALID_HOOK_HANDLE", "Invalid hook handle."),
+    (ERROR_CANNOT_FIND_WND_CLASS, "ERROR_CANNOT_FIND_WND_CLASS", "Cannot find window class."),
+    (ERROR_CLASS_ALREADY_EXISTS, "ERROR_CLASS_ALREADY_EXISTS", "Class already exists."),
+    (ERROR_CLASS_DOES_NOT_EXIST, "ERROR_CLASS_DOES_NOT_EXIST", "Class does not exist."),
+    (ERROR_HOOK_NEEDS_HMOD, "ERROR_HOOK_NEEDS_HMOD", "Cannot set nonlocal hook without a module handle."),
+    (ERROR_INVALID_THREAD_ID, "ERROR_INVALID_THREAD_ID", "Invalid thread identifier."),
+    (ERROR_TIMEOUT, "ERROR_TIMEOUT", "This operation returned because the timeout period expired."),
+];
+
+/// The name of a Win32 error code, such as `ERROR_ACCESS_DENIED`.
+pub fn win32_name(code: u32) -> Option<&'static str> {
+    WIN32_CODES
+        .iter()
+        .find(|&&(value, ..)| value == code)
+        .map(|&(_, name, _)| name)
+}
+
+/// The message of a Win32 error code, without calling into the system.
+pub fn win32_message(code: u32) -> Option<&'static str> {
+    WIN32_CODES
+        .iter()
+        .find(|&&(value, ..)| value == code)
+        .map(|&(.., message)| message)
+}
+
+impl HResult {
+    /// Converts to `Ok` on success, including the `S_FALSE` like codes.
+    #[inline]
+    pub fn ok(self) -> Result<(), Self> {
+        if self.is_success() {
+            Ok(())
+        } else {
+            Err(self)
+        }
+    }
+
+    #[inline]
+    pub fn is_success(self) -> bool {
+        self.0 >= 0
+    }
+
+    #[inline]
+    pub fn is_failure(self) -> bool {
+        self.0 < 0
+    }
+
+    #[inline]
+    pub fn facility(self) -> u16 {
+        (self.0 as u32 >> 16) as u16 & 0x1FFF
+    }
+
+    #[inline]
+    pub fn code(self) -> u16 {
+        self.0 as u16
+    }
+
+    pub fn name(self) -> Option<&'static str> {
+        CODES
+            .iter()
+            .find(|&&(code, ..)| code == self)
+            .map(|&(_, name, _)| name)
+    }
+
+    /// The message of a named code, or else the system one.
+    pub fn message(self) -> Option<String> {
+        CODES
+            .iter()
+            .find(|&&(code, ..)| code == self)
+            .map(|&(.., message)| message)
+            .or_else(|| self.win32_code().and_then(win32_message))
+            .map(ToOwned::to_owned)
+            .or_else(|| self.system_message())
+    }
+
+    /// The Win32 error code of a `HRESULT_FROM_WIN32` result.
+    #[inline]
+    pub fn win32_code(self) -> Option<u32> {
+        (self.is_failure() && self.facility() == FACILITY_WIN32 as u16).then(|| self.code() as u32)
+    }
+
+    #[cfg(all(windows, feature = "winbase"))]
+    fn system_message(self) -> Option<String> {
+        crate::um::winbase::format_message()
+            .message_id(self.0 as _)
+            .call()
+            .ok()
+    }
+
+    #[cfg(not(all(windows, feature = "winbase")))]
+    fn system_message(self) -> Option<String> {
+        None
+    }
+}
+
+impl Debug for HResult {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let mut debug_tuple = f.debug_tuple("HResult");
+        match self.name() {
+            Some(name) => debug_tuple.field(&format_args!("{}", name)),
+            None => debug_tuple.field(&format_args!("{:#010x}", self.0)),
+        };
+        debug_tuple.finish()
+    }
+}
+
+impl Display for HResult {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "{:#010x}", self.0)?;
+        if let Some(name) = self.name() {
+            write!(f, " {}", name)?;
+        }
+        if let Some(message) = self.message() {
+            write!(f, ": {}", message)?;
+        }
+        Ok(())
+    }
+}
+
+impl Error for HResult {}
+
+#[cfg(test)]
+mod tests {
+    use super::{win32_message, win32_name, HResult};
+
+    #[test]
+    fn decode() {
+        let device_removed = HResult::from(0x887A0005_u32 as i32);
+        assert_eq!(HResult::DXGI_ERROR_DEVICE_REMOVED, device_removed);
+        assert!(device_removed.is_failure());
+        assert_eq!(0x87A, device_removed.facility());
+        assert_eq!(5, device_removed.code());
+        assert_eq!(Err(device_removed), device_removed.ok());
+        assert_eq!(Ok(()), HResult::S_FALSE.ok());
+        assert_eq!(7, HResult::E_INVALIDARG.facility());
+        assert_eq!(0x57, HResult::E_INVALIDARG.code());
+    }
+
+    #[test]
+    fn win32() {
+        let file_not_found = HResult::from(0x80070002_u32 as i32);
+        assert_eq!(Some(2), file_not_found.win32_code());
+        assert_eq!(Some(5), HResult::E_ACCESSDENIED.win32_code());
+        assert_eq!(None, HResult::E_FAIL.win32_code());
+        assert_eq!(Some("ERROR_FILE_NOT_FOUND"), win32_name(2));
+        assert_eq!(Some("Access is denied."), win32_message(5));
+        assert_eq!(None, win32_name(0xFFFF));
+        assert_eq!(
+            "0x80070002: The system cannot find the file specified.",
+            file_not_found.to_string(),
+        );
+    }
+
+    #[test]
+    fn format() {
+        assert_eq!(
+            "0x80070057 E_INVALIDARG: One or more arguments are invalid.",
+            HResult::E_INVALIDARG.to_string(),
+        );
+        assert_eq!(
+            "HResult(DXGI_ERROR_DEVICE_REMOVED)",
+            format!("{:?}", HResult::DXGI_ERROR_DEVICE_REMOVED),
+        );
+        assert_eq!(
+            "HResult(0x88990001)",
+            format!("{:?}", HResult::from(0x88990001_u32 as i32)),
+        );
+        assert_eq!(None, HResult::from(0x88990001_u32 as i32).name());
+    }
+}
@@ -1,19 +1,19 @@
-use anyhow::{ensure, Result};
-use std::io;
+use crate::{error::ensure, r#macro::FnOnce, utils::Call, Error, Result};
 use winapi::shared::minwindef::FALSE;
 
 /// Allocate console.
+#[derive(FnOnce)]
 pub struct AllocConsole;
 
-impl FnOnce<()> for AllocConsole {
+impl Call for AllocConsole {
     type Output = Result<()>;
 
-    extern "rust-call" fn call_once(self, _args: ()) -> Self::Output {
+    fn call(self) -> Self::Output {
         use winapi::um::consoleapi::AllocConsole;
 
         unsafe {
             let r#return = AllocConsole();
-            ensure!(r#return != FALSE, io::Error::last_os_error());
+            ensure!(r#return != FALSE, Error::last_os_error());
             Ok(())
         }
     }
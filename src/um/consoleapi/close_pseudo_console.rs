@@ -1,4 +1,8 @@
-use crate::{r#macro::FnOnce, um::wincontypes::PseudoConsoleHandle, utils::AsStrictRawHandle};
+use crate::{
+    r#macro::FnOnce,
+    um::wincontypes::PseudoConsoleHandle,
+    utils::{AsStrictRawHandle, Call},
+};
 use typed_builder::TypedBuilder;
 
 /// Close pseudo console.
@@ -7,10 +11,10 @@ pub struct ClosePseudoConsole {
     handle: PseudoConsoleHandle,
 }
 
-impl FnOnce<()> for ClosePseudoConsole {
+impl Call for ClosePseudoConsole {
     type Output = ();
 
-    extern "rust-call" fn call_once(self, _args: ()) -> Self::Output {
+    fn call(self) -> Self::Output {
         use winapi::um::consoleapi::ClosePseudoConsole;
 
         #[allow(non_snake_case)]
@@ -1,10 +1,12 @@
 use crate::{
+    error::ensure,
     r#macro::FnOnce,
-    shared::ntdef::Handle,
+    shared::{ntdef::OwnedHandle, winerror::HResult},
     um::wincontypes::{Coordinates, PseudoConsoleHandle},
+    utils::Call,
+    Result,
 };
-use anyhow::{ensure, Result};
-use std::{io, mem::MaybeUninit, os::windows::io::AsRawHandle};
+use std::{mem::MaybeUninit, os::windows::io::AsRawHandle};
 use typed_builder::TypedBuilder;
 use winapi::shared::winerror::S_OK;
 
@@ -12,18 +14,18 @@ use winapi::shared::winerror::S_OK;
 #[derive(FnOnce, TypedBuilder)]
 pub struct CreatePseudoConsole {
     size: Coordinates,
-    input: Handle,
-    output: Handle,
+    input: OwnedHandle,
+    output: OwnedHandle,
     #[builder(default)]
     flags: u32,
     #[builder(default = MaybeUninit::zeroed(), setter(skip))]
     handle: MaybeUninit<PseudoConsoleHandle>,
 }
 
-impl FnOnce<()> for CreatePseudoConsole {
+impl Call for CreatePseudoConsole {
     type Output = Result<PseudoConsoleHandle>;
 
-    extern "rust-call" fn call_once(mut self, _args: ()) -> Self::Output {
+    fn call(mut self) -> Self::Output {
         use winapi::um::consoleapi::CreatePseudoConsole;
 
         #[allow(non_snake_case)]
@@ -34,7 +36,7 @@ impl FnOnce<()> for CreatePseudoConsole {
             let dwFlags = self.flags;
             let phPC = self.handle.as_mut_ptr() as _;
             let r#return = CreatePseudoConsole(size, hInput, hOutput, dwFlags, phPC);
-            ensure!(r#return != S_OK, io::Error::from_raw_os_error(r#return));
+            ensure!(r#return == S_OK, HResult::from(r#return));
             Ok(self.handle.assume_init())
         }
     }
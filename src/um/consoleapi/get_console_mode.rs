@@ -0,0 +1,35 @@
+use super::ConsoleMode;
+use crate::{
+    error::ensure, r#macro::FnOnce, shared::ntdef::BorrowedHandle, utils::Call, Error, Result,
+};
+use std::os::windows::io::AsRawHandle;
+use typed_builder::TypedBuilder;
+use winapi::shared::minwindef::FALSE;
+
+/// Get console mode.
+#[derive(FnOnce, TypedBuilder)]
+pub struct GetConsoleMode<'a> {
+    #[builder(setter(into))]
+    console_handle: BorrowedHandle<'a>,
+    #[builder(default, setter(skip))]
+    mode: u32,
+}
+
+impl Call for GetConsoleMode<'_> {
+    type Output = Result<ConsoleMode>;
+
+    fn call(mut self) -> Self::Output {
+        use winapi::um::consoleapi::GetConsoleMode;
+
+        #[allow(non_snake_case)]
+        unsafe {
+            let hConsoleHandle = self.console_handle.as_raw_handle();
+            let lpMode = &mut self.mode;
+            let r#return = GetConsoleMode(hConsoleHandle, lpMode);
+            ensure!(r#return != FALSE, Error::last_os_error());
+            // Keeps the flags unknown to `ConsoleMode`, so the mode can be set
+            // back as it was.
+            Ok(ConsoleMode::from_bits_unchecked(self.mode))
+        }
+    }
+}
@@ -1,21 +1,23 @@
-use crate::{r#macro::FnOnce, shared::ntdef::Handle};
-use anyhow::{ensure, Result};
-use std::{io, os::windows::io::AsRawHandle};
+use crate::{
+    error::ensure, r#macro::FnOnce, shared::ntdef::BorrowedHandle, utils::Call, Error, Result,
+};
+use std::os::windows::io::AsRawHandle;
 use typed_builder::TypedBuilder;
 use winapi::shared::minwindef::FALSE;
 
 /// Get number of console input events.
 #[derive(FnOnce, TypedBuilder)]
 pub struct GetNumberOfConsoleInputEvents<'a> {
-    console_input: &'a Handle,
+    #[builder(setter(into))]
+    console_input: BorrowedHandle<'a>,
     #[builder(default, setter(skip))]
     number_of_events: u32,
 }
 
-impl FnOnce<()> for GetNumberOfConsoleInputEvents<'_> {
+impl Call for GetNumberOfConsoleInputEvents<'_> {
     type Output = Result<u32>;
 
-    extern "rust-call" fn call_once(mut self, _args: ()) -> Self::Output {
+    fn call(mut self) -> Self::Output {
         use winapi::um::consoleapi::GetNumberOfConsoleInputEvents;
 
         #[allow(non_snake_case)]
@@ -23,7 +25,7 @@ impl FnOnce<()> for GetNumberOfConsoleInputEvents<'_> {
             let hConsoleInput = self.console_input.as_raw_handle();
             let lpcNumberOfEvents = &mut self.number_of_events;
             let r#return = GetNumberOfConsoleInputEvents(hConsoleInput, lpcNumberOfEvents);
-            ensure!(r#return != FALSE, io::Error::last_os_error());
+            ensure!(r#return != FALSE, Error::last_os_error());
             Ok(self.number_of_events)
         }
     }
@@ -2,14 +2,27 @@ pub(crate) use self::{
     alloc_console::AllocConsole,
     close_pseudo_console::{ClosePseudoConsole, ClosePseudoConsoleBuilder},
     create_pseudo_console::{CreatePseudoConsole, CreatePseudoConsoleBuilder},
+    get_console_mode::{GetConsoleMode, GetConsoleModeBuilder},
     get_number_of_console_input_events::{
         GetNumberOfConsoleInputEvents, GetNumberOfConsoleInputEventsBuilder,
     },
+    read_console_input::{ReadConsoleInput, ReadConsoleInputBuilder},
+    resize_pseudo_console::{ResizePseudoConsole, ResizePseudoConsoleBuilder},
+    set_console_ctrl_handler::{SetConsoleCtrlHandler, SetConsoleCtrlHandlerBuilder},
+    set_console_mode::{SetConsoleMode, SetConsoleModeBuilder},
+};
+use crate::{utils::Call, Result};
+use bitflags::bitflags;
+use winapi::um::wincon::{
+    DISABLE_NEWLINE_AUTO_RETURN, ENABLE_AUTO_POSITION, ENABLE_ECHO_INPUT, ENABLE_EXTENDED_FLAGS,
+    ENABLE_INSERT_MODE, ENABLE_LINE_INPUT, ENABLE_LVB_GRID_WORLDWIDE, ENABLE_MOUSE_INPUT,
+    ENABLE_PROCESSED_INPUT, ENABLE_PROCESSED_OUTPUT, ENABLE_QUICK_EDIT_MODE,
+    ENABLE_VIRTUAL_TERMINAL_INPUT, ENABLE_VIRTUAL_TERMINAL_PROCESSING, ENABLE_WINDOW_INPUT,
+    ENABLE_WRAP_AT_EOL_OUTPUT,
 };
-use anyhow::Result;
 
 pub fn alloc_console() -> Result<()> {
-    AllocConsole()
+    AllocConsole.call()
 }
 
 pub fn close_pseudo_console() -> ClosePseudoConsoleBuilder<((),)> {
@@ -20,16 +33,60 @@ pub fn create_pseudo_console() -> CreatePseudoConsoleBuilder<((), (), (), ())> {
     CreatePseudoConsole::builder()
 }
 
+pub fn get_console_mode<'a>() -> GetConsoleModeBuilder<'a, ((),)> {
+    GetConsoleMode::builder()
+}
+
 pub fn get_number_of_console_input_events<'a>() -> GetNumberOfConsoleInputEventsBuilder<'a, ((),)> {
     GetNumberOfConsoleInputEvents::builder()
 }
 
-enum Flags {
-    Standard,
-    Inherit,
+pub fn read_console_input<'a>() -> ReadConsoleInputBuilder<'a, ((), ())> {
+    ReadConsoleInput::builder()
+}
+
+pub fn resize_pseudo_console() -> ResizePseudoConsoleBuilder<((), ())> {
+    ResizePseudoConsole::builder()
+}
+
+pub fn set_console_ctrl_handler() -> SetConsoleCtrlHandlerBuilder<((), ())> {
+    SetConsoleCtrlHandler::builder()
+}
+
+pub fn set_console_mode<'a>() -> SetConsoleModeBuilder<'a, ((), ())> {
+    SetConsoleMode::builder()
+}
+
+bitflags! {
+    /// Console mode.
+    ///
+    /// The input and output modes share bits, their meaning depends on whether
+    /// the handle is an input or a screen buffer.
+    pub struct ConsoleMode: u32 {
+        const PROCESSED_INPUT = ENABLE_PROCESSED_INPUT;
+        const LINE_INPUT = ENABLE_LINE_INPUT;
+        const ECHO_INPUT = ENABLE_ECHO_INPUT;
+        const WINDOW_INPUT = ENABLE_WINDOW_INPUT;
+        const MOUSE_INPUT = ENABLE_MOUSE_INPUT;
+        const INSERT_MODE = ENABLE_INSERT_MODE;
+        const QUICK_EDIT_MODE = ENABLE_QUICK_EDIT_MODE;
+        const EXTENDED_FLAGS = ENABLE_EXTENDED_FLAGS;
+        const AUTO_POSITION = ENABLE_AUTO_POSITION;
+        const VIRTUAL_TERMINAL_INPUT = ENABLE_VIRTUAL_TERMINAL_INPUT;
+        const PROCESSED_OUTPUT = ENABLE_PROCESSED_OUTPUT;
+        const WRAP_AT_EOL_OUTPUT = ENABLE_WRAP_AT_EOL_OUTPUT;
+        const VIRTUAL_TERMINAL_PROCESSING = ENABLE_VIRTUAL_TERMINAL_PROCESSING;
+        const DISABLE_NEWLINE_AUTO_RETURN = DISABLE_NEWLINE_AUTO_RETURN;
+        const LVB_GRID_WORLDWIDE = ENABLE_LVB_GRID_WORLDWIDE;
+    }
 }
 
 mod alloc_console;
 mod close_pseudo_console;
 mod create_pseudo_console;
+mod get_console_mode;
 mod get_number_of_console_input_events;
+mod read_console_input;
+mod resize_pseudo_console;
+mod set_console_ctrl_handler;
+mod set_console_mode;
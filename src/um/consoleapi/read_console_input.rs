@@ -0,0 +1,51 @@
+use crate::{
+    error::ensure,
+    r#macro::FnOnce,
+    shared::ntdef::BorrowedHandle,
+    um::wincontypes::{InputEvent, InputRecord},
+    utils::Call,
+    Error, Result,
+};
+use std::{mem::zeroed, os::windows::io::AsRawHandle};
+use typed_builder::TypedBuilder;
+use winapi::shared::minwindef::FALSE;
+
+/// Read console input.
+///
+/// Blocks until at least one event is available and reads up to `length`
+/// events. Each record is decoded on its own, the ones of an unknown event type
+/// are skipped.
+#[derive(FnOnce, TypedBuilder)]
+pub struct ReadConsoleInput<'a> {
+    #[builder(setter(into))]
+    console_input: BorrowedHandle<'a>,
+    #[builder(default = 1)]
+    length: u32,
+    #[builder(default, setter(skip))]
+    number_of_events_read: u32,
+}
+
+impl Call for ReadConsoleInput<'_> {
+    type Output = Result<Vec<InputEvent>>;
+
+    fn call(mut self) -> Self::Output {
+        use winapi::um::consoleapi::ReadConsoleInputW;
+
+        let mut buffer: Vec<InputRecord> = vec![unsafe { zeroed() }; self.length as _];
+        #[allow(non_snake_case)]
+        unsafe {
+            let hConsoleInput = self.console_input.as_raw_handle();
+            let lpBuffer = buffer.as_mut_ptr() as _;
+            let nLength = self.length;
+            let lpNumberOfEventsRead = &mut self.number_of_events_read;
+            let r#return =
+                ReadConsoleInputW(hConsoleInput, lpBuffer, nLength, lpNumberOfEventsRead);
+            ensure!(r#return != FALSE, Error::last_os_error());
+        }
+        buffer.truncate(self.number_of_events_read as _);
+        Ok(buffer
+            .iter()
+            .filter_map(|input_record| input_record.event().ok())
+            .collect())
+    }
+}
@@ -0,0 +1,34 @@
+use crate::{
+    error::ensure,
+    r#macro::FnOnce,
+    shared::winerror::HResult,
+    um::wincontypes::{Coordinates, PseudoConsoleHandle},
+    utils::{AsStrictRawHandle, Call},
+    Result,
+};
+use typed_builder::TypedBuilder;
+use winapi::shared::winerror::S_OK;
+
+/// Resize pseudo console.
+#[derive(FnOnce, TypedBuilder)]
+pub struct ResizePseudoConsole {
+    handle: PseudoConsoleHandle,
+    size: Coordinates,
+}
+
+impl Call for ResizePseudoConsole {
+    type Output = Result<()>;
+
+    fn call(self) -> Self::Output {
+        use winapi::um::consoleapi::ResizePseudoConsole;
+
+        #[allow(non_snake_case)]
+        unsafe {
+            let hPC = self.handle.as_strict_raw_handle();
+            let size = *self.size;
+            let r#return = ResizePseudoConsole(hPC, size);
+            ensure!(r#return == S_OK, HResult::from(r#return));
+            Ok(())
+        }
+    }
+}
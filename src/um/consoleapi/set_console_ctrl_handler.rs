@@ -0,0 +1,34 @@
+use crate::{error::ensure, r#macro::FnOnce, utils::Call, Error, Result};
+use typed_builder::TypedBuilder;
+use winapi::{
+    shared::minwindef::{FALSE, TRUE},
+    um::wincon::PHANDLER_ROUTINE,
+};
+
+/// Set console ctrl handler.
+///
+/// Without a `handler_routine` the calling process ignores (`add`) or stops
+/// ignoring CTRL+C.
+#[derive(FnOnce, TypedBuilder)]
+pub struct SetConsoleCtrlHandler {
+    #[builder(default)]
+    handler_routine: PHANDLER_ROUTINE,
+    add: bool,
+}
+
+impl Call for SetConsoleCtrlHandler {
+    type Output = Result<()>;
+
+    fn call(self) -> Self::Output {
+        use winapi::um::consoleapi::SetConsoleCtrlHandler;
+
+        #[allow(non_snake_case)]
+        unsafe {
+            let HandlerRoutine = self.handler_routine;
+            let Add = if self.add { TRUE } else { FALSE };
+            let r#return = SetConsoleCtrlHandler(HandlerRoutine, Add);
+            ensure!(r#return != FALSE, Error::last_os_error());
+            Ok(())
+        }
+    }
+}
@@ -0,0 +1,32 @@
+use super::ConsoleMode;
+use crate::{
+    error::ensure, r#macro::FnOnce, shared::ntdef::BorrowedHandle, utils::Call, Error, Result,
+};
+use std::os::windows::io::AsRawHandle;
+use typed_builder::TypedBuilder;
+use winapi::shared::minwindef::FALSE;
+
+/// Set console mode.
+#[derive(FnOnce, TypedBuilder)]
+pub struct SetConsoleMode<'a> {
+    #[builder(setter(into))]
+    console_handle: BorrowedHandle<'a>,
+    mode: ConsoleMode,
+}
+
+impl Call for SetConsoleMode<'_> {
+    type Output = Result<()>;
+
+    fn call(self) -> Self::Output {
+        use winapi::um::consoleapi::SetConsoleMode;
+
+        #[allow(non_snake_case)]
+        unsafe {
+            let hConsoleHandle = self.console_handle.as_raw_handle();
+            let dwMode = self.mode.bits();
+            let r#return = SetConsoleMode(hConsoleHandle, dwMode);
+            ensure!(r#return != FALSE, Error::last_os_error());
+            Ok(())
+        }
+    }
+}
@@ -1,4 +1,4 @@
-use crate::r#macro::FnOnce;
+use crate::{r#macro::FnOnce, utils::Call};
 use std::ffi::c_void;
 use typed_builder::TypedBuilder;
 use winapi::um::d3dcommon::ID3DBlob;
@@ -10,14 +10,10 @@ pub struct GetBufferPointer {
     blob: ComPtr<ID3DBlob>,
 }
 
-impl FnOnce<()> for GetBufferPointer {
+impl Call for GetBufferPointer {
     type Output = *mut c_void;
 
-    extern "rust-call" fn call_once(self, _args: ()) -> Self::Output {
-        #[allow(non_snake_case)]
-        unsafe {
-            let r#return = self.blob.GetBufferPointer();
-            r#return
-        }
+    fn call(self) -> Self::Output {
+        unsafe { self.blob.GetBufferPointer() }
     }
 }
@@ -1,4 +1,4 @@
-use crate::r#macro::FnOnce;
+use crate::{r#macro::FnOnce, utils::Call};
 use typed_builder::TypedBuilder;
 use winapi::um::d3dcommon::ID3DBlob;
 use wio::com::ComPtr;
@@ -9,14 +9,10 @@ pub struct GetBufferSize {
     blob: ComPtr<ID3DBlob>,
 }
 
-impl FnOnce<()> for GetBufferSize {
+impl Call for GetBufferSize {
     type Output = usize;
 
-    extern "rust-call" fn call_once(self, _args: ()) -> Self::Output {
-        #[allow(non_snake_case)]
-        unsafe {
-            let r#return = self.blob.GetBufferSize();
-            r#return
-        }
+    fn call(self) -> Self::Output {
+        unsafe { self.blob.GetBufferSize() }
     }
 }
@@ -14,17 +14,17 @@ pub trait Blob {
 
 impl Blob for ComPtr<ID3DBlob> {
     fn buffer(&self) -> &[u8] {
-        let data = GetBufferPointer::builder().blob(self.clone()).build()();
-        let len = GetBufferSize::builder().blob(self.clone()).build()();
+        let data = GetBufferPointer::builder().blob(self.clone()).call();
+        let len = GetBufferSize::builder().blob(self.clone()).call();
         unsafe { from_raw_parts(data as _, len) }
     }
 
     fn get_buffer_pointer(&self) -> *mut c_void {
-        GetBufferPointer::builder().blob(self.clone()).build()()
+        GetBufferPointer::builder().blob(self.clone()).call()
     }
 
     fn get_buffer_size(&self) -> usize {
-        GetBufferSize::builder().blob(self.clone()).build()()
+        GetBufferSize::builder().blob(self.clone()).call()
     }
 }
 
@@ -1,5 +1,5 @@
 use super::Description;
-use crate::r#macro::FnOnce;
+use crate::{r#macro::FnOnce, utils::Call};
 use std::mem::MaybeUninit;
 use typed_builder::TypedBuilder;
 use winapi::um::d3d11::ID3D11Buffer;
@@ -13,10 +13,10 @@ pub struct GetDesc {
     description: MaybeUninit<Description>,
 }
 
-impl FnOnce<()> for GetDesc {
+impl Call for GetDesc {
     type Output = Description;
 
-    extern "rust-call" fn call_once(mut self, _args: ()) -> Self::Output {
+    fn call(mut self) -> Self::Output {
         #[allow(non_snake_case)]
         unsafe {
             let pDesc = self.description.as_mut_ptr() as _;
@@ -1,4 +1,5 @@
 use self::get_desc::GetDesc;
+use crate::r#macro::Accessors;
 use derive_more::{Deref, DerefMut, From, Into};
 use winapi::um::d3d11::{ID3D11Buffer, D3D11_BUFFER_DESC, D3D11_USAGE};
 use wio::com::ComPtr;
@@ -10,45 +11,24 @@ pub trait Buffer {
 
 impl Buffer for ComPtr<ID3D11Buffer> {
     fn get_description(&self) -> Description {
-        GetDesc::builder().buffer(self.clone()).build()()
+        GetDesc::builder().buffer(self.clone()).call()
     }
 }
 
 /// Buffer description.
-#[derive(Deref, DerefMut, From, Into)]
+#[derive(Accessors, Deref, DerefMut, From, Into)]
+#[accessors(
+    deserialize,
+    fields(
+        byte_width(from = "ByteWidth", ty = "u32"),
+        usage(from = "Usage", ty = "D3D11_USAGE"),
+        bind_flags(from = "BindFlags", ty = "u32"),
+        cpu_access_flags(from = "CPUAccessFlags", ty = "u32"),
+        misc_flags(from = "MiscFlags", ty = "u32"),
+        structure_byte_stride(from = "StructureByteStride", ty = "u32")
+    )
+)]
 #[repr(transparent)]
 pub struct Description(D3D11_BUFFER_DESC);
 
-impl Description {
-    #[inline]
-    pub fn byte_width(&self) -> u32 {
-        self.0.ByteWidth
-    }
-
-    #[inline]
-    pub fn usage(&self) -> D3D11_USAGE {
-        self.0.Usage
-    }
-
-    #[inline]
-    pub fn bind_flags(&self) -> u32 {
-        self.0.BindFlags
-    }
-
-    #[inline]
-    pub fn cpu_access_flags(&self) -> u32 {
-        self.0.CPUAccessFlags
-    }
-
-    #[inline]
-    pub fn misc_flags(&self) -> u32 {
-        self.0.MiscFlags
-    }
-
-    #[inline]
-    pub fn structure_byte_stride(&self) -> u32 {
-        self.0.StructureByteStride
-    }
-}
-
 mod get_desc;
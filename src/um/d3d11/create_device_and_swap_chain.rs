@@ -1,19 +1,15 @@
 use crate::{
     r#macro::FnOnce,
-    shared::{dxgi::swap_chain::Description, minwindef::ModuleHandle},
-    utils::AsStrictRawHandle,
+    shared::{dxgi::swap_chain::Description, minwindef::ModuleHandle, winerror::HResult},
+    utils::{AsStrictRawHandle, Call},
 };
-use anyhow::{ensure, Result};
 use std::{
     mem::MaybeUninit,
     ptr::{null, null_mut},
 };
 use typed_builder::TypedBuilder;
 use winapi::{
-    shared::{
-        dxgi::{IDXGIAdapter, IDXGISwapChain},
-        winerror::SUCCEEDED,
-    },
+    shared::dxgi::{IDXGIAdapter, IDXGISwapChain},
     um::{
         d3d11::{ID3D11Device, ID3D11DeviceContext, D3D11_SDK_VERSION},
         d3dcommon::{D3D_DRIVER_TYPE, D3D_FEATURE_LEVEL},
@@ -47,14 +43,17 @@ pub struct CreateDeviceAndSwapChain<'a> {
     device_context: Option<MaybeUninit<ComPtr<ID3D11DeviceContext>>>,
 }
 
-impl FnOnce<()> for CreateDeviceAndSwapChain<'_> {
-    type Output = Result<(
-        ComPtr<IDXGISwapChain>,
-        Option<ComPtr<ID3D11Device>>,
-        Option<ComPtr<ID3D11DeviceContext>>,
-    )>;
+impl Call for CreateDeviceAndSwapChain<'_> {
+    type Output = Result<
+        (
+            ComPtr<IDXGISwapChain>,
+            Option<ComPtr<ID3D11Device>>,
+            Option<ComPtr<ID3D11DeviceContext>>,
+        ),
+        HResult,
+    >;
 
-    extern "rust-call" fn call_once(mut self, _args: ()) -> Self::Output {
+    fn call(mut self) -> Self::Output {
         use winapi::um::d3d11::D3D11CreateDeviceAndSwapChain;
 
         #[allow(non_snake_case)]
@@ -96,11 +95,7 @@ impl FnOnce<()> for CreateDeviceAndSwapChain<'_> {
                 pFeatureLevel,
                 ppImmediateContext,
             );
-            ensure!(
-                SUCCEEDED(r#return),
-                "The D3D11CreateDeviceAndSwapChain call FAILED ({:#x}).",
-                r#return,
-            );
+            HResult::from(r#return).ok()?;
             Ok((
                 self.swap_chain.assume_init(),
                 self.device.map(|v| v.assume_init()),
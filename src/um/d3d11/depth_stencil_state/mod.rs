@@ -1,11 +1,9 @@
 use self::get_desc::GetDesc;
+use crate::r#macro::Accessors;
 use derive_more::{Deref, DerefMut, From, Into};
-use winapi::{
-    shared::minwindef::TRUE,
-    um::d3d11::{
-        ID3D11DepthStencilState, D3D11_COMPARISON_FUNC, D3D11_DEPTH_STENCILOP_DESC,
-        D3D11_DEPTH_STENCIL_DESC, D3D11_DEPTH_WRITE_MASK,
-    },
+use winapi::um::d3d11::{
+    ID3D11DepthStencilState, D3D11_COMPARISON_FUNC, D3D11_DEPTH_STENCILOP_DESC,
+    D3D11_DEPTH_STENCIL_DESC, D3D11_DEPTH_WRITE_MASK, D3D11_STENCIL_OP,
 };
 use wio::com::ComPtr;
 
@@ -16,55 +14,48 @@ pub trait DepthStencilState {
 
 impl DepthStencilState for ComPtr<ID3D11DepthStencilState> {
     fn get_description(&self) -> Description {
-        GetDesc::builder().depth_stencil_state(self.clone()).build()()
+        GetDesc::builder().depth_stencil_state(self.clone()).call()
     }
 }
 
 /// Depth stencil state description.
-#[derive(Deref, DerefMut, From, Into)]
+#[derive(Accessors, Deref, DerefMut, From, Into)]
+#[accessors(
+    deserialize,
+    fields(
+        depth_enable(from = "DepthEnable", convert = "bool"),
+        depth_write_mask(from = "DepthWriteMask", ty = "D3D11_DEPTH_WRITE_MASK"),
+        depth_func(from = "DepthFunc", ty = "D3D11_COMPARISON_FUNC"),
+        stencil_enable(from = "StencilEnable", convert = "bool"),
+        stencil_read_mask(from = "StencilReadMask", ty = "u8"),
+        stencil_write_mask(from = "StencilWriteMask", ty = "u8"),
+        front_face(
+            from = "FrontFace",
+            ty = "StencilOperationDescription",
+            convert = "from"
+        ),
+        back_face(
+            from = "BackFace",
+            ty = "StencilOperationDescription",
+            convert = "from"
+        )
+    )
+)]
 #[repr(transparent)]
 pub struct Description(D3D11_DEPTH_STENCIL_DESC);
 
-impl Description {
-    #[inline]
-    pub fn depth_enable(&self) -> bool {
-        self.0.DepthEnable == TRUE
-    }
-
-    #[inline]
-    pub fn depth_write_mask(&self) -> D3D11_DEPTH_WRITE_MASK {
-        self.0.DepthWriteMask
-    }
-
-    #[inline]
-    pub fn depth_func(&self) -> D3D11_COMPARISON_FUNC {
-        self.0.DepthFunc
-    }
-
-    #[inline]
-    pub fn stencil_enable(&self) -> bool {
-        self.0.StencilEnable == TRUE
-    }
-
-    #[inline]
-    pub fn stencil_read_mask(&self) -> u8 {
-        self.0.StencilReadMask
-    }
-
-    #[inline]
-    pub fn stencil_write_mask(&self) -> u8 {
-        self.0.StencilWriteMask
-    }
-
-    #[inline]
-    pub fn front_face(&self) -> D3D11_DEPTH_STENCILOP_DESC {
-        self.0.FrontFace
-    }
-
-    #[inline]
-    pub fn back_face(&self) -> D3D11_DEPTH_STENCILOP_DESC {
-        self.0.BackFace
-    }
-}
+/// Depth stencil operation description.
+#[derive(Accessors, Clone, Copy, Deref, DerefMut, From, Into)]
+#[accessors(
+    deserialize,
+    fields(
+        fail_op(from = "StencilFailOp", ty = "D3D11_STENCIL_OP"),
+        depth_fail_op(from = "StencilDepthFailOp", ty = "D3D11_STENCIL_OP"),
+        pass_op(from = "StencilPassOp", ty = "D3D11_STENCIL_OP"),
+        func(from = "StencilFunc", ty = "D3D11_COMPARISON_FUNC")
+    )
+)]
+#[repr(transparent)]
+pub struct StencilOperationDescription(D3D11_DEPTH_STENCILOP_DESC);
 
 mod get_desc;
@@ -1,6 +1,10 @@
 use self::get_desc::GetDesc;
+use crate::r#macro::Accessors;
 use derive_more::{Deref, DerefMut, From, Into};
-use winapi::um::d3d11::{ID3D11DepthStencilView, D3D11_DEPTH_STENCIL_VIEW_DESC};
+use winapi::{
+    shared::dxgiformat::DXGI_FORMAT,
+    um::d3d11::{ID3D11DepthStencilView, D3D11_DEPTH_STENCIL_VIEW_DESC, D3D11_DSV_DIMENSION},
+};
 use wio::com::ComPtr;
 
 /// Depth stencil view.
@@ -10,12 +14,17 @@ pub trait DepthStencilView {
 
 impl DepthStencilView for ComPtr<ID3D11DepthStencilView> {
     fn get_description(&self) -> Description {
-        GetDesc::builder().depth_stencil_view(self.clone()).build()()
+        GetDesc::builder().depth_stencil_view(self.clone()).call()
     }
 }
 
 /// Depth stencil view description.
-#[derive(Deref, DerefMut, From, Into)]
+#[derive(Accessors, Deref, DerefMut, From, Into)]
+#[accessors(fields(
+    format(from = "Format", ty = "DXGI_FORMAT"),
+    view_dimension(from = "ViewDimension", ty = "D3D11_DSV_DIMENSION"),
+    flags(from = "Flags", ty = "u32")
+))]
 #[repr(transparent)]
 pub struct Description(D3D11_DEPTH_STENCIL_VIEW_DESC);
 
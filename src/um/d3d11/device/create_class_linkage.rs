@@ -1,11 +1,7 @@
-use crate::r#macro::FnOnce;
-use anyhow::{ensure, Result};
+use crate::{r#macro::FnOnce, shared::winerror::HResult, utils::Call, Result};
 use std::mem::MaybeUninit;
 use typed_builder::TypedBuilder;
-use winapi::{
-    shared::winerror::SUCCEEDED,
-    um::d3d11::{ID3D11ClassLinkage, ID3D11Device},
-};
+use winapi::um::d3d11::{ID3D11ClassLinkage, ID3D11Device};
 use wio::com::ComPtr;
 
 /// Create class linkage.
@@ -16,19 +12,15 @@ pub struct CreateClassLinkage {
     linkage: MaybeUninit<ComPtr<ID3D11ClassLinkage>>,
 }
 
-impl FnOnce<()> for CreateClassLinkage {
+impl Call for CreateClassLinkage {
     type Output = Result<ComPtr<ID3D11ClassLinkage>>;
 
-    extern "rust-call" fn call_once(mut self, _args: ()) -> Self::Output {
+    fn call(mut self) -> Self::Output {
         #[allow(non_snake_case)]
         unsafe {
             let ppLinkage = self.linkage.as_mut_ptr() as _;
             let r#return = self.device.CreateClassLinkage(ppLinkage);
-            ensure!(
-                SUCCEEDED(r#return),
-                "The ID3D11Device::CreateClassLinkage call FAILED ({:#x}).",
-                r#return,
-            );
+            HResult::from(r#return).ok()?;
             Ok(self.linkage.assume_init())
         }
     }
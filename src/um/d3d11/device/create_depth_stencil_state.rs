@@ -1,11 +1,10 @@
-use crate::{r#macro::FnOnce, um::d3d11::depth_stencil_state::Description};
-use anyhow::{ensure, Result};
+use crate::{
+    r#macro::FnOnce, shared::winerror::HResult, um::d3d11::depth_stencil_state::Description,
+    utils::Call, Result,
+};
 use std::mem::MaybeUninit;
 use typed_builder::TypedBuilder;
-use winapi::{
-    shared::winerror::SUCCEEDED,
-    um::d3d11::{ID3D11DepthStencilState, ID3D11Device},
-};
+use winapi::um::d3d11::{ID3D11DepthStencilState, ID3D11Device};
 use wio::com::ComPtr;
 
 /// Create depth stencil state.
@@ -18,10 +17,10 @@ pub struct CreateDepthStencilState {
     depth_stencil_state: MaybeUninit<ComPtr<ID3D11DepthStencilState>>,
 }
 
-impl FnOnce<()> for CreateDepthStencilState {
+impl Call for CreateDepthStencilState {
     type Output = Result<ComPtr<ID3D11DepthStencilState>>;
 
-    extern "rust-call" fn call_once(mut self, _args: ()) -> Self::Output {
+    fn call(mut self) -> Self::Output {
         #[allow(non_snake_case)]
         unsafe {
             let pDepthStencilDesc = &*self.description;
@@ -29,11 +28,7 @@ impl FnOnce<()> for CreateDepthStencilState {
             let r#return = self
                 .device
                 .CreateDepthStencilState(pDepthStencilDesc, ppDepthStencilState);
-            ensure!(
-                SUCCEEDED(r#return),
-                "The ID3D11Device::CreateDepthStencilState call FAILED ({:#x}).",
-                r#return,
-            );
+            HResult::from(r#return).ok()?;
             Ok(self.depth_stencil_state.assume_init())
         }
     }
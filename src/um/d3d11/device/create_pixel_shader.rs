@@ -1,13 +1,9 @@
-use crate::r#macro::FnOnce;
-use anyhow::{ensure, Result};
+use crate::{r#macro::FnOnce, shared::winerror::HResult, utils::Call, Result};
 use std::{mem::MaybeUninit, ptr::null_mut};
 use typed_builder::TypedBuilder;
-use winapi::{
-    shared::winerror::SUCCEEDED,
-    um::{
-        d3d11::{ID3D11ClassLinkage, ID3D11Device, ID3D11PixelShader},
-        d3dcommon::ID3DBlob,
-    },
+use winapi::um::{
+    d3d11::{ID3D11ClassLinkage, ID3D11Device, ID3D11PixelShader},
+    d3dcommon::ID3DBlob,
 };
 use wio::com::ComPtr;
 
@@ -22,10 +18,10 @@ pub struct CreatePixelShader {
     pixel_shader: MaybeUninit<ComPtr<ID3D11PixelShader>>,
 }
 
-impl FnOnce<()> for CreatePixelShader {
+impl Call for CreatePixelShader {
     type Output = Result<ComPtr<ID3D11PixelShader>>;
 
-    extern "rust-call" fn call_once(mut self, _args: ()) -> Self::Output {
+    fn call(mut self) -> Self::Output {
         #[allow(non_snake_case)]
         unsafe {
             let pShaderBytecode = self.bytecode.GetBufferPointer();
@@ -38,11 +34,7 @@ impl FnOnce<()> for CreatePixelShader {
                 pClassLinkage,
                 ppPixelShader,
             );
-            ensure!(
-                SUCCEEDED(r#return),
-                "The ID3D11Device::CreatePixelShader call FAILED ({:#x}).",
-                r#return,
-            );
+            HResult::from(r#return).ok()?;
             Ok(self.pixel_shader.assume_init())
         }
     }
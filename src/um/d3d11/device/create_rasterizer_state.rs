@@ -1,11 +1,10 @@
-use crate::{r#macro::FnOnce, um::d3d11::rasterizer_state::Description};
-use anyhow::{ensure, Result};
+use crate::{
+    r#macro::FnOnce, shared::winerror::HResult, um::d3d11::rasterizer_state::Description,
+    utils::Call, Result,
+};
 use std::mem::MaybeUninit;
 use typed_builder::TypedBuilder;
-use winapi::{
-    shared::winerror::SUCCEEDED,
-    um::d3d11::{ID3D11Device, ID3D11RasterizerState},
-};
+use winapi::um::d3d11::{ID3D11Device, ID3D11RasterizerState};
 use wio::com::ComPtr;
 
 /// Create rasterizer state.
@@ -18,10 +17,10 @@ pub struct CreateRasterizerState {
     rasterizer_state: MaybeUninit<ComPtr<ID3D11RasterizerState>>,
 }
 
-impl FnOnce<()> for CreateRasterizerState {
+impl Call for CreateRasterizerState {
     type Output = Result<ComPtr<ID3D11RasterizerState>>;
 
-    extern "rust-call" fn call_once(mut self, _args: ()) -> Self::Output {
+    fn call(mut self) -> Self::Output {
         #[allow(non_snake_case)]
         unsafe {
             let pRasterizerDesc = &*self.description;
@@ -29,11 +28,7 @@ impl FnOnce<()> for CreateRasterizerState {
             let r#return = self
                 .device
                 .CreateRasterizerState(pRasterizerDesc, ppRasterizerState);
-            ensure!(
-                SUCCEEDED(r#return),
-                "The ID3D11Device::CreateRasterizerState call FAILED ({:#x}).",
-                r#return,
-            );
+            HResult::from(r#return).ok()?;
             Ok(self.rasterizer_state.assume_init())
         }
     }
@@ -1,11 +1,10 @@
-use crate::{r#macro::FnOnce, um::d3d11::render_target_view::Description};
-use anyhow::{ensure, Result};
+use crate::{
+    r#macro::FnOnce, shared::winerror::HResult, um::d3d11::render_target_view::Description,
+    utils::Call, Result,
+};
 use std::{mem::MaybeUninit, ptr::null};
 use typed_builder::TypedBuilder;
-use winapi::{
-    shared::winerror::SUCCEEDED,
-    um::d3d11::{ID3D11Device, ID3D11RenderTargetView, ID3D11Resource},
-};
+use winapi::um::d3d11::{ID3D11Device, ID3D11RenderTargetView, ID3D11Resource};
 use wio::com::ComPtr;
 
 /// Create render target view.
@@ -19,10 +18,10 @@ pub struct CreateRenderTargetView {
     render_target_view: MaybeUninit<ComPtr<ID3D11RenderTargetView>>,
 }
 
-impl FnOnce<()> for CreateRenderTargetView {
+impl Call for CreateRenderTargetView {
     type Output = Result<ComPtr<ID3D11RenderTargetView>>;
 
-    extern "rust-call" fn call_once(mut self, _args: ()) -> Self::Output {
+    fn call(mut self) -> Self::Output {
         #[allow(non_snake_case)]
         unsafe {
             let pResource = self.resource.as_raw();
@@ -31,11 +30,7 @@ impl FnOnce<()> for CreateRenderTargetView {
             let r#return = self
                 .device
                 .CreateRenderTargetView(pResource, pDesc, ppRTView);
-            ensure!(
-                SUCCEEDED(r#return),
-                "The ID3D11Device::CreateRenderTargetView call FAILED ({:#x}).",
-                r#return,
-            );
+            HResult::from(r#return).ok()?;
             Ok(self.render_target_view.assume_init())
         }
     }
@@ -1,11 +1,10 @@
-use crate::{r#macro::FnOnce, um::d3d11::sampler_state::Description};
-use anyhow::{ensure, Result};
+use crate::{
+    r#macro::FnOnce, shared::winerror::HResult, um::d3d11::sampler_state::Description, utils::Call,
+    Result,
+};
 use std::mem::MaybeUninit;
 use typed_builder::TypedBuilder;
-use winapi::{
-    shared::winerror::SUCCEEDED,
-    um::d3d11::{ID3D11Device, ID3D11SamplerState},
-};
+use winapi::um::d3d11::{ID3D11Device, ID3D11SamplerState};
 use wio::com::ComPtr;
 
 /// Create sampler state.
@@ -18,20 +17,16 @@ pub struct CreateSamplerState {
     sampler_state: MaybeUninit<ComPtr<ID3D11SamplerState>>,
 }
 
-impl FnOnce<()> for CreateSamplerState {
+impl Call for CreateSamplerState {
     type Output = Result<ComPtr<ID3D11SamplerState>>;
 
-    extern "rust-call" fn call_once(mut self, _args: ()) -> Self::Output {
+    fn call(mut self) -> Self::Output {
         #[allow(non_snake_case)]
         unsafe {
             let pSamplerDesc = &*self.description;
             let ppSamplerState = self.sampler_state.as_mut_ptr() as _;
             let r#return = self.device.CreateSamplerState(pSamplerDesc, ppSamplerState);
-            ensure!(
-                SUCCEEDED(r#return),
-                "The ID3D11Device::CreateSamplerState call FAILED ({:#x}).",
-                r#return,
-            );
+            HResult::from(r#return).ok()?;
             Ok(self.sampler_state.assume_init())
         }
     }
@@ -1,11 +1,10 @@
-use crate::{r#macro::FnOnce, um::d3d11::shader_resource_view::Description};
-use anyhow::{ensure, Result};
+use crate::{
+    r#macro::FnOnce, shared::winerror::HResult, um::d3d11::shader_resource_view::Description,
+    utils::Call, Result,
+};
 use std::{mem::MaybeUninit, ptr::null};
 use typed_builder::TypedBuilder;
-use winapi::{
-    shared::winerror::SUCCEEDED,
-    um::d3d11::{ID3D11Device, ID3D11Resource, ID3D11ShaderResourceView},
-};
+use winapi::um::d3d11::{ID3D11Device, ID3D11Resource, ID3D11ShaderResourceView};
 use wio::com::ComPtr;
 
 /// Create shader resource view.
@@ -19,10 +18,10 @@ pub struct CreateShaderResourceView {
     shader_resource_view: MaybeUninit<ComPtr<ID3D11ShaderResourceView>>,
 }
 
-impl FnOnce<()> for CreateShaderResourceView {
+impl Call for CreateShaderResourceView {
     type Output = Result<ComPtr<ID3D11ShaderResourceView>>;
 
-    extern "rust-call" fn call_once(mut self, _args: ()) -> Self::Output {
+    fn call(mut self) -> Self::Output {
         #[allow(non_snake_case)]
         unsafe {
             let pResource = self.resource.as_raw();
@@ -31,11 +30,7 @@ impl FnOnce<()> for CreateShaderResourceView {
             let r#return = self
                 .device
                 .CreateShaderResourceView(pResource, pDesc, ppSRView);
-            ensure!(
-                SUCCEEDED(r#return),
-                "The ID3D11Device::CreateShaderResourceView call FAILED ({:#x}).",
-                r#return,
-            );
+            HResult::from(r#return).ok()?;
             Ok(self.shader_resource_view.assume_init())
         }
     }
@@ -1,11 +1,13 @@
-use crate::{r#macro::FnOnce, um::d3d11::texture_2d::Description, utils::Transparent};
-use anyhow::{ensure, Result};
+use crate::{
+    r#macro::FnOnce,
+    shared::winerror::HResult,
+    um::d3d11::texture_2d::Description,
+    utils::{Call, Transparent},
+    Result,
+};
 use std::{mem::MaybeUninit, ptr::null};
 use typed_builder::TypedBuilder;
-use winapi::{
-    shared::winerror::SUCCEEDED,
-    um::d3d11::{ID3D11Device, ID3D11Texture2D, D3D11_SUBRESOURCE_DATA},
-};
+use winapi::um::d3d11::{ID3D11Device, ID3D11Texture2D, D3D11_SUBRESOURCE_DATA};
 use wio::com::ComPtr;
 
 /// Create texture 2D.
@@ -23,13 +25,13 @@ where
     texture_2d: MaybeUninit<ComPtr<ID3D11Texture2D>>,
 }
 
-impl<T> FnOnce<()> for CreateTexture2D<'_, T>
+impl<T> Call for CreateTexture2D<'_, T>
 where
     T: Transparent<Target = D3D11_SUBRESOURCE_DATA>,
 {
     type Output = Result<ComPtr<ID3D11Texture2D>>;
 
-    extern "rust-call" fn call_once(mut self, _args: ()) -> Self::Output {
+    fn call(mut self) -> Self::Output {
         #[allow(non_snake_case)]
         unsafe {
             let pDesc = &*self.description;
@@ -38,11 +40,7 @@ where
             let r#return = self
                 .device
                 .CreateTexture2D(pDesc, pInitialData, ppTexture2D);
-            ensure!(
-                SUCCEEDED(r#return),
-                "The ID3D11Device::CreateTexture2D call FAILED ({:#x}).",
-                r#return,
-            );
+            HResult::from(r#return).ok()?;
             Ok(self.texture_2d.assume_init())
         }
     }
@@ -1,4 +1,4 @@
-use crate::r#macro::FnOnce;
+use crate::{r#macro::FnOnce, utils::Call};
 use std::mem::MaybeUninit;
 use typed_builder::TypedBuilder;
 use winapi::um::d3d11::{ID3D11Device, ID3D11DeviceContext};
@@ -12,10 +12,10 @@ pub struct GetImmediateContext {
     context: MaybeUninit<ComPtr<ID3D11DeviceContext>>,
 }
 
-impl FnOnce<()> for GetImmediateContext {
+impl Call for GetImmediateContext {
     type Output = ComPtr<ID3D11DeviceContext>;
 
-    extern "rust-call" fn call_once(mut self, _args: ()) -> Self::Output {
+    fn call(mut self) -> Self::Output {
         #[allow(non_snake_case)]
         unsafe {
             let ppImmediateContext = self.context.as_mut_ptr() as _;
@@ -9,8 +9,7 @@ use self::{
     create_texture_2d::{CreateTexture2D, CreateTexture2DBuilder},
     get_immediate_context::GetImmediateContext,
 };
-use crate::utils::Transparent;
-use anyhow::Result;
+use crate::{utils::Transparent, Result};
 use winapi::um::d3d11::{
     ID3D11ClassLinkage, ID3D11Device, ID3D11DeviceContext, D3D11_SUBRESOURCE_DATA,
 };
@@ -44,18 +43,18 @@ pub trait Device {
 
     fn create_texture_2d<'a, T>(
         &'a self,
-    ) -> CreateTexture2DBuilder<((ComPtr<ID3D11Device>,), (), ()), T>
+    ) -> CreateTexture2DBuilder<'a, ((ComPtr<ID3D11Device>,), (), ()), T>
     where
         T: 'a + Transparent<Target = D3D11_SUBRESOURCE_DATA>;
 }
 
 impl Device for ComPtr<ID3D11Device> {
     fn get_immediate_context(&self) -> ComPtr<ID3D11DeviceContext> {
-        GetImmediateContext::builder().device(self.clone()).build()()
+        GetImmediateContext::builder().device(self.clone()).call()
     }
 
     fn create_class_linkage(&self) -> Result<ComPtr<ID3D11ClassLinkage>> {
-        CreateClassLinkage::builder().device(self.clone()).build()()
+        CreateClassLinkage::builder().device(self.clone()).call()
     }
 
     fn create_depth_stencil_state(
@@ -92,7 +91,7 @@ impl Device for ComPtr<ID3D11Device> {
 
     fn create_texture_2d<'a, T>(
         &'a self,
-    ) -> CreateTexture2DBuilder<((ComPtr<ID3D11Device>,), (), ()), T>
+    ) -> CreateTexture2DBuilder<'a, ((ComPtr<ID3D11Device>,), (), ()), T>
     where
         T: 'a + Transparent<Target = D3D11_SUBRESOURCE_DATA>,
     {
@@ -1,4 +1,4 @@
-use crate::r#macro::FnOnce;
+use crate::{r#macro::FnOnce, utils::Call};
 use std::mem::MaybeUninit;
 use typed_builder::TypedBuilder;
 use winapi::{
@@ -19,10 +19,10 @@ pub struct IAGetIndexBuffer {
     offset: MaybeUninit<u32>,
 }
 
-impl FnOnce<()> for IAGetIndexBuffer {
+impl Call for IAGetIndexBuffer {
     type Output = (ComPtr<ID3D11Buffer>, DXGI_FORMAT, u32);
 
-    extern "rust-call" fn call_once(mut self, _args: ()) -> Self::Output {
+    fn call(mut self) -> Self::Output {
         #[allow(non_snake_case)]
         unsafe {
             let pIndexBuffer = self.index_buffer.as_mut_ptr() as _;
@@ -1,4 +1,4 @@
-use crate::r#macro::FnOnce;
+use crate::{r#macro::FnOnce, utils::Call};
 use typed_builder::TypedBuilder;
 use winapi::um::d3d11::{
     ID3D11Buffer, ID3D11DeviceContext, D3D11_IA_VERTEX_INPUT_RESOURCE_SLOT_COUNT,
@@ -24,14 +24,14 @@ pub struct IAGetVertexBuffers {
     offsets: Vec<u32>,
 }
 
-impl FnOnce<()> for IAGetVertexBuffers {
+impl Call for IAGetVertexBuffers {
     type Output = (Vec<ComPtr<ID3D11Buffer>>, Vec<u32>, Vec<u32>);
 
-    extern "rust-call" fn call_once(mut self, _args: ()) -> Self::Output {
+    fn call(mut self) -> Self::Output {
         #[allow(non_snake_case)]
         unsafe {
             let StartSlot = self.start_slot;
-            assert!(StartSlot <= D3D11_IA_VERTEX_INPUT_RESOURCE_SLOT_COUNT - 1);
+            assert!(StartSlot < D3D11_IA_VERTEX_INPUT_RESOURCE_SLOT_COUNT);
             let NumBuffers = self.number_of_buffers;
             assert!(NumBuffers <= D3D11_IA_VERTEX_INPUT_RESOURCE_SLOT_COUNT - StartSlot);
             let ppVertexBuffers = self.vertex_buffers.as_mut_ptr() as _;
@@ -36,7 +36,15 @@ use winapi::{
 use wio::com::ComPtr;
 
 /// Device context.
-pub trait DeviceContext = InputAssembler + OutputMerger + PixelShader + Rasterizer + VertexShader;
+pub trait DeviceContext:
+    InputAssembler + OutputMerger + PixelShader + Rasterizer + VertexShader
+{
+}
+
+impl<T> DeviceContext for T where
+    T: InputAssembler + OutputMerger + PixelShader + Rasterizer + VertexShader + ?Sized
+{
+}
 
 /// Input assembler.
 pub trait InputAssembler {
@@ -51,7 +59,7 @@ impl InputAssembler for ComPtr<ID3D11DeviceContext> {
     fn get_index_buffer(&self) -> (ComPtr<ID3D11Buffer>, DXGI_FORMAT, u32) {
         IAGetIndexBuffer::builder()
             .device_context(self.clone())
-            .build()()
+            .call()
     }
 
     fn get_vertex_buffers(
@@ -75,14 +83,14 @@ pub trait OutputMerger {
 
     fn set_render_targets(
         &self,
-    ) -> OMSetRenderTargetsBuilder<((ComPtr<ID3D11DeviceContext>,), (), ())>;
+    ) -> OMSetRenderTargetsBuilder<'_, ((ComPtr<ID3D11DeviceContext>,), (), ())>;
 }
 
 impl OutputMerger for ComPtr<ID3D11DeviceContext> {
     fn get_depth_stencil_state(&self) -> (ComPtr<ID3D11DepthStencilState>, u32) {
         OMGetDepthStencilState::builder()
             .device_context(self.clone())
-            .build()()
+            .call()
     }
 
     fn get_render_targets(
@@ -99,34 +107,34 @@ impl OutputMerger for ComPtr<ID3D11DeviceContext> {
 
     fn set_render_targets(
         &self,
-    ) -> OMSetRenderTargetsBuilder<((ComPtr<ID3D11DeviceContext>,), (), ())> {
+    ) -> OMSetRenderTargetsBuilder<'_, ((ComPtr<ID3D11DeviceContext>,), (), ())> {
         OMSetRenderTargets::builder().device_context(self.clone())
     }
 }
 
 /// Pixel shader.
 pub trait PixelShader {
-    fn set_samplers(&self) -> PSSetSamplersBuilder<((ComPtr<ID3D11DeviceContext>,), (), ())>;
+    fn set_samplers(&self) -> PSSetSamplersBuilder<'_, ((ComPtr<ID3D11DeviceContext>,), (), ())>;
 
-    fn set_shader(&self) -> PSSetShaderBuilder<((ComPtr<ID3D11DeviceContext>,), (), ())>;
+    fn set_shader(&self) -> PSSetShaderBuilder<'_, ((ComPtr<ID3D11DeviceContext>,), (), ())>;
 
     fn set_shader_resources(
         &self,
-    ) -> PSSetShaderResourcesBuilder<((ComPtr<ID3D11DeviceContext>,), (), ())>;
+    ) -> PSSetShaderResourcesBuilder<'_, ((ComPtr<ID3D11DeviceContext>,), (), ())>;
 }
 
 impl PixelShader for ComPtr<ID3D11DeviceContext> {
-    fn set_samplers(&self) -> PSSetSamplersBuilder<((ComPtr<ID3D11DeviceContext>,), (), ())> {
+    fn set_samplers(&self) -> PSSetSamplersBuilder<'_, ((ComPtr<ID3D11DeviceContext>,), (), ())> {
         PSSetSamplers::builder().device_context(self.clone())
     }
 
-    fn set_shader(&self) -> PSSetShaderBuilder<((ComPtr<ID3D11DeviceContext>,), (), ())> {
+    fn set_shader(&self) -> PSSetShaderBuilder<'_, ((ComPtr<ID3D11DeviceContext>,), (), ())> {
         PSSetShader::builder().device_context(self.clone())
     }
 
     fn set_shader_resources(
         &self,
-    ) -> PSSetShaderResourcesBuilder<((ComPtr<ID3D11DeviceContext>,), (), ())> {
+    ) -> PSSetShaderResourcesBuilder<'_, ((ComPtr<ID3D11DeviceContext>,), (), ())> {
         PSSetShaderResources::builder().device_context(self.clone())
     }
 }
@@ -1,4 +1,4 @@
-use crate::r#macro::FnOnce;
+use crate::{r#macro::FnOnce, utils::Call};
 use std::mem::MaybeUninit;
 use typed_builder::TypedBuilder;
 use winapi::um::d3d11::{ID3D11DepthStencilState, ID3D11DeviceContext};
@@ -14,10 +14,10 @@ pub struct OMGetDepthStencilState {
     stencil_reference: MaybeUninit<u32>,
 }
 
-impl FnOnce<()> for OMGetDepthStencilState {
+impl Call for OMGetDepthStencilState {
     type Output = (ComPtr<ID3D11DepthStencilState>, u32);
 
-    extern "rust-call" fn call_once(mut self, _args: ()) -> Self::Output {
+    fn call(mut self) -> Self::Output {
         #[allow(non_snake_case)]
         unsafe {
             let ppDepthStencilState = self.depth_stencil_state.as_mut_ptr() as _;
@@ -1,4 +1,4 @@
-use crate::r#macro::FnOnce;
+use crate::{r#macro::FnOnce, utils::Call};
 use std::{mem::MaybeUninit, ptr::null_mut};
 use typed_builder::TypedBuilder;
 use winapi::um::d3d11::{
@@ -22,13 +22,13 @@ pub struct OMGetRenderTargets {
     depth_stencil_view: Option<MaybeUninit<ComPtr<ID3D11DepthStencilView>>>,
 }
 
-impl FnOnce<()> for OMGetRenderTargets {
+impl Call for OMGetRenderTargets {
     type Output = (
         Vec<ComPtr<ID3D11RenderTargetView>>,
         Option<ComPtr<ID3D11DepthStencilView>>,
     );
 
-    extern "rust-call" fn call_once(mut self, _args: ()) -> Self::Output {
+    fn call(mut self) -> Self::Output {
         #[allow(non_snake_case)]
         unsafe {
             let NumViews = self.number_of_views;
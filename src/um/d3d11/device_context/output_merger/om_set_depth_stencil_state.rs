@@ -1,4 +1,4 @@
-use crate::r#macro::FnOnce;
+use crate::{r#macro::FnOnce, utils::Call};
 use std::ptr::null_mut;
 use typed_builder::TypedBuilder;
 use winapi::um::d3d11::{ID3D11DepthStencilState, ID3D11DeviceContext};
@@ -13,10 +13,10 @@ pub struct OMSetDepthStencilState {
     stencil_reference: u32,
 }
 
-impl FnOnce<()> for OMSetDepthStencilState {
+impl Call for OMSetDepthStencilState {
     type Output = ();
 
-    extern "rust-call" fn call_once(self, _args: ()) -> Self::Output {
+    fn call(self) -> Self::Output {
         #[allow(non_snake_case)]
         unsafe {
             let pDepthStencilState = self
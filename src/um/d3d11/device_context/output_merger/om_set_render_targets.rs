@@ -1,4 +1,4 @@
-use crate::r#macro::FnOnce;
+use crate::{r#macro::FnOnce, utils::Call};
 use std::ptr::{null, null_mut};
 use typed_builder::TypedBuilder;
 use winapi::um::d3d11::{
@@ -17,10 +17,10 @@ pub struct OMSetRenderTargets<'a> {
     depth_stencil_view: Option<ComPtr<ID3D11DepthStencilView>>,
 }
 
-impl FnOnce<()> for OMSetRenderTargets<'_> {
+impl Call for OMSetRenderTargets<'_> {
     type Output = ();
 
-    extern "rust-call" fn call_once(self, _args: ()) -> Self::Output {
+    fn call(self) -> Self::Output {
         #[allow(non_snake_case)]
         unsafe {
             let NumViews = self.render_target_views.len() as _;
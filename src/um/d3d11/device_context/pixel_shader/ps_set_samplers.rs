@@ -1,4 +1,4 @@
-use crate::r#macro::FnOnce;
+use crate::{r#macro::FnOnce, utils::Call};
 use typed_builder::TypedBuilder;
 use winapi::um::d3d11::{
     ID3D11DeviceContext, ID3D11SamplerState, D3D11_COMMONSHADER_SAMPLER_SLOT_COUNT,
@@ -14,14 +14,14 @@ pub struct PSSetSamplers<'a> {
     samplers: &'a [ComPtr<ID3D11SamplerState>],
 }
 
-impl FnOnce<()> for PSSetSamplers<'_> {
+impl Call for PSSetSamplers<'_> {
     type Output = ();
 
-    extern "rust-call" fn call_once(self, _args: ()) -> Self::Output {
+    fn call(self) -> Self::Output {
         #[allow(non_snake_case)]
         unsafe {
             let StartSlot = self.start_slot;
-            assert!(StartSlot <= D3D11_COMMONSHADER_SAMPLER_SLOT_COUNT - 1);
+            assert!(StartSlot < D3D11_COMMONSHADER_SAMPLER_SLOT_COUNT);
             let NumSamplers = self.samplers.len() as _;
             assert!(NumSamplers <= D3D11_COMMONSHADER_SAMPLER_SLOT_COUNT - StartSlot);
             let ppSamplers = self.samplers.as_ptr() as _;
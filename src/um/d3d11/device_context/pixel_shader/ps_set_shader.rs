@@ -1,4 +1,4 @@
-use crate::r#macro::FnOnce;
+use crate::{r#macro::FnOnce, utils::Call};
 use std::ptr::null_mut;
 use typed_builder::TypedBuilder;
 use winapi::um::d3d11::{ID3D11DeviceContext, ID3D11PixelShader, ID3D11ShaderResourceView};
@@ -13,10 +13,10 @@ pub struct PSSetShader<'a> {
     class_instances: &'a [ComPtr<ID3D11ShaderResourceView>],
 }
 
-impl FnOnce<()> for PSSetShader<'_> {
+impl Call for PSSetShader<'_> {
     type Output = ();
 
-    extern "rust-call" fn call_once(self, _args: ()) -> Self::Output {
+    fn call(self) -> Self::Output {
         #[allow(non_snake_case)]
         unsafe {
             let pPixelShader = self
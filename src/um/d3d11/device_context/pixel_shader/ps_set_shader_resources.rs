@@ -1,4 +1,4 @@
-use crate::r#macro::FnOnce;
+use crate::{r#macro::FnOnce, utils::Call};
 use typed_builder::TypedBuilder;
 use winapi::um::d3d11::{
     ID3D11DeviceContext, ID3D11ShaderResourceView, D3D11_COMMONSHADER_INPUT_RESOURCE_SLOT_COUNT,
@@ -14,14 +14,14 @@ pub struct PSSetShaderResources<'a> {
     shader_resource_views: &'a [ComPtr<ID3D11ShaderResourceView>],
 }
 
-impl FnOnce<()> for PSSetShaderResources<'_> {
+impl Call for PSSetShaderResources<'_> {
     type Output = ();
 
-    extern "rust-call" fn call_once(self, _args: ()) -> Self::Output {
+    fn call(self) -> Self::Output {
         #[allow(non_snake_case)]
         unsafe {
             let StartSlot = self.start_slot;
-            assert!(StartSlot <= D3D11_COMMONSHADER_INPUT_RESOURCE_SLOT_COUNT - 1);
+            assert!(StartSlot < D3D11_COMMONSHADER_INPUT_RESOURCE_SLOT_COUNT);
             let NumViews = self.shader_resource_views.len() as _;
             assert!(NumViews <= D3D11_COMMONSHADER_INPUT_RESOURCE_SLOT_COUNT - StartSlot);
             let ppShaderResourceViews = self.shader_resource_views.as_ptr() as _;
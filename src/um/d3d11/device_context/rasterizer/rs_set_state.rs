@@ -1,4 +1,4 @@
-use crate::r#macro::FnOnce;
+use crate::{r#macro::FnOnce, utils::Call};
 use typed_builder::TypedBuilder;
 use winapi::um::d3d11::{ID3D11DeviceContext, ID3D11RasterizerState};
 use wio::com::ComPtr;
@@ -10,10 +10,10 @@ pub struct RSSetState {
     rasterizer_state: ComPtr<ID3D11RasterizerState>,
 }
 
-impl FnOnce<()> for RSSetState {
+impl Call for RSSetState {
     type Output = ();
 
-    extern "rust-call" fn call_once(self, _args: ()) -> Self::Output {
+    fn call(self) -> Self::Output {
         #[allow(non_snake_case)]
         unsafe {
             let pRasterizerState = self.rasterizer_state.as_raw();
@@ -9,8 +9,11 @@ pub use self::{
     subresource_data::SubresourceData, texture_2d::Texture2D,
 };
 
+/// The unset fields of `CreateDeviceAndSwapChainBuilder`.
+type CreateDeviceAndSwapChainFields = ((), (), (), (), (), (), (), (), ());
+
 pub fn create_device_and_swap_chain<'a>(
-) -> CreateDeviceAndSwapChainBuilder<'a, ((), (), (), (), (), (), (), (), ())> {
+) -> CreateDeviceAndSwapChainBuilder<'a, CreateDeviceAndSwapChainFields> {
     CreateDeviceAndSwapChain::builder()
 }
 
@@ -19,6 +22,11 @@ pub trait Com<T> {
     /// Constructs a wrapper from a raw pointer. It takes ownership.
     ///
     /// Note: It does __not__ call `AddRef`.
+    ///
+    /// # Safety
+    ///
+    /// The `pointer` must be a valid COM pointer of `T` that nothing else
+    /// releases.
     unsafe fn from_raw(pointer: *mut T) -> Self;
 
     /// Returns a wrapped raw pointer. It consumes the wrapper and takes
@@ -1,6 +1,9 @@
 use self::get_desc::GetDesc;
+use crate::r#macro::Accessors;
 use derive_more::{Deref, DerefMut, From, Into};
-use winapi::um::d3d11::{ID3D11RasterizerState, D3D11_RASTERIZER_DESC};
+use winapi::um::d3d11::{
+    ID3D11RasterizerState, D3D11_CULL_MODE, D3D11_FILL_MODE, D3D11_RASTERIZER_DESC,
+};
 use wio::com::ComPtr;
 
 /// Rasterizer state.
@@ -10,12 +13,27 @@ pub trait RasterizerState {
 
 impl RasterizerState for ComPtr<ID3D11RasterizerState> {
     fn get_description(&self) -> Description {
-        GetDesc::builder().rasterizer_state(self.clone()).build()()
+        GetDesc::builder().rasterizer_state(self.clone()).call()
     }
 }
 
 /// Rasterizer state description.
-#[derive(Deref, DerefMut, From, Into)]
+#[derive(Accessors, Deref, DerefMut, From, Into)]
+#[accessors(
+    deserialize,
+    fields(
+        fill_mode(from = "FillMode", ty = "D3D11_FILL_MODE"),
+        cull_mode(from = "CullMode", ty = "D3D11_CULL_MODE"),
+        front_counter_clockwise(from = "FrontCounterClockwise", convert = "bool"),
+        depth_bias(from = "DepthBias", ty = "i32"),
+        depth_bias_clamp(from = "DepthBiasClamp", ty = "f32"),
+        slope_scaled_depth_bias(from = "SlopeScaledDepthBias", ty = "f32"),
+        depth_clip_enable(from = "DepthClipEnable", convert = "bool"),
+        scissor_enable(from = "ScissorEnable", convert = "bool"),
+        multisample_enable(from = "MultisampleEnable", convert = "bool"),
+        antialiased_line_enable(from = "AntialiasedLineEnable", convert = "bool")
+    )
+)]
 #[repr(transparent)]
 pub struct Description(D3D11_RASTERIZER_DESC);
 
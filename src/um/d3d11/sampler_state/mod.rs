@@ -1,8 +1,10 @@
 use self::get_desc::GetDesc;
+use crate::r#macro::Accessors;
 use derive_more::{Deref, DerefMut, From, Into};
 use winapi::um::d3d11::{
-    ID3D11SamplerState, D3D11_COMPARISON_NEVER, D3D11_FILTER_MIN_MAG_MIP_LINEAR, D3D11_FLOAT32_MAX,
-    D3D11_SAMPLER_DESC, D3D11_TEXTURE_ADDRESS_CLAMP,
+    ID3D11SamplerState, D3D11_COMPARISON_FUNC, D3D11_COMPARISON_NEVER, D3D11_FILTER,
+    D3D11_FILTER_MIN_MAG_MIP_LINEAR, D3D11_FLOAT32_MAX, D3D11_SAMPLER_DESC,
+    D3D11_TEXTURE_ADDRESS_CLAMP, D3D11_TEXTURE_ADDRESS_MODE,
 };
 use wio::com::ComPtr;
 
@@ -13,12 +15,27 @@ pub trait SamplerState {
 
 impl SamplerState for ComPtr<ID3D11SamplerState> {
     fn get_description(&self) -> Description {
-        GetDesc::builder().sampler_state(self.clone()).build()()
+        GetDesc::builder().sampler_state(self.clone()).call()
     }
 }
 
 /// Sampler state description.
-#[derive(Deref, DerefMut, From, Into)]
+#[derive(Accessors, Deref, DerefMut, From, Into)]
+#[accessors(
+    deserialize,
+    fields(
+        filter(from = "Filter", ty = "D3D11_FILTER"),
+        address_u(from = "AddressU", ty = "D3D11_TEXTURE_ADDRESS_MODE"),
+        address_v(from = "AddressV", ty = "D3D11_TEXTURE_ADDRESS_MODE"),
+        address_w(from = "AddressW", ty = "D3D11_TEXTURE_ADDRESS_MODE"),
+        mip_lod_bias(from = "MipLODBias", ty = "f32"),
+        max_anisotropy(from = "MaxAnisotropy", ty = "u32"),
+        comparison_func(from = "ComparisonFunc", ty = "D3D11_COMPARISON_FUNC"),
+        border_color(from = "BorderColor", ty = "[f32; 4]"),
+        min_lod(from = "MinLOD", ty = "f32"),
+        max_lod(from = "MaxLOD", ty = "f32")
+    )
+)]
 #[repr(transparent)]
 pub struct Description(D3D11_SAMPLER_DESC);
 
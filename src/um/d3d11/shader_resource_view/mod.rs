@@ -1,6 +1,10 @@
 use self::get_desc::GetDesc;
+use crate::r#macro::Accessors;
 use derive_more::{Deref, DerefMut, From, Into};
-use winapi::um::d3d11::{ID3D11ShaderResourceView, D3D11_SHADER_RESOURCE_VIEW_DESC};
+use winapi::{
+    shared::dxgiformat::DXGI_FORMAT,
+    um::d3d11::{ID3D11ShaderResourceView, D3D11_SHADER_RESOURCE_VIEW_DESC, D3D11_SRV_DIMENSION},
+};
 use wio::com::ComPtr;
 
 /// Shader resource view.
@@ -10,14 +14,16 @@ pub trait ShaderResourceView {
 
 impl ShaderResourceView for ComPtr<ID3D11ShaderResourceView> {
     fn get_description(&self) -> Description {
-        GetDesc::builder()
-            .shader_resource_view(self.clone())
-            .build()()
+        GetDesc::builder().shader_resource_view(self.clone()).call()
     }
 }
 
 /// Shader resource view description.
-#[derive(Deref, DerefMut, From, Into)]
+#[derive(Accessors, Deref, DerefMut, From, Into)]
+#[accessors(fields(
+    format(from = "Format", ty = "DXGI_FORMAT"),
+    view_dimension(from = "ViewDimension", ty = "D3D11_SRV_DIMENSION")
+))]
 #[repr(transparent)]
 pub struct Description(D3D11_SHADER_RESOURCE_VIEW_DESC);
 
@@ -1,6 +1,10 @@
 use self::get_desc::GetDesc;
+use crate::r#macro::Accessors;
 use derive_more::{Deref, DerefMut, From, Into};
-use winapi::um::d3d11::{ID3D11Texture2D, D3D11_TEXTURE2D_DESC};
+use winapi::{
+    shared::dxgiformat::DXGI_FORMAT,
+    um::d3d11::{ID3D11Texture2D, D3D11_TEXTURE2D_DESC, D3D11_USAGE},
+};
 use wio::com::ComPtr;
 
 /// Texture 2D.
@@ -10,12 +14,28 @@ pub trait Texture2D {
 
 impl Texture2D for ComPtr<ID3D11Texture2D> {
     fn get_description(&self) -> Description {
-        GetDesc::builder().texture_2d(self.clone()).build()()
+        GetDesc::builder().texture_2d(self.clone()).call()
     }
 }
 
 /// Texture 2D description.
-#[derive(Deref, DerefMut, From, Into)]
+#[derive(Accessors, Deref, DerefMut, From, Into)]
+#[accessors(
+    deserialize,
+    fields(
+        width(from = "Width", ty = "u32"),
+        height(from = "Height", ty = "u32"),
+        mip_levels(from = "MipLevels", ty = "u32"),
+        array_size(from = "ArraySize", ty = "u32"),
+        format(from = "Format", ty = "DXGI_FORMAT"),
+        sample_count(from = "SampleDesc.Count", ty = "u32"),
+        sample_quality(from = "SampleDesc.Quality", ty = "u32"),
+        usage(from = "Usage", ty = "D3D11_USAGE"),
+        bind_flags(from = "BindFlags", ty = "u32"),
+        cpu_access_flags(from = "CPUAccessFlags", ty = "u32"),
+        misc_flags(from = "MiscFlags", ty = "u32")
+    )
+)]
 #[repr(transparent)]
 pub struct Description(D3D11_TEXTURE2D_DESC);
 
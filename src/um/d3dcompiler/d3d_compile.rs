@@ -1,19 +1,19 @@
-use crate::{r#macro::FnOnce, um::d3d11::Blob};
-use anyhow::{ensure, Result};
-use format::lazy_format;
+use crate::{
+    r#macro::FnOnce, shared::winerror::HResult, um::d3d11::Blob, utils::Call, Error, Result,
+};
 use std::{
     ffi::{CStr, CString},
     mem::MaybeUninit,
     ptr::{null, null_mut},
 };
 use typed_builder::TypedBuilder;
-use winapi::{
-    shared::winerror::SUCCEEDED,
-    um::d3dcommon::{ID3DBlob, ID3DInclude, D3D_SHADER_MACRO},
-};
+use winapi::um::d3dcommon::{ID3DBlob, ID3DInclude, D3D_SHADER_MACRO};
 use wio::com::ComPtr;
 
 /// Direct 3D compile.
+///
+/// Fails with the [`HResult`] of the call, or with [`Error::Compile`] when the
+/// compiler has error messages.
 #[derive(FnOnce, TypedBuilder)]
 pub struct D3DCompile<'a> {
     source_data: &'a [u8],
@@ -35,10 +35,10 @@ pub struct D3DCompile<'a> {
     error_messages: MaybeUninit<ComPtr<ID3DBlob>>,
 }
 
-impl FnOnce<()> for D3DCompile<'_> {
+impl Call for D3DCompile<'_> {
     type Output = Result<ComPtr<ID3DBlob>>;
 
-    extern "rust-call" fn call_once(mut self, _args: ()) -> Self::Output {
+    fn call(mut self) -> Self::Output {
         use winapi::um::d3dcompiler::D3DCompile;
 
         let source_name = CString::new(self.source_name)?;
@@ -71,24 +71,17 @@ impl FnOnce<()> for D3DCompile<'_> {
                 ppCode,
                 ppErrorMsgs,
             );
-            ensure!(
-                SUCCEEDED(r#return),
-                "The D3DCompile call FAILED ({:#x}).{}",
-                r#return,
-                lazy_format!(|f| {
-                    if !(*ppErrorMsgs).is_null() {
-                        write!(
-                            f,
-                            "\n\t{}",
-                            CStr::from_ptr(
-                                self.error_messages.assume_init_ref().get_buffer_pointer() as _,
-                            )
-                            .to_string_lossy()
-                        )?;
-                    }
-                    Ok(())
-                })
-            );
+            if let Err(error) = HResult::from(r#return).ok() {
+                if (*ppErrorMsgs).is_null() {
+                    return Err(error.into());
+                }
+                let error_messages = &*self.error_messages.as_ptr();
+                let error_messages = CStr::from_ptr(error_messages.get_buffer_pointer() as _);
+                return Err(Error::Compile {
+                    hresult: error,
+                    message: error_messages.to_string_lossy().trim_end().to_owned(),
+                });
+            }
             Ok(self.code.assume_init())
         }
     }
@@ -1,6 +1,9 @@
 pub(crate) use self::d3d_compile::{D3DCompile, D3DCompileBuilder};
 
-pub fn d3d_compile<'a>() -> D3DCompileBuilder<'a, ((), (), (), (), (), (), (), ())> {
+/// The unset fields of `D3DCompileBuilder`.
+type D3DCompileFields = ((), (), (), (), (), (), (), ());
+
+pub fn d3d_compile<'a>() -> D3DCompileBuilder<'a, D3DCompileFields> {
     D3DCompile::builder()
 }
 
@@ -1,8 +1,6 @@
-use crate::r#macro::FnOnce;
-use anyhow::{ensure, Result};
+use crate::{error::ensure, r#macro::FnOnce, utils::Call, Error, Result};
 use std::{
     fs::File,
-    io,
     os::windows::io::{AsRawHandle, FromRawHandle},
     path::Path,
     ptr::null_mut,
@@ -29,10 +27,10 @@ pub struct CreateFile<'a> {
     template_file: Option<&'a mut File>,
 }
 
-impl FnOnce<()> for CreateFile<'_> {
+impl Call for CreateFile<'_> {
     type Output = Result<File>;
 
-    extern "rust-call" fn call_once(self, _args: ()) -> Self::Output {
+    fn call(self) -> Self::Output {
         use winapi::um::fileapi::CreateFileW;
 
         let file_name = WideCString::from_os_str(self.file_name)?;
@@ -55,7 +53,7 @@ impl FnOnce<()> for CreateFile<'_> {
                 dwFlagsAndAttributes,
                 hTemplateFile,
             );
-            ensure!(r#return != INVALID_HANDLE_VALUE, io::Error::last_os_error());
+            ensure!(r#return != INVALID_HANDLE_VALUE, Error::last_os_error());
             Ok(File::from_raw_handle(r#return))
         }
     }
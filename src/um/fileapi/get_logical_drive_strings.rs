@@ -1,7 +1,5 @@
-use crate::r#macro::FnOnce;
-use anyhow::{ensure, Result};
+use crate::{error::ensure, r#macro::FnOnce, utils::Call, Error, Result};
 use itertools::Itertools;
-use std::io;
 use typed_builder::TypedBuilder;
 use widestring::WideCStr;
 
@@ -18,10 +16,10 @@ impl Default for GetLogicalDriveStrings {
     }
 }
 
-impl FnOnce<()> for GetLogicalDriveStrings {
+impl Call for GetLogicalDriveStrings {
     type Output = Result<Vec<String>>;
 
-    extern "rust-call" fn call_once(mut self, _args: ()) -> Self::Output {
+    fn call(mut self) -> Self::Output {
         use winapi::um::fileapi::GetLogicalDriveStringsW;
 
         loop {
@@ -30,7 +28,7 @@ impl FnOnce<()> for GetLogicalDriveStrings {
                 let nBufferLength = self.buffer.capacity() as _;
                 let lpBuffer = self.buffer.as_mut_ptr() as _;
                 let r#return = GetLogicalDriveStringsW(nBufferLength, lpBuffer);
-                ensure!(r#return != 0, io::Error::last_os_error());
+                ensure!(r#return != 0, Error::last_os_error());
                 let length = r#return as _;
                 if length < self.buffer.capacity() {
                     self.buffer.set_len(length);
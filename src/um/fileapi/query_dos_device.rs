@@ -1,8 +1,7 @@
-use crate::r#macro::FnOnce;
-use anyhow::{ensure, Result};
+use crate::{error::ensure, r#macro::FnOnce, utils::Call, Error, Result};
 use itertools::Itertools;
 use log::warn;
-use std::{io, ptr::null};
+use std::ptr::null;
 use typed_builder::TypedBuilder;
 use widestring::{WideCStr, WideCString};
 use winapi::{
@@ -25,10 +24,10 @@ impl Default for QueryDosDevice<'_> {
     }
 }
 
-impl FnOnce<()> for QueryDosDevice<'_> {
+impl Call for QueryDosDevice<'_> {
     type Output = Result<Vec<String>>;
 
-    extern "rust-call" fn call_once(mut self, _args: ()) -> Self::Output {
+    fn call(mut self) -> Self::Output {
         use winapi::um::fileapi::QueryDosDeviceW;
 
         let device_name = self.device_name.map(WideCString::from_str).transpose()?;
@@ -40,14 +39,16 @@ impl FnOnce<()> for QueryDosDevice<'_> {
                 let lpTargetPath = self.target_path.as_mut_ptr() as _;
                 let ucchMax = self.target_path.capacity() as _;
                 let r#return = QueryDosDeviceW(lpDeviceName, lpTargetPath, ucchMax);
-                let last_os_error = io::Error::last_os_error();
+                let last_os_error = Error::last_os_error();
                 if r#return != 0 {
                     let length = r#return as usize;
                     self.target_path.set_len(length - 1);
                     break;
                 }
-                let raw_os_error = last_os_error.raw_os_error().unwrap() as _;
-                ensure!(ERROR_INSUFFICIENT_BUFFER == raw_os_error, last_os_error);
+                ensure!(
+                    last_os_error == Error::Win32(ERROR_INSUFFICIENT_BUFFER),
+                    last_os_error,
+                );
                 warn!("{}", last_os_error);
                 let additional = self.target_path.capacity() * 2 - self.target_path.len();
                 self.target_path.reserve(additional);
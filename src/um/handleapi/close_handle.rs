@@ -1,26 +1,29 @@
-use crate::{r#macro::FnOnce, shared::ntdef::Handle};
-use anyhow::Result;
-use std::{io, os::windows::io::AsRawHandle};
+use crate::{
+    error::ensure, r#macro::FnOnce, shared::ntdef::OwnedHandle, utils::Call, Error, Result,
+};
+use std::os::windows::io::IntoRawHandle;
 use typed_builder::TypedBuilder;
 use winapi::shared::minwindef::FALSE;
 
 /// Close handle.
+///
+/// Takes the ownership, so the handle is not closed once more on drop.
 #[derive(FnOnce, TypedBuilder)]
-pub struct CloseHandle<'a> {
-    handle: &'a Handle,
+pub struct CloseHandle {
+    handle: OwnedHandle,
 }
 
-impl FnOnce<()> for CloseHandle<'_> {
+impl Call for CloseHandle {
     type Output = Result<()>;
 
-    extern "rust-call" fn call_once(self, _args: ()) -> Self::Output {
+    fn call(self) -> Self::Output {
         use winapi::um::handleapi::CloseHandle;
 
         #[allow(non_snake_case)]
         unsafe {
-            let hObject = self.handle.as_raw_handle();
+            let hObject = self.handle.into_raw_handle();
             let r#return = CloseHandle(hObject);
-            assert!(r#return != FALSE, io::Error::last_os_error());
+            ensure!(r#return != FALSE, Error::last_os_error());
             Ok(())
         }
     }
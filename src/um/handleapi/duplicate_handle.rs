@@ -1,17 +1,25 @@
-use crate::{r#macro::FnOnce, shared::ntdef::Handle};
-use anyhow::{ensure, Result};
-use std::{io, mem::MaybeUninit, os::windows::io::AsRawHandle};
+use crate::{
+    error::ensure,
+    r#macro::FnOnce,
+    shared::ntdef::{BorrowedHandle, OwnedHandle},
+    utils::Call,
+    Error, Result,
+};
+use std::{mem::MaybeUninit, os::windows::io::AsRawHandle};
 use typed_builder::TypedBuilder;
 use winapi::shared::minwindef::FALSE;
 
 /// Duplicate handle.
 #[derive(FnOnce, TypedBuilder)]
 pub struct DuplicateHandle<'a> {
-    source_process_handle: &'a Handle,
-    source_handle: &'a Handle,
-    target_process_handle: &'a Handle,
+    #[builder(setter(into))]
+    source_process_handle: BorrowedHandle<'a>,
+    #[builder(setter(into))]
+    source_handle: BorrowedHandle<'a>,
+    #[builder(setter(into))]
+    target_process_handle: BorrowedHandle<'a>,
     #[builder(default = MaybeUninit::zeroed(), setter(skip))]
-    target_handle: MaybeUninit<Handle>,
+    target_handle: MaybeUninit<OwnedHandle>,
     #[builder(default, setter(strip_option))]
     desired_access: Option<u32>,
     #[builder(default, setter(strip_option))]
@@ -20,10 +28,10 @@ pub struct DuplicateHandle<'a> {
     options: Option<u32>,
 }
 
-impl FnOnce<()> for DuplicateHandle<'_> {
-    type Output = Result<Handle>;
+impl Call for DuplicateHandle<'_> {
+    type Output = Result<OwnedHandle>;
 
-    extern "rust-call" fn call_once(mut self, _args: ()) -> Self::Output {
+    fn call(mut self) -> Self::Output {
         use winapi::um::handleapi::DuplicateHandle;
 
         #[allow(non_snake_case)]
@@ -44,7 +52,7 @@ impl FnOnce<()> for DuplicateHandle<'_> {
                 bInheritHandle,
                 dwOptions,
             );
-            ensure!(r#return != FALSE, io::Error::last_os_error());
+            ensure!(r#return != FALSE, Error::last_os_error());
             let target_handle = self.target_handle.assume_init();
             Ok(target_handle)
         }
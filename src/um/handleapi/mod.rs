@@ -3,7 +3,7 @@ pub(crate) use self::{
     duplicate_handle::{DuplicateHandle, DuplicateHandleBuilder},
 };
 
-pub fn close_handle<'a>() -> CloseHandleBuilder<'a, ((),)> {
+pub fn close_handle() -> CloseHandleBuilder<((),)> {
     CloseHandle::builder()
 }
 
@@ -1,7 +1,6 @@
-use crate::r#macro::FnOnce;
-use anyhow::{ensure, Result};
+use crate::{error::ensure, r#macro::FnOnce, utils::Call, Error, Result};
 use std::{
-    default::Default, fs::File, io, mem::size_of_val, os::windows::io::AsRawHandle, ptr::null_mut,
+    default::Default, fs::File, mem::size_of_val, os::windows::io::AsRawHandle, ptr::null_mut,
 };
 use typed_builder::TypedBuilder;
 use winapi::{shared::minwindef::FALSE, um::minwinbase::OVERLAPPED};
@@ -21,10 +20,10 @@ pub struct DeviceIoControl<'a> {
     overlapped: Option<&'a mut OVERLAPPED>,
 }
 
-impl FnOnce<()> for DeviceIoControl<'_> {
+impl Call for DeviceIoControl<'_> {
     type Output = Result<usize>;
 
-    extern "rust-call" fn call_once(mut self, _args: ()) -> Self::Output {
+    fn call(mut self) -> Self::Output {
         use winapi::um::ioapiset::DeviceIoControl;
 
         #[allow(non_snake_case)]
@@ -47,7 +46,7 @@ impl FnOnce<()> for DeviceIoControl<'_> {
                 lpBytesReturned,
                 lpOverlapped,
             );
-            ensure!(r#return != FALSE, io::Error::last_os_error());
+            ensure!(r#return != FALSE, Error::last_os_error());
             Ok(self.bytes_returned as _)
         }
     }
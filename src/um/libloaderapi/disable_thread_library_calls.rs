@@ -1,6 +1,10 @@
-use crate::{r#macro::FnOnce, shared::minwindef::ModuleHandle, utils::AsStrictRawHandle};
-use anyhow::{ensure, Result};
-use std::io;
+use crate::{
+    error::ensure,
+    r#macro::FnOnce,
+    shared::minwindef::ModuleHandle,
+    utils::{AsStrictRawHandle, Call},
+    Error, Result,
+};
 use typed_builder::TypedBuilder;
 use winapi::shared::minwindef::FALSE;
 
@@ -11,17 +15,17 @@ pub struct DisableThreadLibraryCalls {
     handle: ModuleHandle,
 }
 
-impl FnOnce<()> for DisableThreadLibraryCalls {
+impl Call for DisableThreadLibraryCalls {
     type Output = Result<()>;
 
-    extern "rust-call" fn call_once(self, _args: ()) -> Self::Output {
+    fn call(self) -> Self::Output {
         use winapi::um::libloaderapi::DisableThreadLibraryCalls;
 
         #[allow(non_snake_case)]
         unsafe {
             let hLibModule = self.handle.as_strict_raw_handle();
             let r#return = DisableThreadLibraryCalls(hLibModule);
-            ensure!(r#return != FALSE, io::Error::last_os_error());
+            ensure!(r#return != FALSE, Error::last_os_error());
             Ok(())
         }
     }
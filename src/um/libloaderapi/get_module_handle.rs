@@ -1,6 +1,10 @@
-use crate::{r#macro::FnOnce, shared::minwindef::ModuleHandle, utils::FromStrictRawHandle};
-use anyhow::{ensure, Result};
-use std::io;
+use crate::{
+    error::ensure,
+    r#macro::FnOnce,
+    shared::minwindef::ModuleHandle,
+    utils::{Call, FromStrictRawHandle},
+    Error, Result,
+};
 use typed_builder::TypedBuilder;
 use widestring::WideCString;
 
@@ -10,10 +14,10 @@ pub struct GetModuleHandle<'a> {
     name: &'a str,
 }
 
-impl FnOnce<()> for GetModuleHandle<'_> {
+impl Call for GetModuleHandle<'_> {
     type Output = Result<ModuleHandle>;
 
-    extern "rust-call" fn call_once(self, _args: ()) -> Self::Output {
+    fn call(self) -> Self::Output {
         use winapi::um::libloaderapi::GetModuleHandleW;
 
         let name = WideCString::from_str(self.name)?;
@@ -22,7 +26,7 @@ impl FnOnce<()> for GetModuleHandle<'_> {
         unsafe {
             let lpModuleName = name.as_ptr();
             let r#return = GetModuleHandleW(lpModuleName);
-            ensure!(!r#return.is_null(), io::Error::last_os_error());
+            ensure!(!r#return.is_null(), Error::last_os_error());
             Ok(ModuleHandle::from_strict_raw_handle(r#return))
         }
     }
@@ -1,6 +1,11 @@
-use crate::{r#macro::FnOnce, shared::minwindef::ModuleHandle, utils::AsStrictRawHandle};
-use anyhow::{ensure, Result};
-use std::{ffi::CString, io};
+use crate::{
+    error::ensure,
+    r#macro::FnOnce,
+    shared::minwindef::ModuleHandle,
+    utils::{AsStrictRawHandle, Call},
+    Error, Result,
+};
+use std::ffi::CString;
 use typed_builder::TypedBuilder;
 
 /// Get process address.
@@ -10,10 +15,10 @@ pub struct GetProcAddress<'a> {
     name: &'a str,
 }
 
-impl FnOnce<()> for GetProcAddress<'_> {
+impl Call for GetProcAddress<'_> {
     type Output = Result<usize>;
 
-    extern "rust-call" fn call_once(self, _args: ()) -> Self::Output {
+    fn call(self) -> Self::Output {
         use winapi::um::libloaderapi::GetProcAddress;
 
         let name = CString::new(self.name)?;
@@ -23,7 +28,7 @@ impl FnOnce<()> for GetProcAddress<'_> {
             let hModule = self.handle.as_strict_raw_handle();
             let lpProcName = name.as_ptr() as _;
             let r#return = GetProcAddress(hModule, lpProcName);
-            ensure!(!r#return.is_null(), io::Error::last_os_error());
+            ensure!(!r#return.is_null(), Error::last_os_error());
             Ok(r#return as _)
         }
     }
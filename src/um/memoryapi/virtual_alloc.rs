@@ -1,6 +1,7 @@
-use crate::{r#macro::FnOnce, shared::ntdef::Handle};
-use anyhow::{ensure, Result};
-use std::{io, ptr::NonNull};
+use crate::{
+    error::ensure, r#macro::FnOnce, shared::ntdef::BorrowedHandle, utils::Call, Error, Result,
+};
+use std::ptr::NonNull;
 use typed_builder::TypedBuilder;
 
 /// Virtual allocation.
@@ -13,10 +14,10 @@ pub struct VirtualAlloc {
     protect: u32,
 }
 
-impl FnOnce<()> for VirtualAlloc {
+impl Call for VirtualAlloc {
     type Output = Result<NonNull<u8>>;
 
-    extern "rust-call" fn call_once(self, _args: ()) -> Self::Output {
+    fn call(self) -> Self::Output {
         use winapi::um::memoryapi::VirtualAlloc;
 
         #[allow(non_snake_case)]
@@ -26,31 +27,33 @@ impl FnOnce<()> for VirtualAlloc {
             let flAllocationType = self.allocation_type;
             let flProtect = self.protect;
             let r#return = VirtualAlloc(lpAddress, dwSize, flAllocationType, flProtect);
-            ensure!(!r#return.is_null(), io::Error::last_os_error());
+            ensure!(!r#return.is_null(), Error::last_os_error());
             Ok(NonNull::new_unchecked(r#return as _))
         }
     }
 }
 
 impl<T, U, V, W> VirtualAllocBuilder<(T, U, V, W)> {
-    pub fn process(
+    pub fn process<'a>(
         self,
-        process: &Handle,
-    ) -> extended::VirtualAllocBuilder<((&Handle,), T, U, V, W)> {
+        process: impl Into<BorrowedHandle<'a>>,
+    ) -> extended::VirtualAllocBuilder<'a, ((BorrowedHandle<'a>,), T, U, V, W)> {
         extended::VirtualAllocBuilder::from(self).process(process)
     }
 }
 
 pub mod extended {
-    use crate::{r#macro::FnOnce, shared::ntdef::Handle};
-    use anyhow::{ensure, Result};
-    use std::{io, marker::PhantomData, os::windows::io::AsRawHandle, ptr::NonNull};
+    use crate::{
+        error::ensure, r#macro::FnOnce, shared::ntdef::BorrowedHandle, utils::Call, Error, Result,
+    };
+    use std::{marker::PhantomData, os::windows::io::AsRawHandle, ptr::NonNull};
     use typed_builder::TypedBuilder;
 
     /// Virtual allocation extended.
     #[derive(FnOnce, TypedBuilder)]
     pub struct VirtualAlloc<'a> {
-        process: &'a Handle,
+        #[builder(setter(into))]
+        process: BorrowedHandle<'a>,
         #[builder(default)]
         address: usize,
         size: usize,
@@ -58,10 +61,10 @@ pub mod extended {
         protect: u32,
     }
 
-    impl FnOnce<()> for VirtualAlloc<'_> {
+    impl Call for VirtualAlloc<'_> {
         type Output = Result<NonNull<u8>>;
 
-        extern "rust-call" fn call_once(self, _args: ()) -> Self::Output {
+        fn call(self) -> Self::Output {
             use winapi::um::memoryapi::VirtualAllocEx;
 
             #[allow(non_snake_case)]
@@ -73,7 +76,7 @@ pub mod extended {
                 let flProtect = self.protect;
                 let r#return =
                     VirtualAllocEx(hProcess, lpAddress, dwSize, flAllocationType, flProtect);
-                ensure!(!r#return.is_null(), io::Error::last_os_error());
+                ensure!(!r#return.is_null(), Error::last_os_error());
                 Ok(NonNull::new_unchecked(r#return as _))
             }
         }
@@ -1,9 +1,8 @@
-use crate::{r#macro::FnOnce, shared::ntdef::Handle, um::winnt::MemoryBasicInformation};
-use anyhow::{ensure, Result};
-use std::{
-    io,
-    mem::{size_of, MaybeUninit},
+use crate::{
+    error::ensure, r#macro::FnOnce, shared::ntdef::BorrowedHandle,
+    um::winnt::MemoryBasicInformation, utils::Call, Error, Result,
 };
+use std::mem::{size_of, MaybeUninit};
 use typed_builder::TypedBuilder;
 
 /// Virtual query.
@@ -14,10 +13,10 @@ pub struct VirtualQuery {
     memory_basic_information: MaybeUninit<MemoryBasicInformation>,
 }
 
-impl FnOnce<()> for VirtualQuery {
+impl Call for VirtualQuery {
     type Output = Result<MemoryBasicInformation>;
 
-    extern "rust-call" fn call_once(mut self, _args: ()) -> Self::Output {
+    fn call(mut self) -> Self::Output {
         use winapi::um::memoryapi::VirtualQuery;
 
         #[allow(non_snake_case)]
@@ -26,19 +25,15 @@ impl FnOnce<()> for VirtualQuery {
             let lpBuffer = self.memory_basic_information.as_mut_ptr() as _;
             let dwLength = size_of::<MemoryBasicInformation>();
             let r#return = VirtualQuery(lpAddress, lpBuffer, dwLength);
-            ensure!(r#return != 0, io::Error::last_os_error());
+            ensure!(r#return != 0, Error::last_os_error());
             ensure!(
                 r#return == size_of::<MemoryBasicInformation>(),
-                "Invalid buffer size (expected: {}, found: {}).",
-                size_of::<MemoryBasicInformation>(),
-                r#return,
+                Error::unexpected_value("information size", r#return as i64),
             );
             let information = self.memory_basic_information.assume_init();
             ensure!(
                 information.base_address() == self.address,
-                "Invalid base address (expected: {:x}, found: {:x}).",
-                self.address,
-                information.base_address(),
+                Error::unexpected_value("base address", information.base_address() as i64),
             );
             Ok(information)
         }
@@ -46,16 +41,20 @@ impl FnOnce<()> for VirtualQuery {
 }
 
 impl<T> VirtualQueryBuilder<(T,)> {
-    pub fn process(self, process: &Handle) -> extended::VirtualQueryBuilder<'_, ((&Handle,), T)> {
+    pub fn process<'a>(
+        self,
+        process: impl Into<BorrowedHandle<'a>>,
+    ) -> extended::VirtualQueryBuilder<'a, ((BorrowedHandle<'a>,), T)> {
         extended::VirtualQueryBuilder::from(self).process(process)
     }
 }
 
 pub mod extended {
-    use crate::{r#macro::FnOnce, shared::ntdef::Handle, um::winnt::MemoryBasicInformation};
-    use anyhow::{ensure, Result};
+    use crate::{
+        error::ensure, r#macro::FnOnce, shared::ntdef::BorrowedHandle,
+        um::winnt::MemoryBasicInformation, utils::Call, Error, Result,
+    };
     use std::{
-        io,
         marker::PhantomData,
         mem::{size_of, MaybeUninit},
         os::windows::io::AsRawHandle,
@@ -65,16 +64,17 @@ pub mod extended {
     /// Virtual query extended.
     #[derive(FnOnce, TypedBuilder)]
     pub struct VirtualQuery<'a> {
-        process: &'a Handle,
+        #[builder(setter(into))]
+        process: BorrowedHandle<'a>,
         address: usize,
         #[builder(default = MaybeUninit::zeroed(), setter(skip))]
         memory_basic_information: MaybeUninit<MemoryBasicInformation>,
     }
 
-    impl FnOnce<()> for VirtualQuery<'_> {
+    impl Call for VirtualQuery<'_> {
         type Output = Result<MemoryBasicInformation>;
 
-        extern "rust-call" fn call_once(mut self, _args: ()) -> Self::Output {
+        fn call(mut self) -> Self::Output {
             use winapi::um::memoryapi::VirtualQueryEx;
 
             #[allow(non_snake_case)]
@@ -86,19 +86,15 @@ pub mod extended {
             #[allow(non_snake_case)]
             let dwLength = size_of::<MemoryBasicInformation>();
             let r#return = unsafe { VirtualQueryEx(hProcess, lpAddress, lpBuffer, dwLength) };
-            ensure!(r#return != 0, io::Error::last_os_error());
+            ensure!(r#return != 0, Error::last_os_error());
             ensure!(
                 r#return == size_of::<MemoryBasicInformation>(),
-                "Invalid buffer size (expected: {}, found: {}).",
-                size_of::<MemoryBasicInformation>(),
-                r#return,
+                Error::unexpected_value("information size", r#return as i64),
             );
             let information = unsafe { self.memory_basic_information.assume_init() };
             ensure!(
                 information.base_address() == self.address,
-                "Invalid base address (expected: {:x}, found: {:x}).",
-                self.address,
-                information.base_address(),
+                Error::unexpected_value("base address", information.base_address() as i64),
             );
             Ok(information)
         }
@@ -1,23 +1,25 @@
-use crate::{r#macro::FnOnce, shared::ntdef::Handle};
-use anyhow::{ensure, Result};
-use std::{io, os::windows::io::AsRawHandle};
+use crate::{
+    error::ensure, r#macro::FnOnce, shared::ntdef::BorrowedHandle, utils::Call, Error, Result,
+};
+use std::os::windows::io::AsRawHandle;
 use typed_builder::TypedBuilder;
 use winapi::shared::minwindef::FALSE;
 
 /// Write process memory.
 #[derive(FnOnce, TypedBuilder)]
 pub struct WriteProcessMemory<'a> {
-    process: &'a Handle,
+    #[builder(setter(into))]
+    process: BorrowedHandle<'a>,
     base_address: usize,
     buffer: &'a [u8],
     #[builder(default, setter(skip))]
     number_of_bytes_written: usize,
 }
 
-impl FnOnce<()> for WriteProcessMemory<'_> {
+impl Call for WriteProcessMemory<'_> {
     type Output = Result<usize>;
 
-    extern "rust-call" fn call_once(mut self, _args: ()) -> Self::Output {
+    fn call(mut self) -> Self::Output {
         use winapi::um::memoryapi::WriteProcessMemory;
 
         #[allow(non_snake_case)]
@@ -34,7 +36,7 @@ impl FnOnce<()> for WriteProcessMemory<'_> {
                 nSize,
                 lpNumberOfBytesWritten,
             );
-            ensure!(r#return != FALSE, io::Error::last_os_error());
+            ensure!(r#return != FALSE, Error::last_os_error());
             Ok(self.number_of_bytes_written)
         }
     }
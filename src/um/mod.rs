@@ -14,6 +14,8 @@ pub mod ioapiset;
 pub mod libloaderapi;
 #[cfg(feature = "memoryapi")]
 pub mod memoryapi;
+#[cfg(feature = "namedpipeapi")]
+pub mod namedpipeapi;
 #[cfg(feature = "processthreadsapi")]
 pub mod processthreadsapi;
 #[cfg(feature = "profileapi")]
@@ -40,6 +42,8 @@ pub mod wincontypes;
 pub mod winnt;
 #[cfg(feature = "winsvc")]
 pub mod winsvc;
+#[cfg(feature = "ntdll")]
+pub mod winternl;
 #[cfg(feature = "winuser")]
 pub mod winuser;
 #[cfg(feature = "wow64apiset")]
@@ -0,0 +1,48 @@
+use crate::{
+    error::ensure, r#macro::FnOnce, shared::ntdef::OwnedHandle, utils::Call, Error, Result,
+};
+use std::{mem::MaybeUninit, ptr::null_mut};
+use typed_builder::TypedBuilder;
+use winapi::{shared::minwindef::FALSE, um::minwinbase::SECURITY_ATTRIBUTES};
+
+/// Create pipe.
+///
+/// Returns the read and the write handles of an anonymous pipe.
+#[derive(FnOnce, TypedBuilder)]
+pub struct CreatePipe<'a> {
+    #[builder(default, setter(strip_option))]
+    pipe_attributes: Option<&'a mut SECURITY_ATTRIBUTES>,
+    #[builder(default)]
+    size: u32,
+    #[builder(default = MaybeUninit::zeroed(), setter(skip))]
+    read_pipe: MaybeUninit<OwnedHandle>,
+    #[builder(default = MaybeUninit::zeroed(), setter(skip))]
+    write_pipe: MaybeUninit<OwnedHandle>,
+}
+
+impl Default for CreatePipe<'_> {
+    fn default() -> Self {
+        Self::builder().build()
+    }
+}
+
+impl Call for CreatePipe<'_> {
+    type Output = Result<(OwnedHandle, OwnedHandle)>;
+
+    fn call(mut self) -> Self::Output {
+        use winapi::um::namedpipeapi::CreatePipe;
+
+        #[allow(non_snake_case)]
+        unsafe {
+            let hReadPipe = self.read_pipe.as_mut_ptr() as _;
+            let hWritePipe = self.write_pipe.as_mut_ptr() as _;
+            let lpPipeAttributes = self
+                .pipe_attributes
+                .map_or(null_mut(), |pipe_attributes| pipe_attributes as _);
+            let nSize = self.size;
+            let r#return = CreatePipe(hReadPipe, hWritePipe, lpPipeAttributes, nSize);
+            ensure!(r#return != FALSE, Error::last_os_error());
+            Ok((self.read_pipe.assume_init(), self.write_pipe.assume_init()))
+        }
+    }
+}
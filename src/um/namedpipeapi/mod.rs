@@ -0,0 +1,7 @@
+pub(crate) use self::create_pipe::{CreatePipe, CreatePipeBuilder};
+
+pub fn create_pipe<'a>() -> CreatePipeBuilder<'a, ((), ())> {
+    CreatePipe::builder()
+}
+
+mod create_pipe;
@@ -0,0 +1,97 @@
+use super::{ProcThreadAttributeList, ProcessInformation, PROC_THREAD_ATTRIBUTE_PSEUDOCONSOLE};
+use crate::{
+    error::ensure, r#macro::FnOnce, shared::ntdef::OwnedHandle, utils::Call, Error, Result,
+};
+use std::{
+    mem::{size_of, zeroed, MaybeUninit},
+    os::windows::io::FromRawHandle,
+    path::Path,
+    ptr::{null, null_mut},
+};
+use typed_builder::TypedBuilder;
+use widestring::WideCString;
+use winapi::{
+    shared::minwindef::FALSE,
+    um::{
+        processthreadsapi::PROCESS_INFORMATION,
+        winbase::{EXTENDED_STARTUPINFO_PRESENT, STARTF_USESTDHANDLES, STARTUPINFOEXW},
+    },
+};
+
+/// Create process.
+#[derive(FnOnce, TypedBuilder)]
+pub struct CreateProcess<'a> {
+    #[builder(default, setter(strip_option))]
+    application_name: Option<&'a str>,
+    command_line: &'a str,
+    #[builder(default)]
+    inherit_handles: bool,
+    #[builder(default)]
+    creation_flags: u32,
+    #[builder(default, setter(strip_option))]
+    current_directory: Option<&'a Path>,
+    #[builder(default, setter(strip_option))]
+    attribute_list: Option<&'a mut ProcThreadAttributeList>,
+    #[builder(default = MaybeUninit::zeroed(), setter(skip))]
+    process_information: MaybeUninit<PROCESS_INFORMATION>,
+}
+
+impl Call for CreateProcess<'_> {
+    type Output = Result<ProcessInformation>;
+
+    fn call(mut self) -> Self::Output {
+        use winapi::um::processthreadsapi::CreateProcessW;
+
+        let application_name = self
+            .application_name
+            .map(WideCString::from_str)
+            .transpose()?;
+        let mut command_line = WideCString::from_str(self.command_line)?.into_vec_with_nul();
+        let current_directory = self
+            .current_directory
+            .map(WideCString::from_os_str)
+            .transpose()?;
+        let mut startup_info: STARTUPINFOEXW = unsafe { zeroed() };
+        startup_info.StartupInfo.cb = size_of::<STARTUPINFOEXW>() as _;
+
+        #[allow(non_snake_case)]
+        unsafe {
+            let lpApplicationName = application_name.as_ref().map_or(null(), |v| v.as_ptr());
+            let lpCommandLine = command_line.as_mut_ptr();
+            let bInheritHandles = self.inherit_handles as _;
+            let mut dwCreationFlags = self.creation_flags;
+            if let Some(attribute_list) = self.attribute_list {
+                // The null standard handles keep the child from inheriting the
+                // ones of the parent, so it talks to the pseudo console.
+                if attribute_list.contains(PROC_THREAD_ATTRIBUTE_PSEUDOCONSOLE) {
+                    startup_info.StartupInfo.dwFlags |= STARTF_USESTDHANDLES;
+                }
+                startup_info.lpAttributeList = attribute_list.as_mut_ptr();
+                dwCreationFlags |= EXTENDED_STARTUPINFO_PRESENT;
+            }
+            let lpCurrentDirectory = current_directory.as_ref().map_or(null(), |v| v.as_ptr());
+            let lpStartupInfo = &mut startup_info.StartupInfo;
+            let lpProcessInformation = self.process_information.as_mut_ptr();
+            let r#return = CreateProcessW(
+                lpApplicationName,
+                lpCommandLine,
+                null_mut(),
+                null_mut(),
+                bInheritHandles,
+                dwCreationFlags,
+                null_mut(),
+                lpCurrentDirectory,
+                lpStartupInfo,
+                lpProcessInformation,
+            );
+            ensure!(r#return != FALSE, Error::last_os_error());
+            let process_information = self.process_information.assume_init();
+            Ok(ProcessInformation {
+                process: OwnedHandle::from_raw_handle(process_information.hProcess),
+                thread: OwnedHandle::from_raw_handle(process_information.hThread),
+                process_id: process_information.dwProcessId,
+                thread_id: process_information.dwThreadId,
+            })
+        }
+    }
+}
@@ -1,7 +1,11 @@
-use crate::{r#macro::FnOnce, shared::ntdef::Handle};
-use anyhow::{ensure, Result};
+use crate::{
+    error::ensure,
+    r#macro::FnOnce,
+    shared::ntdef::{BorrowedHandle, OwnedHandle},
+    utils::Call,
+    Error, Result,
+};
 use std::{
-    io,
     os::windows::io::{AsRawHandle, FromRawHandle},
     ptr::null_mut,
 };
@@ -11,7 +15,8 @@ use winapi::um::minwinbase::{LPTHREAD_START_ROUTINE, SECURITY_ATTRIBUTES};
 /// Create remote thread.
 #[derive(FnOnce, TypedBuilder)]
 pub struct CreateRemoteThread<'a> {
-    process: &'a Handle,
+    #[builder(setter(into))]
+    process: BorrowedHandle<'a>,
     #[builder(setter(strip_option))]
     thread_attributes: Option<&'a mut SECURITY_ATTRIBUTES>,
     #[builder(setter(strip_option))]
@@ -25,10 +30,10 @@ pub struct CreateRemoteThread<'a> {
     thread_id: u32,
 }
 
-impl FnOnce<()> for CreateRemoteThread<'_> {
-    type Output = Result<(u32, Handle)>;
+impl Call for CreateRemoteThread<'_> {
+    type Output = Result<(u32, OwnedHandle)>;
 
-    extern "rust-call" fn call_once(mut self, _args: ()) -> Self::Output {
+    fn call(mut self) -> Self::Output {
         use winapi::um::processthreadsapi::CreateRemoteThread;
 
         #[allow(non_snake_case)]
@@ -53,8 +58,8 @@ impl FnOnce<()> for CreateRemoteThread<'_> {
                 dwCreationFlags,
                 lpThreadId,
             );
-            ensure!(!r#return.is_null(), io::Error::last_os_error());
-            Ok((self.thread_id, Handle::from_raw_handle(r#return)))
+            ensure!(!r#return.is_null(), Error::last_os_error());
+            Ok((self.thread_id, OwnedHandle::from_raw_handle(r#return)))
         }
     }
 }
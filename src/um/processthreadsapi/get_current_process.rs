@@ -1,18 +1,25 @@
-use crate::shared::ntdef::Handle;
-use std::os::windows::io::FromRawHandle;
+use crate::{
+    r#macro::FnOnce,
+    shared::ntdef::BorrowedHandle,
+    utils::{Call, FromStrictRawHandle},
+};
 
 /// Get current process.
+///
+/// The pseudo handle is never closed, it is the same as
+/// `BorrowedHandle::CURRENT_PROCESS`.
+#[derive(FnOnce)]
 pub struct GetCurrentProcess;
 
-impl FnOnce<()> for GetCurrentProcess {
-    type Output = Handle;
+impl Call for GetCurrentProcess {
+    type Output = BorrowedHandle<'static>;
 
-    extern "rust-call" fn call_once(self, _args: ()) -> Self::Output {
+    fn call(self) -> Self::Output {
         use winapi::um::processthreadsapi::GetCurrentProcess;
 
         unsafe {
             let r#return = GetCurrentProcess();
-            Handle::from_raw_handle(r#return)
+            BorrowedHandle::from_strict_raw_handle(r#return)
         }
     }
 }
@@ -1,18 +1,25 @@
-use crate::shared::ntdef::Handle;
-use std::os::windows::io::FromRawHandle;
+use crate::{
+    r#macro::FnOnce,
+    shared::ntdef::BorrowedHandle,
+    utils::{Call, FromStrictRawHandle},
+};
 
 /// Get current thread.
+///
+/// The pseudo handle is never closed, it is the same as
+/// `BorrowedHandle::CURRENT_THREAD`.
+#[derive(FnOnce)]
 pub struct GetCurrentThread;
 
-impl FnOnce<()> for GetCurrentThread {
-    type Output = Handle;
+impl Call for GetCurrentThread {
+    type Output = BorrowedHandle<'static>;
 
-    extern "rust-call" fn call_once(self, _args: ()) -> Self::Output {
+    fn call(self) -> Self::Output {
         use winapi::um::processthreadsapi::GetCurrentThread;
 
         unsafe {
             let r#return = GetCurrentThread();
-            Handle::from_raw_handle(r#return)
+            BorrowedHandle::from_strict_raw_handle(r#return)
         }
     }
 }
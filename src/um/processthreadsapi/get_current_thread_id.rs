@@ -0,0 +1,15 @@
+use crate::{r#macro::FnOnce, utils::Call};
+
+/// Get current thread id.
+#[derive(FnOnce)]
+pub struct GetCurrentThreadId;
+
+impl Call for GetCurrentThreadId {
+    type Output = u32;
+
+    fn call(self) -> Self::Output {
+        use winapi::um::processthreadsapi::GetCurrentThreadId;
+
+        unsafe { GetCurrentThreadId() }
+    }
+}
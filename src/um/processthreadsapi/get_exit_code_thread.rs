@@ -1,30 +1,16 @@
-use crate::{r#macro::FnOnce, shared::ntdef::Handle};
-use anyhow::{ensure, Result};
-use std::{io, os::windows::io::AsRawHandle};
-use typed_builder::TypedBuilder;
-use winapi::shared::minwindef::FALSE;
+use crate::{r#macro::win32, shared::ntdef::BorrowedHandle};
 
 /// Get thread exit code.
-#[derive(FnOnce, TypedBuilder)]
+#[win32(
+    fn = "GetExitCodeThread",
+    module = "winapi::um::processthreadsapi",
+    fail = "FALSE",
+    out = "exit_code"
+)]
 pub struct GetExitCodeThread<'a> {
-    thread: &'a Handle,
-    #[builder(default, setter(skip))]
+    #[win32(name = "hThread")]
+    #[builder(setter(into))]
+    thread: BorrowedHandle<'a>,
+    #[win32(name = "lpExitCode")]
     exit_code: u32,
 }
-
-impl FnOnce<()> for GetExitCodeThread<'_> {
-    type Output = Result<u32>;
-
-    extern "rust-call" fn call_once(mut self, _args: ()) -> Self::Output {
-        use winapi::um::processthreadsapi::GetExitCodeThread;
-
-        #[allow(non_snake_case)]
-        unsafe {
-            let hThread = self.thread.as_raw_handle();
-            let lpExitCode = &mut self.exit_code;
-            let r#return = GetExitCodeThread(hThread, lpExitCode);
-            ensure!(r#return != FALSE, io::Error::last_os_error());
-            Ok(self.exit_code)
-        }
-    }
-}
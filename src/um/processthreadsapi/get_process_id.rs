@@ -1,23 +1,14 @@
-use crate::{r#macro::FnOnce, shared::ntdef::Handle};
-use std::os::windows::io::AsRawHandle;
-use typed_builder::TypedBuilder;
+use crate::{r#macro::win32, shared::ntdef::BorrowedHandle};
 
 /// GetProcessId.
-#[derive(FnOnce, TypedBuilder)]
+#[win32(
+    fn = "GetProcessId",
+    module = "winapi::um::processthreadsapi",
+    fail = "0",
+    output = "u32"
+)]
 pub struct GetProcessId<'a> {
-    process: &'a Handle,
-}
-
-impl FnOnce<()> for GetProcessId<'_> {
-    type Output = u32;
-
-    extern "rust-call" fn call_once(self, _args: ()) -> Self::Output {
-        use winapi::um::processthreadsapi::GetProcessId;
-
-        #[allow(non_snake_case)]
-        unsafe {
-            let Process = self.process.as_raw_handle();
-            GetProcessId(Process)
-        }
-    }
+    #[win32(name = "Process")]
+    #[builder(setter(into))]
+    process: BorrowedHandle<'a>,
 }
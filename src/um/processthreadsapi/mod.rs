@@ -1,25 +1,53 @@
 pub(crate) use self::{
+    create_process::{CreateProcess, CreateProcessBuilder},
     create_remote_thread::{CreateRemoteThread, CreateRemoteThreadBuilder},
     get_current_process::GetCurrentProcess,
     get_current_thread::GetCurrentThread,
+    get_current_thread_id::GetCurrentThreadId,
     get_exit_code_thread::{GetExitCodeThread, GetExitCodeThreadBuilder},
     get_process_id::{GetProcessId, GetProcessIdBuilder},
     open_process::{OpenProcess, OpenProcessBuilder},
     open_process_token::{OpenProcessToken, OpenProcessTokenBuilder},
     set_thread_affinity_mask::{SetThreadAffinityMask, SetThreadAffinityMaskBuilder},
 };
-use crate::shared::ntdef::Handle;
+use crate::{
+    error::ensure,
+    shared::ntdef::{BorrowedHandle, OwnedHandle},
+    utils::Call,
+    Error, Result,
+};
+use std::{
+    fmt::{self, Debug, Formatter},
+    mem::size_of,
+    ptr::null_mut,
+};
+use winapi::{
+    shared::{minwindef::FALSE, winerror::ERROR_INSUFFICIENT_BUFFER},
+    um::processthreadsapi::LPPROC_THREAD_ATTRIBUTE_LIST,
+};
+
+/// The attribute of the pseudo console a process is attached to, missing from
+/// `winapi`.
+pub const PROC_THREAD_ATTRIBUTE_PSEUDOCONSOLE: usize = 0x00020016;
+
+pub fn create_process<'a>() -> CreateProcessBuilder<'a, ((), (), (), (), (), ())> {
+    CreateProcess::builder()
+}
 
 pub fn create_remote_thread<'a>() -> CreateRemoteThreadBuilder<'a, ((), (), (), (), (), ())> {
     CreateRemoteThread::builder()
 }
 
-pub fn get_current_process<'a>() -> Handle {
-    GetCurrentProcess()
+pub fn get_current_process() -> BorrowedHandle<'static> {
+    GetCurrentProcess.call()
 }
 
-pub fn get_current_thread<'a>() -> Handle {
-    GetCurrentThread()
+pub fn get_current_thread() -> BorrowedHandle<'static> {
+    GetCurrentThread.call()
+}
+
+pub fn get_current_thread_id() -> u32 {
+    GetCurrentThreadId.call()
 }
 
 pub fn get_exit_code_thread<'a>() -> GetExitCodeThreadBuilder<'a, ((),)> {
@@ -42,11 +70,108 @@ pub fn set_thread_affinity_mask<'a>() -> SetThreadAffinityMaskBuilder<'a, ((),)>
     SetThreadAffinityMask::builder()
 }
 
+mod create_process;
 mod create_remote_thread;
 mod get_current_process;
 mod get_current_thread;
+mod get_current_thread_id;
 mod get_exit_code_thread;
 mod get_process_id;
 mod open_process;
 mod open_process_token;
 mod set_thread_affinity_mask;
+
+/// Process information.
+#[derive(Debug)]
+pub struct ProcessInformation {
+    pub process: OwnedHandle,
+    pub thread: OwnedHandle,
+    pub process_id: u32,
+    pub thread_id: u32,
+}
+
+/// Process and thread attribute list.
+pub struct ProcThreadAttributeList {
+    buffer: Vec<usize>,
+    attributes: Vec<usize>,
+}
+
+impl ProcThreadAttributeList {
+    pub fn new(attribute_count: u32) -> Result<Self> {
+        use winapi::um::processthreadsapi::InitializeProcThreadAttributeList;
+
+        let mut size = 0;
+        let r#return =
+            unsafe { InitializeProcThreadAttributeList(null_mut(), attribute_count, 0, &mut size) };
+        let last_os_error = Error::last_os_error();
+        ensure!(
+            r#return == FALSE && last_os_error == Error::Win32(ERROR_INSUFFICIENT_BUFFER),
+            last_os_error,
+        );
+        let mut buffer = vec![0; size.div_ceil(size_of::<usize>())];
+        #[allow(non_snake_case)]
+        unsafe {
+            let lpAttributeList = buffer.as_mut_ptr() as _;
+            let dwAttributeCount = attribute_count;
+            let lpSize = &mut size;
+            let r#return =
+                InitializeProcThreadAttributeList(lpAttributeList, dwAttributeCount, 0, lpSize);
+            ensure!(r#return != FALSE, Error::last_os_error());
+        }
+        Ok(Self {
+            buffer,
+            attributes: Vec::new(),
+        })
+    }
+
+    /// Updates the attribute.
+    ///
+    /// # Safety
+    ///
+    /// The `value` must stay valid until the list is dropped.
+    pub unsafe fn update(&mut self, attribute: usize, value: *mut u8, size: usize) -> Result<()> {
+        use winapi::um::processthreadsapi::UpdateProcThreadAttribute;
+
+        #[allow(non_snake_case)]
+        let lpAttributeList = self.as_mut_ptr();
+        let r#return = UpdateProcThreadAttribute(
+            lpAttributeList,
+            0,
+            attribute,
+            value as _,
+            size,
+            null_mut(),
+            null_mut(),
+        );
+        ensure!(r#return != FALSE, Error::last_os_error());
+        self.attributes.push(attribute);
+        Ok(())
+    }
+
+    /// Whether the attribute has been updated.
+    #[inline]
+    pub fn contains(&self, attribute: usize) -> bool {
+        self.attributes.contains(&attribute)
+    }
+
+    #[inline]
+    pub fn as_mut_ptr(&mut self) -> LPPROC_THREAD_ATTRIBUTE_LIST {
+        self.buffer.as_mut_ptr() as _
+    }
+}
+
+impl Debug for ProcThreadAttributeList {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.debug_tuple("ProcThreadAttributeList")
+            .field(&format_args!("{:#p}", self.buffer.as_ptr()))
+            .finish()
+    }
+}
+
+impl Drop for ProcThreadAttributeList {
+    fn drop(&mut self) {
+        use winapi::um::processthreadsapi::DeleteProcThreadAttributeList;
+
+        unsafe { DeleteProcThreadAttributeList(self.as_mut_ptr()) }
+    }
+}
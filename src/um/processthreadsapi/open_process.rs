@@ -1,6 +1,7 @@
-use crate::{r#macro::FnOnce, shared::ntdef::Handle};
-use anyhow::{ensure, Result};
-use std::{io, os::windows::io::FromRawHandle};
+use crate::{
+    error::ensure, r#macro::FnOnce, shared::ntdef::OwnedHandle, utils::Call, Error, Result,
+};
+use std::os::windows::io::FromRawHandle;
 use typed_builder::TypedBuilder;
 use winapi::um::winnt::PROCESS_ALL_ACCESS;
 
@@ -14,10 +15,10 @@ pub struct OpenProcess {
     id: u32,
 }
 
-impl FnOnce<()> for OpenProcess {
-    type Output = Result<Handle>;
+impl Call for OpenProcess {
+    type Output = Result<OwnedHandle>;
 
-    extern "rust-call" fn call_once(self, _args: ()) -> Self::Output {
+    fn call(self) -> Self::Output {
         use winapi::um::processthreadsapi::OpenProcess;
 
         #[allow(non_snake_case)]
@@ -26,8 +27,8 @@ impl FnOnce<()> for OpenProcess {
             let bInheritHandle = self.inherit_handle as _;
             let dwProcessId = self.id;
             let r#return = OpenProcess(dwDesiredAccess, bInheritHandle, dwProcessId);
-            ensure!(!r#return.is_null(), io::Error::last_os_error());
-            Ok(Handle::from_raw_handle(r#return))
+            ensure!(!r#return.is_null(), Error::last_os_error());
+            Ok(OwnedHandle::from_raw_handle(r#return))
         }
     }
 }
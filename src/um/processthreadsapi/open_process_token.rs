@@ -1,22 +1,28 @@
-use crate::{r#macro::FnOnce, shared::ntdef::Handle};
-use anyhow::{ensure, Result};
-use std::{io, mem::MaybeUninit, os::windows::io::AsRawHandle};
+use crate::{
+    error::ensure,
+    r#macro::FnOnce,
+    shared::ntdef::{BorrowedHandle, OwnedHandle},
+    utils::Call,
+    Error, Result,
+};
+use std::{mem::MaybeUninit, os::windows::io::AsRawHandle};
 use typed_builder::TypedBuilder;
 use winapi::shared::minwindef::FALSE;
 
 /// Open process token.
 #[derive(FnOnce, TypedBuilder)]
 pub struct OpenProcessToken<'a> {
-    process_handle: &'a Handle,
+    #[builder(setter(into))]
+    process_handle: BorrowedHandle<'a>,
     desired_access: u32,
     #[builder(default = MaybeUninit::zeroed(), setter(skip))]
-    token_handle: MaybeUninit<Handle>,
+    token_handle: MaybeUninit<OwnedHandle>,
 }
 
-impl FnOnce<()> for OpenProcessToken<'_> {
-    type Output = Result<Handle>;
+impl Call for OpenProcessToken<'_> {
+    type Output = Result<OwnedHandle>;
 
-    extern "rust-call" fn call_once(mut self, _args: ()) -> Self::Output {
+    fn call(mut self) -> Self::Output {
         use winapi::um::processthreadsapi::OpenProcessToken;
 
         #[allow(non_snake_case)]
@@ -25,7 +31,7 @@ impl FnOnce<()> for OpenProcessToken<'_> {
             let DesiredAccess = self.desired_access;
             let TokenHandle = self.token_handle.as_mut_ptr() as _;
             let r#return = OpenProcessToken(ProcessHandle, DesiredAccess, TokenHandle);
-            ensure!(r#return != FALSE, io::Error::last_os_error());
+            ensure!(r#return != FALSE, Error::last_os_error());
             Ok(self.token_handle.assume_init())
         }
     }
@@ -1,6 +1,4 @@
-use crate::r#macro::FnOnce;
-use anyhow::{ensure, Result};
-use std::io;
+use crate::{error::ensure, r#macro::FnOnce, utils::Call, Error, Result};
 use typed_builder::TypedBuilder;
 use winapi::shared::minwindef::FALSE;
 
@@ -17,17 +15,17 @@ impl Default for QueryPerformanceCounter {
     }
 }
 
-impl FnOnce<()> for QueryPerformanceCounter {
+impl Call for QueryPerformanceCounter {
     type Output = Result<i64>;
 
-    extern "rust-call" fn call_once(mut self, _args: ()) -> Self::Output {
+    fn call(mut self) -> Self::Output {
         use winapi::um::profileapi::QueryPerformanceCounter;
 
         #[allow(non_snake_case)]
         unsafe {
             let lpPerformanceCount = &mut self.performance_count as *mut _ as _;
             let r#return = QueryPerformanceCounter(lpPerformanceCount);
-            ensure!(r#return != FALSE, io::Error::last_os_error());
+            ensure!(r#return != FALSE, Error::last_os_error());
             Ok(self.performance_count)
         }
     }
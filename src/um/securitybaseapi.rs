@@ -1,8 +1,9 @@
-use crate::{r#macro::FnOnce, shared::ntdef::Handle, um::winnt::Privileges};
-use anyhow::{ensure, Error};
+use crate::{
+    error::ensure, r#macro::FnOnce, shared::ntdef::BorrowedHandle, um::winnt::Privileges,
+    utils::Call, Error,
+};
 use log::warn;
 use std::{
-    io,
     mem::size_of_val,
     os::windows::io::AsRawHandle,
     ptr::{null, null_mut},
@@ -13,26 +14,27 @@ use winapi::shared::minwindef::FALSE;
 /// Adjust token privileges.
 #[derive(FnOnce, TypedBuilder)]
 pub struct AdjustTokenPrivileges<'a> {
-    token: &'a Handle,
+    #[builder(setter(into))]
+    token: BorrowedHandle<'a>,
     #[builder(default)]
     disable_all_privileges: bool,
     #[builder(default, setter(strip_option))]
     new_state: Option<&'a Privileges>,
-
-    #[builder(default, setter(skip))]
-    buffer_length: u32,
     #[builder(default, setter(strip_option))]
     previous_state: Option<&'a mut Privileges>,
     #[builder(default, setter(skip))]
     return_length: u32,
 }
 
-impl FnOnce<()> for AdjustTokenPrivileges<'_> {
+impl Call for AdjustTokenPrivileges<'_> {
     type Output = Result<(), Error>;
 
-    extern "rust-call" fn call_once(mut self, _args: ()) -> Self::Output {
+    fn call(mut self) -> Self::Output {
         use winapi::um::securitybaseapi::AdjustTokenPrivileges;
 
+        if self.disable_all_privileges && self.new_state.is_some() {
+            warn!("The disable_all_privileges is true and the new_state is ignored.");
+        }
         #[allow(non_snake_case)]
         let TokenHandle = self.token.as_raw_handle();
         #[allow(non_snake_case)]
@@ -61,26 +63,7 @@ impl FnOnce<()> for AdjustTokenPrivileges<'_> {
                 ReturnLength,
             )
         };
-        ensure!(r#return != FALSE, io::Error::last_os_error());
-        Ok(())
-    }
-}
-
-// TODO:
-// if self.disable_all_privileges.is_some() && self.new_state.is_some() {
-//     warn!("The disable_all_privileges and the new_state are set.");
-// }
-// #[builder(
-//      condition = "self.disable_all_privileges.is_some() && self.new_state.is_some()",
-//      warn = "The disable_all_privileges and the new_state are set."
-// )]
-impl<T, W> AdjustTokenPrivilegesBuilder<'_, (T, (bool,), (Option<&Privileges>,), W)> {
-    fn validate(&self) -> Result<(), String> {
-        let disable_all_privileges = (self.fields.1).0;
-        let new_state = (self.fields.2).0;
-        if disable_all_privileges && new_state.is_some() {
-            warn!("The disable_all_privileges is true and the new_state is some.");
-        }
+        ensure!(r#return != FALSE, Error::last_os_error());
         Ok(())
     }
 }
@@ -88,40 +71,43 @@ impl<T, W> AdjustTokenPrivilegesBuilder<'_, (T, (bool,), (Option<&Privileges>,),
 #[cfg(test)]
 mod tests {
     use super::AdjustTokenPrivileges;
-    use crate::um::{
-        processthreadsapi::{GetCurrentProcess, OpenProcessToken},
-        winnt::Privilege,
+    use crate::{
+        um::{
+            processthreadsapi::{GetCurrentProcess, OpenProcessToken},
+            winnt::Privilege,
+        },
+        utils::Call,
     };
     use anyhow::Error;
     use winapi::um::winnt::{SE_PRIVILEGE_ENABLED, SE_SECURITY_NAME, TOKEN_ADJUST_PRIVILEGES};
 
     #[test]
     fn disable_all_privileges() -> Result<(), Error> {
-        let process = GetCurrentProcess();
+        let process = GetCurrentProcess.call();
         let token = OpenProcessToken::builder()
-            .process_handle(&process)
+            .process_handle(process)
             .desired_access(TOKEN_ADJUST_PRIVILEGES)
-            .build()()?;
+            .call()?;
         AdjustTokenPrivileges::builder()
             .token(&token)
             .disable_all_privileges(true)
-            .build()()?;
+            .call()?;
         Ok(())
     }
 
     #[test]
     fn privileges() -> Result<(), Error> {
-        let process = GetCurrentProcess();
+        let process = GetCurrentProcess.call();
         let token = OpenProcessToken::builder()
-            .process_handle(&process)
+            .process_handle(process)
             .desired_access(TOKEN_ADJUST_PRIVILEGES)
-            .build()()?;
+            .call()?;
         let privilege = Privilege::lookup(SE_SECURITY_NAME)?.attribute(SE_PRIVILEGE_ENABLED);
         let privileges = privilege.into();
         AdjustTokenPrivileges::builder()
             .token(&token)
             .new_state(&privileges)
-            .build()()?;
+            .call()?;
         Ok(())
     }
 }
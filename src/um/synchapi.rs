@@ -8,33 +8,27 @@
 //! [Thread](crate::um::thread::Thread)
 //! Waitable timer
 
-use crate::{r#macro::FnOnce, shared::ntdef::Handle};
-use std::{io, os::windows::io::AsRawHandle, thread::JoinHandle};
+use crate::{r#macro::FnOnce, shared::ntdef::BorrowedHandle, utils::Call, Error, Result};
+use std::os::windows::io::AsRawHandle;
 use typed_builder::TypedBuilder;
 use winapi::{
     shared::winerror::WAIT_TIMEOUT,
     um::winbase::{INFINITE, WAIT_ABANDONED, WAIT_FAILED, WAIT_OBJECT_0},
 };
 
-/// Wait.
-pub trait Wait: AsRawHandle {}
-
-impl Wait for Handle {}
-
-impl<T> Wait for JoinHandle<T> {}
-
 /// Wait for single object.
 #[derive(FnOnce, TypedBuilder)]
-pub struct WaitForSingleObject<T: Wait> {
-    handle: T,
+pub struct WaitForSingleObject<'a> {
+    #[builder(setter(into))]
+    handle: BorrowedHandle<'a>,
     #[builder(default = INFINITE)]
     milliseconds: u32,
 }
 
-impl<T: Wait> FnOnce<()> for WaitForSingleObject<T> {
-    type Output = io::Result<()>;
+impl Call for WaitForSingleObject<'_> {
+    type Output = Result<()>;
 
-    extern "rust-call" fn call_once(self, _args: ()) -> Self::Output {
+    fn call(self) -> Self::Output {
         use winapi::um::synchapi::WaitForSingleObject;
 
         #[allow(non_snake_case)]
@@ -44,9 +38,9 @@ impl<T: Wait> FnOnce<()> for WaitForSingleObject<T> {
         let r#return = unsafe { WaitForSingleObject(hHandle, dwMilliseconds) };
         match r#return {
             WAIT_OBJECT_0 => Ok(()),
-            WAIT_ABANDONED => Err(io::Error::from_raw_os_error(WAIT_ABANDONED as _)),
-            WAIT_TIMEOUT => Err(io::Error::from_raw_os_error(WAIT_TIMEOUT as _)),
-            WAIT_FAILED => Err(io::Error::last_os_error()),
+            WAIT_ABANDONED => Err(Error::Win32(WAIT_ABANDONED)),
+            WAIT_TIMEOUT => Err(Error::Win32(WAIT_TIMEOUT)),
+            WAIT_FAILED => Err(Error::last_os_error()),
             _ => unreachable!(),
         }
     }
@@ -61,18 +55,20 @@ mod tests {
     #[test]
     fn with_handle() -> Result<()> {
         let handle = thread::spawn(|| {});
-        WaitForSingleObject::builder().handle(handle).build()()?;
+        WaitForSingleObject::builder().handle(&handle).call()?;
         Ok(())
     }
 
     #[test]
     #[should_panic(expected = "The wait operation timed out.")]
     fn with_handle_and_milliseconds() {
-        let handle = thread::spawn(|| loop {});
+        let handle = thread::spawn(|| loop {
+            thread::park();
+        });
         WaitForSingleObject::builder()
-            .handle(handle)
+            .handle(&handle)
             .milliseconds(100)
-            .build()()
-        .unwrap();
+            .call()
+            .unwrap();
     }
 }
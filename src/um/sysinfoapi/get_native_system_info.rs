@@ -1,5 +1,5 @@
 use super::SystemInformation;
-use crate::r#macro::FnOnce;
+use crate::{r#macro::FnOnce, utils::Call};
 use std::mem::MaybeUninit;
 use typed_builder::TypedBuilder;
 
@@ -10,10 +10,10 @@ pub struct GetNativeSystemInfo {
     system_information: MaybeUninit<SystemInformation>,
 }
 
-impl FnOnce<()> for GetNativeSystemInfo {
+impl Call for GetNativeSystemInfo {
     type Output = SystemInformation;
 
-    extern "rust-call" fn call_once(mut self, _args: ()) -> Self::Output {
+    fn call(mut self) -> Self::Output {
         use winapi::um::sysinfoapi::GetNativeSystemInfo;
 
         #[allow(non_snake_case)]
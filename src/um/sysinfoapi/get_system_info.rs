@@ -1,5 +1,5 @@
 use super::SystemInformation;
-use crate::r#macro::FnOnce;
+use crate::{r#macro::FnOnce, utils::Call};
 use std::mem::MaybeUninit;
 use typed_builder::TypedBuilder;
 
@@ -16,10 +16,10 @@ impl Default for GetSystemInfo {
     }
 }
 
-impl FnOnce<()> for GetSystemInfo {
+impl Call for GetSystemInfo {
     type Output = SystemInformation;
 
-    extern "rust-call" fn call_once(mut self, _args: ()) -> Self::Output {
+    fn call(mut self) -> Self::Output {
         use winapi::um::sysinfoapi::GetSystemInfo;
 
         #[allow(non_snake_case)]
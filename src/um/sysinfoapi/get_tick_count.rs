@@ -1,12 +1,15 @@
+use crate::{r#macro::FnOnce, utils::Call};
+
 /// Get tick count.
+#[derive(FnOnce)]
 pub struct GetTickCount;
 
-impl FnOnce<()> for GetTickCount {
+impl Call for GetTickCount {
     type Output = u32;
 
-    extern "rust-call" fn call_once(self, _args: ()) -> Self::Output {
+    fn call(self) -> Self::Output {
         use winapi::um::sysinfoapi::GetTickCount;
 
         unsafe { GetTickCount() }
     }
-}
\ No newline at end of file
+}
@@ -2,7 +2,10 @@ pub(crate) use self::{
     get_native_system_info::GetNativeSystemInfo, get_system_info::GetSystemInfo,
     get_tick_count::GetTickCount,
 };
+use crate::utils::Call;
 use derive_more::{Deref, DerefMut, From, Into};
+#[cfg(feature = "serde")]
+use serde::{ser::SerializeStruct, Deserialize, Serialize, Serializer};
 use std::{
     fmt::{self, Debug, Formatter},
     ops::RangeInclusive,
@@ -11,20 +14,20 @@ use winapi::um::{
     sysinfoapi::SYSTEM_INFO,
     winnt::{
         PROCESSOR_ARCHITECTURE_AMD64, PROCESSOR_ARCHITECTURE_ARM, PROCESSOR_ARCHITECTURE_ARM64,
-        PROCESSOR_ARCHITECTURE_IA64, PROCESSOR_ARCHITECTURE_INTEL, PROCESSOR_ARCHITECTURE_UNKNOWN,
+        PROCESSOR_ARCHITECTURE_IA64, PROCESSOR_ARCHITECTURE_INTEL,
     },
 };
 
 pub fn get_native_system_information() -> SystemInformation {
-    GetNativeSystemInfo::builder().build()()
+    GetNativeSystemInfo::builder().call()
 }
 
 pub fn get_system_information() -> SystemInformation {
-    GetSystemInfo::builder().build()()
+    GetSystemInfo::builder().call()
 }
 
 pub fn get_tick_count() -> u32 {
-    GetTickCount()
+    GetTickCount.call()
 }
 
 mod get_native_system_info;
@@ -38,7 +41,7 @@ pub struct SystemInformation(SYSTEM_INFO);
 
 impl SystemInformation {
     pub fn new() -> Self {
-        GetSystemInfo::default()()
+        GetSystemInfo::default().call()
     }
 
     #[inline]
@@ -62,6 +65,12 @@ impl SystemInformation {
     }
 }
 
+impl Default for SystemInformation {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl Debug for SystemInformation {
     fn fmt(&self, f: &mut Formatter) -> Result<(), fmt::Error> {
         f.debug_struct("SystemInformation")
@@ -80,7 +89,20 @@ impl Debug for SystemInformation {
     }
 }
 
+#[cfg(feature = "serde")]
+impl Serialize for SystemInformation {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut state = serializer.serialize_struct("SystemInformation", 4)?;
+        state.serialize_field("processor_architecture", &self.processor_architecture())?;
+        state.serialize_field("page_size", &self.page_size())?;
+        state.serialize_field("application_addresses", &self.application_addresses())?;
+        state.serialize_field("allocation_granularity", &self.allocation_granularity())?;
+        state.end()
+    }
+}
+
 #[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
 pub enum ProcessorArchitecture {
     Intel,
     Amd64,
@@ -98,7 +120,7 @@ impl From<u16> for ProcessorArchitecture {
             PROCESSOR_ARCHITECTURE_ARM => Self::Arm,
             PROCESSOR_ARCHITECTURE_ARM64 => Self::Arm64,
             PROCESSOR_ARCHITECTURE_IA64 => Self::Ia64,
-            PROCESSOR_ARCHITECTURE_UNKNOWN | _ => Self::Unknown,
+            _ => Self::Unknown,
         }
     }
 }
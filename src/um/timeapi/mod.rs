@@ -1,7 +1,8 @@
 pub(crate) use self::time_get_time::TimeGetTime;
+use crate::utils::Call;
 
 pub fn time_get_time() -> u32 {
-    TimeGetTime()
+    TimeGetTime.call()
 }
 
 mod time_get_time;
@@ -1,10 +1,13 @@
+use crate::{r#macro::FnOnce, utils::Call};
+
 /// Get tick count.
+#[derive(FnOnce)]
 pub struct TimeGetTime;
 
-impl FnOnce<()> for TimeGetTime {
+impl Call for TimeGetTime {
     type Output = u32;
 
-    extern "rust-call" fn call_once(self, _args: ()) -> Self::Output {
+    fn call(self) -> Self::Output {
         use winapi::um::timeapi::timeGetTime;
 
         unsafe { timeGetTime() }
@@ -1,6 +1,7 @@
-use crate::{r#macro::FnOnce, shared::ntdef::Handle};
-use anyhow::{ensure, Result};
-use std::{io, os::windows::io::FromRawHandle};
+use crate::{
+    error::ensure, r#macro::FnOnce, shared::ntdef::OwnedHandle, utils::Call, Error, Result,
+};
+use std::os::windows::io::FromRawHandle;
 use typed_builder::TypedBuilder;
 use winapi::um::handleapi::INVALID_HANDLE_VALUE;
 
@@ -12,10 +13,10 @@ pub struct CreateToolhelp32Snapshot {
     process_id: u32,
 }
 
-impl FnOnce<()> for CreateToolhelp32Snapshot {
-    type Output = Result<Handle>;
+impl Call for CreateToolhelp32Snapshot {
+    type Output = Result<OwnedHandle>;
 
-    extern "rust-call" fn call_once(self, _args: ()) -> Self::Output {
+    fn call(self) -> Self::Output {
         use winapi::um::tlhelp32::CreateToolhelp32Snapshot;
 
         #[allow(non_snake_case)]
@@ -23,8 +24,8 @@ impl FnOnce<()> for CreateToolhelp32Snapshot {
             let dwFlags = self.flags;
             let th32ProcessID = self.process_id;
             let r#return = CreateToolhelp32Snapshot(dwFlags, th32ProcessID);
-            ensure!(r#return != INVALID_HANDLE_VALUE, io::Error::last_os_error());
-            Ok(Handle::from_raw_handle(r#return))
+            ensure!(r#return != INVALID_HANDLE_VALUE, Error::last_os_error());
+            Ok(OwnedHandle::from_raw_handle(r#return))
         }
     }
 }
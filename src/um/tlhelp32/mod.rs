@@ -1,12 +1,7 @@
-use crate::{shared::minwindef::ModuleHandle, utils::FromStrictRawHandle};
+use crate::{r#macro::Accessors, shared::minwindef::ModuleHandle};
 use derive_more::{Deref, DerefMut, From, Into};
 use partial_uninit::PartialUninit;
-use std::{
-    fmt::{self, Debug, Formatter},
-    mem::size_of,
-    path::PathBuf,
-};
-use widestring::WideCStr;
+use std::mem::size_of;
 use winapi::um::tlhelp32::{HEAPLIST32, MODULEENTRY32W, PROCESSENTRY32W, THREADENTRY32};
 
 pub(crate) use self::{
@@ -68,43 +63,16 @@ mod thread32_first;
 mod thread32_next;
 
 /// Heap entry.
-#[derive(Clone, Deref, DerefMut, From, Into)]
+#[derive(Accessors, Clone, Deref, DerefMut, From, Into)]
+#[accessors(fields(
+    size(from = "dwSize", ty = "usize"),
+    process_id(from = "th32ProcessID", ty = "u32"),
+    heap_id(from = "th32HeapID", ty = "usize"),
+    flags(from = "dwFlags", ty = "u32")
+))]
 #[repr(transparent)]
 pub struct HeapEntry(HEAPLIST32);
 
-impl HeapEntry {
-    #[inline]
-    pub fn size(&self) -> usize {
-        self.0.dwSize
-    }
-
-    #[inline]
-    pub fn process_id(&self) -> u32 {
-        self.0.th32ProcessID
-    }
-
-    #[inline]
-    pub fn heap_id(&self) -> usize {
-        self.0.th32HeapID
-    }
-
-    #[inline]
-    pub fn flags(&self) -> u32 {
-        self.0.dwFlags
-    }
-}
-
-impl Debug for HeapEntry {
-    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
-        f.debug_struct("HeapEntry")
-            .field("size", &self.size())
-            .field("process_id", &self.process_id())
-            .field("heap_id", &self.heap_id())
-            .field("flags", &self.flags())
-            .finish()
-    }
-}
-
 impl PartialUninit for HeapEntry {
     fn partial_init(&mut self) {
         self.0.dwSize = size_of::<HEAPLIST32>() as _;
@@ -112,60 +80,23 @@ impl PartialUninit for HeapEntry {
 }
 
 /// Module entry.
-#[derive(Clone, Deref, DerefMut, From, Into)]
+#[derive(Accessors, Clone, Deref, DerefMut, From, Into)]
+#[accessors(fields(
+    process_id(from = "th32ProcessID", ty = "u32"),
+    base_address(from = "modBaseAddr", ty = "usize", convert = "cast"),
+    base_size(from = "modBaseSize", ty = "u32"),
+    handle(
+        from = "hModule",
+        ty = "ModuleHandle",
+        convert = "handle",
+        skip_serializing
+    ),
+    name(from = "szModule", convert = "wide_cstr"),
+    path(from = "szExePath", convert = "wide_cstr_path")
+))]
 #[repr(transparent)]
 pub struct ModuleEntry(MODULEENTRY32W);
 
-impl ModuleEntry {
-    #[inline]
-    pub fn process_id(&self) -> u32 {
-        self.0.th32ProcessID
-    }
-
-    #[inline]
-    pub fn base_address(&self) -> usize {
-        self.0.modBaseAddr as _
-    }
-
-    #[inline]
-    pub fn base_size(&self) -> u32 {
-        self.0.modBaseSize
-    }
-
-    #[inline]
-    pub fn handle(&self) -> ModuleHandle {
-        unsafe { ModuleHandle::from_strict_raw_handle(self.0.hModule) }
-    }
-
-    #[inline]
-    pub fn name(&self) -> String {
-        WideCStr::from_slice_with_nul(&self.szModule)
-            .unwrap()
-            .to_string_lossy()
-    }
-
-    #[inline]
-    pub fn path(&self) -> PathBuf {
-        WideCStr::from_slice_with_nul(&self.szExePath)
-            .unwrap()
-            .to_os_string()
-            .into()
-    }
-}
-
-impl Debug for ModuleEntry {
-    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
-        f.debug_struct("ModuleEntry")
-            .field("process_id", &self.process_id())
-            .field("base_address", &self.base_address())
-            .field("base_size", &self.base_size())
-            .field("handle", &self.handle())
-            .field("name", &self.name())
-            .field("path", &self.path())
-            .finish()
-    }
-}
-
 impl PartialUninit for ModuleEntry {
     fn partial_init(&mut self) {
         self.0.dwSize = size_of::<MODULEENTRY32W>() as _;
@@ -173,51 +104,21 @@ impl PartialUninit for ModuleEntry {
 }
 
 /// Process entry.
-#[derive(Clone, Deref, DerefMut, From, Into)]
+#[derive(Accessors, Clone, Deref, DerefMut, From, Into)]
+#[accessors(
+    deserialize,
+    size = "dwSize",
+    fields(
+        process_id(from = "th32ProcessID", ty = "u32"),
+        parent_process_id(from = "th32ParentProcessID", ty = "u32"),
+        threads_count(from = "cntThreads", ty = "u32"),
+        base_priority(from = "pcPriClassBase", ty = "i32"),
+        name(from = "szExeFile", convert = "wide_cstr")
+    )
+)]
 #[repr(transparent)]
 pub struct ProcessEntry(PROCESSENTRY32W);
 
-impl ProcessEntry {
-    #[inline]
-    pub fn process_id(&self) -> u32 {
-        self.0.th32ProcessID
-    }
-
-    #[inline]
-    pub fn parent_process_id(&self) -> u32 {
-        self.0.th32ParentProcessID
-    }
-
-    #[inline]
-    pub fn threads_count(&self) -> u32 {
-        self.0.cntThreads
-    }
-
-    #[inline]
-    pub fn base_priority(&self) -> i32 {
-        self.0.pcPriClassBase
-    }
-
-    #[inline]
-    pub fn name(&self) -> String {
-        WideCStr::from_slice_with_nul(&self.szExeFile)
-            .unwrap()
-            .to_string_lossy()
-    }
-}
-
-impl Debug for ProcessEntry {
-    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
-        f.debug_struct("ProcessEntry")
-            .field("process_id", &self.process_id())
-            .field("parent_process_id", &self.parent_process_id())
-            .field("threads_count", &self.threads_count())
-            .field("base_priority", &self.base_priority())
-            .field("name", &self.name())
-            .finish()
-    }
-}
-
 impl PartialUninit for ProcessEntry {
     fn partial_init(&mut self) {
         self.0.dwSize = size_of::<PROCESSENTRY32W>() as _;
@@ -225,55 +126,22 @@ impl PartialUninit for ProcessEntry {
 }
 
 /// Thread entry.
-#[derive(Clone, Deref, DerefMut, From, Into)]
+#[derive(Accessors, Clone, Deref, DerefMut, From, Into)]
+#[accessors(
+    deserialize,
+    size = "dwSize",
+    fields(
+        size(from = "dwSize", ty = "u32"),
+        thread_id(from = "th32ThreadID", ty = "u32"),
+        process_id(from = "th32OwnerProcessID", ty = "u32"),
+        base_pri(from = "tpBasePri", ty = "i32"),
+        delta_pri(from = "tpDeltaPri", ty = "i32"),
+        flags(from = "dwFlags", ty = "u32")
+    )
+)]
 #[repr(transparent)]
 pub struct ThreadEntry(THREADENTRY32);
 
-impl ThreadEntry {
-    #[inline]
-    pub fn size(&self) -> u32 {
-        self.0.dwSize
-    }
-
-    #[inline]
-    pub fn thread_id(&self) -> u32 {
-        self.0.th32ThreadID
-    }
-
-    #[inline]
-    pub fn process_id(&self) -> u32 {
-        self.0.th32OwnerProcessID
-    }
-
-    #[inline]
-    pub fn base_pri(&self) -> i32 {
-        self.0.tpBasePri
-    }
-
-    #[inline]
-    pub fn delta_pri(&self) -> i32 {
-        self.0.tpDeltaPri
-    }
-
-    #[inline]
-    pub fn flags(&self) -> u32 {
-        self.0.dwFlags
-    }
-}
-
-impl Debug for ThreadEntry {
-    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
-        f.debug_struct("ThreadEntry")
-            .field("size", &self.size())
-            .field("thread_id", &self.thread_id())
-            .field("process_id", &self.process_id())
-            .field("base_pri", &self.base_pri())
-            .field("delta_pri", &self.delta_pri())
-            .field("flags", &self.flags())
-            .finish()
-    }
-}
-
 impl PartialUninit for ThreadEntry {
     fn partial_init(&mut self) {
         self.0.dwSize = size_of::<THREADENTRY32>() as _;
@@ -1,23 +1,25 @@
 use super::ModuleEntry;
-use crate::{r#macro::FnOnce, shared::ntdef::Handle};
-use anyhow::{ensure, Result};
+use crate::{
+    error::ensure, r#macro::FnOnce, shared::ntdef::BorrowedHandle, utils::Call, Error, Result,
+};
 use partial_uninit::PartialUninit;
-use std::{io, mem::MaybeUninit, os::windows::io::AsRawHandle};
+use std::{mem::MaybeUninit, os::windows::io::AsRawHandle};
 use typed_builder::TypedBuilder;
 use winapi::shared::minwindef::FALSE;
 
 /// Module first.
 #[derive(FnOnce, TypedBuilder)]
 pub struct Module32First<'a> {
-    snapshot: &'a Handle,
+    #[builder(setter(into))]
+    snapshot: BorrowedHandle<'a>,
     #[builder(default = PartialUninit::partial_uninit(), setter(skip))]
     entry: MaybeUninit<ModuleEntry>,
 }
 
-impl FnOnce<()> for Module32First<'_> {
+impl Call for Module32First<'_> {
     type Output = Result<ModuleEntry>;
 
-    extern "rust-call" fn call_once(mut self, _args: ()) -> Self::Output {
+    fn call(mut self) -> Self::Output {
         use winapi::um::tlhelp32::Module32FirstW;
 
         #[allow(non_snake_case)]
@@ -25,7 +27,7 @@ impl FnOnce<()> for Module32First<'_> {
             let hSnapshot = self.snapshot.as_raw_handle();
             let lppe = self.entry.as_mut_ptr() as _;
             let r#return = Module32FirstW(hSnapshot, lppe);
-            ensure!(r#return != FALSE, io::Error::last_os_error());
+            ensure!(r#return != FALSE, Error::last_os_error());
             Ok(self.entry.assume_init())
         }
     }
@@ -1,23 +1,25 @@
 use super::ThreadEntry;
-use crate::{r#macro::FnOnce, shared::ntdef::Handle};
-use anyhow::{ensure, Result};
+use crate::{
+    error::ensure, r#macro::FnOnce, shared::ntdef::BorrowedHandle, utils::Call, Error, Result,
+};
 use partial_uninit::PartialUninit;
-use std::{io, mem::MaybeUninit, os::windows::io::AsRawHandle};
+use std::{mem::MaybeUninit, os::windows::io::AsRawHandle};
 use typed_builder::TypedBuilder;
 use winapi::shared::minwindef::FALSE;
 
 /// Thread next.
 #[derive(FnOnce, TypedBuilder)]
 pub struct Thread32Next<'a> {
-    snapshot: &'a Handle,
+    #[builder(setter(into))]
+    snapshot: BorrowedHandle<'a>,
     #[builder(default = PartialUninit::partial_uninit(), setter(skip))]
     entry: MaybeUninit<ThreadEntry>,
 }
 
-impl FnOnce<()> for Thread32Next<'_> {
+impl Call for Thread32Next<'_> {
     type Output = Result<ThreadEntry>;
 
-    extern "rust-call" fn call_once(mut self, _args: ()) -> Self::Output {
+    fn call(mut self) -> Self::Output {
         use winapi::um::tlhelp32::Thread32Next;
 
         #[allow(non_snake_case)]
@@ -25,7 +27,7 @@ impl FnOnce<()> for Thread32Next<'_> {
         #[allow(non_snake_case)]
         let lppe = self.entry.as_mut_ptr() as _;
         let r#return = unsafe { Thread32Next(hSnapshot, lppe) };
-        ensure!(r#return != FALSE, io::Error::last_os_error());
+        ensure!(r#return != FALSE, Error::last_os_error());
         Ok(unsafe { self.entry.assume_init() })
     }
 }
@@ -0,0 +1,47 @@
+use crate::{error::ensure, r#macro::FnOnce, utils::Call, Error, Result};
+use std::ptr::null_mut;
+use typed_builder::TypedBuilder;
+use winapi::um::winbase::{FORMAT_MESSAGE_FROM_SYSTEM, FORMAT_MESSAGE_IGNORE_INSERTS};
+
+/// Format message.
+///
+/// Formats a system message, without the trailing line break.
+#[derive(FnOnce, TypedBuilder)]
+pub struct FormatMessage {
+    #[builder(default = FORMAT_MESSAGE_FROM_SYSTEM | FORMAT_MESSAGE_IGNORE_INSERTS)]
+    flags: u32,
+    message_id: u32,
+    #[builder(default)]
+    language_id: u32,
+    #[builder(default = [0; 512], setter(skip))]
+    buffer: [u16; 512],
+}
+
+impl Call for FormatMessage {
+    type Output = Result<String>;
+
+    fn call(mut self) -> Self::Output {
+        use winapi::um::winbase::FormatMessageW;
+
+        #[allow(non_snake_case)]
+        unsafe {
+            let dwFlags = self.flags;
+            let dwMessageId = self.message_id;
+            let dwLanguageId = self.language_id;
+            let lpBuffer = self.buffer.as_mut_ptr();
+            let nSize = self.buffer.len() as _;
+            let r#return = FormatMessageW(
+                dwFlags,
+                null_mut(),
+                dwMessageId,
+                dwLanguageId,
+                lpBuffer,
+                nSize,
+                null_mut(),
+            );
+            ensure!(r#return != 0, Error::last_os_error());
+            let message = String::from_utf16_lossy(&self.buffer[..r#return as usize]);
+            Ok(message.trim_end().to_owned())
+        }
+    }
+}
@@ -1,6 +1,5 @@
-use crate::{r#macro::FnOnce, um::winnt::Privilege};
-use anyhow::{ensure, Error};
-use std::{io, mem::MaybeUninit, ptr::null};
+use crate::{error::ensure, r#macro::FnOnce, um::winnt::Privilege, utils::Call, Error};
+use std::{mem::MaybeUninit, ptr::null};
 use typed_builder::TypedBuilder;
 use widestring::WideCString;
 use winapi::{
@@ -18,10 +17,10 @@ pub struct LookupPrivilegeValue<'a> {
     luid: MaybeUninit<LUID>,
 }
 
-impl FnOnce<()> for LookupPrivilegeValue<'_> {
+impl Call for LookupPrivilegeValue<'_> {
     type Output = Result<Privilege, Error>;
 
-    extern "rust-call" fn call_once(mut self, _args: ()) -> Self::Output {
+    fn call(mut self) -> Self::Output {
         use winapi::um::winbase::LookupPrivilegeValueW;
 
         let system_name = self.system_name.map(WideCString::from_str).transpose()?;
@@ -33,7 +32,7 @@ impl FnOnce<()> for LookupPrivilegeValue<'_> {
             let lpName = name.as_ptr();
             let lpLuid = self.luid.as_mut_ptr();
             let r#return = LookupPrivilegeValueW(lpSystemName, lpName, lpLuid);
-            ensure!(r#return != FALSE, io::Error::last_os_error());
+            ensure!(r#return != FALSE, Error::last_os_error());
             Ok(Privilege::from(LUID_AND_ATTRIBUTES {
                 Luid: self.luid.assume_init(),
                 Attributes: 0,
@@ -1,7 +1,15 @@
-pub(crate) use self::lookup_privilege_value::{LookupPrivilegeValue, LookupPrivilegeValueBuilder};
+pub(crate) use self::{
+    format_message::{FormatMessage, FormatMessageBuilder},
+    lookup_privilege_value::{LookupPrivilegeValue, LookupPrivilegeValueBuilder},
+};
+
+pub fn format_message() -> FormatMessageBuilder<((), (), ())> {
+    FormatMessage::builder()
+}
 
 pub fn lookup_privilege_value<'a>() -> LookupPrivilegeValueBuilder<'a, ((), ())> {
     LookupPrivilegeValue::builder()
 }
 
+mod format_message;
 mod lookup_privilege_value;
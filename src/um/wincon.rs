@@ -1,40 +1,163 @@
 use crate::{
+    error::ensure,
     r#macro::FnOnce,
-    shared::{ntdef::Handle, windef::WindowHandle},
-    um::wincontypes::InputRecord,
-    utils::FromStrictRawHandle,
+    shared::{ntdef::BorrowedHandle, windef::WindowHandle},
+    um::wincontypes::{CharInfo, Coordinates, InputEvent, InputRecord, SmallRect},
+    utils::{Call, FromStrictRawHandle},
+    Error, Result,
+};
+use bitflags::bitflags;
+use derive_more::{Deref, DerefMut, From, Into};
+use std::{
+    convert::TryFrom,
+    fmt::{self, Debug, Formatter},
+    mem::{size_of, zeroed},
+    os::windows::io::AsRawHandle,
 };
-use anyhow::{ensure, Result};
-use std::{io, os::windows::io::AsRawHandle};
 use typed_builder::TypedBuilder;
-use winapi::shared::minwindef::FALSE;
+use winapi::{
+    shared::{minwindef::FALSE, winerror::ERROR_INVALID_PARAMETER},
+    um::wincon::{
+        BACKGROUND_BLUE, BACKGROUND_GREEN, BACKGROUND_INTENSITY, BACKGROUND_RED,
+        COMMON_LVB_GRID_HORIZONTAL, COMMON_LVB_GRID_LVERTICAL, COMMON_LVB_GRID_RVERTICAL,
+        COMMON_LVB_LEADING_BYTE, COMMON_LVB_REVERSE_VIDEO, COMMON_LVB_TRAILING_BYTE,
+        COMMON_LVB_UNDERSCORE, CONSOLE_SCREEN_BUFFER_INFOEX, CTRL_BREAK_EVENT, CTRL_CLOSE_EVENT,
+        CTRL_C_EVENT, CTRL_LOGOFF_EVENT, CTRL_SHUTDOWN_EVENT, FOREGROUND_BLUE, FOREGROUND_GREEN,
+        FOREGROUND_INTENSITY, FOREGROUND_RED,
+    },
+};
+
+/// Fill console output character.
+#[derive(FnOnce, TypedBuilder)]
+pub struct FillConsoleOutputCharacter<'a> {
+    #[builder(setter(into))]
+    console_output: BorrowedHandle<'a>,
+    character: u16,
+    length: u32,
+    write_coord: Coordinates,
+    #[builder(default, setter(skip))]
+    number_of_chars_written: u32,
+}
+
+impl Call for FillConsoleOutputCharacter<'_> {
+    type Output = Result<u32>;
+
+    fn call(mut self) -> Self::Output {
+        use winapi::um::wincon::FillConsoleOutputCharacterW;
+
+        #[allow(non_snake_case)]
+        let hConsoleOutput = self.console_output.as_raw_handle();
+        #[allow(non_snake_case)]
+        let cCharacter = self.character;
+        #[allow(non_snake_case)]
+        let nLength = self.length;
+        #[allow(non_snake_case)]
+        let dwWriteCoord = *self.write_coord;
+        #[allow(non_snake_case)]
+        let lpNumberOfCharsWritten = &mut self.number_of_chars_written;
+        let r#return = unsafe {
+            FillConsoleOutputCharacterW(
+                hConsoleOutput,
+                cCharacter,
+                nLength,
+                dwWriteCoord,
+                lpNumberOfCharsWritten,
+            )
+        };
+        ensure!(r#return != FALSE, Error::last_os_error());
+        Ok(self.number_of_chars_written)
+    }
+}
 
 /// Free console.
+#[derive(FnOnce)]
 pub struct FreeConsole;
 
-impl FnOnce<()> for FreeConsole {
+impl Call for FreeConsole {
     type Output = Result<()>;
 
-    extern "rust-call" fn call_once(self, _args: ()) -> Self::Output {
+    fn call(self) -> Self::Output {
         use winapi::um::wincon::FreeConsole;
 
         let r#return = unsafe { FreeConsole() };
-        ensure!(r#return != FALSE, io::Error::last_os_error());
+        ensure!(r#return != FALSE, Error::last_os_error());
+        Ok(())
+    }
+}
+
+/// Generate console ctrl event.
+///
+/// Sends the `ctrl_event` to the console process group `process_group_id`, or
+/// to all processes sharing the console for 0. Only `CtrlC` and `CtrlBreak`
+/// can be generated.
+#[derive(FnOnce, TypedBuilder)]
+pub struct GenerateConsoleCtrlEvent {
+    ctrl_event: ControlEvent,
+    #[builder(default)]
+    process_group_id: u32,
+}
+
+impl Call for GenerateConsoleCtrlEvent {
+    type Output = Result<()>;
+
+    fn call(self) -> Self::Output {
+        use winapi::um::wincon::GenerateConsoleCtrlEvent;
+
+        ensure!(
+            matches!(
+                self.ctrl_event,
+                ControlEvent::CtrlC | ControlEvent::CtrlBreak
+            ),
+            Error::Win32(ERROR_INVALID_PARAMETER),
+        );
+        #[allow(non_snake_case)]
+        let dwCtrlEvent = self.ctrl_event.into();
+        #[allow(non_snake_case)]
+        let dwProcessGroupId = self.process_group_id;
+        let r#return = unsafe { GenerateConsoleCtrlEvent(dwCtrlEvent, dwProcessGroupId) };
+        ensure!(r#return != FALSE, Error::last_os_error());
         Ok(())
     }
 }
 
+/// Get console screen buffer info ex.
+#[derive(FnOnce, TypedBuilder)]
+pub struct GetConsoleScreenBufferInfoEx<'a> {
+    #[builder(setter(into))]
+    console_output: BorrowedHandle<'a>,
+    #[builder(default, setter(skip))]
+    screen_buffer_info: ScreenBufferInfo,
+}
+
+impl Call for GetConsoleScreenBufferInfoEx<'_> {
+    type Output = Result<ScreenBufferInfo>;
+
+    fn call(mut self) -> Self::Output {
+        use winapi::um::wincon::GetConsoleScreenBufferInfoEx;
+
+        #[allow(non_snake_case)]
+        let hConsoleOutput = self.console_output.as_raw_handle();
+        #[allow(non_snake_case)]
+        let lpConsoleScreenBufferInfoEx = &mut *self.screen_buffer_info;
+        let r#return =
+            unsafe { GetConsoleScreenBufferInfoEx(hConsoleOutput, lpConsoleScreenBufferInfoEx) };
+        ensure!(r#return != FALSE, Error::last_os_error());
+        Ok(self.screen_buffer_info)
+    }
+}
+
 /// Get console window.
+#[derive(FnOnce)]
 pub struct GetConsoleWindow;
 
-impl FnOnce<()> for GetConsoleWindow {
+impl Call for GetConsoleWindow {
     type Output = Option<WindowHandle>;
 
-    extern "rust-call" fn call_once(self, _args: ()) -> Self::Output {
+    fn call(self) -> Self::Output {
         use winapi::um::wincon::GetConsoleWindow;
 
         let r#return = unsafe { GetConsoleWindow() };
-        (!r#return.is_null()).then_some(unsafe { WindowHandle::from_strict_raw_handle(r#return) })
+        (!r#return.is_null()).then(|| unsafe { WindowHandle::from_strict_raw_handle(r#return) })
     }
 }
 
@@ -45,36 +168,202 @@ pub struct GetNumberOfConsoleMouseButtons {
     number_of_mouse_buttons: u32,
 }
 
-impl FnOnce<()> for GetNumberOfConsoleMouseButtons {
+impl Call for GetNumberOfConsoleMouseButtons {
     type Output = Result<u32>;
 
-    extern "rust-call" fn call_once(mut self, _args: ()) -> Self::Output {
+    fn call(mut self) -> Self::Output {
         use winapi::um::wincon::GetNumberOfConsoleMouseButtons;
 
         #[allow(non_snake_case)]
         let lpNumberOfMouseButtons = &mut self.number_of_mouse_buttons;
         let r#return = unsafe { GetNumberOfConsoleMouseButtons(lpNumberOfMouseButtons) };
-        ensure!(r#return != FALSE, io::Error::last_os_error());
+        ensure!(r#return != FALSE, Error::last_os_error());
         Ok(self.number_of_mouse_buttons)
     }
 }
 
+/// Peek console input.
+///
+/// Reads up to `length` events without removing them from the buffer. Returns
+/// immediately, with no events if the buffer is empty. Each record is decoded
+/// on its own, the ones of an unknown event type are skipped.
+#[derive(FnOnce, TypedBuilder)]
+pub struct PeekConsoleInput<'a> {
+    #[builder(setter(into))]
+    console_input: BorrowedHandle<'a>,
+    #[builder(default = 1)]
+    length: u32,
+    #[builder(default, setter(skip))]
+    number_of_events_read: u32,
+}
+
+impl Call for PeekConsoleInput<'_> {
+    type Output = Result<Vec<InputEvent>>;
+
+    fn call(mut self) -> Self::Output {
+        use winapi::um::wincon::PeekConsoleInputW;
+
+        let mut buffer: Vec<InputRecord> = vec![unsafe { zeroed() }; self.length as _];
+        #[allow(non_snake_case)]
+        let hConsoleInput = self.console_input.as_raw_handle();
+        #[allow(non_snake_case)]
+        let lpBuffer = buffer.as_mut_ptr() as _;
+        #[allow(non_snake_case)]
+        let nLength = self.length;
+        #[allow(non_snake_case)]
+        let lpNumberOfEventsRead = &mut self.number_of_events_read;
+        let r#return =
+            unsafe { PeekConsoleInputW(hConsoleInput, lpBuffer, nLength, lpNumberOfEventsRead) };
+        ensure!(r#return != FALSE, Error::last_os_error());
+        buffer.truncate(self.number_of_events_read as _);
+        Ok(buffer
+            .iter()
+            .filter_map(|input_record| input_record.event().ok())
+            .collect())
+    }
+}
+
+/// Read console output.
+///
+/// Reads the cells of the `read_region` into a buffer of the `buffer_size`
+/// starting at the `buffer_coord`. Returns the buffer and the region actually
+/// read.
+#[derive(FnOnce, TypedBuilder)]
+pub struct ReadConsoleOutput<'a> {
+    #[builder(setter(into))]
+    console_output: BorrowedHandle<'a>,
+    buffer_size: Coordinates,
+    #[builder(default = Coordinates::new(0, 0))]
+    buffer_coord: Coordinates,
+    read_region: SmallRect,
+}
+
+impl Call for ReadConsoleOutput<'_> {
+    type Output = Result<(Vec<CharInfo>, SmallRect)>;
+
+    fn call(mut self) -> Self::Output {
+        use winapi::um::wincon::ReadConsoleOutputW;
+
+        let length = self.buffer_size.X.max(0) as usize * self.buffer_size.Y.max(0) as usize;
+        let mut buffer = vec![CharInfo::new(0, 0); length];
+        #[allow(non_snake_case)]
+        let hConsoleOutput = self.console_output.as_raw_handle();
+        #[allow(non_snake_case)]
+        let lpBuffer = buffer.as_mut_ptr() as _;
+        #[allow(non_snake_case)]
+        let dwBufferSize = *self.buffer_size;
+        #[allow(non_snake_case)]
+        let dwBufferCoord = *self.buffer_coord;
+        #[allow(non_snake_case)]
+        let lpReadRegion = &mut *self.read_region;
+        let r#return = unsafe {
+            ReadConsoleOutputW(
+                hConsoleOutput,
+                lpBuffer,
+                dwBufferSize,
+                dwBufferCoord,
+                lpReadRegion,
+            )
+        };
+        ensure!(r#return != FALSE, Error::last_os_error());
+        Ok((buffer, self.read_region))
+    }
+}
+
+/// Set console cursor position.
+#[derive(FnOnce, TypedBuilder)]
+pub struct SetConsoleCursorPosition<'a> {
+    #[builder(setter(into))]
+    console_output: BorrowedHandle<'a>,
+    cursor_position: Coordinates,
+}
+
+impl Call for SetConsoleCursorPosition<'_> {
+    type Output = Result<()>;
+
+    fn call(self) -> Self::Output {
+        use winapi::um::wincon::SetConsoleCursorPosition;
+
+        #[allow(non_snake_case)]
+        let hConsoleOutput = self.console_output.as_raw_handle();
+        #[allow(non_snake_case)]
+        let dwCursorPosition = *self.cursor_position;
+        let r#return = unsafe { SetConsoleCursorPosition(hConsoleOutput, dwCursorPosition) };
+        ensure!(r#return != FALSE, Error::last_os_error());
+        Ok(())
+    }
+}
+
+/// Set console screen buffer info ex.
+///
+/// Note: The window is set from the inclusive `window` rectangle read by
+/// `GetConsoleScreenBufferInfoEx`, but interpreted as exclusive, so setting an
+/// unchanged screen buffer info shrinks the window by a cell.
+#[derive(FnOnce, TypedBuilder)]
+pub struct SetConsoleScreenBufferInfoEx<'a> {
+    #[builder(setter(into))]
+    console_output: BorrowedHandle<'a>,
+    screen_buffer_info: ScreenBufferInfo,
+}
+
+impl Call for SetConsoleScreenBufferInfoEx<'_> {
+    type Output = Result<()>;
+
+    fn call(mut self) -> Self::Output {
+        use winapi::um::wincon::SetConsoleScreenBufferInfoEx;
+
+        #[allow(non_snake_case)]
+        let hConsoleOutput = self.console_output.as_raw_handle();
+        #[allow(non_snake_case)]
+        let lpConsoleScreenBufferInfoEx = &mut *self.screen_buffer_info;
+        let r#return =
+            unsafe { SetConsoleScreenBufferInfoEx(hConsoleOutput, lpConsoleScreenBufferInfoEx) };
+        ensure!(r#return != FALSE, Error::last_os_error());
+        Ok(())
+    }
+}
+
+/// Set console text attribute.
+#[derive(FnOnce, TypedBuilder)]
+pub struct SetConsoleTextAttribute<'a> {
+    #[builder(setter(into))]
+    console_output: BorrowedHandle<'a>,
+    attributes: CharacterAttributes,
+}
+
+impl Call for SetConsoleTextAttribute<'_> {
+    type Output = Result<()>;
+
+    fn call(self) -> Self::Output {
+        use winapi::um::wincon::SetConsoleTextAttribute;
+
+        #[allow(non_snake_case)]
+        let hConsoleOutput = self.console_output.as_raw_handle();
+        #[allow(non_snake_case)]
+        let wAttributes = self.attributes.bits();
+        let r#return = unsafe { SetConsoleTextAttribute(hConsoleOutput, wAttributes) };
+        ensure!(r#return != FALSE, Error::last_os_error());
+        Ok(())
+    }
+}
+
 /// Write console input.
 #[derive(FnOnce, TypedBuilder)]
 pub struct WriteConsoleInput<'a> {
-    console_input: &'a Handle,
+    #[builder(setter(into))]
+    console_input: BorrowedHandle<'a>,
     buffer: &'a [InputRecord],
     #[builder(default, setter(skip))]
     number_of_events_written: u32,
 }
 
-impl FnOnce<()> for WriteConsoleInput<'_> {
+impl Call for WriteConsoleInput<'_> {
     type Output = Result<u32>;
 
-    extern "rust-call" fn call_once(mut self, _args: ()) -> Self::Output {
+    fn call(mut self) -> Self::Output {
         use winapi::um::wincon::WriteConsoleInputW;
 
-        if self.buffer.len() == 0 {
+        if self.buffer.is_empty() {
             return Ok(0);
         }
 
@@ -89,30 +378,286 @@ impl FnOnce<()> for WriteConsoleInput<'_> {
         let r#return = unsafe {
             WriteConsoleInputW(hConsoleInput, lpBuffer, nLength, lpNumberOfEventsWritten)
         };
-        ensure!(r#return != FALSE, io::Error::last_os_error());
+        ensure!(r#return != FALSE, Error::last_os_error());
         Ok(self.number_of_events_written)
     }
 }
 
+/// Write console output.
+///
+/// Writes the cells of a buffer of the `buffer_size` starting at the
+/// `buffer_coord` into the `write_region`. Returns the region actually written.
+#[derive(FnOnce, TypedBuilder)]
+pub struct WriteConsoleOutput<'a> {
+    #[builder(setter(into))]
+    console_output: BorrowedHandle<'a>,
+    buffer: &'a [CharInfo],
+    buffer_size: Coordinates,
+    #[builder(default = Coordinates::new(0, 0))]
+    buffer_coord: Coordinates,
+    write_region: SmallRect,
+}
+
+impl Call for WriteConsoleOutput<'_> {
+    type Output = Result<SmallRect>;
+
+    fn call(mut self) -> Self::Output {
+        use winapi::um::wincon::WriteConsoleOutputW;
+
+        let length = self.buffer_size.X.max(0) as usize * self.buffer_size.Y.max(0) as usize;
+        ensure!(
+            self.buffer.len() >= length,
+            Error::BufferTooSmall { needed: length },
+        );
+        #[allow(non_snake_case)]
+        let hConsoleOutput = self.console_output.as_raw_handle();
+        #[allow(non_snake_case)]
+        let lpBuffer = self.buffer.as_ptr() as _;
+        #[allow(non_snake_case)]
+        let dwBufferSize = *self.buffer_size;
+        #[allow(non_snake_case)]
+        let dwBufferCoord = *self.buffer_coord;
+        #[allow(non_snake_case)]
+        let lpWriteRegion = &mut *self.write_region;
+        let r#return = unsafe {
+            WriteConsoleOutputW(
+                hConsoleOutput,
+                lpBuffer,
+                dwBufferSize,
+                dwBufferCoord,
+                lpWriteRegion,
+            )
+        };
+        ensure!(r#return != FALSE, Error::last_os_error());
+        Ok(self.write_region)
+    }
+}
+
+/// Control event.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum ControlEvent {
+    CtrlC,
+    CtrlBreak,
+    Close,
+    Logoff,
+    Shutdown,
+}
+
+impl From<ControlEvent> for u32 {
+    fn from(from: ControlEvent) -> Self {
+        match from {
+            ControlEvent::CtrlC => CTRL_C_EVENT,
+            ControlEvent::CtrlBreak => CTRL_BREAK_EVENT,
+            ControlEvent::Close => CTRL_CLOSE_EVENT,
+            ControlEvent::Logoff => CTRL_LOGOFF_EVENT,
+            ControlEvent::Shutdown => CTRL_SHUTDOWN_EVENT,
+        }
+    }
+}
+
+impl TryFrom<u32> for ControlEvent {
+    type Error = Error;
+
+    fn try_from(from: u32) -> Result<Self> {
+        Ok(match from {
+            CTRL_C_EVENT => ControlEvent::CtrlC,
+            CTRL_BREAK_EVENT => ControlEvent::CtrlBreak,
+            CTRL_CLOSE_EVENT => ControlEvent::Close,
+            CTRL_LOGOFF_EVENT => ControlEvent::Logoff,
+            CTRL_SHUTDOWN_EVENT => ControlEvent::Shutdown,
+            _ => return Err(Error::unexpected_value("control event", from)),
+        })
+    }
+}
+
+bitflags! {
+    /// Character attributes.
+    pub struct CharacterAttributes: u16 {
+        const FOREGROUND_BLUE = FOREGROUND_BLUE;
+        const FOREGROUND_GREEN = FOREGROUND_GREEN;
+        const FOREGROUND_RED = FOREGROUND_RED;
+        const FOREGROUND_INTENSITY = FOREGROUND_INTENSITY;
+        const BACKGROUND_BLUE = BACKGROUND_BLUE;
+        const BACKGROUND_GREEN = BACKGROUND_GREEN;
+        const BACKGROUND_RED = BACKGROUND_RED;
+        const BACKGROUND_INTENSITY = BACKGROUND_INTENSITY;
+        const LEADING_BYTE = COMMON_LVB_LEADING_BYTE;
+        const TRAILING_BYTE = COMMON_LVB_TRAILING_BYTE;
+        const GRID_HORIZONTAL = COMMON_LVB_GRID_HORIZONTAL;
+        const GRID_LVERTICAL = COMMON_LVB_GRID_LVERTICAL;
+        const GRID_RVERTICAL = COMMON_LVB_GRID_RVERTICAL;
+        const REVERSE_VIDEO = COMMON_LVB_REVERSE_VIDEO;
+        const UNDERSCORE = COMMON_LVB_UNDERSCORE;
+    }
+}
+
+/// Screen buffer info.
+#[derive(Clone, Copy, Deref, DerefMut, From, Into)]
+#[repr(transparent)]
+pub struct ScreenBufferInfo(CONSOLE_SCREEN_BUFFER_INFOEX);
+
+impl ScreenBufferInfo {
+    #[inline]
+    pub fn size(&self) -> Coordinates {
+        self.0.dwSize.into()
+    }
+
+    #[inline]
+    pub fn cursor_position(&self) -> Coordinates {
+        self.0.dwCursorPosition.into()
+    }
+
+    #[inline]
+    pub fn attributes(&self) -> CharacterAttributes {
+        CharacterAttributes::from_bits_truncate(self.0.wAttributes)
+    }
+
+    #[inline]
+    pub fn window(&self) -> SmallRect {
+        self.0.srWindow.into()
+    }
+
+    #[inline]
+    pub fn maximum_window_size(&self) -> Coordinates {
+        self.0.dwMaximumWindowSize.into()
+    }
+
+    #[inline]
+    pub fn popup_attributes(&self) -> CharacterAttributes {
+        CharacterAttributes::from_bits_truncate(self.0.wPopupAttributes)
+    }
+
+    #[inline]
+    pub fn fullscreen_supported(&self) -> bool {
+        self.0.bFullscreenSupported != FALSE
+    }
+
+    /// The `0x00bbggrr` colors of the 16 console attributes.
+    #[inline]
+    pub fn color_table(&self) -> &[u32; 16] {
+        &self.0.ColorTable
+    }
+
+    #[inline]
+    pub fn color_table_mut(&mut self) -> &mut [u32; 16] {
+        &mut self.0.ColorTable
+    }
+}
+
+impl Default for ScreenBufferInfo {
+    fn default() -> Self {
+        let mut screen_buffer_info: CONSOLE_SCREEN_BUFFER_INFOEX = unsafe { zeroed() };
+        screen_buffer_info.cbSize = size_of::<CONSOLE_SCREEN_BUFFER_INFOEX>() as _;
+        ScreenBufferInfo(screen_buffer_info)
+    }
+}
+
+impl Debug for ScreenBufferInfo {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.debug_struct("ScreenBufferInfo")
+            .field("size", &self.size())
+            .field("cursor_position", &self.cursor_position())
+            .field("attributes", &self.attributes())
+            .field("window", &self.window())
+            .field("maximum_window_size", &self.maximum_window_size())
+            .field("popup_attributes", &self.popup_attributes())
+            .field("fullscreen_supported", &self.fullscreen_supported())
+            .field("color_table", &self.color_table())
+            .finish()
+    }
+}
+
 #[cfg(test)]
 mod test {
-    use super::WriteConsoleInput;
+    use super::{
+        ControlEvent, GenerateConsoleCtrlEvent, ScreenBufferInfo, WriteConsoleInput,
+        WriteConsoleOutput,
+    };
+
+    mod control_event {
+        use super::ControlEvent;
+        use std::convert::TryFrom;
+
+        #[test]
+        fn test() {
+            for &control_event in &[
+                ControlEvent::CtrlC,
+                ControlEvent::CtrlBreak,
+                ControlEvent::Close,
+                ControlEvent::Logoff,
+                ControlEvent::Shutdown,
+            ] {
+                let ctrl_type = u32::from(control_event);
+                assert_eq!(control_event, ControlEvent::try_from(ctrl_type).unwrap());
+            }
+            assert!(ControlEvent::try_from(3).is_err());
+        }
+    }
+
+    mod generate_console_ctrl_event {
+        use super::{ControlEvent, GenerateConsoleCtrlEvent};
+        use crate::utils::Call;
+
+        #[test]
+        fn test() {
+            let generate_console_ctrl_event = GenerateConsoleCtrlEvent::builder()
+                .ctrl_event(ControlEvent::Close)
+                .build();
+            assert!(generate_console_ctrl_event.call().is_err());
+        }
+    }
+
+    mod screen_buffer_info {
+        use super::ScreenBufferInfo;
+        use std::mem::size_of;
+        use winapi::um::wincon::CONSOLE_SCREEN_BUFFER_INFOEX;
+
+        #[test]
+        fn test() {
+            let screen_buffer_info = ScreenBufferInfo::default();
+            assert_eq!(
+                size_of::<CONSOLE_SCREEN_BUFFER_INFOEX>(),
+                screen_buffer_info.cbSize as usize,
+            );
+            assert!(screen_buffer_info.attributes().is_empty());
+        }
+    }
 
     mod write_console_input {
         use super::WriteConsoleInput;
-        use crate::shared::ntdef::Handle;
-        use std::os::windows::io::{FromRawHandle, IntoRawHandle};
+        use crate::{shared::ntdef::BorrowedHandle, utils::FromStrictRawHandle};
 
         #[test]
         fn test() {
-            let handle = unsafe { Handle::from_raw_handle(0 as _) };
+            let handle = unsafe { BorrowedHandle::from_strict_raw_handle(0 as _) };
             let buffer = &[];
             let write_console_input = WriteConsoleInput::builder()
-                .console_input(&handle)
+                .console_input(handle)
                 .buffer(buffer)
                 .build();
             assert_eq!(0, write_console_input.buffer.len());
-            let _ = handle.into_raw_handle();
+        }
+    }
+
+    mod write_console_output {
+        use super::WriteConsoleOutput;
+        use crate::{
+            shared::ntdef::BorrowedHandle,
+            um::wincontypes::{CharInfo, Coordinates, SmallRect},
+            utils::{Call, FromStrictRawHandle},
+        };
+
+        #[test]
+        fn test() {
+            let handle = unsafe { BorrowedHandle::from_strict_raw_handle(0 as _) };
+            let buffer = &[CharInfo::new('a' as _, 0); 3];
+            let write_console_output = WriteConsoleOutput::builder()
+                .console_output(handle)
+                .buffer(buffer)
+                .buffer_size(Coordinates::new(2, 2))
+                .write_region(SmallRect::new(0, 0, 1, 1))
+                .build();
+            assert!(write_console_output.call().is_err());
         }
     }
 }
@@ -1,10 +1,68 @@
-use derive_more::{Deref, DerefMut, Display, From, Into};
+use crate::{wrap::key::Key, Error, Result};
+use bitflags::bitflags;
+use derive_more::{Deref, DerefMut, From, Into};
 use rustapi_macro::Handle;
-use std::fmt::{self, Debug, Formatter};
-use winapi::um::wincontypes::{COORD, HPCON, INPUT_RECORD};
+use std::{
+    convert::TryFrom,
+    fmt::{self, Debug, Formatter},
+    mem::zeroed,
+};
+use typed_builder::TypedBuilder;
+use winapi::{
+    shared::minwindef::{FALSE, TRUE},
+    um::wincontypes::{
+        CAPSLOCK_ON, CHAR_INFO, COORD, DOUBLE_CLICK, ENHANCED_KEY, FOCUS_EVENT,
+        FROM_LEFT_1ST_BUTTON_PRESSED, FROM_LEFT_2ND_BUTTON_PRESSED, FROM_LEFT_3RD_BUTTON_PRESSED,
+        FROM_LEFT_4TH_BUTTON_PRESSED, HPCON, INPUT_RECORD, KEY_EVENT, LEFT_ALT_PRESSED,
+        LEFT_CTRL_PRESSED, MENU_EVENT, MOUSE_EVENT, MOUSE_HWHEELED, MOUSE_MOVED, MOUSE_WHEELED,
+        NUMLOCK_ON, RIGHTMOST_BUTTON_PRESSED, RIGHT_ALT_PRESSED, RIGHT_CTRL_PRESSED, SCROLLLOCK_ON,
+        SHIFT_PRESSED, SMALL_RECT, WINDOW_BUFFER_SIZE_EVENT,
+    },
+};
+
+/// Character information.
+#[derive(Clone, Copy, Deref, DerefMut, From, Into)]
+#[repr(transparent)]
+pub struct CharInfo(CHAR_INFO);
+
+impl CharInfo {
+    pub fn new(character: u16, attributes: u16) -> Self {
+        let mut char_info: CHAR_INFO = unsafe { zeroed() };
+        unsafe { *char_info.Char.UnicodeChar_mut() = character };
+        char_info.Attributes = attributes;
+        CharInfo(char_info)
+    }
+
+    #[inline]
+    pub fn character(&self) -> u16 {
+        unsafe { *self.0.Char.UnicodeChar() }
+    }
+
+    #[inline]
+    pub fn attributes(&self) -> u16 {
+        self.0.Attributes
+    }
+}
+
+impl Debug for CharInfo {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.debug_struct("CharInfo")
+            .field("character", &self.character())
+            .field("attributes", &format_args!("{:#06x}", self.attributes()))
+            .finish()
+    }
+}
+
+impl Eq for CharInfo {}
+
+impl PartialEq for CharInfo {
+    fn eq(&self, other: &Self) -> bool {
+        (self.character(), self.attributes()) == (other.character(), other.attributes())
+    }
+}
 
 /// Coordinates.
-#[derive(Clone, Deref, DerefMut, From, Into)]
+#[derive(Clone, Copy, Deref, DerefMut, From, Into)]
 #[repr(transparent)]
 pub struct Coordinates(COORD);
 
@@ -14,6 +72,71 @@ impl Coordinates {
     }
 }
 
+impl Debug for Coordinates {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.debug_struct("Coordinates")
+            .field("x", &self.0.X)
+            .field("y", &self.0.Y)
+            .finish()
+    }
+}
+
+impl Eq for Coordinates {}
+
+impl PartialEq for Coordinates {
+    fn eq(&self, other: &Self) -> bool {
+        (self.0.X, self.0.Y) == (other.0.X, other.0.Y)
+    }
+}
+
+/// Small rectangle.
+///
+/// The edges are inclusive.
+#[derive(Clone, Copy, Deref, DerefMut, From, Into)]
+#[repr(transparent)]
+pub struct SmallRect(SMALL_RECT);
+
+impl SmallRect {
+    pub fn new(left: i16, top: i16, right: i16, bottom: i16) -> Self {
+        SmallRect(SMALL_RECT {
+            Left: left,
+            Top: top,
+            Right: right,
+            Bottom: bottom,
+        })
+    }
+
+    #[inline]
+    pub fn width(&self) -> i16 {
+        self.0.Right - self.0.Left + 1
+    }
+
+    #[inline]
+    pub fn height(&self) -> i16 {
+        self.0.Bottom - self.0.Top + 1
+    }
+}
+
+impl Debug for SmallRect {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.debug_struct("SmallRect")
+            .field("left", &self.0.Left)
+            .field("top", &self.0.Top)
+            .field("right", &self.0.Right)
+            .field("bottom", &self.0.Bottom)
+            .finish()
+    }
+}
+
+impl Eq for SmallRect {}
+
+impl PartialEq for SmallRect {
+    fn eq(&self, other: &Self) -> bool {
+        let rect = |SmallRect(rect): &SmallRect| (rect.Left, rect.Top, rect.Right, rect.Bottom);
+        rect(self) == rect(other)
+    }
+}
+
 /// Input record.
 #[derive(Clone, Deref, DerefMut, From, Into)]
 #[repr(transparent)]
@@ -24,25 +147,370 @@ impl InputRecord {
     pub fn event_type(&self) -> u16 {
         self.0.EventType
     }
+
+    pub fn event(&self) -> Result<InputEvent> {
+        InputEvent::try_from(self)
+    }
 }
 
-// TODO:
 impl Debug for InputRecord {
     fn fmt(&self, f: &mut Formatter) -> fmt::Result {
-        f.debug_tuple("InputRecord").finish()
+        match self.event() {
+            Ok(event) => f.debug_tuple("InputRecord").field(&event).finish(),
+            Err(_) => f
+                .debug_struct("InputRecord")
+                .field("event_type", &self.event_type())
+                .finish(),
+        }
+    }
+}
+
+impl From<InputEvent> for InputRecord {
+    fn from(from: InputEvent) -> Self {
+        let mut input_record: INPUT_RECORD = unsafe { zeroed() };
+        unsafe {
+            match from {
+                InputEvent::Key {
+                    down,
+                    repeat,
+                    virtual_key_code,
+                    scan_code,
+                    char,
+                    modifiers,
+                    ..
+                } => {
+                    input_record.EventType = KEY_EVENT;
+                    let key_event = input_record.Event.KeyEvent_mut();
+                    key_event.bKeyDown = if down { TRUE } else { FALSE };
+                    key_event.wRepeatCount = repeat;
+                    key_event.wVirtualKeyCode = virtual_key_code;
+                    key_event.wVirtualScanCode = scan_code;
+                    *key_event.uChar.UnicodeChar_mut() = char;
+                    key_event.dwControlKeyState = modifiers.bits();
+                }
+                InputEvent::Mouse {
+                    position,
+                    buttons,
+                    wheel_delta,
+                    flags,
+                    modifiers,
+                } => {
+                    input_record.EventType = MOUSE_EVENT;
+                    let mouse_event = input_record.Event.MouseEvent_mut();
+                    mouse_event.dwMousePosition = *position;
+                    mouse_event.dwButtonState = buttons.bits() | (wheel_delta as u16 as u32) << 16;
+                    mouse_event.dwControlKeyState = modifiers.bits();
+                    mouse_event.dwEventFlags = flags.bits();
+                }
+                InputEvent::WindowBufferSize(size) => {
+                    input_record.EventType = WINDOW_BUFFER_SIZE_EVENT;
+                    input_record.Event.WindowBufferSizeEvent_mut().dwSize = *size;
+                }
+                InputEvent::Menu(command_id) => {
+                    input_record.EventType = MENU_EVENT;
+                    input_record.Event.MenuEvent_mut().dwCommandId = command_id;
+                }
+                InputEvent::Focus(set_focus) => {
+                    input_record.EventType = FOCUS_EVENT;
+                    input_record.Event.FocusEvent_mut().bSetFocus =
+                        if set_focus { TRUE } else { FALSE };
+                }
+            }
+        }
+        InputRecord(input_record)
+    }
+}
+
+/// Input event.
+///
+/// The decoded input record. A key record with a virtual key code unknown to
+/// `Key`, such as `VK_PROCESSKEY` sent by an IME, has no `virtual_key`, the
+/// `virtual_key_code` is written back to the record.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum InputEvent {
+    Key {
+        down: bool,
+        repeat: u16,
+        virtual_key: Option<Key>,
+        virtual_key_code: u16,
+        scan_code: u16,
+        /// The UTF-16 code unit, a surrogate pair arrives as two events.
+        char: u16,
+        modifiers: ControlKeyState,
+    },
+    Mouse {
+        position: Coordinates,
+        buttons: MouseButtons,
+        /// The signed distance the wheel is rotated by, in multiples of
+        /// `WHEEL_DELTA`.
+        wheel_delta: i16,
+        flags: MouseFlags,
+        modifiers: ControlKeyState,
+    },
+    WindowBufferSize(Coordinates),
+    Menu(u32),
+    Focus(bool),
+}
+
+impl TryFrom<&InputRecord> for InputEvent {
+    type Error = Error;
+
+    fn try_from(from: &InputRecord) -> Result<Self> {
+        let input_record = &from.0;
+        unsafe {
+            Ok(match input_record.EventType {
+                KEY_EVENT => {
+                    let key_event = input_record.Event.KeyEvent();
+                    InputEvent::Key {
+                        down: key_event.bKeyDown != FALSE,
+                        repeat: key_event.wRepeatCount,
+                        virtual_key: Key::try_from(key_event.wVirtualKeyCode as i32).ok(),
+                        virtual_key_code: key_event.wVirtualKeyCode,
+                        scan_code: key_event.wVirtualScanCode,
+                        char: *key_event.uChar.UnicodeChar(),
+                        modifiers: ControlKeyState::from_bits_truncate(key_event.dwControlKeyState),
+                    }
+                }
+                MOUSE_EVENT => {
+                    let mouse_event = input_record.Event.MouseEvent();
+                    InputEvent::Mouse {
+                        position: Coordinates(mouse_event.dwMousePosition),
+                        buttons: MouseButtons::from_bits_truncate(mouse_event.dwButtonState),
+                        wheel_delta: (mouse_event.dwButtonState >> 16) as i16,
+                        flags: MouseFlags::from_bits_truncate(mouse_event.dwEventFlags),
+                        modifiers: ControlKeyState::from_bits_truncate(
+                            mouse_event.dwControlKeyState,
+                        ),
+                    }
+                }
+                WINDOW_BUFFER_SIZE_EVENT => InputEvent::WindowBufferSize(Coordinates(
+                    input_record.Event.WindowBufferSizeEvent().dwSize,
+                )),
+                MENU_EVENT => InputEvent::Menu(input_record.Event.MenuEvent().dwCommandId),
+                FOCUS_EVENT => {
+                    InputEvent::Focus(input_record.Event.FocusEvent().bSetFocus != FALSE)
+                }
+                event_type => return Err(Error::unexpected_value("input event type", event_type)),
+            })
+        }
+    }
+}
+
+impl From<SyntheticKey> for InputEvent {
+    fn from(from: SyntheticKey) -> Self {
+        InputEvent::Key {
+            down: from.down,
+            repeat: from.repeat,
+            virtual_key: Some(from.virtual_key),
+            virtual_key_code: i32::from(from.virtual_key) as _,
+            scan_code: from.scan_code,
+            char: from.char,
+            modifiers: from.modifiers,
+        }
+    }
+}
+
+impl From<SyntheticMouse> for InputEvent {
+    fn from(from: SyntheticMouse) -> Self {
+        InputEvent::Mouse {
+            position: from.position,
+            buttons: from.buttons,
+            wheel_delta: from.wheel_delta,
+            flags: from.flags,
+            modifiers: from.modifiers,
+        }
+    }
+}
+
+/// Synthetic key.
+///
+/// Builds a key event, pressed once by default.
+#[derive(Clone, Copy, Debug, TypedBuilder)]
+pub struct SyntheticKey {
+    virtual_key: Key,
+    #[builder(default = true)]
+    down: bool,
+    #[builder(default = 1)]
+    repeat: u16,
+    #[builder(default)]
+    scan_code: u16,
+    #[builder(default)]
+    char: u16,
+    #[builder(default = ControlKeyState::empty())]
+    modifiers: ControlKeyState,
+}
+
+/// Synthetic mouse.
+///
+/// Builds a mouse event at the position.
+#[derive(Clone, Copy, Debug, TypedBuilder)]
+pub struct SyntheticMouse {
+    position: Coordinates,
+    #[builder(default = MouseButtons::empty())]
+    buttons: MouseButtons,
+    #[builder(default)]
+    wheel_delta: i16,
+    #[builder(default = MouseFlags::empty())]
+    flags: MouseFlags,
+    #[builder(default = ControlKeyState::empty())]
+    modifiers: ControlKeyState,
+}
+
+bitflags! {
+    /// Control key state.
+    pub struct ControlKeyState: u32 {
+        const RIGHT_ALT_PRESSED = RIGHT_ALT_PRESSED;
+        const LEFT_ALT_PRESSED = LEFT_ALT_PRESSED;
+        const RIGHT_CTRL_PRESSED = RIGHT_CTRL_PRESSED;
+        const LEFT_CTRL_PRESSED = LEFT_CTRL_PRESSED;
+        const SHIFT_PRESSED = SHIFT_PRESSED;
+        const NUMLOCK_ON = NUMLOCK_ON;
+        const SCROLLLOCK_ON = SCROLLLOCK_ON;
+        const CAPSLOCK_ON = CAPSLOCK_ON;
+        const ENHANCED_KEY = ENHANCED_KEY;
+    }
+}
+
+bitflags! {
+    /// Mouse buttons.
+    pub struct MouseButtons: u32 {
+        const FROM_LEFT_1ST_BUTTON_PRESSED = FROM_LEFT_1ST_BUTTON_PRESSED;
+        const RIGHTMOST_BUTTON_PRESSED = RIGHTMOST_BUTTON_PRESSED;
+        const FROM_LEFT_2ND_BUTTON_PRESSED = FROM_LEFT_2ND_BUTTON_PRESSED;
+        const FROM_LEFT_3RD_BUTTON_PRESSED = FROM_LEFT_3RD_BUTTON_PRESSED;
+        const FROM_LEFT_4TH_BUTTON_PRESSED = FROM_LEFT_4TH_BUTTON_PRESSED;
+    }
+}
+
+bitflags! {
+    /// Mouse flags.
+    pub struct MouseFlags: u32 {
+        const MOUSE_MOVED = MOUSE_MOVED;
+        const DOUBLE_CLICK = DOUBLE_CLICK;
+        const MOUSE_WHEELED = MOUSE_WHEELED;
+        const MOUSE_HWHEELED = MOUSE_HWHEELED;
     }
 }
 
 /// Pseudo console handle.
-#[derive(Clone, Copy, Display, From, Handle, Into)]
-#[display(fmt = "{:#p}", _0)]
+///
+/// Note: `HPCON` is a `RawHandle`, so it is built with `TryFrom` instead of
+/// `From`.
+#[derive(Clone, Copy, Handle, Into)]
 #[repr(transparent)]
 pub struct PseudoConsoleHandle(HPCON);
 
-impl Debug for PseudoConsoleHandle {
-    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
-        f.debug_tuple("PseudoConsoleHandle")
-            .field(&format_args!("{:#p}", self.0))
-            .finish()
+#[cfg(test)]
+mod tests {
+    use super::{
+        CharInfo, ControlKeyState, Coordinates, InputEvent, InputRecord, MouseButtons, MouseFlags,
+        SmallRect, SyntheticKey, SyntheticMouse,
+    };
+    use crate::wrap::key::Key;
+
+    #[test]
+    fn char_info() {
+        let char_info = CharInfo::new('x' as _, 0x1F);
+        assert_eq!('x' as u16, char_info.character());
+        assert_eq!(0x1F, char_info.attributes());
+        assert_eq!(
+            "CharInfo { character: 120, attributes: 0x001f }",
+            format!("{:?}", char_info),
+        );
+    }
+
+    #[test]
+    fn small_rect() {
+        let small_rect = SmallRect::new(0, 5, 79, 29);
+        assert_eq!((80, 25), (small_rect.width(), small_rect.height()));
+        assert_eq!(small_rect, SmallRect::new(0, 5, 79, 29));
+    }
+
+    #[test]
+    fn key() {
+        let event = InputEvent::from(
+            SyntheticKey::builder()
+                .virtual_key(Key::A)
+                .char('A' as _)
+                .modifiers(ControlKeyState::SHIFT_PRESSED)
+                .build(),
+        );
+        let input_record = InputRecord::from(event);
+        assert_eq!(1, input_record.event_type());
+        assert_eq!(
+            InputEvent::Key {
+                down: true,
+                repeat: 1,
+                virtual_key: Some(Key::A),
+                virtual_key_code: 0x41,
+                scan_code: 0,
+                char: 'A' as _,
+                modifiers: ControlKeyState::SHIFT_PRESSED,
+            },
+            input_record.event().unwrap(),
+        );
+    }
+
+    #[test]
+    fn unknown_key() {
+        // No key, `VK_PROCESSKEY` and a reserved code.
+        for &code in &[0, 0xE5, 0xFF] {
+            let event = InputEvent::from(SyntheticKey::builder().virtual_key(Key::A).build());
+            let mut input_record = InputRecord::from(event);
+            unsafe { input_record.Event.KeyEvent_mut().wVirtualKeyCode = code };
+            let event = input_record.event().unwrap();
+            match event {
+                InputEvent::Key {
+                    virtual_key,
+                    virtual_key_code,
+                    ..
+                } => assert_eq!((None, code), (virtual_key, virtual_key_code)),
+                _ => panic!("{:?}", event),
+            }
+            let input_record = InputRecord::from(event);
+            assert_eq!(code, unsafe {
+                input_record.Event.KeyEvent().wVirtualKeyCode
+            });
+        }
+    }
+
+    #[test]
+    fn mouse() {
+        let event = InputEvent::from(
+            SyntheticMouse::builder()
+                .position(Coordinates::new(3, 4))
+                .buttons(MouseButtons::FROM_LEFT_1ST_BUTTON_PRESSED)
+                .wheel_delta(-120)
+                .flags(MouseFlags::MOUSE_WHEELED)
+                .build(),
+        );
+        let input_record = InputRecord::from(event);
+        assert_eq!(2, input_record.event_type());
+        assert_eq!(0xFF88_0001, unsafe {
+            input_record.Event.MouseEvent().dwButtonState
+        });
+        assert_eq!(event, input_record.event().unwrap());
+    }
+
+    #[test]
+    fn other() {
+        for &event in &[
+            InputEvent::WindowBufferSize(Coordinates::new(120, 30)),
+            InputEvent::Menu(7),
+            InputEvent::Focus(true),
+        ] {
+            assert_eq!(event, InputRecord::from(event).event().unwrap());
+        }
+    }
+
+    #[test]
+    fn unknown() {
+        let mut input_record = InputRecord::from(InputEvent::Focus(false));
+        input_record.EventType = 0x20;
+        assert!(input_record.event().is_err());
+        assert_eq!(
+            "InputRecord { event_type: 32 }",
+            format!("{:?}", input_record),
+        );
     }
 }
@@ -5,9 +5,12 @@ use crate::{
         securitybaseapi::AdjustTokenPrivileges,
         winbase::LookupPrivilegeValue,
     },
+    utils::Call,
+    Result,
 };
-use anyhow::Result;
 use derive_more::{Deref, DerefMut, Display, From, Into};
+#[cfg(feature = "serde")]
+use serde::{ser::SerializeStruct, Deserialize, Deserializer, Serialize, Serializer};
 use std::fmt::{self, Debug, Display, Formatter};
 use winapi::um::winnt::{
     LUID_AND_ATTRIBUTES, MEMORY_BASIC_INFORMATION, MEM_COMMIT, MEM_FREE, MEM_IMAGE, MEM_MAPPED,
@@ -88,6 +91,50 @@ impl Debug for MemoryBasicInformation {
     }
 }
 
+#[cfg(feature = "serde")]
+impl Serialize for MemoryBasicInformation {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut state = serializer.serialize_struct("MemoryBasicInformation", 7)?;
+        state.serialize_field("state", &self.state())?;
+        state.serialize_field("type", &self.r#type())?;
+        state.serialize_field("base_address", &self.base_address())?;
+        state.serialize_field("size", &self.size())?;
+        state.serialize_field("protect", &self.protect())?;
+        state.serialize_field("allocation_base_address", &self.allocation_base_address())?;
+        state.serialize_field("allocation_protect", &self.allocation_protect())?;
+        state.end()
+    }
+}
+
+/// The undefined values of the state are zeroed.
+#[cfg(feature = "serde")]
+impl<'de> Deserialize<'de> for MemoryBasicInformation {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        #[derive(Deserialize)]
+        #[serde(rename = "MemoryBasicInformation")]
+        struct Fields {
+            state: State,
+            r#type: Option<Type>,
+            base_address: usize,
+            size: usize,
+            protect: Option<Protect>,
+            allocation_base_address: Option<usize>,
+            allocation_protect: Option<Protect>,
+        }
+
+        let fields = Fields::deserialize(deserializer)?;
+        Ok(Self(MEMORY_BASIC_INFORMATION {
+            BaseAddress: fields.base_address as _,
+            AllocationBase: fields.allocation_base_address.unwrap_or_default() as _,
+            AllocationProtect: fields.allocation_protect.map_or(0, Into::into),
+            RegionSize: fields.size,
+            State: fields.state.into(),
+            Protect: fields.protect.map_or(0, Into::into),
+            Type: fields.r#type.map_or(0, Into::into),
+        }))
+    }
+}
+
 /// Privilege.
 #[derive(Clone, Copy, Deref, Display, From, Into)]
 #[display(fmt = "luid: {}", "self.luid()")]
@@ -98,23 +145,23 @@ impl Privilege {
     /// Enable privilege for current process.
     #[inline(never)]
     pub fn enable(name: &str) -> Result<()> {
-        let process = GetCurrentProcess();
+        let process = GetCurrentProcess.call();
         let token = OpenProcessToken::builder()
-            .process_handle(&process)
+            .process_handle(process)
             .desired_access(TOKEN_ADJUST_PRIVILEGES)
-            .build()()?;
+            .call()?;
         let privilege = Privilege::lookup(name)?.attribute(SE_PRIVILEGE_ENABLED);
         let privileges = privilege.into();
         AdjustTokenPrivileges::builder()
             .token(&token)
             .new_state(&privileges)
-            .build()()?;
+            .call()?;
         Ok(())
     }
 
     /// Lookup privilege on the local system.
     pub fn lookup(name: &str) -> Result<Self> {
-        LookupPrivilegeValue::builder().name(name).build()()
+        LookupPrivilegeValue::builder().name(name).call()
     }
 
     #[inline]
@@ -163,6 +210,7 @@ impl From<Privilege> for Privileges {
 
 /// Protect.
 #[derive(Clone, Copy, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
 pub struct Protect {
     pub access: Access,
     pub flags: Flags,
@@ -333,6 +381,7 @@ impl From<u32> for Protect {
 
 /// Access.
 #[derive(Clone, Copy, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
 pub struct Access {
     pub execute: bool,
     pub read: bool,
@@ -342,6 +391,7 @@ pub struct Access {
 
 /// Flags.
 #[derive(Clone, Copy, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
 pub struct Flags {
     pub guard: bool,
     pub no_cache: bool,
@@ -350,6 +400,7 @@ pub struct Flags {
 
 /// State.
 #[derive(Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
 pub enum State {
     Free,
     Reserve,
@@ -379,6 +430,7 @@ impl From<u32> for State {
 
 /// Type.
 #[derive(Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
 pub enum Type {
     Image,
     Mapped,
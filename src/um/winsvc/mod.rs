@@ -0,0 +1,108 @@
+pub(crate) use self::{
+    register_service_ctrl_handler_ex::{
+        RegisterServiceCtrlHandlerEx, RegisterServiceCtrlHandlerExBuilder,
+    },
+    set_service_status::{SetServiceStatus, SetServiceStatusBuilder},
+    start_service_ctrl_dispatcher::{
+        StartServiceCtrlDispatcher, StartServiceCtrlDispatcherBuilder,
+    },
+};
+pub use crate::wrap::service::{ControlAccept, CurrentState};
+use derive_more::{Deref, DerefMut, From, Into};
+use rustapi_macro::{Accessors, Handle};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use winapi::um::{
+    winnt::{
+        SERVICE_FILE_SYSTEM_DRIVER, SERVICE_INTERACTIVE_PROCESS, SERVICE_KERNEL_DRIVER,
+        SERVICE_USER_OWN_PROCESS, SERVICE_USER_SHARE_PROCESS, SERVICE_WIN32_OWN_PROCESS,
+        SERVICE_WIN32_SHARE_PROCESS,
+    },
+    winsvc::{SERVICE_STATUS, SERVICE_STATUS_HANDLE},
+};
+
+pub fn register_service_ctrl_handler_ex<'a>(
+) -> RegisterServiceCtrlHandlerExBuilder<'a, ((), (), ())> {
+    RegisterServiceCtrlHandlerEx::builder()
+}
+
+pub fn set_service_status() -> SetServiceStatusBuilder<((), ())> {
+    SetServiceStatus::builder()
+}
+
+pub fn start_service_ctrl_dispatcher<'a>() -> StartServiceCtrlDispatcherBuilder<'a, ((), ())> {
+    StartServiceCtrlDispatcher::builder()
+}
+
+mod register_service_ctrl_handler_ex;
+mod set_service_status;
+mod start_service_ctrl_dispatcher;
+
+/// Service status handle.
+///
+/// Note: It is owned by the service control manager and must not be closed.
+#[derive(Clone, Copy, From, Handle, Into)]
+#[repr(transparent)]
+pub struct ServiceStatusHandle(SERVICE_STATUS_HANDLE);
+
+unsafe impl Send for ServiceStatusHandle {}
+
+unsafe impl Sync for ServiceStatusHandle {}
+
+/// Status.
+#[derive(Accessors, Clone, Copy, Deref, DerefMut, From, Into)]
+#[accessors(
+    deserialize,
+    fields(
+        service_type(from = "dwServiceType", ty = "Type", convert = "from"),
+        current_state(from = "dwCurrentState", ty = "CurrentState", convert = "from"),
+        controls_accepted(from = "dwControlsAccepted", ty = "ControlAccept", convert = "bits"),
+        win32_exit_code(from = "dwWin32ExitCode", ty = "u32"),
+        service_specific_exit_code(from = "dwServiceSpecificExitCode", ty = "u32"),
+        check_point(from = "dwCheckPoint", ty = "u32"),
+        wait_hint(from = "dwWaitHint", ty = "u32")
+    )
+)]
+#[repr(transparent)]
+pub struct Status(SERVICE_STATUS);
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
+pub enum Type {
+    FileSystemDriver,
+    KernelDriver,
+    Win32OwnProcess,
+    Win32ShareProcess,
+    UserOwnProcess,
+    UserShareProcess,
+    InteractiveProcess,
+}
+
+impl From<Type> for u32 {
+    fn from(from: Type) -> Self {
+        match from {
+            Type::FileSystemDriver => SERVICE_FILE_SYSTEM_DRIVER,
+            Type::KernelDriver => SERVICE_KERNEL_DRIVER,
+            Type::Win32OwnProcess => SERVICE_WIN32_OWN_PROCESS,
+            Type::Win32ShareProcess => SERVICE_WIN32_SHARE_PROCESS,
+            Type::UserOwnProcess => SERVICE_USER_OWN_PROCESS,
+            Type::UserShareProcess => SERVICE_USER_SHARE_PROCESS,
+            Type::InteractiveProcess => SERVICE_INTERACTIVE_PROCESS,
+        }
+    }
+}
+
+impl From<u32> for Type {
+    fn from(from: u32) -> Self {
+        match from {
+            SERVICE_FILE_SYSTEM_DRIVER => Type::FileSystemDriver,
+            SERVICE_KERNEL_DRIVER => Type::KernelDriver,
+            SERVICE_WIN32_OWN_PROCESS => Type::Win32OwnProcess,
+            SERVICE_WIN32_SHARE_PROCESS => Type::Win32ShareProcess,
+            SERVICE_USER_OWN_PROCESS => Type::UserOwnProcess,
+            SERVICE_USER_SHARE_PROCESS => Type::UserShareProcess,
+            SERVICE_INTERACTIVE_PROCESS => Type::InteractiveProcess,
+            _ => unimplemented!(),
+        }
+    }
+}
@@ -0,0 +1,39 @@
+use crate::{
+    error::ensure,
+    r#macro::FnOnce,
+    um::winsvc::ServiceStatusHandle,
+    utils::{Call, FromStrictRawHandle},
+    Error, Result,
+};
+use typed_builder::TypedBuilder;
+use widestring::WideCString;
+use winapi::um::winsvc::LPHANDLER_FUNCTION_EX;
+
+/// Register service control handler extended.
+#[derive(FnOnce, TypedBuilder)]
+pub struct RegisterServiceCtrlHandlerEx<'a> {
+    service_name: &'a str,
+    handler_proc: LPHANDLER_FUNCTION_EX,
+    #[builder(default)]
+    context: usize,
+}
+
+impl Call for RegisterServiceCtrlHandlerEx<'_> {
+    type Output = Result<ServiceStatusHandle>;
+
+    fn call(self) -> Self::Output {
+        use winapi::um::winsvc::RegisterServiceCtrlHandlerExW;
+
+        let service_name = WideCString::from_str(self.service_name)?;
+
+        #[allow(non_snake_case)]
+        unsafe {
+            let lpServiceName = service_name.as_ptr();
+            let lpHandlerProc = self.handler_proc;
+            let lpContext = self.context as _;
+            let r#return = RegisterServiceCtrlHandlerExW(lpServiceName, lpHandlerProc, lpContext);
+            ensure!(!r#return.is_null(), Error::last_os_error());
+            Ok(ServiceStatusHandle::from_strict_raw_handle(r#return))
+        }
+    }
+}
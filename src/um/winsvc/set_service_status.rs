@@ -0,0 +1,33 @@
+use crate::{
+    error::ensure,
+    r#macro::FnOnce,
+    um::winsvc::{ServiceStatusHandle, Status},
+    utils::{AsStrictRawHandle, Call},
+    Error, Result,
+};
+use typed_builder::TypedBuilder;
+use winapi::shared::minwindef::FALSE;
+
+/// Set service status.
+#[derive(FnOnce, TypedBuilder)]
+pub struct SetServiceStatus {
+    handle: ServiceStatusHandle,
+    status: Status,
+}
+
+impl Call for SetServiceStatus {
+    type Output = Result<()>;
+
+    fn call(mut self) -> Self::Output {
+        use winapi::um::winsvc::SetServiceStatus;
+
+        #[allow(non_snake_case)]
+        unsafe {
+            let hServiceStatus = self.handle.as_strict_raw_handle();
+            let lpServiceStatus = &mut *self.status;
+            let r#return = SetServiceStatus(hServiceStatus, lpServiceStatus);
+            ensure!(r#return != FALSE, Error::last_os_error());
+            Ok(())
+        }
+    }
+}
@@ -0,0 +1,45 @@
+use crate::{error::ensure, r#macro::FnOnce, utils::Call, Error, Result};
+use std::ptr::null_mut;
+use typed_builder::TypedBuilder;
+use widestring::WideCString;
+use winapi::{
+    shared::minwindef::FALSE,
+    um::winsvc::{LPSERVICE_MAIN_FUNCTIONW, SERVICE_TABLE_ENTRYW},
+};
+
+/// Start service control dispatcher.
+///
+/// Connects the main thread of a service process to the service control
+/// manager. It does not return until the service has stopped.
+#[derive(FnOnce, TypedBuilder)]
+pub struct StartServiceCtrlDispatcher<'a> {
+    service_name: &'a str,
+    service_main: LPSERVICE_MAIN_FUNCTIONW,
+}
+
+impl Call for StartServiceCtrlDispatcher<'_> {
+    type Output = Result<()>;
+
+    fn call(self) -> Self::Output {
+        use winapi::um::winsvc::StartServiceCtrlDispatcherW;
+
+        let mut service_name = WideCString::from_str(self.service_name)?.into_vec_with_nul();
+
+        #[allow(non_snake_case)]
+        unsafe {
+            let lpServiceStartTable = [
+                SERVICE_TABLE_ENTRYW {
+                    lpServiceName: service_name.as_mut_ptr(),
+                    lpServiceProc: self.service_main,
+                },
+                SERVICE_TABLE_ENTRYW {
+                    lpServiceName: null_mut(),
+                    lpServiceProc: None,
+                },
+            ];
+            let r#return = StartServiceCtrlDispatcherW(lpServiceStartTable.as_ptr());
+            ensure!(r#return != FALSE, Error::last_os_error());
+            Ok(())
+        }
+    }
+}
@@ -0,0 +1,110 @@
+//! The native API declarations that winapi does not have.
+
+#![allow(
+    clippy::upper_case_acronyms,
+    non_camel_case_types,
+    non_snake_case,
+    non_upper_case_globals
+)]
+
+use winapi::shared::{
+    basetsd::{SIZE_T, ULONG_PTR},
+    ntdef::{HANDLE, LARGE_INTEGER, LONG, NTSTATUS, PULONG, PVOID, ULONG, UNICODE_STRING, USHORT},
+};
+
+pub(crate) type KPRIORITY = LONG;
+pub(crate) type PROCESSINFOCLASS = u32;
+pub(crate) type SYSTEM_INFORMATION_CLASS = u32;
+
+pub(crate) const ProcessBasicInformation: PROCESSINFOCLASS = 0;
+pub(crate) const ProcessImageFileName: PROCESSINFOCLASS = 27;
+pub(crate) const ProcessCommandLineInformation: PROCESSINFOCLASS = 60;
+
+pub(crate) const SystemProcessInformation: SYSTEM_INFORMATION_CLASS = 5;
+pub(crate) const SystemExtendedHandleInformation: SYSTEM_INFORMATION_CLASS = 64;
+
+#[derive(Clone, Copy)]
+#[repr(C)]
+pub(crate) struct PROCESS_BASIC_INFORMATION {
+    pub ExitStatus: NTSTATUS,
+    pub PebBaseAddress: PVOID,
+    pub AffinityMask: ULONG_PTR,
+    pub BasePriority: KPRIORITY,
+    pub UniqueProcessId: HANDLE,
+    pub InheritedFromUniqueProcessId: HANDLE,
+}
+
+/// The documented prefix of the entry, the thread array follows it.
+#[derive(Clone, Copy)]
+#[repr(C)]
+pub(crate) struct SYSTEM_PROCESS_INFORMATION {
+    pub NextEntryOffset: ULONG,
+    pub NumberOfThreads: ULONG,
+    pub WorkingSetPrivateSize: LARGE_INTEGER,
+    pub HardFaultCount: ULONG,
+    pub NumberOfThreadsHighWatermark: ULONG,
+    pub CycleTime: u64,
+    pub CreateTime: LARGE_INTEGER,
+    pub UserTime: LARGE_INTEGER,
+    pub KernelTime: LARGE_INTEGER,
+    pub ImageName: UNICODE_STRING,
+    pub BasePriority: KPRIORITY,
+    pub UniqueProcessId: HANDLE,
+    pub InheritedFromUniqueProcessId: HANDLE,
+    pub HandleCount: ULONG,
+    pub SessionId: ULONG,
+    pub UniqueProcessKey: ULONG_PTR,
+    pub PeakVirtualSize: SIZE_T,
+    pub VirtualSize: SIZE_T,
+    pub PageFaultCount: ULONG,
+    pub PeakWorkingSetSize: SIZE_T,
+    pub WorkingSetSize: SIZE_T,
+    pub QuotaPeakPagedPoolUsage: SIZE_T,
+    pub QuotaPagedPoolUsage: SIZE_T,
+    pub QuotaPeakNonPagedPoolUsage: SIZE_T,
+    pub QuotaNonPagedPoolUsage: SIZE_T,
+    pub PagefileUsage: SIZE_T,
+    pub PeakPagefileUsage: SIZE_T,
+    pub PrivatePageCount: SIZE_T,
+}
+
+#[derive(Clone, Copy)]
+#[repr(C)]
+pub(crate) struct SYSTEM_HANDLE_TABLE_ENTRY_INFO_EX {
+    pub Object: PVOID,
+    pub UniqueProcessId: ULONG_PTR,
+    pub HandleValue: ULONG_PTR,
+    pub GrantedAccess: ULONG,
+    pub CreatorBackTraceIndex: USHORT,
+    pub ObjectTypeIndex: USHORT,
+    pub HandleAttributes: ULONG,
+    pub Reserved: ULONG,
+}
+
+/// The header of the handle list, the entries follow it.
+#[derive(Clone, Copy)]
+#[repr(C)]
+pub(crate) struct SYSTEM_HANDLE_INFORMATION_EX {
+    pub NumberOfHandles: ULONG_PTR,
+    pub Reserved: ULONG_PTR,
+}
+
+#[link(name = "ntdll")]
+extern "system" {
+    pub(crate) fn NtQueryInformationProcess(
+        ProcessHandle: HANDLE,
+        ProcessInformationClass: PROCESSINFOCLASS,
+        ProcessInformation: PVOID,
+        ProcessInformationLength: ULONG,
+        ReturnLength: PULONG,
+    ) -> NTSTATUS;
+
+    pub(crate) fn NtQuerySystemInformation(
+        SystemInformationClass: SYSTEM_INFORMATION_CLASS,
+        SystemInformation: PVOID,
+        SystemInformationLength: ULONG,
+        ReturnLength: PULONG,
+    ) -> NTSTATUS;
+
+    pub(crate) fn RtlNtStatusToDosError(Status: NTSTATUS) -> ULONG;
+}
@@ -0,0 +1,355 @@
+use self::ffi::{
+    PROCESS_BASIC_INFORMATION, SYSTEM_HANDLE_INFORMATION_EX, SYSTEM_HANDLE_TABLE_ENTRY_INFO_EX,
+    SYSTEM_PROCESS_INFORMATION,
+};
+pub(crate) use self::{
+    nt_query_information_process::{NtQueryInformationProcess, NtQueryInformationProcessBuilder},
+    nt_query_system_information::{NtQuerySystemInformation, NtQuerySystemInformationBuilder},
+    rtl_nt_status_to_dos_error::{RtlNtStatusToDosError, RtlNtStatusToDosErrorBuilder},
+};
+use crate::{
+    shared::{ntdef::AsHandle, ntstatus::NtStatus},
+    Result,
+};
+use std::{
+    fmt::{self, Debug, Formatter},
+    mem::size_of,
+    path::PathBuf,
+    slice,
+};
+use winapi::shared::ntdef::UNICODE_STRING;
+
+pub fn nt_query_information_process<'a>() -> NtQueryInformationProcessBuilder<'a, ((), ())> {
+    NtQueryInformationProcess::builder()
+}
+
+pub fn nt_query_system_information() -> NtQuerySystemInformationBuilder<((),)> {
+    NtQuerySystemInformation::builder()
+}
+
+pub fn rtl_nt_status_to_dos_error() -> RtlNtStatusToDosErrorBuilder<((),)> {
+    RtlNtStatusToDosError::builder()
+}
+
+/// The basic information of a process, including its PEB address.
+pub fn query_process_basic_information(process: &impl AsHandle) -> Result<ProcessBasicInformation> {
+    let information = nt_query_information_process()
+        .process(process)
+        .class(ProcessInformationClass::Basic)
+        .call()?;
+    Ok(unsafe { process_basic_information(&information) })
+}
+
+/// The image path of a process, in the native `\Device\HarddiskVolume1\...`
+/// form.
+pub fn query_process_image_file_name(process: &impl AsHandle) -> Result<PathBuf> {
+    let information = nt_query_information_process()
+        .process(process)
+        .class(ProcessInformationClass::ImageFileName)
+        .call()?;
+    Ok(PathBuf::from(unsafe { unicode_string(&information)? }))
+}
+
+/// The command line of a process, available since Windows 8.1.
+pub fn query_process_command_line(process: &impl AsHandle) -> Result<String> {
+    let information = nt_query_information_process()
+        .process(process)
+        .class(ProcessInformationClass::CommandLine)
+        .call()?;
+    unsafe { unicode_string(&information) }
+}
+
+/// The processes running on the system.
+pub fn query_system_processes() -> Result<Vec<SystemProcess>> {
+    let information = nt_query_system_information()
+        .class(SystemInformationClass::Process)
+        .call()?;
+    unsafe { system_processes(&information) }
+}
+
+/// The handles opened on the system, by all the processes.
+pub fn query_system_handles() -> Result<Vec<SystemHandle>> {
+    let information = nt_query_system_information()
+        .class(SystemInformationClass::ExtendedHandle)
+        .call()?;
+    Ok(unsafe { system_handles(&information) })
+}
+
+mod ffi;
+mod nt_query_information_process;
+mod nt_query_system_information;
+mod rtl_nt_status_to_dos_error;
+
+/// The number of words a length in bytes fits in.
+fn words(length: u32) -> usize {
+    (length as usize).div_ceil(size_of::<usize>())
+}
+
+/// Resizes the buffer to the length the API asked for, or doubles it when the
+/// length is unknown or already outdated.
+fn grow(buffer: &mut Vec<usize>, length: u32) {
+    let len = match words(length) {
+        0 => buffer.len() * 2,
+        len if len == buffer.len() => buffer.len() * 2,
+        len => len,
+    };
+    buffer.resize(len, 0);
+}
+
+unsafe fn string(string: &UNICODE_STRING) -> Result<String> {
+    if string.Buffer.is_null() {
+        return Ok(String::new());
+    }
+    let buffer = slice::from_raw_parts(string.Buffer, string.Length as usize / 2);
+    Ok(String::from_utf16(buffer)?)
+}
+
+unsafe fn unicode_string(information: &[usize]) -> Result<String> {
+    if information.is_empty() {
+        return Ok(String::new());
+    }
+    string(&*(information.as_ptr() as *const UNICODE_STRING))
+}
+
+unsafe fn process_basic_information(information: &[usize]) -> ProcessBasicInformation {
+    let information = &*(information.as_ptr() as *const PROCESS_BASIC_INFORMATION);
+    ProcessBasicInformation {
+        exit_status: NtStatus::from(information.ExitStatus),
+        peb_address: information.PebBaseAddress as _,
+        affinity_mask: information.AffinityMask,
+        base_priority: information.BasePriority,
+        process_id: information.UniqueProcessId as _,
+        parent_process_id: information.InheritedFromUniqueProcessId as _,
+    }
+}
+
+unsafe fn system_processes(information: &[usize]) -> Result<Vec<SystemProcess>> {
+    let mut processes = Vec::new();
+    if information.is_empty() {
+        return Ok(processes);
+    }
+    let mut entry = information.as_ptr() as *const u8;
+    loop {
+        let process = &*(entry as *const SYSTEM_PROCESS_INFORMATION);
+        processes.push(SystemProcess {
+            process_id: process.UniqueProcessId as _,
+            parent_process_id: process.InheritedFromUniqueProcessId as _,
+            image_name: string(&process.ImageName)?,
+            thread_count: process.NumberOfThreads,
+            handle_count: process.HandleCount,
+            session_id: process.SessionId,
+            base_priority: process.BasePriority,
+            create_time: *process.CreateTime.QuadPart(),
+            user_time: *process.UserTime.QuadPart(),
+            kernel_time: *process.KernelTime.QuadPart(),
+            virtual_size: process.VirtualSize,
+            working_set_size: process.WorkingSetSize,
+            private_page_count: process.PrivatePageCount,
+        });
+        if process.NextEntryOffset == 0 {
+            break;
+        }
+        entry = entry.add(process.NextEntryOffset as _);
+    }
+    Ok(processes)
+}
+
+unsafe fn system_handles(information: &[usize]) -> Vec<SystemHandle> {
+    if information.is_empty() {
+        return Vec::new();
+    }
+    let header = information.as_ptr() as *const SYSTEM_HANDLE_INFORMATION_EX;
+    let entries = slice::from_raw_parts(
+        header.add(1) as *const SYSTEM_HANDLE_TABLE_ENTRY_INFO_EX,
+        (*header).NumberOfHandles,
+    );
+    entries
+        .iter()
+        .map(|entry| SystemHandle {
+            process_id: entry.UniqueProcessId as _,
+            handle: entry.HandleValue,
+            object: entry.Object as _,
+            object_type_index: entry.ObjectTypeIndex,
+            granted_access: entry.GrantedAccess,
+            attributes: entry.HandleAttributes,
+        })
+        .collect()
+}
+
+/// Process information class.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum ProcessInformationClass {
+    Basic,
+    ImageFileName,
+    CommandLine,
+}
+
+impl From<ProcessInformationClass> for u32 {
+    fn from(from: ProcessInformationClass) -> Self {
+        match from {
+            ProcessInformationClass::Basic => ffi::ProcessBasicInformation,
+            ProcessInformationClass::ImageFileName => ffi::ProcessImageFileName,
+            ProcessInformationClass::CommandLine => ffi::ProcessCommandLineInformation,
+        }
+    }
+}
+
+/// System information class.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum SystemInformationClass {
+    Process,
+    ExtendedHandle,
+}
+
+impl From<SystemInformationClass> for u32 {
+    fn from(from: SystemInformationClass) -> Self {
+        match from {
+            SystemInformationClass::Process => ffi::SystemProcessInformation,
+            SystemInformationClass::ExtendedHandle => ffi::SystemExtendedHandleInformation,
+        }
+    }
+}
+
+/// Process basic information.
+#[derive(Clone, Copy, Eq, PartialEq)]
+pub struct ProcessBasicInformation {
+    pub exit_status: NtStatus,
+    pub peb_address: usize,
+    pub affinity_mask: usize,
+    pub base_priority: i32,
+    pub process_id: u32,
+    pub parent_process_id: u32,
+}
+
+impl Debug for ProcessBasicInformation {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.debug_struct("ProcessBasicInformation")
+            .field("exit_status", &self.exit_status)
+            .field("peb_address", &format_args!("{:#x}", self.peb_address))
+            .field("affinity_mask", &format_args!("{:#b}", self.affinity_mask))
+            .field("base_priority", &self.base_priority)
+            .field("process_id", &self.process_id)
+            .field("parent_process_id", &self.parent_process_id)
+            .finish()
+    }
+}
+
+/// System process, the times are in 100 nanoseconds intervals.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SystemProcess {
+    pub process_id: u32,
+    pub parent_process_id: u32,
+    pub image_name: String,
+    pub thread_count: u32,
+    pub handle_count: u32,
+    pub session_id: u32,
+    pub base_priority: i32,
+    pub create_time: i64,
+    pub user_time: i64,
+    pub kernel_time: i64,
+    pub virtual_size: usize,
+    pub working_set_size: usize,
+    pub private_page_count: usize,
+}
+
+/// System handle.
+#[derive(Clone, Copy, Eq, PartialEq)]
+pub struct SystemHandle {
+    pub process_id: u32,
+    pub handle: usize,
+    pub object: usize,
+    pub object_type_index: u16,
+    pub granted_access: u32,
+    pub attributes: u32,
+}
+
+impl Debug for SystemHandle {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.debug_struct("SystemHandle")
+            .field("process_id", &self.process_id)
+            .field("handle", &format_args!("{:#x}", self.handle))
+            .field("object", &format_args!("{:#x}", self.object))
+            .field("object_type_index", &self.object_type_index)
+            .field(
+                "granted_access",
+                &format_args!("{:#x}", self.granted_access),
+            )
+            .field("attributes", &self.attributes)
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        ffi::{
+            SYSTEM_HANDLE_INFORMATION_EX, SYSTEM_HANDLE_TABLE_ENTRY_INFO_EX,
+            SYSTEM_PROCESS_INFORMATION,
+        },
+        grow, system_handles, system_processes,
+    };
+    use std::{
+        mem::{size_of, zeroed},
+        ptr,
+    };
+
+    #[test]
+    fn grow_to_length() {
+        let mut buffer = vec![0; 0x40];
+        grow(&mut buffer, 6 * size_of::<usize>() as u32);
+        assert_eq!(6, buffer.len());
+        grow(&mut buffer, 6 * size_of::<usize>() as u32);
+        assert_eq!(12, buffer.len());
+        grow(&mut buffer, 0);
+        assert_eq!(24, buffer.len());
+    }
+
+    #[test]
+    fn processes() {
+        let mut name = "System".encode_utf16().collect::<Vec<_>>();
+        let entry_size = size_of::<SYSTEM_PROCESS_INFORMATION>();
+        let mut information = vec![0_usize; 2 * entry_size / size_of::<usize>()];
+        unsafe {
+            let mut first: SYSTEM_PROCESS_INFORMATION = zeroed();
+            first.NextEntryOffset = entry_size as _;
+            first.NumberOfThreads = 2;
+            let mut second: SYSTEM_PROCESS_INFORMATION = zeroed();
+            second.UniqueProcessId = 4 as _;
+            second.ImageName.Length = (name.len() * 2) as _;
+            second.ImageName.MaximumLength = (name.len() * 2) as _;
+            second.ImageName.Buffer = name.as_mut_ptr();
+            *second.CreateTime.QuadPart_mut() = 42;
+            let entries = information.as_mut_ptr() as *mut SYSTEM_PROCESS_INFORMATION;
+            ptr::write(entries, first);
+            ptr::write(entries.add(1), second);
+            let processes = system_processes(&information).unwrap();
+            assert_eq!(2, processes.len());
+            assert_eq!("", processes[0].image_name);
+            assert_eq!(2, processes[0].thread_count);
+            assert_eq!("System", processes[1].image_name);
+            assert_eq!(4, processes[1].process_id);
+            assert_eq!(42, processes[1].create_time);
+            assert!(system_processes(&[]).unwrap().is_empty());
+        }
+    }
+
+    #[test]
+    fn handles() {
+        let header_size = size_of::<SYSTEM_HANDLE_INFORMATION_EX>();
+        let entry_size = size_of::<SYSTEM_HANDLE_TABLE_ENTRY_INFO_EX>();
+        let mut information = vec![0_usize; (header_size + 2 * entry_size) / size_of::<usize>()];
+        unsafe {
+            let header = information.as_mut_ptr() as *mut SYSTEM_HANDLE_INFORMATION_EX;
+            (*header).NumberOfHandles = 2;
+            let entries = header.add(1) as *mut SYSTEM_HANDLE_TABLE_ENTRY_INFO_EX;
+            (*entries.add(1)).UniqueProcessId = 4;
+            (*entries.add(1)).HandleValue = 0x1C;
+            (*entries.add(1)).GrantedAccess = 0x1F0FFF;
+            let handles = system_handles(&information);
+            assert_eq!(2, handles.len());
+            assert_eq!(4, handles[1].process_id);
+            assert_eq!(0x1C, handles[1].handle);
+            assert_eq!(0x1F0FFF, handles[1].granted_access);
+        }
+    }
+}
@@ -0,0 +1,58 @@
+use super::{grow, words, ProcessInformationClass};
+use crate::{
+    r#macro::FnOnce,
+    shared::{ntdef::BorrowedHandle, ntstatus::NtStatus},
+    utils::Call,
+    Result,
+};
+use std::{mem::size_of, os::windows::io::AsRawHandle};
+use typed_builder::TypedBuilder;
+
+/// NT query information process.
+///
+/// Returns the information in a word aligned buffer, grown until it fits.
+#[derive(FnOnce, TypedBuilder)]
+pub struct NtQueryInformationProcess<'a> {
+    #[builder(setter(into))]
+    process: BorrowedHandle<'a>,
+    class: ProcessInformationClass,
+    #[builder(default = vec![0; 0x40], setter(skip))]
+    information: Vec<usize>,
+}
+
+impl Call for NtQueryInformationProcess<'_> {
+    type Output = Result<Vec<usize>>;
+
+    fn call(mut self) -> Self::Output {
+        use super::ffi::NtQueryInformationProcess;
+
+        #[allow(non_snake_case)]
+        unsafe {
+            let ProcessHandle = self.process.as_raw_handle();
+            let ProcessInformationClass = self.class.into();
+            let mut ReturnLength = 0;
+            loop {
+                let ProcessInformation = self.information.as_mut_ptr() as _;
+                let ProcessInformationLength = (self.information.len() * size_of::<usize>()) as _;
+                let r#return = NtQueryInformationProcess(
+                    ProcessHandle,
+                    ProcessInformationClass,
+                    ProcessInformation,
+                    ProcessInformationLength,
+                    &mut ReturnLength,
+                );
+                match NtStatus::from(r#return) {
+                    NtStatus::STATUS_INFO_LENGTH_MISMATCH
+                    | NtStatus::STATUS_BUFFER_TOO_SMALL
+                    | NtStatus::STATUS_BUFFER_OVERFLOW => grow(&mut self.information, ReturnLength),
+                    status => {
+                        status.ok()?;
+                        break;
+                    }
+                }
+            }
+            self.information.truncate(words(ReturnLength));
+            Ok(self.information)
+        }
+    }
+}
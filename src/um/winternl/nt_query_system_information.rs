@@ -0,0 +1,49 @@
+use super::{grow, words, SystemInformationClass};
+use crate::{r#macro::FnOnce, shared::ntstatus::NtStatus, utils::Call, Result};
+use std::mem::size_of;
+use typed_builder::TypedBuilder;
+
+/// NT query system information.
+///
+/// Returns the information in a word aligned buffer, grown until it fits.
+#[derive(FnOnce, TypedBuilder)]
+pub struct NtQuerySystemInformation {
+    class: SystemInformationClass,
+    #[builder(default = vec![0; 0x8000], setter(skip))]
+    information: Vec<usize>,
+}
+
+impl Call for NtQuerySystemInformation {
+    type Output = Result<Vec<usize>>;
+
+    fn call(mut self) -> Self::Output {
+        use super::ffi::NtQuerySystemInformation;
+
+        #[allow(non_snake_case)]
+        unsafe {
+            let SystemInformationClass = self.class.into();
+            let mut ReturnLength = 0;
+            loop {
+                let SystemInformation = self.information.as_mut_ptr() as _;
+                let SystemInformationLength = (self.information.len() * size_of::<usize>()) as _;
+                let r#return = NtQuerySystemInformation(
+                    SystemInformationClass,
+                    SystemInformation,
+                    SystemInformationLength,
+                    &mut ReturnLength,
+                );
+                match NtStatus::from(r#return) {
+                    NtStatus::STATUS_INFO_LENGTH_MISMATCH
+                    | NtStatus::STATUS_BUFFER_TOO_SMALL
+                    | NtStatus::STATUS_BUFFER_OVERFLOW => grow(&mut self.information, ReturnLength),
+                    status => {
+                        status.ok()?;
+                        break;
+                    }
+                }
+            }
+            self.information.truncate(words(ReturnLength));
+            Ok(self.information)
+        }
+    }
+}
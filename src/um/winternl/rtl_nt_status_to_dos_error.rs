@@ -0,0 +1,22 @@
+use crate::{r#macro::FnOnce, shared::ntstatus::NtStatus, utils::Call};
+use typed_builder::TypedBuilder;
+
+/// RTL NT status to DOS error.
+#[derive(FnOnce, TypedBuilder)]
+pub struct RtlNtStatusToDosError {
+    status: NtStatus,
+}
+
+impl Call for RtlNtStatusToDosError {
+    type Output = u32;
+
+    fn call(self) -> Self::Output {
+        use super::ffi::RtlNtStatusToDosError;
+
+        #[allow(non_snake_case)]
+        unsafe {
+            let Status = self.status.into();
+            RtlNtStatusToDosError(Status)
+        }
+    }
+}
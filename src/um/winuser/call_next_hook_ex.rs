@@ -0,0 +1,28 @@
+use crate::{r#macro::FnOnce, utils::Call};
+use std::ptr::null_mut;
+use typed_builder::TypedBuilder;
+use winapi::shared::minwindef::{LPARAM, LRESULT, WPARAM};
+
+/// Call next hook ex.
+#[derive(FnOnce, TypedBuilder)]
+pub struct CallNextHookEx {
+    code: i32,
+    w_param: WPARAM,
+    l_param: LPARAM,
+}
+
+impl Call for CallNextHookEx {
+    type Output = LRESULT;
+
+    fn call(self) -> Self::Output {
+        use winapi::um::winuser::CallNextHookEx;
+
+        #[allow(non_snake_case)]
+        unsafe {
+            let nCode = self.code;
+            let wParam = self.w_param;
+            let lParam = self.l_param;
+            CallNextHookEx(null_mut(), nCode, wParam, lParam)
+        }
+    }
+}
@@ -0,0 +1,80 @@
+use super::{WindowExStyle, WindowStyle};
+use crate::{
+    error::ensure,
+    r#macro::FnOnce,
+    shared::{minwindef::ModuleHandle, windef::WindowHandle},
+    utils::{AsStrictRawHandle, Call, FromStrictRawHandle},
+    Error, Result,
+};
+use std::{ffi::c_void, ptr::null_mut};
+use typed_builder::TypedBuilder;
+use widestring::WideCString;
+use winapi::um::winuser::CW_USEDEFAULT;
+
+/// Create window ex.
+#[derive(FnOnce, TypedBuilder)]
+pub struct CreateWindowEx<'a> {
+    #[builder(default = WindowExStyle::empty())]
+    ex_style: WindowExStyle,
+    class_name: &'a str,
+    #[builder(default)]
+    window_name: &'a str,
+    #[builder(default = WindowStyle::empty())]
+    style: WindowStyle,
+    #[builder(default = CW_USEDEFAULT)]
+    x: i32,
+    #[builder(default = CW_USEDEFAULT)]
+    y: i32,
+    #[builder(default = CW_USEDEFAULT)]
+    width: i32,
+    #[builder(default = CW_USEDEFAULT)]
+    height: i32,
+    #[builder(default)]
+    parent: Option<WindowHandle>,
+    #[builder(default)]
+    instance: Option<ModuleHandle>,
+    #[builder(default = null_mut())]
+    param: *mut c_void,
+}
+
+impl Call for CreateWindowEx<'_> {
+    type Output = Result<WindowHandle>;
+
+    fn call(self) -> Self::Output {
+        use winapi::um::winuser::CreateWindowExW;
+
+        let class_name = WideCString::from_str(self.class_name)?;
+        let window_name = WideCString::from_str(self.window_name)?;
+
+        #[allow(non_snake_case)]
+        unsafe {
+            let dwExStyle = self.ex_style.bits();
+            let lpClassName = class_name.as_ptr();
+            let lpWindowName = window_name.as_ptr();
+            let dwStyle = self.style.bits();
+            let hWndParent = self
+                .parent
+                .map_or(null_mut(), |parent| parent.as_strict_raw_handle());
+            let hInstance = self
+                .instance
+                .map_or(null_mut(), |instance| instance.as_strict_raw_handle());
+            let lpParam = self.param as _;
+            let r#return = CreateWindowExW(
+                dwExStyle,
+                lpClassName,
+                lpWindowName,
+                dwStyle,
+                self.x,
+                self.y,
+                self.width,
+                self.height,
+                hWndParent,
+                null_mut(),
+                hInstance,
+                lpParam,
+            );
+            ensure!(!r#return.is_null(), Error::last_os_error());
+            Ok(WindowHandle::from_strict_raw_handle(r#return))
+        }
+    }
+}
@@ -0,0 +1,33 @@
+use crate::{
+    r#macro::FnOnce,
+    shared::windef::WindowHandle,
+    utils::{AsStrictRawHandle, Call},
+};
+use typed_builder::TypedBuilder;
+use winapi::shared::minwindef::{LPARAM, LRESULT, WPARAM};
+
+/// Def window proc.
+#[derive(FnOnce, TypedBuilder)]
+pub struct DefWindowProc {
+    window_handle: WindowHandle,
+    message: u32,
+    w_param: WPARAM,
+    l_param: LPARAM,
+}
+
+impl Call for DefWindowProc {
+    type Output = LRESULT;
+
+    fn call(self) -> Self::Output {
+        use winapi::um::winuser::DefWindowProcW;
+
+        #[allow(non_snake_case)]
+        unsafe {
+            let hWnd = self.window_handle.as_strict_raw_handle();
+            let Msg = self.message;
+            let wParam = self.w_param;
+            let lParam = self.l_param;
+            DefWindowProcW(hWnd, Msg, wParam, lParam)
+        }
+    }
+}
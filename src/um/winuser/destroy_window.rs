@@ -0,0 +1,31 @@
+use crate::{
+    error::ensure,
+    r#macro::FnOnce,
+    shared::windef::WindowHandle,
+    utils::{AsStrictRawHandle, Call},
+    Error, Result,
+};
+use typed_builder::TypedBuilder;
+use winapi::shared::minwindef::FALSE;
+
+/// Destroy window.
+#[derive(FnOnce, TypedBuilder)]
+pub struct DestroyWindow {
+    window_handle: WindowHandle,
+}
+
+impl Call for DestroyWindow {
+    type Output = Result<()>;
+
+    fn call(self) -> Self::Output {
+        use winapi::um::winuser::DestroyWindow;
+
+        #[allow(non_snake_case)]
+        unsafe {
+            let hWnd = self.window_handle.as_strict_raw_handle();
+            let r#return = DestroyWindow(hWnd);
+            ensure!(r#return != FALSE, Error::last_os_error());
+            Ok(())
+        }
+    }
+}
@@ -0,0 +1,23 @@
+use crate::{r#macro::FnOnce, utils::Call};
+use typed_builder::TypedBuilder;
+use winapi::{shared::minwindef::LRESULT, um::winuser::MSG};
+
+/// Dispatch message.
+#[derive(FnOnce, TypedBuilder)]
+pub struct DispatchMessage<'a> {
+    message: &'a MSG,
+}
+
+impl Call for DispatchMessage<'_> {
+    type Output = LRESULT;
+
+    fn call(self) -> Self::Output {
+        use winapi::um::winuser::DispatchMessageW;
+
+        #[allow(non_snake_case)]
+        unsafe {
+            let lpMsg = self.message;
+            DispatchMessageW(lpMsg)
+        }
+    }
+}
@@ -0,0 +1,34 @@
+use crate::{
+    r#macro::FnOnce,
+    shared::windef::WindowHandle,
+    utils::{AsStrictRawHandle, Call},
+};
+use typed_builder::TypedBuilder;
+use winapi::{shared::minwindef::LPARAM, um::winuser::WNDENUMPROC};
+
+/// Enum child windows.
+///
+/// The return value is not used, as documented.
+#[derive(FnOnce, TypedBuilder)]
+pub struct EnumChildWindows {
+    window_handle: WindowHandle,
+    enum_function: WNDENUMPROC,
+    #[builder(default)]
+    l_param: LPARAM,
+}
+
+impl Call for EnumChildWindows {
+    type Output = ();
+
+    fn call(self) -> Self::Output {
+        use winapi::um::winuser::EnumChildWindows;
+
+        #[allow(non_snake_case)]
+        unsafe {
+            let hWndParent = self.window_handle.as_strict_raw_handle();
+            let lpEnumFunc = self.enum_function;
+            let lParam = self.l_param;
+            EnumChildWindows(hWndParent, lpEnumFunc, lParam);
+        }
+    }
+}
@@ -0,0 +1,31 @@
+use crate::{error::ensure, r#macro::FnOnce, utils::Call, Error, Result};
+use typed_builder::TypedBuilder;
+use winapi::{
+    shared::minwindef::{FALSE, LPARAM},
+    um::winuser::WNDENUMPROC,
+};
+
+/// Enum windows.
+#[derive(FnOnce, TypedBuilder)]
+pub struct EnumWindows {
+    enum_function: WNDENUMPROC,
+    #[builder(default)]
+    l_param: LPARAM,
+}
+
+impl Call for EnumWindows {
+    type Output = Result<()>;
+
+    fn call(self) -> Self::Output {
+        use winapi::um::winuser::EnumWindows;
+
+        #[allow(non_snake_case)]
+        unsafe {
+            let lpEnumFunc = self.enum_function;
+            let lParam = self.l_param;
+            let r#return = EnumWindows(lpEnumFunc, lParam);
+            ensure!(r#return != FALSE, Error::last_os_error());
+            Ok(())
+        }
+    }
+}
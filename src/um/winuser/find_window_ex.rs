@@ -0,0 +1,53 @@
+use crate::{
+    r#macro::FnOnce,
+    shared::windef::WindowHandle,
+    utils::{AsStrictRawHandle, Call, FromStrictRawHandle},
+    Result,
+};
+use std::ptr::{null, null_mut};
+use typed_builder::TypedBuilder;
+use widestring::WideCString;
+
+/// Find window ex.
+///
+/// Returns `None` when no window matches.
+#[derive(FnOnce, TypedBuilder)]
+pub struct FindWindowEx<'a> {
+    #[builder(default)]
+    parent: Option<WindowHandle>,
+    #[builder(default)]
+    child_after: Option<WindowHandle>,
+    #[builder(default)]
+    class_name: Option<&'a str>,
+    #[builder(default)]
+    window_name: Option<&'a str>,
+}
+
+impl Call for FindWindowEx<'_> {
+    type Output = Result<Option<WindowHandle>>;
+
+    fn call(self) -> Self::Output {
+        use winapi::um::winuser::FindWindowExW;
+
+        let class_name = self.class_name.map(WideCString::from_str).transpose()?;
+        let window_name = self.window_name.map(WideCString::from_str).transpose()?;
+
+        #[allow(non_snake_case)]
+        unsafe {
+            let hWndParent = self
+                .parent
+                .map_or(null_mut(), |parent| parent.as_strict_raw_handle());
+            let hWndChildAfter = self
+                .child_after
+                .map_or(null_mut(), |child_after| child_after.as_strict_raw_handle());
+            let lpszClass = class_name
+                .as_ref()
+                .map_or(null(), |class_name| class_name.as_ptr());
+            let lpszWindow = window_name
+                .as_ref()
+                .map_or(null(), |window_name| window_name.as_ptr());
+            let r#return = FindWindowExW(hWndParent, hWndChildAfter, lpszClass, lpszWindow);
+            Ok((!r#return.is_null()).then(|| WindowHandle::from_strict_raw_handle(r#return)))
+        }
+    }
+}
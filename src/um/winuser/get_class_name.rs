@@ -0,0 +1,34 @@
+use crate::{
+    error::ensure,
+    r#macro::FnOnce,
+    shared::windef::WindowHandle,
+    utils::{AsStrictRawHandle, Call},
+    Error, Result,
+};
+use typed_builder::TypedBuilder;
+
+/// Get class name.
+#[derive(FnOnce, TypedBuilder)]
+pub struct GetClassName {
+    window_handle: WindowHandle,
+    #[builder(default = [0; 256], setter(skip))]
+    buffer: [u16; 256],
+}
+
+impl Call for GetClassName {
+    type Output = Result<String>;
+
+    fn call(mut self) -> Self::Output {
+        use winapi::um::winuser::GetClassNameW;
+
+        #[allow(non_snake_case)]
+        unsafe {
+            let hWnd = self.window_handle.as_strict_raw_handle();
+            let lpClassName = self.buffer.as_mut_ptr();
+            let nMaxCount = self.buffer.len() as _;
+            let r#return = GetClassNameW(hWnd, lpClassName, nMaxCount);
+            ensure!(r#return != 0, Error::last_os_error());
+            Ok(String::from_utf16_lossy(&self.buffer[..r#return as usize]))
+        }
+    }
+}
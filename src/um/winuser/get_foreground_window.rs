@@ -1,17 +1,22 @@
-use crate::{shared::windef::WindowHandle, utils::FromStrictRawHandle};
+use crate::{
+    r#macro::FnOnce,
+    shared::windef::WindowHandle,
+    utils::{Call, FromStrictRawHandle},
+};
 
 /// Get foreground window.
+#[derive(FnOnce)]
 pub struct GetForegroundWindow;
 
-impl FnOnce<()> for GetForegroundWindow {
+impl Call for GetForegroundWindow {
     type Output = Option<WindowHandle>;
 
-    extern "rust-call" fn call_once(self, _args: ()) -> Self::Output {
+    fn call(self) -> Self::Output {
         use winapi::um::winuser::GetForegroundWindow;
 
         unsafe {
             let r#return = GetForegroundWindow();
-            (!r#return.is_null()).then_some(WindowHandle::from_strict_raw_handle(r#return))
+            (!r#return.is_null()).then(|| WindowHandle::from_strict_raw_handle(r#return))
         }
     }
 }
@@ -0,0 +1,28 @@
+use crate::{error::ensure, r#macro::FnOnce, utils::Call, Error, Result};
+use typed_builder::TypedBuilder;
+
+/// Get key name text.
+#[derive(FnOnce, TypedBuilder)]
+pub struct GetKeyNameText {
+    l_param: i32,
+    #[builder(default = [0; 64], setter(skip))]
+    buffer: [u16; 64],
+}
+
+impl Call for GetKeyNameText {
+    type Output = Result<String>;
+
+    fn call(mut self) -> Self::Output {
+        use winapi::um::winuser::GetKeyNameTextW;
+
+        #[allow(non_snake_case)]
+        unsafe {
+            let lParam = self.l_param;
+            let lpString = self.buffer.as_mut_ptr();
+            let cchSize = self.buffer.len() as _;
+            let r#return = GetKeyNameTextW(lParam, lpString, cchSize);
+            ensure!(r#return != 0, Error::last_os_error());
+            Ok(String::from_utf16_lossy(&self.buffer[..r#return as usize]))
+        }
+    }
+}
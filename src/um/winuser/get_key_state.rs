@@ -1,4 +1,4 @@
-use crate::{r#macro::FnOnce, wrap::key::Key};
+use crate::{r#macro::FnOnce, utils::Call, wrap::key::Key};
 use typed_builder::TypedBuilder;
 
 /// Get key state.
@@ -7,10 +7,10 @@ pub struct GetKeyState {
     virtual_key: Key,
 }
 
-impl FnOnce<()> for GetKeyState {
+impl Call for GetKeyState {
     type Output = (bool, bool);
 
-    extern "rust-call" fn call_once(self, _args: ()) -> Self::Output {
+    fn call(self) -> Self::Output {
         use winapi::um::winuser::GetKeyState;
 
         #[allow(non_snake_case)]
@@ -0,0 +1,30 @@
+use crate::{
+    r#macro::FnOnce,
+    shared::minwindef::KeyboardLayoutHandle,
+    utils::{Call, FromStrictRawHandle},
+};
+use typed_builder::TypedBuilder;
+
+/// Get keyboard layout.
+///
+/// The thread id defaults to the current thread.
+#[derive(FnOnce, TypedBuilder)]
+pub struct GetKeyboardLayout {
+    #[builder(default)]
+    thread_id: u32,
+}
+
+impl Call for GetKeyboardLayout {
+    type Output = Option<KeyboardLayoutHandle>;
+
+    fn call(self) -> Self::Output {
+        use winapi::um::winuser::GetKeyboardLayout;
+
+        #[allow(non_snake_case)]
+        unsafe {
+            let idThread = self.thread_id;
+            let r#return = GetKeyboardLayout(idThread);
+            (!r#return.is_null()).then(|| KeyboardLayoutHandle::from_strict_raw_handle(r#return))
+        }
+    }
+}
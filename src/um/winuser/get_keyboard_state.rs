@@ -1,6 +1,4 @@
-use crate::r#macro::FnOnce;
-use anyhow::{ensure, Result};
-use std::io;
+use crate::{error::ensure, r#macro::FnOnce, utils::Call, Error, Result};
 use typed_builder::TypedBuilder;
 use winapi::shared::minwindef::FALSE;
 
@@ -11,17 +9,17 @@ pub struct GetKeyboardState {
     key_states: [u8; 256],
 }
 
-impl FnOnce<()> for GetKeyboardState {
+impl Call for GetKeyboardState {
     type Output = Result<[u8; 256]>;
 
-    extern "rust-call" fn call_once(mut self, _args: ()) -> Self::Output {
+    fn call(mut self) -> Self::Output {
         use winapi::um::winuser::GetKeyboardState;
 
         #[allow(non_snake_case)]
         unsafe {
             let lpKeyState = self.key_states.as_mut_ptr();
             let r#return = GetKeyboardState(lpKeyState);
-            ensure!(r#return != FALSE, io::Error::last_os_error());
+            ensure!(r#return != FALSE, Error::last_os_error());
             Ok(self.key_states)
         }
     }
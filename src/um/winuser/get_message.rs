@@ -0,0 +1,45 @@
+use crate::{
+    error::ensure,
+    r#macro::FnOnce,
+    shared::windef::WindowHandle,
+    utils::{AsStrictRawHandle, Call},
+    Error, Result,
+};
+use std::{mem::zeroed, ptr::null_mut};
+use typed_builder::TypedBuilder;
+use winapi::um::winuser::MSG;
+
+/// Get message.
+///
+/// Returns `None` on `WM_QUIT`.
+#[derive(FnOnce, TypedBuilder)]
+pub struct GetMessage {
+    #[builder(default, setter(strip_option))]
+    window_handle: Option<WindowHandle>,
+    #[builder(default)]
+    message_filter_min: u32,
+    #[builder(default)]
+    message_filter_max: u32,
+}
+
+impl Call for GetMessage {
+    type Output = Result<Option<MSG>>;
+
+    fn call(self) -> Self::Output {
+        use winapi::um::winuser::GetMessageW;
+
+        #[allow(non_snake_case)]
+        unsafe {
+            let mut message = zeroed();
+            let lpMsg = &mut message;
+            let hWnd = self.window_handle.map_or(null_mut(), |window_handle| {
+                window_handle.as_strict_raw_handle()
+            });
+            let wMsgFilterMin = self.message_filter_min;
+            let wMsgFilterMax = self.message_filter_max;
+            let r#return = GetMessageW(lpMsg, hWnd, wMsgFilterMin, wMsgFilterMax);
+            ensure!(r#return != -1, Error::last_os_error());
+            Ok((r#return != 0).then(|| message))
+        }
+    }
+}
@@ -0,0 +1,29 @@
+use crate::{
+    r#macro::FnOnce,
+    shared::windef::WindowHandle,
+    utils::{AsStrictRawHandle, Call, FromStrictRawHandle},
+};
+use typed_builder::TypedBuilder;
+
+/// Get parent.
+///
+/// Returns the parent of a child window, or the owner of a top level one.
+#[derive(FnOnce, TypedBuilder)]
+pub struct GetParent {
+    window_handle: WindowHandle,
+}
+
+impl Call for GetParent {
+    type Output = Option<WindowHandle>;
+
+    fn call(self) -> Self::Output {
+        use winapi::um::winuser::GetParent;
+
+        #[allow(non_snake_case)]
+        unsafe {
+            let hWnd = self.window_handle.as_strict_raw_handle();
+            let r#return = GetParent(hWnd);
+            (!r#return.is_null()).then(|| WindowHandle::from_strict_raw_handle(r#return))
+        }
+    }
+}
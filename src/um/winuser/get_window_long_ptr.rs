@@ -0,0 +1,28 @@
+use crate::{
+    r#macro::FnOnce,
+    shared::windef::WindowHandle,
+    utils::{AsStrictRawHandle, Call},
+};
+use typed_builder::TypedBuilder;
+
+/// Get window long ptr.
+#[derive(FnOnce, TypedBuilder)]
+pub struct GetWindowLongPtr {
+    window_handle: WindowHandle,
+    index: i32,
+}
+
+impl Call for GetWindowLongPtr {
+    type Output = isize;
+
+    fn call(self) -> Self::Output {
+        use winapi::um::winuser::GetWindowLongPtrW;
+
+        #[allow(non_snake_case)]
+        unsafe {
+            let hWnd = self.window_handle.as_strict_raw_handle();
+            let nIndex = self.index;
+            GetWindowLongPtrW(hWnd, nIndex) as _
+        }
+    }
+}
@@ -0,0 +1,34 @@
+use crate::{
+    error::ensure,
+    r#macro::FnOnce,
+    shared::windef::{Rect, WindowHandle},
+    utils::{AsStrictRawHandle, Call},
+    Error, Result,
+};
+use typed_builder::TypedBuilder;
+use winapi::shared::minwindef::FALSE;
+
+/// Get window rect.
+#[derive(FnOnce, TypedBuilder)]
+pub struct GetWindowRect {
+    window_handle: WindowHandle,
+    #[builder(default, setter(skip))]
+    rect: Rect,
+}
+
+impl Call for GetWindowRect {
+    type Output = Result<Rect>;
+
+    fn call(mut self) -> Self::Output {
+        use winapi::um::winuser::GetWindowRect;
+
+        #[allow(non_snake_case)]
+        unsafe {
+            let hWnd = self.window_handle.as_strict_raw_handle();
+            let lpRect = &mut *self.rect;
+            let r#return = GetWindowRect(hWnd, lpRect);
+            ensure!(r#return != FALSE, Error::last_os_error());
+            Ok(self.rect)
+        }
+    }
+}
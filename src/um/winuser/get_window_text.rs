@@ -0,0 +1,48 @@
+use crate::{
+    error::ensure,
+    r#macro::FnOnce,
+    shared::windef::WindowHandle,
+    utils::{AsStrictRawHandle, Call},
+    Error, Result,
+};
+use typed_builder::TypedBuilder;
+use winapi::shared::winerror::ERROR_SUCCESS;
+
+/// Get window text.
+#[derive(FnOnce, TypedBuilder)]
+pub struct GetWindowText {
+    window_handle: WindowHandle,
+}
+
+impl Call for GetWindowText {
+    type Output = Result<String>;
+
+    fn call(self) -> Self::Output {
+        use winapi::um::{
+            errhandlingapi::SetLastError,
+            winuser::{GetWindowTextLengthW, GetWindowTextW},
+        };
+
+        #[allow(non_snake_case)]
+        unsafe {
+            let hWnd = self.window_handle.as_strict_raw_handle();
+            SetLastError(0);
+            let length = GetWindowTextLengthW(hWnd);
+            let last_os_error = Error::last_os_error();
+            ensure!(
+                length != 0 || last_os_error == Error::Win32(ERROR_SUCCESS),
+                last_os_error
+            );
+            let mut buffer = vec![0; length as usize + 1];
+            let lpString = buffer.as_mut_ptr();
+            let nMaxCount = buffer.len() as _;
+            let r#return = GetWindowTextW(hWnd, lpString, nMaxCount);
+            let last_os_error = Error::last_os_error();
+            ensure!(
+                r#return != 0 || last_os_error == Error::Win32(ERROR_SUCCESS),
+                last_os_error
+            );
+            Ok(String::from_utf16_lossy(&buffer[..r#return as usize]))
+        }
+    }
+}
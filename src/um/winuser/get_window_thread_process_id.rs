@@ -0,0 +1,34 @@
+use crate::{
+    error::ensure,
+    r#macro::FnOnce,
+    shared::windef::WindowHandle,
+    utils::{AsStrictRawHandle, Call},
+    Error, Result,
+};
+use typed_builder::TypedBuilder;
+
+/// Get window thread process id.
+///
+/// Returns the thread id and the process id.
+#[derive(FnOnce, TypedBuilder)]
+pub struct GetWindowThreadProcessId {
+    window_handle: WindowHandle,
+}
+
+impl Call for GetWindowThreadProcessId {
+    type Output = Result<(u32, u32)>;
+
+    fn call(self) -> Self::Output {
+        use winapi::um::winuser::GetWindowThreadProcessId;
+
+        #[allow(non_snake_case)]
+        unsafe {
+            let hWnd = self.window_handle.as_strict_raw_handle();
+            let mut process_id = 0;
+            let lpdwProcessId = &mut process_id;
+            let r#return = GetWindowThreadProcessId(hWnd, lpdwProcessId);
+            ensure!(r#return != 0, Error::last_os_error());
+            Ok((r#return, process_id))
+        }
+    }
+}
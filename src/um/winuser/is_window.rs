@@ -0,0 +1,27 @@
+use crate::{
+    r#macro::FnOnce,
+    shared::windef::WindowHandle,
+    utils::{AsStrictRawHandle, Call},
+};
+use typed_builder::TypedBuilder;
+use winapi::shared::minwindef::FALSE;
+
+/// Is window.
+#[derive(FnOnce, TypedBuilder)]
+pub struct IsWindow {
+    window_handle: WindowHandle,
+}
+
+impl Call for IsWindow {
+    type Output = bool;
+
+    fn call(self) -> Self::Output {
+        use winapi::um::winuser::IsWindow;
+
+        #[allow(non_snake_case)]
+        unsafe {
+            let hWnd = self.window_handle.as_strict_raw_handle();
+            IsWindow(hWnd) != FALSE
+        }
+    }
+}
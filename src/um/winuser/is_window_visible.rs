@@ -0,0 +1,27 @@
+use crate::{
+    r#macro::FnOnce,
+    shared::windef::WindowHandle,
+    utils::{AsStrictRawHandle, Call},
+};
+use typed_builder::TypedBuilder;
+use winapi::shared::minwindef::FALSE;
+
+/// Is window visible.
+#[derive(FnOnce, TypedBuilder)]
+pub struct IsWindowVisible {
+    window_handle: WindowHandle,
+}
+
+impl Call for IsWindowVisible {
+    type Output = bool;
+
+    fn call(self) -> Self::Output {
+        use winapi::um::winuser::IsWindowVisible;
+
+        #[allow(non_snake_case)]
+        unsafe {
+            let hWnd = self.window_handle.as_strict_raw_handle();
+            IsWindowVisible(hWnd) != FALSE
+        }
+    }
+}
@@ -0,0 +1,33 @@
+use crate::{
+    r#macro::FnOnce,
+    shared::minwindef::KeyboardLayoutHandle,
+    utils::{AsStrictRawHandle, Call},
+};
+use typed_builder::TypedBuilder;
+
+/// Map virtual key ex.
+///
+/// Returns `None` when there is no translation.
+#[derive(FnOnce, TypedBuilder)]
+pub struct MapVirtualKeyEx {
+    code: u32,
+    map_type: u32,
+    keyboard_layout: KeyboardLayoutHandle,
+}
+
+impl Call for MapVirtualKeyEx {
+    type Output = Option<u32>;
+
+    fn call(self) -> Self::Output {
+        use winapi::um::winuser::MapVirtualKeyExW;
+
+        #[allow(non_snake_case)]
+        unsafe {
+            let uCode = self.code;
+            let uMapType = self.map_type;
+            let dwhkl = self.keyboard_layout.as_strict_raw_handle();
+            let r#return = MapVirtualKeyExW(uCode, uMapType, dwhkl);
+            (r#return != 0).then(|| r#return)
+        }
+    }
+}
@@ -1,34 +1,408 @@
 pub(crate) use self::{
+    call_next_hook_ex::{CallNextHookEx, CallNextHookExBuilder},
+    create_window_ex::{CreateWindowEx, CreateWindowExBuilder},
+    def_window_proc::{DefWindowProc, DefWindowProcBuilder},
+    destroy_window::{DestroyWindow, DestroyWindowBuilder},
+    dispatch_message::{DispatchMessage, DispatchMessageBuilder},
+    enum_child_windows::{EnumChildWindows, EnumChildWindowsBuilder},
+    enum_windows::{EnumWindows, EnumWindowsBuilder},
+    find_window_ex::{FindWindowEx, FindWindowExBuilder},
     get_async_key_state::{GetAsyncKeyState, GetAsyncKeyStateBuilder},
+    get_class_name::{GetClassName, GetClassNameBuilder},
+    get_client_rect::{GetClientRect, GetClientRectBuilder},
     get_foreground_window::GetForegroundWindow,
+    get_key_name_text::{GetKeyNameText, GetKeyNameTextBuilder},
     get_key_state::{GetKeyState, GetKeyStateBuilder},
+    get_keyboard_layout::{GetKeyboardLayout, GetKeyboardLayoutBuilder},
     get_keyboard_state::{GetKeyboardState, GetKeyboardStateBuilder},
+    get_message::{GetMessage, GetMessageBuilder},
+    get_parent::{GetParent, GetParentBuilder},
+    get_window_long_ptr::{GetWindowLongPtr, GetWindowLongPtrBuilder},
+    get_window_rect::{GetWindowRect, GetWindowRectBuilder},
+    get_window_text::{GetWindowText, GetWindowTextBuilder},
+    get_window_thread_process_id::{GetWindowThreadProcessId, GetWindowThreadProcessIdBuilder},
+    is_window::{IsWindow, IsWindowBuilder},
+    is_window_visible::{IsWindowVisible, IsWindowVisibleBuilder},
+    map_virtual_key_ex::{MapVirtualKeyEx, MapVirtualKeyExBuilder},
+    peek_message::{PeekMessage, PeekMessageBuilder},
+    post_message::{PostMessage, PostMessageBuilder},
+    post_quit_message::{PostQuitMessage, PostQuitMessageBuilder},
+    post_thread_message::{PostThreadMessage, PostThreadMessageBuilder},
+    register_class_ex::{RegisterClassEx, RegisterClassExBuilder},
+    send_input::{SendInput, SendInputBuilder},
     set_foreground_window::{SetForegroundWindow, SetForegroundWindowBuilder},
+    set_window_long_ptr::{SetWindowLongPtr, SetWindowLongPtrBuilder},
+    set_windows_hook_ex::{SetWindowsHookEx, SetWindowsHookExBuilder},
+    show_window::{ShowWindow, ShowWindowBuilder},
+    to_unicode_ex::{ToUnicodeEx, ToUnicodeExBuilder},
+    translate_message::{TranslateMessage, TranslateMessageBuilder},
+    unhook_windows_hook_ex::{UnhookWindowsHookEx, UnhookWindowsHookExBuilder},
+    unregister_class::{UnregisterClass, UnregisterClassBuilder},
 };
-use crate::shared::windef::WindowHandle;
+use crate::{shared::windef::WindowHandle, utils::Call, Error, Result};
+use bitflags::bitflags;
+use std::convert::TryFrom;
+use winapi::{
+    ctypes::c_int,
+    um::winuser::{
+        CS_BYTEALIGNCLIENT, CS_BYTEALIGNWINDOW, CS_CLASSDC, CS_DBLCLKS, CS_DROPSHADOW,
+        CS_GLOBALCLASS, CS_HREDRAW, CS_NOCLOSE, CS_OWNDC, CS_PARENTDC, CS_SAVEBITS, CS_VREDRAW,
+        SW_FORCEMINIMIZE, SW_HIDE, SW_MAXIMIZE, SW_MINIMIZE, SW_RESTORE, SW_SHOW, SW_SHOWDEFAULT,
+        SW_SHOWMINIMIZED, SW_SHOWMINNOACTIVE, SW_SHOWNA, SW_SHOWNOACTIVATE, SW_SHOWNORMAL,
+        WS_BORDER, WS_CAPTION, WS_CHILD, WS_CLIPCHILDREN, WS_CLIPSIBLINGS, WS_DISABLED,
+        WS_DLGFRAME, WS_EX_ACCEPTFILES, WS_EX_APPWINDOW, WS_EX_CLIENTEDGE, WS_EX_COMPOSITED,
+        WS_EX_CONTEXTHELP, WS_EX_CONTROLPARENT, WS_EX_DLGMODALFRAME, WS_EX_LAYERED,
+        WS_EX_LAYOUTRTL, WS_EX_LEFTSCROLLBAR, WS_EX_MDICHILD, WS_EX_NOACTIVATE,
+        WS_EX_NOINHERITLAYOUT, WS_EX_NOPARENTNOTIFY, WS_EX_NOREDIRECTIONBITMAP,
+        WS_EX_OVERLAPPEDWINDOW, WS_EX_PALETTEWINDOW, WS_EX_RIGHT, WS_EX_RTLREADING,
+        WS_EX_STATICEDGE, WS_EX_TOOLWINDOW, WS_EX_TOPMOST, WS_EX_TRANSPARENT, WS_EX_WINDOWEDGE,
+        WS_GROUP, WS_HSCROLL, WS_MAXIMIZE, WS_MAXIMIZEBOX, WS_MINIMIZE, WS_MINIMIZEBOX,
+        WS_OVERLAPPED, WS_OVERLAPPEDWINDOW, WS_POPUP, WS_POPUPWINDOW, WS_SYSMENU, WS_TABSTOP,
+        WS_THICKFRAME, WS_VISIBLE, WS_VSCROLL,
+    },
+};
+
+pub fn call_next_hook_ex() -> CallNextHookExBuilder<((), (), ())> {
+    CallNextHookEx::builder()
+}
+
+/// The unset fields of `CreateWindowExBuilder`.
+type CreateWindowExFields = ((), (), (), (), (), (), (), (), (), (), ());
+
+pub fn create_window_ex<'a>() -> CreateWindowExBuilder<'a, CreateWindowExFields> {
+    CreateWindowEx::builder()
+}
+
+pub fn def_window_proc() -> DefWindowProcBuilder<((), (), (), ())> {
+    DefWindowProc::builder()
+}
+
+pub fn destroy_window() -> DestroyWindowBuilder<((),)> {
+    DestroyWindow::builder()
+}
+
+pub fn dispatch_message<'a>() -> DispatchMessageBuilder<'a, ((),)> {
+    DispatchMessage::builder()
+}
+
+pub fn enum_child_windows() -> EnumChildWindowsBuilder<((), (), ())> {
+    EnumChildWindows::builder()
+}
+
+pub fn enum_windows() -> EnumWindowsBuilder<((), ())> {
+    EnumWindows::builder()
+}
+
+pub fn find_window_ex<'a>() -> FindWindowExBuilder<'a, ((), (), (), ())> {
+    FindWindowEx::builder()
+}
 
 pub fn get_async_key_state() -> GetAsyncKeyStateBuilder<((),)> {
     GetAsyncKeyState::builder()
 }
 
+pub fn get_class_name() -> GetClassNameBuilder<((),)> {
+    GetClassName::builder()
+}
+
+pub fn get_client_rect() -> GetClientRectBuilder<((),)> {
+    GetClientRect::builder()
+}
+
 pub fn get_foreground_window() -> Option<WindowHandle> {
-    GetForegroundWindow()
+    GetForegroundWindow.call()
+}
+
+pub fn get_key_name_text() -> GetKeyNameTextBuilder<((),)> {
+    GetKeyNameText::builder()
 }
 
 pub fn get_key_state() -> GetKeyStateBuilder<((),)> {
     GetKeyState::builder()
 }
 
+pub fn get_keyboard_layout() -> GetKeyboardLayoutBuilder<((),)> {
+    GetKeyboardLayout::builder()
+}
+
 pub fn get_keyboard_state() -> GetKeyboardStateBuilder<()> {
     GetKeyboardState::builder()
 }
 
+pub fn get_message() -> GetMessageBuilder<((), (), ())> {
+    GetMessage::builder()
+}
+
+pub fn get_parent() -> GetParentBuilder<((),)> {
+    GetParent::builder()
+}
+
+pub fn get_window_long_ptr() -> GetWindowLongPtrBuilder<((), ())> {
+    GetWindowLongPtr::builder()
+}
+
+pub fn get_window_rect() -> GetWindowRectBuilder<((),)> {
+    GetWindowRect::builder()
+}
+
+pub fn get_window_text() -> GetWindowTextBuilder<((),)> {
+    GetWindowText::builder()
+}
+
+pub fn get_window_thread_process_id() -> GetWindowThreadProcessIdBuilder<((),)> {
+    GetWindowThreadProcessId::builder()
+}
+
+pub fn is_window() -> IsWindowBuilder<((),)> {
+    IsWindow::builder()
+}
+
+pub fn is_window_visible() -> IsWindowVisibleBuilder<((),)> {
+    IsWindowVisible::builder()
+}
+
+pub fn map_virtual_key_ex() -> MapVirtualKeyExBuilder<((), (), ())> {
+    MapVirtualKeyEx::builder()
+}
+
+pub fn peek_message() -> PeekMessageBuilder<((), (), (), ())> {
+    PeekMessage::builder()
+}
+
+pub fn post_message() -> PostMessageBuilder<((), (), (), ())> {
+    PostMessage::builder()
+}
+
+pub fn post_quit_message() -> PostQuitMessageBuilder<((),)> {
+    PostQuitMessage::builder()
+}
+
+pub fn post_thread_message() -> PostThreadMessageBuilder<((), (), (), ())> {
+    PostThreadMessage::builder()
+}
+
+pub fn register_class_ex<'a>() -> RegisterClassExBuilder<'a, ((), (), (), ())> {
+    RegisterClassEx::builder()
+}
+
+pub fn send_input<'a>() -> SendInputBuilder<'a, ((),)> {
+    SendInput::builder()
+}
+
 pub fn set_foreground_window() -> SetForegroundWindowBuilder<((),)> {
     SetForegroundWindow::builder()
 }
 
+pub fn set_window_long_ptr() -> SetWindowLongPtrBuilder<((), (), ())> {
+    SetWindowLongPtr::builder()
+}
+
+pub fn set_windows_hook_ex() -> SetWindowsHookExBuilder<((), (), (), ())> {
+    SetWindowsHookEx::builder()
+}
+
+pub fn show_window() -> ShowWindowBuilder<((), ())> {
+    ShowWindow::builder()
+}
+
+pub fn to_unicode_ex<'a>() -> ToUnicodeExBuilder<'a, ((), (), (), (), ())> {
+    ToUnicodeEx::builder()
+}
+
+pub fn translate_message<'a>() -> TranslateMessageBuilder<'a, ((),)> {
+    TranslateMessage::builder()
+}
+
+pub fn unhook_windows_hook_ex() -> UnhookWindowsHookExBuilder<((),)> {
+    UnhookWindowsHookEx::builder()
+}
+
+pub fn unregister_class<'a>() -> UnregisterClassBuilder<'a, ((), ())> {
+    UnregisterClass::builder()
+}
+
+mod call_next_hook_ex;
+mod create_window_ex;
+mod def_window_proc;
+mod destroy_window;
+mod dispatch_message;
+mod enum_child_windows;
+mod enum_windows;
+mod find_window_ex;
 mod get_async_key_state;
+mod get_class_name;
+mod get_client_rect;
 mod get_foreground_window;
+mod get_key_name_text;
 mod get_key_state;
+mod get_keyboard_layout;
 mod get_keyboard_state;
+mod get_message;
+mod get_parent;
+mod get_window_long_ptr;
+mod get_window_rect;
+mod get_window_text;
+mod get_window_thread_process_id;
+mod is_window;
+mod is_window_visible;
+mod map_virtual_key_ex;
+mod peek_message;
+mod post_message;
+mod post_quit_message;
+mod post_thread_message;
+mod register_class_ex;
+mod send_input;
 mod set_foreground_window;
+mod set_window_long_ptr;
+mod set_windows_hook_ex;
+mod show_window;
+mod to_unicode_ex;
+mod translate_message;
+mod unhook_windows_hook_ex;
+mod unregister_class;
+
+bitflags! {
+    /// Class style.
+    pub struct ClassStyle: u32 {
+        const VREDRAW = CS_VREDRAW;
+        const HREDRAW = CS_HREDRAW;
+        const DBLCLKS = CS_DBLCLKS;
+        const OWNDC = CS_OWNDC;
+        const CLASSDC = CS_CLASSDC;
+        const PARENTDC = CS_PARENTDC;
+        const NOCLOSE = CS_NOCLOSE;
+        const SAVEBITS = CS_SAVEBITS;
+        const BYTEALIGNCLIENT = CS_BYTEALIGNCLIENT;
+        const BYTEALIGNWINDOW = CS_BYTEALIGNWINDOW;
+        const GLOBALCLASS = CS_GLOBALCLASS;
+        const DROPSHADOW = CS_DROPSHADOW;
+    }
+}
+
+bitflags! {
+    /// Window style.
+    pub struct WindowStyle: u32 {
+        const OVERLAPPED = WS_OVERLAPPED;
+        const POPUP = WS_POPUP;
+        const CHILD = WS_CHILD;
+        const MINIMIZE = WS_MINIMIZE;
+        const VISIBLE = WS_VISIBLE;
+        const DISABLED = WS_DISABLED;
+        const CLIPSIBLINGS = WS_CLIPSIBLINGS;
+        const CLIPCHILDREN = WS_CLIPCHILDREN;
+        const MAXIMIZE = WS_MAXIMIZE;
+        const CAPTION = WS_CAPTION;
+        const BORDER = WS_BORDER;
+        const DLGFRAME = WS_DLGFRAME;
+        const VSCROLL = WS_VSCROLL;
+        const HSCROLL = WS_HSCROLL;
+        const SYSMENU = WS_SYSMENU;
+        const THICKFRAME = WS_THICKFRAME;
+        const GROUP = WS_GROUP;
+        const TABSTOP = WS_TABSTOP;
+        const MINIMIZEBOX = WS_MINIMIZEBOX;
+        const MAXIMIZEBOX = WS_MAXIMIZEBOX;
+        const OVERLAPPEDWINDOW = WS_OVERLAPPEDWINDOW;
+        const POPUPWINDOW = WS_POPUPWINDOW;
+    }
+}
+
+bitflags! {
+    /// Window extended style.
+    pub struct WindowExStyle: u32 {
+        const DLGMODALFRAME = WS_EX_DLGMODALFRAME;
+        const NOPARENTNOTIFY = WS_EX_NOPARENTNOTIFY;
+        const TOPMOST = WS_EX_TOPMOST;
+        const ACCEPTFILES = WS_EX_ACCEPTFILES;
+        const TRANSPARENT = WS_EX_TRANSPARENT;
+        const MDICHILD = WS_EX_MDICHILD;
+        const TOOLWINDOW = WS_EX_TOOLWINDOW;
+        const WINDOWEDGE = WS_EX_WINDOWEDGE;
+        const CLIENTEDGE = WS_EX_CLIENTEDGE;
+        const CONTEXTHELP = WS_EX_CONTEXTHELP;
+        const RIGHT = WS_EX_RIGHT;
+        const RTLREADING = WS_EX_RTLREADING;
+        const LEFTSCROLLBAR = WS_EX_LEFTSCROLLBAR;
+        const CONTROLPARENT = WS_EX_CONTROLPARENT;
+        const STATICEDGE = WS_EX_STATICEDGE;
+        const APPWINDOW = WS_EX_APPWINDOW;
+        const OVERLAPPEDWINDOW = WS_EX_OVERLAPPEDWINDOW;
+        const PALETTEWINDOW = WS_EX_PALETTEWINDOW;
+        const LAYERED = WS_EX_LAYERED;
+        const NOINHERITLAYOUT = WS_EX_NOINHERITLAYOUT;
+        const NOREDIRECTIONBITMAP = WS_EX_NOREDIRECTIONBITMAP;
+        const LAYOUTRTL = WS_EX_LAYOUTRTL;
+        const COMPOSITED = WS_EX_COMPOSITED;
+        const NOACTIVATE = WS_EX_NOACTIVATE;
+    }
+}
+
+/// Show command.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum ShowCommand {
+    Hide,
+    /// Activates and shows the window, restoring it if minimized or maximized.
+    Normal,
+    /// Activates the window and minimizes it.
+    Minimized,
+    /// Activates the window and maximizes it.
+    Maximize,
+    /// Shows the window in its most recent size and position, without activating
+    /// it.
+    NormalNoActivate,
+    /// Activates the window and shows it in its current size and position.
+    Show,
+    /// Minimizes the window and activates the next top level window.
+    Minimize,
+    /// Minimizes the window without activating it.
+    MinimizedNoActivate,
+    /// Shows the window in its current size and position, without activating it.
+    ShowNoActivate,
+    /// Activates and shows the window, restoring it if minimized or maximized.
+    Restore,
+    /// Shows the window as the process was started with.
+    Default,
+    /// Minimizes the window, even if its thread is not responding.
+    ForceMinimize,
+}
+
+impl From<ShowCommand> for c_int {
+    fn from(from: ShowCommand) -> Self {
+        match from {
+            ShowCommand::Hide => SW_HIDE,
+            ShowCommand::Normal => SW_SHOWNORMAL,
+            ShowCommand::Minimized => SW_SHOWMINIMIZED,
+            ShowCommand::Maximize => SW_MAXIMIZE,
+            ShowCommand::NormalNoActivate => SW_SHOWNOACTIVATE,
+            ShowCommand::Show => SW_SHOW,
+            ShowCommand::Minimize => SW_MINIMIZE,
+            ShowCommand::MinimizedNoActivate => SW_SHOWMINNOACTIVE,
+            ShowCommand::ShowNoActivate => SW_SHOWNA,
+            ShowCommand::Restore => SW_RESTORE,
+            ShowCommand::Default => SW_SHOWDEFAULT,
+            ShowCommand::ForceMinimize => SW_FORCEMINIMIZE,
+        }
+    }
+}
+
+impl TryFrom<c_int> for ShowCommand {
+    type Error = Error;
+
+    fn try_from(from: c_int) -> Result<Self> {
+        Ok(match from {
+            SW_HIDE => ShowCommand::Hide,
+            SW_SHOWNORMAL => ShowCommand::Normal,
+            SW_SHOWMINIMIZED => ShowCommand::Minimized,
+            SW_MAXIMIZE => ShowCommand::Maximize,
+            SW_SHOWNOACTIVATE => ShowCommand::NormalNoActivate,
+            SW_SHOW => ShowCommand::Show,
+            SW_MINIMIZE => ShowCommand::Minimize,
+            SW_SHOWMINNOACTIVE => ShowCommand::MinimizedNoActivate,
+            SW_SHOWNA => ShowCommand::ShowNoActivate,
+            SW_RESTORE => ShowCommand::Restore,
+            SW_SHOWDEFAULT => ShowCommand::Default,
+            SW_FORCEMINIMIZE => ShowCommand::ForceMinimize,
+            _ => return Err(Error::unexpected_value("show command", from)),
+        })
+    }
+}
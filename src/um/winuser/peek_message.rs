@@ -0,0 +1,46 @@
+use crate::{
+    r#macro::FnOnce,
+    shared::windef::WindowHandle,
+    utils::{AsStrictRawHandle, Call},
+};
+use std::{mem::zeroed, ptr::null_mut};
+use typed_builder::TypedBuilder;
+use winapi::{
+    shared::minwindef::FALSE,
+    um::winuser::{MSG, PM_NOREMOVE},
+};
+
+/// Peek message.
+#[derive(FnOnce, TypedBuilder)]
+pub struct PeekMessage {
+    #[builder(default, setter(strip_option))]
+    window_handle: Option<WindowHandle>,
+    #[builder(default)]
+    message_filter_min: u32,
+    #[builder(default)]
+    message_filter_max: u32,
+    #[builder(default = PM_NOREMOVE)]
+    remove_message: u32,
+}
+
+impl Call for PeekMessage {
+    type Output = Option<MSG>;
+
+    fn call(self) -> Self::Output {
+        use winapi::um::winuser::PeekMessageW;
+
+        #[allow(non_snake_case)]
+        unsafe {
+            let mut message = zeroed();
+            let lpMsg = &mut message;
+            let hWnd = self.window_handle.map_or(null_mut(), |window_handle| {
+                window_handle.as_strict_raw_handle()
+            });
+            let wMsgFilterMin = self.message_filter_min;
+            let wMsgFilterMax = self.message_filter_max;
+            let wRemoveMsg = self.remove_message;
+            let r#return = PeekMessageW(lpMsg, hWnd, wMsgFilterMin, wMsgFilterMax, wRemoveMsg);
+            (r#return != FALSE).then(|| message)
+        }
+    }
+}
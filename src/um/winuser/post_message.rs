@@ -0,0 +1,39 @@
+use crate::{
+    error::ensure,
+    r#macro::FnOnce,
+    shared::windef::WindowHandle,
+    utils::{AsStrictRawHandle, Call},
+    Error, Result,
+};
+use typed_builder::TypedBuilder;
+use winapi::shared::minwindef::{FALSE, LPARAM, WPARAM};
+
+/// Post message.
+#[derive(FnOnce, TypedBuilder)]
+pub struct PostMessage {
+    window_handle: WindowHandle,
+    message: u32,
+    #[builder(default)]
+    w_param: WPARAM,
+    #[builder(default)]
+    l_param: LPARAM,
+}
+
+impl Call for PostMessage {
+    type Output = Result<()>;
+
+    fn call(self) -> Self::Output {
+        use winapi::um::winuser::PostMessageW;
+
+        #[allow(non_snake_case)]
+        unsafe {
+            let hWnd = self.window_handle.as_strict_raw_handle();
+            let Msg = self.message;
+            let wParam = self.w_param;
+            let lParam = self.l_param;
+            let r#return = PostMessageW(hWnd, Msg, wParam, lParam);
+            ensure!(r#return != FALSE, Error::last_os_error());
+            Ok(())
+        }
+    }
+}
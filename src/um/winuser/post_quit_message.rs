@@ -0,0 +1,23 @@
+use crate::{r#macro::FnOnce, utils::Call};
+use typed_builder::TypedBuilder;
+
+/// Post quit message.
+#[derive(FnOnce, TypedBuilder)]
+pub struct PostQuitMessage {
+    #[builder(default)]
+    exit_code: i32,
+}
+
+impl Call for PostQuitMessage {
+    type Output = ();
+
+    fn call(self) -> Self::Output {
+        use winapi::um::winuser::PostQuitMessage;
+
+        #[allow(non_snake_case)]
+        unsafe {
+            let nExitCode = self.exit_code;
+            PostQuitMessage(nExitCode)
+        }
+    }
+}
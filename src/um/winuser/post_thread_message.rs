@@ -0,0 +1,33 @@
+use crate::{error::ensure, r#macro::FnOnce, utils::Call, Error, Result};
+use typed_builder::TypedBuilder;
+use winapi::shared::minwindef::{FALSE, LPARAM, WPARAM};
+
+/// Post thread message.
+#[derive(FnOnce, TypedBuilder)]
+pub struct PostThreadMessage {
+    thread_id: u32,
+    message: u32,
+    #[builder(default)]
+    w_param: WPARAM,
+    #[builder(default)]
+    l_param: LPARAM,
+}
+
+impl Call for PostThreadMessage {
+    type Output = Result<()>;
+
+    fn call(self) -> Self::Output {
+        use winapi::um::winuser::PostThreadMessageW;
+
+        #[allow(non_snake_case)]
+        unsafe {
+            let idThread = self.thread_id;
+            let Msg = self.message;
+            let wParam = self.w_param;
+            let lParam = self.l_param;
+            let r#return = PostThreadMessageW(idThread, Msg, wParam, lParam);
+            ensure!(r#return != FALSE, Error::last_os_error());
+            Ok(())
+        }
+    }
+}
@@ -0,0 +1,60 @@
+use super::ClassStyle;
+use crate::{
+    error::ensure,
+    r#macro::FnOnce,
+    shared::minwindef::ModuleHandle,
+    utils::{AsStrictRawHandle, Call},
+    Error, Result,
+};
+use std::{mem::size_of, ptr::null_mut};
+use typed_builder::TypedBuilder;
+use widestring::WideCString;
+use winapi::um::winuser::{IDC_ARROW, WNDCLASSEXW, WNDPROC};
+
+/// Register class ex.
+///
+/// Registers the class with the arrow cursor and no background brush, and
+/// returns its atom.
+#[derive(FnOnce, TypedBuilder)]
+pub struct RegisterClassEx<'a> {
+    #[builder(default = ClassStyle::empty())]
+    style: ClassStyle,
+    window_procedure: WNDPROC,
+    #[builder(default)]
+    instance: Option<ModuleHandle>,
+    class_name: &'a str,
+}
+
+impl Call for RegisterClassEx<'_> {
+    type Output = Result<u16>;
+
+    fn call(self) -> Self::Output {
+        use winapi::um::winuser::{LoadCursorW, RegisterClassExW};
+
+        let class_name = WideCString::from_str(self.class_name)?;
+
+        #[allow(non_snake_case)]
+        unsafe {
+            let window_class = WNDCLASSEXW {
+                cbSize: size_of::<WNDCLASSEXW>() as _,
+                style: self.style.bits(),
+                lpfnWndProc: self.window_procedure,
+                cbClsExtra: 0,
+                cbWndExtra: 0,
+                hInstance: self
+                    .instance
+                    .map_or(null_mut(), |instance| instance.as_strict_raw_handle()),
+                hIcon: null_mut(),
+                hCursor: LoadCursorW(null_mut(), IDC_ARROW),
+                hbrBackground: null_mut(),
+                lpszMenuName: null_mut(),
+                lpszClassName: class_name.as_ptr(),
+                hIconSm: null_mut(),
+            };
+            let lpwcx = &window_class;
+            let r#return = RegisterClassExW(lpwcx);
+            ensure!(r#return != 0, Error::last_os_error());
+            Ok(r#return)
+        }
+    }
+}
@@ -0,0 +1,31 @@
+use crate::{error::ensure, r#macro::FnOnce, utils::Call, Error, Result};
+use std::mem::size_of;
+use typed_builder::TypedBuilder;
+use winapi::um::winuser::INPUT;
+
+/// Send input.
+///
+/// Fails when fewer events than given are inserted, e.g. when blocked by
+/// User Interface Privilege Isolation.
+#[derive(FnOnce, TypedBuilder)]
+pub struct SendInput<'a> {
+    inputs: &'a [INPUT],
+}
+
+impl Call for SendInput<'_> {
+    type Output = Result<u32>;
+
+    fn call(self) -> Self::Output {
+        use winapi::um::winuser::SendInput;
+
+        #[allow(non_snake_case)]
+        unsafe {
+            let cInputs = self.inputs.len() as _;
+            let pInputs = self.inputs.as_ptr() as _;
+            let cbSize = size_of::<INPUT>() as _;
+            let r#return = SendInput(cInputs, pInputs, cbSize);
+            ensure!(r#return == cInputs, Error::last_os_error());
+            Ok(r#return)
+        }
+    }
+}
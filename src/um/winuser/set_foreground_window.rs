@@ -1,6 +1,10 @@
-use crate::{r#macro::FnOnce, shared::windef::WindowHandle, utils::AsStrictRawHandle};
-use anyhow::{ensure, Result};
-use std::io;
+use crate::{
+    error::ensure,
+    r#macro::FnOnce,
+    shared::windef::WindowHandle,
+    utils::{AsStrictRawHandle, Call},
+    Error, Result,
+};
 use typed_builder::TypedBuilder;
 use winapi::shared::minwindef::FALSE;
 
@@ -10,17 +14,17 @@ pub struct SetForegroundWindow {
     window_handle: WindowHandle,
 }
 
-impl FnOnce<()> for SetForegroundWindow {
+impl Call for SetForegroundWindow {
     type Output = Result<bool>;
 
-    extern "rust-call" fn call_once(self, _args: ()) -> Self::Output {
+    fn call(self) -> Self::Output {
         use winapi::um::winuser::SetForegroundWindow;
 
         #[allow(non_snake_case)]
         unsafe {
             let hWnd = self.window_handle.as_strict_raw_handle();
             let r#return = SetForegroundWindow(hWnd);
-            ensure!(r#return != FALSE, io::Error::last_os_error());
+            ensure!(r#return != FALSE, Error::last_os_error());
             Ok(r#return != FALSE)
         }
     }
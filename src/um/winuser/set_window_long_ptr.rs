@@ -0,0 +1,42 @@
+use crate::{
+    error::ensure,
+    r#macro::FnOnce,
+    shared::windef::WindowHandle,
+    utils::{AsStrictRawHandle, Call},
+    Error, Result,
+};
+use typed_builder::TypedBuilder;
+use winapi::shared::winerror::ERROR_SUCCESS;
+
+/// Set window long ptr.
+///
+/// Returns the previous value.
+#[derive(FnOnce, TypedBuilder)]
+pub struct SetWindowLongPtr {
+    window_handle: WindowHandle,
+    index: i32,
+    new_long: isize,
+}
+
+impl Call for SetWindowLongPtr {
+    type Output = Result<isize>;
+
+    fn call(self) -> Self::Output {
+        use winapi::um::{errhandlingapi::SetLastError, winuser::SetWindowLongPtrW};
+
+        #[allow(non_snake_case)]
+        unsafe {
+            let hWnd = self.window_handle.as_strict_raw_handle();
+            let nIndex = self.index;
+            let dwNewLong = self.new_long as _;
+            SetLastError(0);
+            let r#return = SetWindowLongPtrW(hWnd, nIndex, dwNewLong);
+            let last_os_error = Error::last_os_error();
+            ensure!(
+                r#return != 0 || last_os_error == Error::Win32(ERROR_SUCCESS),
+                last_os_error
+            );
+            Ok(r#return as _)
+        }
+    }
+}
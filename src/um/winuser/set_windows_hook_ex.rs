@@ -0,0 +1,42 @@
+use crate::{
+    error::ensure,
+    r#macro::FnOnce,
+    shared::{minwindef::ModuleHandle, windef::HookHandle},
+    utils::{AsStrictRawHandle, Call, FromStrictRawHandle},
+    Error, Result,
+};
+use std::ptr::null_mut;
+use typed_builder::TypedBuilder;
+use winapi::um::winuser::HOOKPROC;
+
+/// Set windows hook ex.
+#[derive(FnOnce, TypedBuilder)]
+pub struct SetWindowsHookEx {
+    id_hook: i32,
+    hook_procedure: HOOKPROC,
+    #[builder(default, setter(strip_option))]
+    module_handle: Option<ModuleHandle>,
+    #[builder(default)]
+    thread_id: u32,
+}
+
+impl Call for SetWindowsHookEx {
+    type Output = Result<HookHandle>;
+
+    fn call(self) -> Self::Output {
+        use winapi::um::winuser::SetWindowsHookExW;
+
+        #[allow(non_snake_case)]
+        unsafe {
+            let idHook = self.id_hook;
+            let lpfn = self.hook_procedure;
+            let hmod = self.module_handle.map_or(null_mut(), |module_handle| {
+                module_handle.as_strict_raw_handle()
+            });
+            let dwThreadId = self.thread_id;
+            let r#return = SetWindowsHookExW(idHook, lpfn, hmod, dwThreadId);
+            ensure!(!r#return.is_null(), Error::last_os_error());
+            Ok(HookHandle::from_strict_raw_handle(r#return))
+        }
+    }
+}
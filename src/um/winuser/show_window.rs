@@ -0,0 +1,32 @@
+use super::ShowCommand;
+use crate::{
+    r#macro::FnOnce,
+    shared::windef::WindowHandle,
+    utils::{AsStrictRawHandle, Call},
+};
+use typed_builder::TypedBuilder;
+use winapi::shared::minwindef::FALSE;
+
+/// Show window.
+///
+/// Returns whether the window was previously visible.
+#[derive(FnOnce, TypedBuilder)]
+pub struct ShowWindow {
+    window_handle: WindowHandle,
+    show_command: ShowCommand,
+}
+
+impl Call for ShowWindow {
+    type Output = bool;
+
+    fn call(self) -> Self::Output {
+        use winapi::um::winuser::ShowWindow;
+
+        #[allow(non_snake_case)]
+        unsafe {
+            let hWnd = self.window_handle.as_strict_raw_handle();
+            let nCmdShow = self.show_command.into();
+            ShowWindow(hWnd, nCmdShow) != FALSE
+        }
+    }
+}
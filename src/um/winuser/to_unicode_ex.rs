@@ -0,0 +1,46 @@
+use crate::{
+    r#macro::FnOnce,
+    shared::minwindef::KeyboardLayoutHandle,
+    utils::{AsStrictRawHandle, Call},
+};
+use typed_builder::TypedBuilder;
+
+/// To unicode ex.
+///
+/// Returns the raw result, negative for a dead key, with the UTF-16 code units
+/// written.
+#[derive(FnOnce, TypedBuilder)]
+pub struct ToUnicodeEx<'a> {
+    virtual_key: u32,
+    scan_code: u32,
+    key_state: &'a [u8; 256],
+    #[builder(default)]
+    flags: u32,
+    keyboard_layout: KeyboardLayoutHandle,
+    #[builder(default = [0; 16], setter(skip))]
+    buffer: [u16; 16],
+}
+
+impl Call for ToUnicodeEx<'_> {
+    type Output = (i32, Vec<u16>);
+
+    fn call(mut self) -> Self::Output {
+        use winapi::um::winuser::ToUnicodeEx;
+
+        #[allow(non_snake_case)]
+        unsafe {
+            let wVirtKey = self.virtual_key;
+            let wScanCode = self.scan_code;
+            let lpKeyState = self.key_state.as_ptr();
+            let pwszBuff = self.buffer.as_mut_ptr();
+            let cchBuff = self.buffer.len() as _;
+            let wFlags = self.flags;
+            let dwhkl = self.keyboard_layout.as_strict_raw_handle();
+            let r#return = ToUnicodeEx(
+                wVirtKey, wScanCode, lpKeyState, pwszBuff, cchBuff, wFlags, dwhkl,
+            );
+            let length = (r#return.unsigned_abs() as usize).min(self.buffer.len());
+            (r#return, self.buffer[..length].to_vec())
+        }
+    }
+}
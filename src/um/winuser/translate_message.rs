@@ -0,0 +1,25 @@
+use crate::{r#macro::FnOnce, utils::Call};
+use typed_builder::TypedBuilder;
+use winapi::{shared::minwindef::FALSE, um::winuser::MSG};
+
+/// Translate message.
+///
+/// Returns whether the message was translated into a character message.
+#[derive(FnOnce, TypedBuilder)]
+pub struct TranslateMessage<'a> {
+    message: &'a MSG,
+}
+
+impl Call for TranslateMessage<'_> {
+    type Output = bool;
+
+    fn call(self) -> Self::Output {
+        use winapi::um::winuser::TranslateMessage;
+
+        #[allow(non_snake_case)]
+        unsafe {
+            let lpMsg = self.message;
+            TranslateMessage(lpMsg) != FALSE
+        }
+    }
+}
@@ -0,0 +1,31 @@
+use crate::{
+    error::ensure,
+    r#macro::FnOnce,
+    shared::windef::HookHandle,
+    utils::{AsStrictRawHandle, Call},
+    Error, Result,
+};
+use typed_builder::TypedBuilder;
+use winapi::shared::minwindef::FALSE;
+
+/// Unhook windows hook ex.
+#[derive(FnOnce, TypedBuilder)]
+pub struct UnhookWindowsHookEx {
+    hook_handle: HookHandle,
+}
+
+impl Call for UnhookWindowsHookEx {
+    type Output = Result<()>;
+
+    fn call(self) -> Self::Output {
+        use winapi::um::winuser::UnhookWindowsHookEx;
+
+        #[allow(non_snake_case)]
+        unsafe {
+            let hhk = self.hook_handle.as_strict_raw_handle();
+            let r#return = UnhookWindowsHookEx(hhk);
+            ensure!(r#return != FALSE, Error::last_os_error());
+            Ok(())
+        }
+    }
+}
@@ -0,0 +1,40 @@
+use crate::{
+    error::ensure,
+    r#macro::FnOnce,
+    shared::minwindef::ModuleHandle,
+    utils::{AsStrictRawHandle, Call},
+    Error, Result,
+};
+use std::ptr::null_mut;
+use typed_builder::TypedBuilder;
+use widestring::WideCString;
+use winapi::shared::minwindef::FALSE;
+
+/// Unregister class.
+#[derive(FnOnce, TypedBuilder)]
+pub struct UnregisterClass<'a> {
+    class_name: &'a str,
+    #[builder(default)]
+    instance: Option<ModuleHandle>,
+}
+
+impl Call for UnregisterClass<'_> {
+    type Output = Result<()>;
+
+    fn call(self) -> Self::Output {
+        use winapi::um::winuser::UnregisterClassW;
+
+        let class_name = WideCString::from_str(self.class_name)?;
+
+        #[allow(non_snake_case)]
+        unsafe {
+            let lpClassName = class_name.as_ptr();
+            let hInstance = self
+                .instance
+                .map_or(null_mut(), |instance| instance.as_strict_raw_handle());
+            let r#return = UnregisterClassW(lpClassName, hInstance);
+            ensure!(r#return != FALSE, Error::last_os_error());
+            Ok(())
+        }
+    }
+}
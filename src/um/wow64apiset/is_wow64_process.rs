@@ -1,21 +1,23 @@
-use crate::{r#macro::FnOnce, shared::ntdef::Handle};
-use anyhow::{ensure, Result};
-use std::{io, os::windows::io::AsRawHandle};
+use crate::{
+    error::ensure, r#macro::FnOnce, shared::ntdef::BorrowedHandle, utils::Call, Error, Result,
+};
+use std::os::windows::io::AsRawHandle;
 use typed_builder::TypedBuilder;
 use winapi::shared::minwindef::FALSE;
 
 /// Is wow64 process.
 #[derive(FnOnce, TypedBuilder)]
 pub struct IsWow64Process<'a> {
-    process: &'a Handle,
+    #[builder(setter(into))]
+    process: BorrowedHandle<'a>,
     #[builder(default = FALSE, setter(skip))]
     wow64_process: i32,
 }
 
-impl FnOnce<()> for IsWow64Process<'_> {
+impl Call for IsWow64Process<'_> {
     type Output = Result<bool>;
 
-    extern "rust-call" fn call_once(mut self, _args: ()) -> Self::Output {
+    fn call(mut self) -> Self::Output {
         use winapi::um::wow64apiset::IsWow64Process;
 
         #[allow(non_snake_case)]
@@ -23,7 +25,7 @@ impl FnOnce<()> for IsWow64Process<'_> {
             let hProcess = self.process.as_raw_handle();
             let Wow64Process = &mut self.wow64_process;
             let r#return = IsWow64Process(hProcess, Wow64Process);
-            ensure!(r#return != FALSE, io::Error::last_os_error());
+            ensure!(r#return != FALSE, Error::last_os_error());
             Ok(self.wow64_process != FALSE)
         }
     }
@@ -7,6 +7,10 @@ pub trait AsStrictRawHandle: FromStrictRawHandle {
 pub trait FromStrictRawHandle {
     type StrictRawHandle;
 
+    /// # Safety
+    ///
+    /// The `handle` must be valid for the wrapper, an owning one closes it on
+    /// drop.
     unsafe fn from_strict_raw_handle(handle: Self::StrictRawHandle) -> Self;
 }
 
@@ -15,6 +19,16 @@ pub trait IntoStrictRawHandle: FromStrictRawHandle {
     fn into_strict_raw_handle(self) -> Self::StrictRawHandle;
 }
 
+/// Call.
+///
+/// The stable way to call a wrapper, implemented instead of `FnOnce<()>`,
+/// which `#[derive(FnOnce)]` adds with the `nightly` feature.
+pub trait Call {
+    type Output;
+
+    fn call(self) -> Self::Output;
+}
+
 /// Transparent.
 pub trait Transparent {
     type Target;
@@ -0,0 +1,563 @@
+//! Hotkey chords and sequences.
+
+use super::{
+    key::Key,
+    keyboard::{KeyEvent, KeyboardState},
+};
+use crate::{error::ensure, Error, Result};
+use bitflags::bitflags;
+use std::{
+    fmt::{self, Display, Formatter},
+    str::FromStr,
+    time::{Duration, Instant},
+};
+
+const DOWN: u8 = 0x80;
+
+bitflags! {
+    /// Modifiers.
+    ///
+    /// The sideless modifiers are satisfied by either side.
+    pub struct Modifiers: u16 {
+        const CTRL = 0x0001;
+        const LEFT_CTRL = 0x0002;
+        const RIGHT_CTRL = 0x0004;
+        const SHIFT = 0x0008;
+        const LEFT_SHIFT = 0x0010;
+        const RIGHT_SHIFT = 0x0020;
+        const ALT = 0x0040;
+        const LEFT_ALT = 0x0080;
+        const RIGHT_ALT = 0x0100;
+        const WIN = 0x0200;
+        const LEFT_WIN = 0x0400;
+        const RIGHT_WIN = 0x0800;
+    }
+}
+
+/// A family of modifiers: the sideless flag, the sided flags and their keys.
+struct Family {
+    any: Modifiers,
+    left: (Modifiers, Key),
+    right: (Modifiers, Key),
+    key: Option<Key>,
+    name: &'static str,
+}
+
+const FAMILIES: [Family; 4] = [
+    Family {
+        any: Modifiers::CTRL,
+        left: (Modifiers::LEFT_CTRL, Key::LeftCtrl),
+        right: (Modifiers::RIGHT_CTRL, Key::RightCtrl),
+        key: Some(Key::Ctrl),
+        name: "Ctrl",
+    },
+    Family {
+        any: Modifiers::SHIFT,
+        left: (Modifiers::LEFT_SHIFT, Key::LeftShift),
+        right: (Modifiers::RIGHT_SHIFT, Key::RightShift),
+        key: Some(Key::Shift),
+        name: "Shift",
+    },
+    Family {
+        any: Modifiers::ALT,
+        left: (Modifiers::LEFT_ALT, Key::LeftAlt),
+        right: (Modifiers::RIGHT_ALT, Key::RightAlt),
+        key: Some(Key::Alt),
+        name: "Alt",
+    },
+    Family {
+        any: Modifiers::WIN,
+        left: (Modifiers::LEFT_WIN, Key::LeftWindows),
+        right: (Modifiers::RIGHT_WIN, Key::RightWindows),
+        key: None,
+        name: "Win",
+    },
+];
+
+impl Family {
+    fn contains(&self, key: Key) -> bool {
+        self.key == Some(key) || self.left.1 == key || self.right.1 == key
+    }
+
+    fn matches(&self, modifiers: Modifiers, state: &[u8; 256]) -> bool {
+        let left = is_down(state, self.left.1);
+        let right = is_down(state, self.right.1);
+        let any = left || right || self.key.is_some_and(|key| is_down(state, key));
+        let required_left = modifiers.contains(self.left.0);
+        let required_right = modifiers.contains(self.right.0);
+        if required_left || required_right {
+            left == required_left && right == required_right
+        } else if modifiers.contains(self.any) {
+            any
+        } else {
+            !any
+        }
+    }
+}
+
+impl Modifiers {
+    pub(crate) fn from_key(key: Key) -> Option<Self> {
+        FAMILIES.iter().find_map(|family| {
+            if family.key == Some(key) {
+                Some(family.any)
+            } else if family.left.1 == key {
+                Some(family.left.0)
+            } else if family.right.1 == key {
+                Some(family.right.0)
+            } else {
+                None
+            }
+        })
+    }
+
+    /// The keys to press for the modifiers, the sideless Windows modifier
+    /// being pressed as the left Windows key.
+    pub fn keys(self) -> impl Iterator<Item = Key> {
+        FAMILIES.iter().flat_map(move |family| {
+            let any = family.key.unwrap_or(family.left.1);
+            vec![(family.any, any), family.left, family.right]
+                .into_iter()
+                .filter(move |&(modifier, _)| self.contains(modifier))
+                .map(|(_, key)| key)
+        })
+    }
+
+    fn parse(s: &str) -> Option<Self> {
+        match &*s.to_ascii_lowercase() {
+            "win" | "windows" => Some(Modifiers::WIN),
+            "leftwin" => Some(Modifiers::LEFT_WIN),
+            "rightwin" => Some(Modifiers::RIGHT_WIN),
+            _ => s.parse().ok().and_then(Modifiers::from_key),
+        }
+    }
+}
+
+impl Display for Modifiers {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let mut separator = "";
+        for family in &FAMILIES {
+            let names = [
+                (family.any, family.name),
+                (family.left.0, family.left.1.name()),
+                (family.right.0, family.right.1.name()),
+            ];
+            for &(modifier, name) in &names {
+                if self.contains(modifier) {
+                    write!(f, "{}{}", separator, name)?;
+                    separator = "+";
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Chord.
+///
+/// A key pressed with modifiers, such as `Ctrl+Shift+F5`.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct Chord {
+    pub modifiers: Modifiers,
+    pub key: Key,
+}
+
+impl Chord {
+    pub fn new(modifiers: Modifiers, key: Key) -> Self {
+        Self { modifiers, key }
+    }
+
+    /// Whether the key and exactly the modifiers are down in the keyboard
+    /// `state`, as returned by `get_keyboard_state`.
+    pub fn matches(&self, state: &[u8; 256]) -> bool {
+        is_down(state, self.key)
+            && FAMILIES
+                .iter()
+                .all(|family| family.contains(self.key) || family.matches(self.modifiers, state))
+    }
+}
+
+impl Display for Chord {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        if !self.modifiers.is_empty() {
+            write!(f, "{}+", self.modifiers)?;
+        }
+        write!(f, "{}", self.key)
+    }
+}
+
+impl FromStr for Chord {
+    type Err = Error;
+
+    /// Parses the `+` separated modifiers followed by the key, ignoring the case
+    /// and the spaces around the parts. A trailing `+` belongs to the key, as in
+    /// `Alt+NumPad+`.
+    fn from_str(s: &str) -> Result<Self> {
+        let s = s.trim();
+        let (modifiers, key) = match s.strip_suffix('+') {
+            Some(rest) => match rest.rfind('+') {
+                Some(index) => (&rest[..index], &s[index + 1..]),
+                None => ("", s),
+            },
+            None => match s.rfind('+') {
+                Some(index) => (&s[..index], &s[index + 1..]),
+                None => ("", s),
+            },
+        };
+        let key = key.trim();
+        let key = key.parse()?;
+        let mut chord = Chord::new(Modifiers::empty(), key);
+        if modifiers.is_empty() {
+            return Ok(chord);
+        }
+        for modifier in modifiers.split('+').map(str::trim) {
+            let modifier = Modifiers::parse(modifier)
+                .ok_or_else(|| Error::unknown_name("modifier", modifier))?;
+            ensure!(
+                !chord.modifiers.contains(modifier),
+                Error::invalid_value("chord", s),
+            );
+            chord.modifiers.insert(modifier);
+        }
+        Ok(chord)
+    }
+}
+
+/// Sequence.
+///
+/// Chords pressed one after another, such as `Ctrl+K, Ctrl+C`.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct Sequence(Vec<Chord>);
+
+impl Sequence {
+    pub fn new(chords: Vec<Chord>) -> Result<Self> {
+        ensure!(!chords.is_empty(), Error::invalid_value("sequence", ""));
+        Ok(Sequence(chords))
+    }
+
+    #[inline]
+    pub fn chords(&self) -> &[Chord] {
+        &self.0
+    }
+}
+
+impl Display for Sequence {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        for (index, chord) in self.0.iter().enumerate() {
+            if index != 0 {
+                f.write_str(", ")?;
+            }
+            write!(f, "{}", chord)?;
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for Sequence {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        Sequence::new(s.split(',').map(str::parse).collect::<Result<_>>()?)
+    }
+}
+
+/// Progress.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Progress {
+    /// No chord of the sequence is pressed.
+    Idle,
+    /// The number of chords pressed so far.
+    Pending(usize),
+    /// The whole sequence is pressed.
+    Complete,
+}
+
+/// Sequence matcher.
+///
+/// A state machine fed with successive keyboard states. A chord advances the
+/// sequence when its key goes down. Pressing another non-modifier key, or
+/// waiting longer than the timeout between chords, starts over.
+#[derive(Clone, Debug)]
+pub struct SequenceMatcher {
+    sequence: Sequence,
+    timeout: Duration,
+    position: usize,
+    deadline: Option<Instant>,
+    previous: KeyboardState,
+}
+
+impl SequenceMatcher {
+    pub fn new(sequence: Sequence, timeout: Duration) -> Self {
+        Self {
+            sequence,
+            timeout,
+            position: 0,
+            deadline: None,
+            previous: KeyboardState::default(),
+        }
+    }
+
+    #[inline]
+    pub fn sequence(&self) -> &Sequence {
+        &self.sequence
+    }
+
+    pub fn progress(&self) -> Progress {
+        match self.position {
+            0 => Progress::Idle,
+            position => Progress::Pending(position),
+        }
+    }
+
+    pub fn reset(&mut self) {
+        self.position = 0;
+        self.deadline = None;
+    }
+
+    /// Advances with the keyboard `state` observed at `now`.
+    pub fn advance(&mut self, state: &[u8; 256], now: Instant) -> Progress {
+        let current = KeyboardState::from(*state);
+        let pressed = current
+            .diff(&self.previous)
+            .filter_map(|event| match event {
+                KeyEvent::Pressed(key) => Some(key),
+                KeyEvent::Released(_) => None,
+            })
+            .collect::<Vec<_>>();
+        self.previous = current;
+        if self.deadline.is_some_and(|deadline| now > deadline) {
+            self.reset();
+        }
+        if pressed.is_empty() {
+            return self.progress();
+        }
+        if self.step(&pressed, state, now) {
+            return self.complete();
+        }
+        if pressed
+            .iter()
+            .all(|&key| Modifiers::from_key(key).is_some())
+        {
+            return self.progress();
+        }
+        if self.position != 0 {
+            self.reset();
+            if self.step(&pressed, state, now) {
+                return self.complete();
+            }
+        }
+        self.progress()
+    }
+
+    fn step(&mut self, pressed: &[Key], state: &[u8; 256], now: Instant) -> bool {
+        let chord = self.sequence.0[self.position];
+        if !pressed.contains(&chord.key) || !chord.matches(state) {
+            return false;
+        }
+        self.position += 1;
+        self.deadline = Some(now + self.timeout);
+        true
+    }
+
+    fn complete(&mut self) -> Progress {
+        if self.position == self.sequence.0.len() {
+            self.reset();
+            Progress::Complete
+        } else {
+            self.progress()
+        }
+    }
+}
+
+fn is_down(state: &[u8; 256], key: Key) -> bool {
+    state[i32::from(key) as usize] & DOWN != 0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Chord, Modifiers, Progress, Sequence, SequenceMatcher};
+    use crate::wrap::key::{Function, Key, NumericKeypad};
+    use std::time::{Duration, Instant};
+
+    fn state(keys: &[Key]) -> [u8; 256] {
+        let mut state = [0; 256];
+        for &key in keys {
+            state[i32::from(key) as usize] = 0x80;
+        }
+        state
+    }
+
+    #[test]
+    fn parse() {
+        assert_eq!(
+            Chord::new(
+                Modifiers::CTRL | Modifiers::SHIFT,
+                Key::Function(Function::F5)
+            ),
+            "Ctrl+Shift+F5".parse().unwrap(),
+        );
+        assert_eq!(
+            Chord::new(Modifiers::ALT, Key::NumPad(NumericKeypad::Add)),
+            "Alt+NumPad+".parse().unwrap(),
+        );
+        assert_eq!(
+            Chord::new(Modifiers::empty(), Key::NumPad(NumericKeypad::Add)),
+            "NumPad+".parse().unwrap(),
+        );
+        assert_eq!(
+            Chord::new(Modifiers::LEFT_CTRL | Modifiers::WIN, Key::A),
+            " leftctrl + win + a ".parse().unwrap(),
+        );
+        assert_eq!(
+            Chord::new(Modifiers::CTRL, Key::Shift),
+            "Control+Shift".parse().unwrap(),
+        );
+        for invalid in &[
+            "",
+            "+",
+            "Ctrl+",
+            "Ctrl++A",
+            "Ctrl+Ctrl+A",
+            "Foo+A",
+            "Ctrl+Foo",
+        ] {
+            assert!(invalid.parse::<Chord>().is_err(), "{:?}", invalid);
+        }
+    }
+
+    #[test]
+    fn format() {
+        for &chord in &[
+            "Ctrl+Shift+F5",
+            "Alt+NumPad+",
+            "LeftCtrl+RightAlt+Delete",
+            "Ctrl+Shift+Alt+Win+Escape",
+            "RightWindows+L",
+            "Space",
+        ] {
+            assert_eq!(chord, chord.parse::<Chord>().unwrap().to_string());
+        }
+        assert_eq!(
+            "Ctrl+Alt+A",
+            "alt+control+a".parse::<Chord>().unwrap().to_string(),
+        );
+    }
+
+    #[test]
+    fn matches() {
+        let chord = "Ctrl+Shift+F5".parse::<Chord>().unwrap();
+        let f5 = Key::Function(Function::F5);
+        assert!(chord.matches(&state(&[
+            Key::Ctrl,
+            Key::LeftCtrl,
+            Key::Shift,
+            Key::RightShift,
+            f5
+        ])));
+        assert!(chord.matches(&state(&[Key::Ctrl, Key::Shift, f5])));
+        assert!(!chord.matches(&state(&[Key::Ctrl, Key::Shift])));
+        assert!(!chord.matches(&state(&[Key::Ctrl, f5])));
+        assert!(!chord.matches(&state(&[Key::Ctrl, Key::Shift, Key::Alt, f5])));
+        assert!(!chord.matches(&state(&[Key::Ctrl, Key::Shift, Key::LeftWindows, f5])));
+    }
+
+    #[test]
+    fn matches_sided() {
+        let chord = "LeftCtrl+A".parse::<Chord>().unwrap();
+        assert!(chord.matches(&state(&[Key::Ctrl, Key::LeftCtrl, Key::A])));
+        assert!(!chord.matches(&state(&[Key::Ctrl, Key::RightCtrl, Key::A])));
+        assert!(!chord.matches(&state(&[Key::Ctrl, Key::LeftCtrl, Key::RightCtrl, Key::A])));
+        let chord = "Ctrl+Shift".parse::<Chord>().unwrap();
+        assert!(chord.matches(&state(&[Key::Ctrl, Key::Shift, Key::LeftShift])));
+        assert!(!chord.matches(&state(&[Key::Ctrl])));
+    }
+
+    #[test]
+    fn sequence() {
+        let sequence = "Ctrl+K, Ctrl+C".parse::<Sequence>().unwrap();
+        assert_eq!("Ctrl+K, Ctrl+C", sequence.to_string());
+        assert_eq!(2, sequence.chords().len());
+        assert!("".parse::<Sequence>().is_err());
+        assert!("Ctrl+K,".parse::<Sequence>().is_err());
+    }
+
+    #[test]
+    fn sequence_matcher() {
+        let sequence = "Ctrl+K, Ctrl+C".parse().unwrap();
+        let mut matcher = SequenceMatcher::new(sequence, Duration::from_secs(1));
+        let now = Instant::now();
+        let at = |millis| now + Duration::from_millis(millis);
+        assert_eq!(Progress::Idle, matcher.advance(&state(&[Key::Ctrl]), at(0)));
+        assert_eq!(
+            Progress::Pending(1),
+            matcher.advance(&state(&[Key::Ctrl, Key::K]), at(10)),
+        );
+        assert_eq!(
+            Progress::Pending(1),
+            matcher.advance(&state(&[Key::Ctrl]), at(20))
+        );
+        assert_eq!(
+            Progress::Complete,
+            matcher.advance(&state(&[Key::Ctrl, Key::C]), at(30)),
+        );
+        assert_eq!(Progress::Idle, matcher.progress());
+    }
+
+    #[test]
+    fn sequence_matcher_timeout() {
+        let sequence = "Ctrl+K, Ctrl+C".parse().unwrap();
+        let mut matcher = SequenceMatcher::new(sequence, Duration::from_secs(1));
+        let now = Instant::now();
+        let at = |millis| now + Duration::from_millis(millis);
+        matcher.advance(&state(&[Key::Ctrl, Key::K]), at(0));
+        matcher.advance(&state(&[Key::Ctrl]), at(10));
+        assert_eq!(
+            Progress::Idle,
+            matcher.advance(&state(&[Key::Ctrl]), at(1500))
+        );
+        assert_eq!(
+            Progress::Idle,
+            matcher.advance(&state(&[Key::Ctrl, Key::C]), at(1510)),
+        );
+    }
+
+    #[test]
+    fn sequence_matcher_restart() {
+        let sequence = "Ctrl+K, Ctrl+C".parse().unwrap();
+        let mut matcher = SequenceMatcher::new(sequence, Duration::from_secs(1));
+        let now = Instant::now();
+        matcher.advance(&state(&[Key::Ctrl, Key::K]), now);
+        matcher.advance(&state(&[Key::Ctrl]), now);
+        // A wrong key starts over.
+        assert_eq!(
+            Progress::Idle,
+            matcher.advance(&state(&[Key::Ctrl, Key::X]), now)
+        );
+        matcher.advance(&state(&[Key::Ctrl]), now);
+        assert_eq!(
+            Progress::Idle,
+            matcher.advance(&state(&[Key::Ctrl, Key::C]), now)
+        );
+        matcher.advance(&state(&[Key::Ctrl]), now);
+        // The first chord restarts the sequence.
+        matcher.advance(&state(&[Key::Ctrl, Key::K]), now);
+        matcher.advance(&state(&[Key::Ctrl]), now);
+        assert_eq!(
+            Progress::Pending(1),
+            matcher.advance(&state(&[Key::Ctrl, Key::K]), now),
+        );
+        matcher.advance(&state(&[]), now);
+        // Modifiers alone do not.
+        assert_eq!(
+            Progress::Pending(1),
+            matcher.advance(&state(&[Key::Shift]), now),
+        );
+        assert_eq!(
+            Progress::Pending(1),
+            matcher.advance(&state(&[Key::Ctrl]), now),
+        );
+        assert_eq!(
+            Progress::Complete,
+            matcher.advance(&state(&[Key::Ctrl, Key::C]), now),
+        );
+    }
+}
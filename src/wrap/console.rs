@@ -0,0 +1,276 @@
+//! Console.
+
+use crate::{
+    shared::ntdef::BorrowedHandle,
+    um::{
+        consoleapi::{get_console_mode, set_console_ctrl_handler, set_console_mode, ConsoleMode},
+        wincon::ControlEvent,
+    },
+    Result,
+};
+use lazy_static::lazy_static;
+use std::{
+    convert::TryFrom,
+    panic::{self, AssertUnwindSafe},
+    sync::{
+        mpsc::{channel, Receiver},
+        Arc, Mutex, MutexGuard, PoisonError,
+    },
+};
+use winapi::shared::minwindef::{BOOL, DWORD, FALSE, TRUE};
+
+type Handler = Arc<dyn Fn(ControlEvent) -> bool + Send + Sync>;
+
+lazy_static! {
+    static ref CONTROL_HANDLERS: Mutex<ControlHandlers> = Mutex::new(ControlHandlers::new());
+}
+
+/// Console mode guard.
+///
+/// Sets the mode of a console handle and restores the previous mode on drop,
+/// also when unwinding from a panic.
+#[derive(Debug)]
+pub struct ConsoleModeGuard<'a> {
+    handle: BorrowedHandle<'a>,
+    previous: ConsoleMode,
+}
+
+impl<'a> ConsoleModeGuard<'a> {
+    pub fn new(handle: impl Into<BorrowedHandle<'a>>, mode: ConsoleMode) -> Result<Self> {
+        let handle = handle.into();
+        let previous = get_console_mode().console_handle(handle).call()?;
+        set_console_mode()
+            .console_handle(handle)
+            .mode(mode)
+            .call()?;
+        Ok(Self { handle, previous })
+    }
+
+    /// Sets the previous mode with the `insert` flags inserted and the `remove`
+    /// flags removed.
+    pub fn modify(
+        handle: impl Into<BorrowedHandle<'a>>,
+        insert: ConsoleMode,
+        remove: ConsoleMode,
+    ) -> Result<Self> {
+        let handle = handle.into();
+        let previous = get_console_mode().console_handle(handle).call()?;
+        let mode = (previous | insert) - remove;
+        set_console_mode()
+            .console_handle(handle)
+            .mode(mode)
+            .call()?;
+        Ok(Self { handle, previous })
+    }
+
+    #[inline]
+    pub fn previous(&self) -> ConsoleMode {
+        self.previous
+    }
+}
+
+impl Drop for ConsoleModeGuard<'_> {
+    fn drop(&mut self) {
+        let _ = set_console_mode()
+            .console_handle(self.handle)
+            .mode(self.previous)
+            .call();
+    }
+}
+
+/// Control handlers.
+///
+/// The registry of the handlers of console control events. The handlers are
+/// called from the most recently added one until one of them returns `true`.
+#[derive(Default)]
+pub struct ControlHandlers {
+    handlers: Vec<(usize, Handler)>,
+    next_id: usize,
+}
+
+impl ControlHandlers {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.handlers.len()
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.handlers.is_empty()
+    }
+
+    /// Adds the `handler` and returns its id.
+    pub fn add<F>(&mut self, handler: F) -> usize
+    where
+        F: Fn(ControlEvent) -> bool + Send + Sync + 'static,
+    {
+        let id = self.next_id;
+        self.next_id += 1;
+        self.handlers.push((id, Arc::new(handler)));
+        id
+    }
+
+    /// Removes the handler of the `id`, returns whether it was registered.
+    pub fn remove(&mut self, id: usize) -> bool {
+        let len = self.handlers.len();
+        self.handlers.retain(|&(handler_id, _)| handler_id != id);
+        self.handlers.len() != len
+    }
+
+    /// Dispatches the `event`, returns whether a handler handled it.
+    pub fn dispatch(&self, event: ControlEvent) -> bool {
+        dispatch(self.handlers(), event)
+    }
+
+    fn handlers(&self) -> Vec<Handler> {
+        self.handlers
+            .iter()
+            .rev()
+            .map(|(_, handler)| handler.clone())
+            .collect()
+    }
+}
+
+/// Control handler.
+///
+/// The registration of a handler in the process registry, removed on drop.
+#[derive(Debug)]
+pub struct ControlHandler {
+    id: usize,
+}
+
+impl ControlHandler {
+    /// Adds the `handler` of the console control events of the process.
+    ///
+    /// The handlers run on a thread created by the system. For `Close`,
+    /// `Logoff` and `Shutdown` the process is terminated once the handlers
+    /// return.
+    pub fn add<F>(handler: F) -> Result<Self>
+    where
+        F: Fn(ControlEvent) -> bool + Send + Sync + 'static,
+    {
+        let mut control_handlers = control_handlers();
+        if control_handlers.is_empty() {
+            set_console_ctrl_handler()
+                .handler_routine(Some(handler_routine))
+                .add(true)
+                .call()?;
+        }
+        let id = control_handlers.add(handler);
+        Ok(Self { id })
+    }
+
+    /// Adds a handler sending the console control events to the returned
+    /// receiver and handling them.
+    pub fn channel() -> Result<(Self, Receiver<ControlEvent>)> {
+        let (sender, receiver) = channel();
+        let sender = Mutex::new(sender);
+        let control_handler = Self::add(move |event| sender.lock().unwrap().send(event).is_ok())?;
+        Ok((control_handler, receiver))
+    }
+}
+
+impl Drop for ControlHandler {
+    fn drop(&mut self) {
+        let mut control_handlers = control_handlers();
+        if control_handlers.remove(self.id) && control_handlers.is_empty() {
+            let _ = set_console_ctrl_handler()
+                .handler_routine(Some(handler_routine))
+                .add(false)
+                .call();
+        }
+    }
+}
+
+/// The process registry, also after a handler has panicked while holding it.
+fn control_handlers() -> MutexGuard<'static, ControlHandlers> {
+    CONTROL_HANDLERS
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner)
+}
+
+/// Calls the `handlers` until one of them handles the `event`. A handler that
+/// panics doesn't, the panic must not unwind into the system.
+fn dispatch(handlers: Vec<Handler>, event: ControlEvent) -> bool {
+    handlers
+        .iter()
+        .any(|handler| panic::catch_unwind(AssertUnwindSafe(|| handler(event))).unwrap_or(false))
+}
+
+unsafe extern "system" fn handler_routine(ctrl_type: DWORD) -> BOOL {
+    let event = match ControlEvent::try_from(ctrl_type) {
+        Ok(event) => event,
+        Err(_) => return FALSE,
+    };
+    // The handlers are called without the lock, so they can add or remove
+    // handlers.
+    let handlers = control_handlers().handlers();
+    if dispatch(handlers, event) {
+        TRUE
+    } else {
+        FALSE
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ControlEvent, ControlHandlers};
+    use std::sync::{mpsc::channel, Mutex};
+
+    #[test]
+    fn dispatch() {
+        let mut control_handlers = ControlHandlers::new();
+        assert!(!control_handlers.dispatch(ControlEvent::CtrlC));
+        let (sender, receiver) = channel();
+        let first = Mutex::new(sender.clone());
+        control_handlers.add(move |event| {
+            first.lock().unwrap().send((1, event)).unwrap();
+            true
+        });
+        let second = Mutex::new(sender);
+        let id = control_handlers.add(move |event| {
+            second.lock().unwrap().send((2, event)).unwrap();
+            event == ControlEvent::CtrlBreak
+        });
+        assert!(control_handlers.dispatch(ControlEvent::CtrlBreak));
+        assert_eq!(
+            vec![(2, ControlEvent::CtrlBreak)],
+            receiver.try_iter().collect::<Vec<_>>()
+        );
+        assert!(control_handlers.dispatch(ControlEvent::Close));
+        assert_eq!(
+            vec![(2, ControlEvent::Close), (1, ControlEvent::Close)],
+            receiver.try_iter().collect::<Vec<_>>(),
+        );
+        assert!(control_handlers.remove(id));
+        assert!(!control_handlers.remove(id));
+        assert!(control_handlers.dispatch(ControlEvent::Logoff));
+        assert_eq!(
+            vec![(1, ControlEvent::Logoff)],
+            receiver.try_iter().collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn panic() {
+        let mut control_handlers = ControlHandlers::new();
+        control_handlers.add(|event| event == ControlEvent::CtrlC);
+        control_handlers.add(|_| panic!("handler"));
+        assert!(control_handlers.dispatch(ControlEvent::CtrlC));
+        assert!(!control_handlers.dispatch(ControlEvent::CtrlBreak));
+    }
+
+    #[test]
+    fn unhandled() {
+        let mut control_handlers = ControlHandlers::new();
+        let id = control_handlers.add(|_| false);
+        assert_eq!(1, control_handlers.len());
+        assert!(!control_handlers.dispatch(ControlEvent::Shutdown));
+        control_handlers.remove(id);
+        assert!(control_handlers.is_empty());
+    }
+}
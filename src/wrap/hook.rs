@@ -0,0 +1,416 @@
+//! Low level keyboard and mouse hooks.
+
+use super::key::{Key, Mouse};
+use crate::{
+    shared::windef::HookHandle,
+    um::{
+        processthreadsapi::get_current_thread_id,
+        winuser::{
+            call_next_hook_ex, get_message, peek_message, post_thread_message, set_windows_hook_ex,
+            unhook_windows_hook_ex,
+        },
+    },
+    Result,
+};
+use bitflags::bitflags;
+use std::{
+    cell::RefCell,
+    convert::TryFrom,
+    panic::{self, AssertUnwindSafe},
+    sync::mpsc::{channel, sync_channel, Receiver, Sender},
+    thread::{self, JoinHandle},
+};
+use winapi::{
+    ctypes::c_int,
+    shared::minwindef::{LPARAM, LRESULT, WPARAM},
+    um::winuser::{
+        HC_ACTION, KBDLLHOOKSTRUCT, LLKHF_ALTDOWN, LLKHF_EXTENDED, LLKHF_INJECTED, LLKHF_UP,
+        LLMHF_INJECTED, MSLLHOOKSTRUCT, WH_KEYBOARD_LL, WH_MOUSE_LL, WM_LBUTTONDOWN, WM_LBUTTONUP,
+        WM_MBUTTONDOWN, WM_MBUTTONUP, WM_MOUSEHWHEEL, WM_MOUSEMOVE, WM_MOUSEWHEEL, WM_QUIT,
+        WM_RBUTTONDOWN, WM_RBUTTONUP, WM_XBUTTONDOWN, WM_XBUTTONUP, XBUTTON1, XBUTTON2,
+    },
+};
+
+bitflags! {
+    /// Hook kinds.
+    pub struct HookKinds: u32 {
+        const KEYBOARD = 0x1;
+        const MOUSE = 0x2;
+    }
+}
+
+/// Keyboard event.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct KeyboardEvent {
+    pub key: Option<Key>,
+    pub virtual_key_code: u32,
+    pub scan_code: u32,
+    pub up: bool,
+    pub extended: bool,
+    pub alt: bool,
+    pub injected: bool,
+    pub time: u32,
+}
+
+impl From<&KBDLLHOOKSTRUCT> for KeyboardEvent {
+    fn from(from: &KBDLLHOOKSTRUCT) -> Self {
+        Self {
+            key: Key::try_from(from.vkCode as i32).ok(),
+            virtual_key_code: from.vkCode,
+            scan_code: from.scanCode,
+            up: from.flags & LLKHF_UP != 0,
+            extended: from.flags & LLKHF_EXTENDED != 0,
+            alt: from.flags & LLKHF_ALTDOWN != 0,
+            injected: from.flags & LLKHF_INJECTED != 0,
+            time: from.time,
+        }
+    }
+}
+
+/// Mouse action.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum MouseAction {
+    Move,
+    Button { button: Mouse, up: bool },
+    Wheel { delta: i16, horizontal: bool },
+}
+
+/// Mouse event.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct MouseEvent {
+    pub action: MouseAction,
+    pub x: i32,
+    pub y: i32,
+    pub injected: bool,
+    pub time: u32,
+}
+
+impl MouseEvent {
+    /// Translates the hook structure of the mouse `message`, or `None` for an
+    /// unknown message.
+    pub fn new(message: u32, from: &MSLLHOOKSTRUCT) -> Option<Self> {
+        let high = (from.mouseData >> 16) as u16;
+        let button = |button, up| MouseAction::Button { button, up };
+        let action = match message {
+            WM_MOUSEMOVE => MouseAction::Move,
+            WM_LBUTTONDOWN => button(Mouse::LeftButton, false),
+            WM_LBUTTONUP => button(Mouse::LeftButton, true),
+            WM_RBUTTONDOWN => button(Mouse::RightButton, false),
+            WM_RBUTTONUP => button(Mouse::RightButton, true),
+            WM_MBUTTONDOWN => button(Mouse::MiddleButton, false),
+            WM_MBUTTONUP => button(Mouse::MiddleButton, true),
+            WM_XBUTTONDOWN | WM_XBUTTONUP => {
+                let up = message == WM_XBUTTONUP;
+                match high {
+                    XBUTTON1 => button(Mouse::X1Button, up),
+                    XBUTTON2 => button(Mouse::X2Button, up),
+                    _ => return None,
+                }
+            }
+            WM_MOUSEWHEEL | WM_MOUSEHWHEEL => MouseAction::Wheel {
+                delta: high as i16,
+                horizontal: message == WM_MOUSEHWHEEL,
+            },
+            _ => return None,
+        };
+        Some(Self {
+            action,
+            x: from.pt.x,
+            y: from.pt.y,
+            injected: from.flags & LLMHF_INJECTED != 0,
+            time: from.time,
+        })
+    }
+}
+
+/// Hook event.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum HookEvent {
+    Keyboard(KeyboardEvent),
+    Mouse(MouseEvent),
+}
+
+/// What to do with a hooked event.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Action {
+    /// Passes the event to the next hook.
+    Pass,
+    /// Suppresses the event, so no other hook nor window receives it.
+    Suppress,
+}
+
+type Policy = Box<dyn Fn(&HookEvent) -> Action + Send>;
+
+struct State {
+    sender: Sender<HookEvent>,
+    policy: Policy,
+}
+
+thread_local! {
+    static STATE: RefCell<Option<State>> = const { RefCell::new(None) };
+}
+
+/// Hook.
+///
+/// Installs the hooks on a thread of its own, running the message loop, and
+/// uninstalls them on drop.
+#[derive(Debug)]
+pub struct Hook {
+    thread_id: u32,
+    thread: Option<JoinHandle<Result<()>>>,
+}
+
+impl Hook {
+    pub fn keyboard() -> Result<(Self, Receiver<HookEvent>)> {
+        Self::new(HookKinds::KEYBOARD, |_| Action::Pass)
+    }
+
+    pub fn mouse() -> Result<(Self, Receiver<HookEvent>)> {
+        Self::new(HookKinds::MOUSE, |_| Action::Pass)
+    }
+
+    /// Hooks the `kinds` of events, asking the `policy` whether to suppress
+    /// each one. The `policy` runs on the hook thread and must be quick, lest
+    /// the system skips the hook.
+    pub fn new(
+        kinds: HookKinds,
+        policy: impl Fn(&HookEvent) -> Action + Send + 'static,
+    ) -> Result<(Self, Receiver<HookEvent>)> {
+        let (sender, receiver) = channel();
+        let (ready_sender, ready_receiver) = sync_channel(1);
+        let policy = Box::new(policy);
+        let thread = thread::spawn(move || -> Result<()> {
+            STATE.with(|state| *state.borrow_mut() = Some(State { sender, policy }));
+            // Creates the message queue before reporting the thread id.
+            peek_message().call();
+            let hook_handles = match install(kinds) {
+                Ok(hook_handles) => {
+                    let _ = ready_sender.send(Ok(get_current_thread_id()));
+                    hook_handles
+                }
+                Err(error) => {
+                    let _ = ready_sender.send(Err(error));
+                    return Ok(());
+                }
+            };
+            let result = (|| -> Result<()> {
+                while get_message().call()?.is_some() {}
+                Ok(())
+            })();
+            for hook_handle in hook_handles {
+                unhook_windows_hook_ex().hook_handle(hook_handle).call()?;
+            }
+            result
+        });
+        let thread_id = match ready_receiver.recv() {
+            Ok(thread_id) => thread_id?,
+            // The thread sends before it returns, so it has panicked.
+            Err(_) => panic::resume_unwind(thread.join().unwrap_err()),
+        };
+        let hook = Self {
+            thread_id,
+            thread: Some(thread),
+        };
+        Ok((hook, receiver))
+    }
+}
+
+impl Drop for Hook {
+    fn drop(&mut self) {
+        let quit = post_thread_message()
+            .thread_id(self.thread_id)
+            .message(WM_QUIT);
+        if quit.call().is_ok() {
+            if let Some(thread) = self.thread.take() {
+                let _ = thread.join();
+            }
+        }
+    }
+}
+
+fn install(kinds: HookKinds) -> Result<Vec<HookHandle>> {
+    let mut hook_handles = Vec::new();
+    let procedures: [(HookKinds, c_int, HookProcedure); 2] = [
+        (HookKinds::KEYBOARD, WH_KEYBOARD_LL, keyboard_procedure),
+        (HookKinds::MOUSE, WH_MOUSE_LL, mouse_procedure),
+    ];
+    for &(kind, id_hook, procedure) in &procedures {
+        if !kinds.contains(kind) {
+            continue;
+        }
+        match set_windows_hook_ex()
+            .id_hook(id_hook)
+            .hook_procedure(Some(procedure))
+            .call()
+        {
+            Ok(hook_handle) => hook_handles.push(hook_handle),
+            Err(error) => {
+                for hook_handle in hook_handles {
+                    let _ = unhook_windows_hook_ex().hook_handle(hook_handle).call();
+                }
+                return Err(error);
+            }
+        }
+    }
+    Ok(hook_handles)
+}
+
+type HookProcedure = unsafe extern "system" fn(c_int, WPARAM, LPARAM) -> LRESULT;
+
+/// Asks the policy what to do with the `event` and sends it. A policy that
+/// panics passes it, the panic must not unwind into the system.
+fn dispatch(event: HookEvent) -> Action {
+    panic::catch_unwind(AssertUnwindSafe(|| {
+        STATE.with(|state| match &*state.borrow() {
+            Some(state) => {
+                let action = (state.policy)(&event);
+                let _ = state.sender.send(event);
+                action
+            }
+            None => Action::Pass,
+        })
+    }))
+    .unwrap_or(Action::Pass)
+}
+
+unsafe extern "system" fn keyboard_procedure(
+    code: c_int,
+    w_param: WPARAM,
+    l_param: LPARAM,
+) -> LRESULT {
+    if code == HC_ACTION {
+        let event = KeyboardEvent::from(&*(l_param as *const KBDLLHOOKSTRUCT));
+        if dispatch(HookEvent::Keyboard(event)) == Action::Suppress {
+            return 1;
+        }
+    }
+    call_next_hook_ex()
+        .code(code)
+        .w_param(w_param)
+        .l_param(l_param)
+        .call()
+}
+
+unsafe extern "system" fn mouse_procedure(
+    code: c_int,
+    w_param: WPARAM,
+    l_param: LPARAM,
+) -> LRESULT {
+    if code == HC_ACTION {
+        if let Some(event) = MouseEvent::new(w_param as _, &*(l_param as *const MSLLHOOKSTRUCT)) {
+            if dispatch(HookEvent::Mouse(event)) == Action::Suppress {
+                return 1;
+            }
+        }
+    }
+    call_next_hook_ex()
+        .code(code)
+        .w_param(w_param)
+        .l_param(l_param)
+        .call()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{KeyboardEvent, MouseAction, MouseEvent};
+    use crate::wrap::key::{Key, Mouse};
+    use std::mem::zeroed;
+    use winapi::um::winuser::{
+        KBDLLHOOKSTRUCT, LLKHF_EXTENDED, LLKHF_INJECTED, LLKHF_UP, LLMHF_INJECTED, MSLLHOOKSTRUCT,
+        VK_RCONTROL, WM_KEYDOWN, WM_LBUTTONUP, WM_MOUSEHWHEEL, WM_MOUSEMOVE, WM_MOUSEWHEEL,
+        WM_XBUTTONDOWN,
+    };
+
+    fn mouse(data: u32, flags: u32) -> MSLLHOOKSTRUCT {
+        let mut from: MSLLHOOKSTRUCT = unsafe { zeroed() };
+        from.pt.x = -10;
+        from.pt.y = 20;
+        from.mouseData = data;
+        from.flags = flags;
+        from.time = 42;
+        from
+    }
+
+    #[test]
+    fn keyboard_event() {
+        let from = KBDLLHOOKSTRUCT {
+            vkCode: VK_RCONTROL as _,
+            scanCode: 0x1D,
+            flags: LLKHF_EXTENDED | LLKHF_UP | LLKHF_INJECTED,
+            time: 42,
+            dwExtraInfo: 0,
+        };
+        assert_eq!(
+            KeyboardEvent {
+                key: Some(Key::RightCtrl),
+                virtual_key_code: VK_RCONTROL as _,
+                scan_code: 0x1D,
+                up: true,
+                extended: true,
+                alt: false,
+                injected: true,
+                time: 42,
+            },
+            KeyboardEvent::from(&from),
+        );
+        let from = KBDLLHOOKSTRUCT {
+            vkCode: 0xFF,
+            scanCode: 0,
+            flags: 0,
+            time: 0,
+            dwExtraInfo: 0,
+        };
+        let event = KeyboardEvent::from(&from);
+        assert_eq!(None, event.key);
+        assert!(!event.up);
+    }
+
+    #[test]
+    fn mouse_event() {
+        let event = MouseEvent::new(WM_MOUSEMOVE, &mouse(0, LLMHF_INJECTED)).unwrap();
+        assert_eq!(MouseAction::Move, event.action);
+        assert_eq!(
+            (-10, 20, true, 42),
+            (event.x, event.y, event.injected, event.time)
+        );
+        assert_eq!(
+            MouseAction::Button {
+                button: Mouse::LeftButton,
+                up: true
+            },
+            MouseEvent::new(WM_LBUTTONUP, &mouse(0, 0)).unwrap().action,
+        );
+        assert_eq!(
+            MouseAction::Button {
+                button: Mouse::X2Button,
+                up: false
+            },
+            MouseEvent::new(WM_XBUTTONDOWN, &mouse(0x0002_0000, 0))
+                .unwrap()
+                .action,
+        );
+        assert_eq!(
+            MouseAction::Wheel {
+                delta: -120,
+                horizontal: false
+            },
+            MouseEvent::new(WM_MOUSEWHEEL, &mouse(0xFF88_0000, 0))
+                .unwrap()
+                .action,
+        );
+        assert_eq!(
+            MouseAction::Wheel {
+                delta: 240,
+                horizontal: true
+            },
+            MouseEvent::new(WM_MOUSEHWHEEL, &mouse(0x00F0_0000, 0))
+                .unwrap()
+                .action,
+        );
+        assert_eq!(
+            None,
+            MouseEvent::new(WM_XBUTTONDOWN, &mouse(0x0003_0000, 0))
+        );
+        assert_eq!(None, MouseEvent::new(WM_KEYDOWN, &mouse(0, 0)));
+    }
+}
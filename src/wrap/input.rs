@@ -0,0 +1,338 @@
+#[cfg(windows)]
+use super::key::NumericKeypad;
+use super::{
+    chord::Chord,
+    key::{Key, Mouse},
+};
+#[cfg(windows)]
+use crate::{um::winuser::send_input, Result};
+#[cfg(windows)]
+use std::mem::zeroed;
+#[cfg(windows)]
+use winapi::um::winuser::{
+    INPUT, INPUT_KEYBOARD, INPUT_MOUSE, KEYEVENTF_EXTENDEDKEY, KEYEVENTF_KEYUP, KEYEVENTF_SCANCODE,
+    KEYEVENTF_UNICODE, MOUSEEVENTF_ABSOLUTE, MOUSEEVENTF_HWHEEL, MOUSEEVENTF_LEFTDOWN,
+    MOUSEEVENTF_LEFTUP, MOUSEEVENTF_MIDDLEDOWN, MOUSEEVENTF_MIDDLEUP, MOUSEEVENTF_MOVE,
+    MOUSEEVENTF_RIGHTDOWN, MOUSEEVENTF_RIGHTUP, MOUSEEVENTF_WHEEL, MOUSEEVENTF_XDOWN,
+    MOUSEEVENTF_XUP, XBUTTON1, XBUTTON2,
+};
+
+/// Input.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Input {
+    /// A key by virtual key code.
+    Key { key: Key, up: bool },
+    /// A key by hardware scan code.
+    ScanCode {
+        scan_code: u16,
+        extended: bool,
+        up: bool,
+    },
+    /// A UTF-16 code unit, sent as a `VK_PACKET` keystroke.
+    Packet { character: u16, up: bool },
+    /// A mouse move, either relative in pixels or absolute in normalized
+    /// coordinates from 0 to 65535 over the primary monitor.
+    MouseMove { x: i32, y: i32, absolute: bool },
+    /// A mouse button.
+    MouseButton { button: Mouse, up: bool },
+    /// A mouse wheel rotation, in multiples of `WHEEL_DELTA` per notch.
+    MouseWheel { delta: i32, horizontal: bool },
+}
+
+impl Input {
+    /// The key down and up inputs.
+    pub fn click(key: Key) -> [Input; 2] {
+        [Input::Key { key, up: false }, Input::Key { key, up: true }]
+    }
+}
+
+/// The inputs pressing the modifiers, clicking the key and releasing the
+/// modifiers in reverse.
+pub fn chord(chord: Chord) -> Vec<Input> {
+    let modifiers = chord.modifiers.keys().collect::<Vec<_>>();
+    let mut inputs = Vec::with_capacity(2 * modifiers.len() + 2);
+    inputs.extend(modifiers.iter().map(|&key| Input::Key { key, up: false }));
+    inputs.extend(&Input::click(chord.key));
+    inputs.extend(
+        modifiers
+            .iter()
+            .rev()
+            .map(|&key| Input::Key { key, up: true }),
+    );
+    inputs
+}
+
+/// The inputs typing the text. Line breaks and tabs are typed as the `Enter`
+/// and `Tab` keys, everything else as packets.
+pub fn text(text: &str) -> Vec<Input> {
+    let mut inputs = Vec::with_capacity(2 * text.len());
+    let mut previous = None;
+    for character in text.chars() {
+        match character {
+            '\n' if previous == Some('\r') => {}
+            '\r' | '\n' => inputs.extend(&Input::click(Key::Enter)),
+            '\t' => inputs.extend(&Input::click(Key::Tab)),
+            _ => {
+                for &character in character.encode_utf16(&mut [0; 2]).iter() {
+                    inputs.push(Input::Packet {
+                        character,
+                        up: false,
+                    });
+                    inputs.push(Input::Packet {
+                        character,
+                        up: true,
+                    });
+                }
+            }
+        }
+        previous = Some(character);
+    }
+    inputs
+}
+
+/// Translates the inputs to `INPUT` structures for `send_input`.
+#[cfg(windows)]
+pub fn to_raw(inputs: &[Input]) -> Vec<INPUT> {
+    inputs.iter().map(|&input| raw(input)).collect()
+}
+
+/// Sends the inputs.
+#[cfg(windows)]
+pub fn send(inputs: &[Input]) -> Result<()> {
+    send_input().inputs(&to_raw(inputs)).call().map(drop)
+}
+
+/// Presses the chord.
+#[cfg(windows)]
+pub fn press(chord: Chord) -> Result<()> {
+    send(&self::chord(chord))
+}
+
+/// Types the text.
+#[cfg(windows)]
+pub fn type_text(text: &str) -> Result<()> {
+    send(&self::text(text))
+}
+
+#[cfg(windows)]
+fn raw(input: Input) -> INPUT {
+    let mut raw: INPUT = unsafe { zeroed() };
+    match input {
+        Input::Key { key, up } => {
+            raw.type_ = INPUT_KEYBOARD;
+            let ki = unsafe { raw.u.ki_mut() };
+            ki.wVk = i32::from(key) as _;
+            ki.dwFlags = key_up(up)
+                | if is_extended(key) {
+                    KEYEVENTF_EXTENDEDKEY
+                } else {
+                    0
+                };
+        }
+        Input::ScanCode {
+            scan_code,
+            extended,
+            up,
+        } => {
+            raw.type_ = INPUT_KEYBOARD;
+            let ki = unsafe { raw.u.ki_mut() };
+            ki.wScan = scan_code;
+            ki.dwFlags =
+                KEYEVENTF_SCANCODE | key_up(up) | if extended { KEYEVENTF_EXTENDEDKEY } else { 0 };
+        }
+        Input::Packet { character, up } => {
+            raw.type_ = INPUT_KEYBOARD;
+            let ki = unsafe { raw.u.ki_mut() };
+            ki.wScan = character;
+            ki.dwFlags = KEYEVENTF_UNICODE | key_up(up);
+        }
+        Input::MouseMove { x, y, absolute } => {
+            raw.type_ = INPUT_MOUSE;
+            let mi = unsafe { raw.u.mi_mut() };
+            mi.dx = x;
+            mi.dy = y;
+            mi.dwFlags = MOUSEEVENTF_MOVE;
+            if absolute {
+                mi.dwFlags |= MOUSEEVENTF_ABSOLUTE;
+            }
+        }
+        Input::MouseButton { button, up } => {
+            raw.type_ = INPUT_MOUSE;
+            let mi = unsafe { raw.u.mi_mut() };
+            let (down_flag, up_flag, data) = match button {
+                Mouse::LeftButton => (MOUSEEVENTF_LEFTDOWN, MOUSEEVENTF_LEFTUP, 0),
+                Mouse::RightButton => (MOUSEEVENTF_RIGHTDOWN, MOUSEEVENTF_RIGHTUP, 0),
+                Mouse::MiddleButton => (MOUSEEVENTF_MIDDLEDOWN, MOUSEEVENTF_MIDDLEUP, 0),
+                Mouse::X1Button => (MOUSEEVENTF_XDOWN, MOUSEEVENTF_XUP, XBUTTON1),
+                Mouse::X2Button => (MOUSEEVENTF_XDOWN, MOUSEEVENTF_XUP, XBUTTON2),
+            };
+            mi.dwFlags = if up { up_flag } else { down_flag };
+            mi.mouseData = data as _;
+        }
+        Input::MouseWheel { delta, horizontal } => {
+            raw.type_ = INPUT_MOUSE;
+            let mi = unsafe { raw.u.mi_mut() };
+            mi.dwFlags = if horizontal {
+                MOUSEEVENTF_HWHEEL
+            } else {
+                MOUSEEVENTF_WHEEL
+            };
+            mi.mouseData = delta as _;
+        }
+    }
+    raw
+}
+
+#[cfg(windows)]
+fn key_up(up: bool) -> u32 {
+    if up {
+        KEYEVENTF_KEYUP
+    } else {
+        0
+    }
+}
+
+/// Whether the key sits in the extended part of the keyboard, so its scan code
+/// is prefixed with `0xE0`.
+#[cfg(windows)]
+fn is_extended(key: Key) -> bool {
+    matches!(
+        key,
+        Key::RightCtrl
+            | Key::RightAlt
+            | Key::LeftWindows
+            | Key::RightWindows
+            | Key::Applications
+            | Key::Insert
+            | Key::Delete
+            | Key::Home
+            | Key::End
+            | Key::PageUp
+            | Key::PageDown
+            | Key::NumLock
+            | Key::PrintScreen
+            | Key::ControlBreak
+            | Key::CursorControl(_)
+            | Key::NumPad(NumericKeypad::Divide)
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    #[cfg(windows)]
+    use super::to_raw;
+    use super::{chord, text, Input};
+    use crate::wrap::key::Key;
+    #[cfg(windows)]
+    use crate::wrap::key::{CursorControl, Mouse};
+    #[cfg(windows)]
+    use winapi::um::winuser::{
+        INPUT_KEYBOARD, INPUT_MOUSE, KEYEVENTF_EXTENDEDKEY, KEYEVENTF_KEYUP, KEYEVENTF_UNICODE,
+        MOUSEEVENTF_ABSOLUTE, MOUSEEVENTF_MOVE, MOUSEEVENTF_WHEEL, MOUSEEVENTF_XUP, VK_CONTROL,
+        VK_LSHIFT, XBUTTON2,
+    };
+
+    fn down(key: Key) -> Input {
+        Input::Key { key, up: false }
+    }
+
+    fn up(key: Key) -> Input {
+        Input::Key { key, up: true }
+    }
+
+    #[test]
+    fn chord_inputs() {
+        assert_eq!(
+            vec![
+                down(Key::Ctrl),
+                down(Key::LeftShift),
+                down(Key::K),
+                up(Key::K),
+                up(Key::LeftShift),
+                up(Key::Ctrl),
+            ],
+            chord("Ctrl+LeftShift+K".parse().unwrap()),
+        );
+        assert_eq!(
+            vec![
+                down(Key::LeftWindows),
+                down(Key::L),
+                up(Key::L),
+                up(Key::LeftWindows)
+            ],
+            chord("Win+L".parse().unwrap()),
+        );
+    }
+
+    #[test]
+    fn text_inputs() {
+        let packet = |character, up| Input::Packet { character, up };
+        assert_eq!(
+            vec![
+                packet(0x61, false),
+                packet(0x61, true),
+                down(Key::Enter),
+                up(Key::Enter),
+                down(Key::Tab),
+                up(Key::Tab),
+                packet(0xD83D, false),
+                packet(0xD83D, true),
+                packet(0xDE00, false),
+                packet(0xDE00, true),
+            ],
+            text("a\r\n\t\u{1F600}"),
+        );
+        assert_eq!(4, text("\n\n").len());
+    }
+
+    #[cfg(windows)]
+    #[test]
+    fn raw_inputs() {
+        let raw = to_raw(&[
+            down(Key::Ctrl),
+            up(Key::CursorControl(CursorControl::LeftArrow)),
+            Input::Packet {
+                character: 0x263A,
+                up: false,
+            },
+            Input::MouseMove {
+                x: 32768,
+                y: 0,
+                absolute: true,
+            },
+            Input::MouseButton {
+                button: Mouse::X2Button,
+                up: true,
+            },
+            Input::MouseWheel {
+                delta: -120,
+                horizontal: false,
+            },
+        ]);
+        assert_eq!(6, raw.len());
+        unsafe {
+            assert_eq!(INPUT_KEYBOARD, raw[0].type_);
+            assert_eq!(VK_CONTROL as u16, raw[0].u.ki().wVk);
+            assert_eq!(0, raw[0].u.ki().dwFlags);
+            assert_eq!(
+                KEYEVENTF_KEYUP | KEYEVENTF_EXTENDEDKEY,
+                raw[1].u.ki().dwFlags
+            );
+            assert_eq!(0, raw[2].u.ki().wVk);
+            assert_eq!(0x263A, raw[2].u.ki().wScan);
+            assert_eq!(KEYEVENTF_UNICODE, raw[2].u.ki().dwFlags);
+            assert_eq!(INPUT_MOUSE, raw[3].type_);
+            assert_eq!(32768, raw[3].u.mi().dx);
+            assert_eq!(
+                MOUSEEVENTF_MOVE | MOUSEEVENTF_ABSOLUTE,
+                raw[3].u.mi().dwFlags
+            );
+            assert_eq!(MOUSEEVENTF_XUP, raw[4].u.mi().dwFlags);
+            assert_eq!(XBUTTON2 as u32, raw[4].u.mi().mouseData);
+            assert_eq!(MOUSEEVENTF_WHEEL, raw[5].u.mi().dwFlags);
+            assert_eq!(-120, raw[5].u.mi().mouseData as i32);
+        }
+        let raw = to_raw(&[down(Key::LeftShift)]);
+        assert_eq!(VK_LSHIFT as u16, unsafe { raw[0].u.ki().wVk });
+    }
+}
@@ -0,0 +1,1130 @@
+use self::vk::{
+    VK_ADD, VK_APPS, VK_ATTN, VK_BACK, VK_BROWSER_BACK, VK_BROWSER_FAVORITES, VK_BROWSER_FORWARD,
+    VK_BROWSER_HOME, VK_BROWSER_REFRESH, VK_BROWSER_SEARCH, VK_BROWSER_STOP, VK_CANCEL, VK_CAPITAL,
+    VK_CLEAR, VK_CONTROL, VK_CRSEL, VK_DECIMAL, VK_DELETE, VK_DIVIDE, VK_DOWN, VK_END, VK_EREOF,
+    VK_ESCAPE, VK_EXECUTE, VK_EXSEL, VK_F1, VK_F10, VK_F11, VK_F12, VK_F13, VK_F14, VK_F15, VK_F16,
+    VK_F17, VK_F18, VK_F19, VK_F2, VK_F20, VK_F21, VK_F22, VK_F23, VK_F24, VK_F3, VK_F4, VK_F5,
+    VK_F6, VK_F7, VK_F8, VK_F9, VK_HELP, VK_HOME, VK_INSERT, VK_LAUNCH_APP1, VK_LAUNCH_APP2,
+    VK_LAUNCH_MAIL, VK_LAUNCH_MEDIA_SELECT, VK_LBUTTON, VK_LCONTROL, VK_LEFT, VK_LMENU, VK_LSHIFT,
+    VK_LWIN, VK_MBUTTON, VK_MEDIA_NEXT_TRACK, VK_MEDIA_PLAY_PAUSE, VK_MEDIA_PREV_TRACK,
+    VK_MEDIA_STOP, VK_MENU, VK_MULTIPLY, VK_NEXT, VK_NONAME, VK_NUMLOCK, VK_NUMPAD0, VK_NUMPAD1,
+    VK_NUMPAD2, VK_NUMPAD3, VK_NUMPAD4, VK_NUMPAD5, VK_NUMPAD6, VK_NUMPAD7, VK_NUMPAD8, VK_NUMPAD9,
+    VK_OEM_1, VK_OEM_102, VK_OEM_2, VK_OEM_3, VK_OEM_4, VK_OEM_5, VK_OEM_6, VK_OEM_7, VK_OEM_8,
+    VK_OEM_CLEAR, VK_OEM_COMMA, VK_OEM_MINUS, VK_OEM_PERIOD, VK_OEM_PLUS, VK_PA1, VK_PACKET,
+    VK_PAUSE, VK_PLAY, VK_PRINT, VK_PRIOR, VK_RBUTTON, VK_RCONTROL, VK_RETURN, VK_RIGHT, VK_RMENU,
+    VK_RSHIFT, VK_RWIN, VK_SCROLL, VK_SELECT, VK_SEPARATOR, VK_SHIFT, VK_SLEEP, VK_SNAPSHOT,
+    VK_SPACE, VK_SUBTRACT, VK_TAB, VK_UP, VK_VOLUME_DOWN, VK_VOLUME_MUTE, VK_VOLUME_UP,
+    VK_XBUTTON1, VK_XBUTTON2, VK_ZOOM,
+};
+#[cfg(windows)]
+use crate::um::winuser::{GetAsyncKeyState, GetKeyState};
+use crate::{Error, Result};
+use std::{
+    convert::TryFrom,
+    fmt::{self, Display, Formatter},
+    str::FromStr,
+};
+
+mod vk;
+
+/// Key.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Key {
+    /// 0 key
+    Zero,
+    /// 1 key
+    One,
+    /// 2 key
+    Two,
+    /// 3 key
+    Three,
+    /// 4 key
+    Four,
+    /// 5 key
+    Five,
+    /// 6 key
+    Six,
+    /// 7 key
+    Seven,
+    /// 8 key
+    Eight,
+    /// 9 key
+    Nine,
+
+    /// A key
+    A,
+    /// B key
+    B,
+    /// C key
+    C,
+    /// D key
+    D,
+    /// E key
+    E,
+    /// F key
+    F,
+    /// G key
+    G,
+    /// H key
+    H,
+    /// I key
+    I,
+    /// J key
+    J,
+    /// K key
+    K,
+    /// L key
+    L,
+    /// M key
+    M,
+    /// N key
+    N,
+    /// O key
+    O,
+    /// P key
+    P,
+    /// Q key
+    Q,
+    /// R key
+    R,
+    /// S key
+    S,
+    /// T key
+    T,
+    /// U key
+    U,
+    /// V key
+    V,
+    /// W key
+    W,
+    /// X key
+    X,
+    /// Y key
+    Y,
+    /// Z key
+    Z,
+
+    /// ESC key
+    Escape,
+
+    /// BACKSPACE key
+    Backspace,
+    /// CAPS LOCK key
+    CapsLock,
+    /// ENTER key
+    Enter,
+    /// SPACEBAR
+    Spacebar,
+    /// TAB key
+    Tab,
+
+    /// ALT key
+    Alt,
+    /// CTRL key
+    Ctrl,
+    /// Left CONTROL key
+    LeftCtrl,
+    /// Right CONTROL key
+    RightCtrl,
+    /// SHIFT key
+    Shift,
+    /// Left SHIFT key
+    LeftShift,
+    /// Right SHIFT key
+    RightShift,
+    /// Left MENU key
+    LeftAlt,
+    /// Right MENU key
+    RightAlt,
+
+    /// Applications key (Natural keyboard)
+    Applications,
+    /// Left Windows key (Natural keyboard)
+    LeftWindows,
+    /// Right Windows key (Natural keyboard)
+    RightWindows,
+
+    /// END key
+    End,
+    /// DEL key
+    Delete,
+    /// HOME key
+    Home,
+    /// INS key
+    Insert,
+    /// PAGE UP key
+    PageUp,
+    /// PAGE DOWN key
+    PageDown,
+    /// PRINT SCREEN key
+    PrintScreen,
+
+    /// NUM LOCK key
+    NumLock,
+    /// SCROLL LOCK key
+    ScrollLock,
+
+    /// Control-break processing
+    ControlBreak,
+    /// Computer Sleep key
+    ComputerSleep,
+
+    /// CLEAR key
+    Clear,
+    /// PAUSE key
+    Pause,
+    /// SELECT key
+    Select,
+    /// PRINT key
+    Print,
+    /// EXECUTE key
+    Execute,
+    /// HELP key
+    Help,
+
+    /// Used to pass Unicode characters as if they were keystrokes. The VK_PACKET key is the low word of a 32-bit Virtual Key value used for non-keyboard input methods. For more information, see Remark in KEYBDINPUT, SendInput, WM_KEYDOWN, and WM_KEYUP
+    Packet,
+    /// Attn key
+    Attn,
+    /// CrSel key
+    CrSel,
+    /// ExSel key
+    ExSel,
+    /// Erase EOF key
+    EraseEof,
+    /// Play key
+    Play,
+    /// Zoom key
+    Zoom,
+    /// Reserved
+    Noname,
+    /// PA1 key
+    PA1,
+
+    /// Browser
+    Browser(Browser),
+    /// Cursor control
+    CursorControl(CursorControl),
+    /// Function
+    Function(Function),
+    /// Launch
+    Launch(Launch),
+    /// Media
+    Media(Media),
+    /// Mouse
+    Mouse(Mouse),
+    /// NumericKeypad
+    NumPad(NumericKeypad),
+    /// Volume
+    Volume(Volume),
+    /// OEM
+    Oem(Oem),
+    // /// IME
+    // Ime(Ime),
+}
+
+impl Key {
+    /// The canonical name, as formatted and parsed.
+    pub fn name(self) -> &'static str {
+        match self {
+            Key::Zero => "0",
+            Key::One => "1",
+            Key::Two => "2",
+            Key::Three => "3",
+            Key::Four => "4",
+            Key::Five => "5",
+            Key::Six => "6",
+            Key::Seven => "7",
+            Key::Eight => "8",
+            Key::Nine => "9",
+            Key::A => "A",
+            Key::B => "B",
+            Key::C => "C",
+            Key::D => "D",
+            Key::E => "E",
+            Key::F => "F",
+            Key::G => "G",
+            Key::H => "H",
+            Key::I => "I",
+            Key::J => "J",
+            Key::K => "K",
+            Key::L => "L",
+            Key::M => "M",
+            Key::N => "N",
+            Key::O => "O",
+            Key::P => "P",
+            Key::Q => "Q",
+            Key::R => "R",
+            Key::S => "S",
+            Key::T => "T",
+            Key::U => "U",
+            Key::V => "V",
+            Key::W => "W",
+            Key::X => "X",
+            Key::Y => "Y",
+            Key::Z => "Z",
+            Key::Escape => "Escape",
+            Key::Backspace => "Backspace",
+            Key::CapsLock => "CapsLock",
+            Key::Enter => "Enter",
+            Key::Spacebar => "Space",
+            Key::Tab => "Tab",
+            Key::Alt => "Alt",
+            Key::Ctrl => "Ctrl",
+            Key::LeftCtrl => "LeftCtrl",
+            Key::RightCtrl => "RightCtrl",
+            Key::Shift => "Shift",
+            Key::LeftShift => "LeftShift",
+            Key::RightShift => "RightShift",
+            Key::LeftAlt => "LeftAlt",
+            Key::RightAlt => "RightAlt",
+            Key::Applications => "Applications",
+            Key::LeftWindows => "LeftWindows",
+            Key::RightWindows => "RightWindows",
+            Key::End => "End",
+            Key::Delete => "Delete",
+            Key::Home => "Home",
+            Key::Insert => "Insert",
+            Key::PageUp => "PageUp",
+            Key::PageDown => "PageDown",
+            Key::PrintScreen => "PrintScreen",
+            Key::NumLock => "NumLock",
+            Key::ScrollLock => "ScrollLock",
+            Key::ControlBreak => "ControlBreak",
+            Key::ComputerSleep => "Sleep",
+            Key::Clear => "Clear",
+            Key::Pause => "Pause",
+            Key::Select => "Select",
+            Key::Print => "Print",
+            Key::Execute => "Execute",
+            Key::Help => "Help",
+            Key::Packet => "Packet",
+            Key::Attn => "Attn",
+            Key::CrSel => "CrSel",
+            Key::ExSel => "ExSel",
+            Key::EraseEof => "EraseEof",
+            Key::Play => "Play",
+            Key::Zoom => "Zoom",
+            Key::Noname => "Noname",
+            Key::PA1 => "PA1",
+            Key::Browser(Browser::Back) => "BrowserBack",
+            Key::Browser(Browser::Forward) => "BrowserForward",
+            Key::Browser(Browser::Refresh) => "BrowserRefresh",
+            Key::Browser(Browser::Stop) => "BrowserStop",
+            Key::Browser(Browser::Search) => "BrowserSearch",
+            Key::Browser(Browser::Favorites) => "BrowserFavorites",
+            Key::Browser(Browser::Home) => "BrowserHome",
+            Key::CursorControl(CursorControl::LeftArrow) => "Left",
+            Key::CursorControl(CursorControl::UpArrow) => "Up",
+            Key::CursorControl(CursorControl::RightArrow) => "Right",
+            Key::CursorControl(CursorControl::DownArrow) => "Down",
+            Key::Function(Function::F1) => "F1",
+            Key::Function(Function::F2) => "F2",
+            Key::Function(Function::F3) => "F3",
+            Key::Function(Function::F4) => "F4",
+            Key::Function(Function::F5) => "F5",
+            Key::Function(Function::F6) => "F6",
+            Key::Function(Function::F7) => "F7",
+            Key::Function(Function::F8) => "F8",
+            Key::Function(Function::F9) => "F9",
+            Key::Function(Function::F10) => "F10",
+            Key::Function(Function::F11) => "F11",
+            Key::Function(Function::F12) => "F12",
+            Key::Function(Function::F13) => "F13",
+            Key::Function(Function::F14) => "F14",
+            Key::Function(Function::F15) => "F15",
+            Key::Function(Function::F16) => "F16",
+            Key::Function(Function::F17) => "F17",
+            Key::Function(Function::F18) => "F18",
+            Key::Function(Function::F19) => "F19",
+            Key::Function(Function::F20) => "F20",
+            Key::Function(Function::F21) => "F21",
+            Key::Function(Function::F22) => "F22",
+            Key::Function(Function::F23) => "F23",
+            Key::Function(Function::F24) => "F24",
+            Key::Launch(Launch::Mail) => "LaunchMail",
+            Key::Launch(Launch::MediaSelect) => "LaunchMediaSelect",
+            Key::Launch(Launch::Application1) => "LaunchApplication1",
+            Key::Launch(Launch::Application2) => "LaunchApplication2",
+            Key::Media(Media::NextTrack) => "MediaNextTrack",
+            Key::Media(Media::PreviousTrack) => "MediaPreviousTrack",
+            Key::Media(Media::Stop) => "MediaStop",
+            Key::Media(Media::PlayPause) => "MediaPlayPause",
+            Key::Mouse(Mouse::LeftButton) => "LeftButton",
+            Key::Mouse(Mouse::RightButton) => "RightButton",
+            Key::Mouse(Mouse::MiddleButton) => "MiddleButton",
+            Key::Mouse(Mouse::X1Button) => "X1Button",
+            Key::Mouse(Mouse::X2Button) => "X2Button",
+            Key::NumPad(NumericKeypad::Add) => "NumPad+",
+            Key::NumPad(NumericKeypad::Decimal) => "NumPad.",
+            Key::NumPad(NumericKeypad::Divide) => "NumPad/",
+            Key::NumPad(NumericKeypad::Multiply) => "NumPad*",
+            Key::NumPad(NumericKeypad::Separator) => "NumPadSeparator",
+            Key::NumPad(NumericKeypad::Subtract) => "NumPad-",
+            Key::NumPad(NumericKeypad::Zero) => "NumPad0",
+            Key::NumPad(NumericKeypad::One) => "NumPad1",
+            Key::NumPad(NumericKeypad::Two) => "NumPad2",
+            Key::NumPad(NumericKeypad::Three) => "NumPad3",
+            Key::NumPad(NumericKeypad::Four) => "NumPad4",
+            Key::NumPad(NumericKeypad::Five) => "NumPad5",
+            Key::NumPad(NumericKeypad::Six) => "NumPad6",
+            Key::NumPad(NumericKeypad::Seven) => "NumPad7",
+            Key::NumPad(NumericKeypad::Eight) => "NumPad8",
+            Key::NumPad(NumericKeypad::Nine) => "NumPad9",
+            Key::Oem(Oem::_1) => "Oem1",
+            Key::Oem(Oem::_2) => "Oem2",
+            Key::Oem(Oem::_3) => "Oem3",
+            Key::Oem(Oem::_4) => "Oem4",
+            Key::Oem(Oem::_5) => "Oem5",
+            Key::Oem(Oem::_6) => "Oem6",
+            Key::Oem(Oem::_7) => "Oem7",
+            Key::Oem(Oem::_8) => "Oem8",
+            Key::Oem(Oem::_102) => "Oem102",
+            Key::Oem(Oem::Clear) => "OemClear",
+            Key::Oem(Oem::Comma) => "OemComma",
+            Key::Oem(Oem::Minus) => "OemMinus",
+            Key::Oem(Oem::Period) => "OemPeriod",
+            Key::Oem(Oem::Plus) => "OemPlus",
+            Key::Volume(Volume::Mute) => "VolumeMute",
+            Key::Volume(Volume::Down) => "VolumeDown",
+            Key::Volume(Volume::Up) => "VolumeUp",
+        }
+    }
+
+    #[cfg(windows)]
+    pub fn absolute(self) -> bool {
+        self.async_state_lossy().0
+    }
+
+    #[cfg(windows)]
+    pub fn async_state(self) -> Result<(bool, bool)> {
+        GetAsyncKeyState::builder().key(self).call()
+    }
+
+    #[cfg(windows)]
+    pub fn async_state_lossy(self) -> (bool, bool) {
+        self.async_state().unwrap_or_default()
+    }
+
+    #[cfg(windows)]
+    pub fn relative(self) -> bool {
+        self.async_state_lossy().1
+    }
+
+    #[cfg(windows)]
+    pub fn state(self) -> (bool, bool) {
+        GetKeyState::builder().virtual_key(self).call()
+    }
+}
+
+impl From<Browser> for Key {
+    fn from(from: Browser) -> Self {
+        Key::Browser(from)
+    }
+}
+
+impl From<CursorControl> for Key {
+    fn from(from: CursorControl) -> Self {
+        Key::CursorControl(from)
+    }
+}
+
+impl From<Function> for Key {
+    fn from(from: Function) -> Self {
+        Key::Function(from)
+    }
+}
+
+impl From<Launch> for Key {
+    fn from(from: Launch) -> Self {
+        Key::Launch(from)
+    }
+}
+
+impl From<Media> for Key {
+    fn from(from: Media) -> Self {
+        Key::Media(from)
+    }
+}
+
+impl From<Mouse> for Key {
+    fn from(from: Mouse) -> Self {
+        Key::Mouse(from)
+    }
+}
+
+impl From<NumericKeypad> for Key {
+    fn from(from: NumericKeypad) -> Self {
+        Key::NumPad(from)
+    }
+}
+
+impl From<Oem> for Key {
+    fn from(from: Oem) -> Self {
+        Key::Oem(from)
+    }
+}
+
+impl From<Volume> for Key {
+    fn from(from: Volume) -> Self {
+        Key::Volume(from)
+    }
+}
+
+impl From<Key> for i32 {
+    fn from(from: Key) -> Self {
+        match from {
+            Key::Zero => 0x30,
+            Key::One => 0x31,
+            Key::Two => 0x32,
+            Key::Three => 0x33,
+            Key::Four => 0x34,
+            Key::Five => 0x35,
+            Key::Six => 0x36,
+            Key::Seven => 0x37,
+            Key::Eight => 0x38,
+            Key::Nine => 0x39,
+            Key::A => 0x41,
+            Key::B => 0x42,
+            Key::C => 0x43,
+            Key::D => 0x44,
+            Key::E => 0x45,
+            Key::F => 0x46,
+            Key::G => 0x47,
+            Key::H => 0x48,
+            Key::I => 0x49,
+            Key::J => 0x4A,
+            Key::K => 0x4B,
+            Key::L => 0x4C,
+            Key::M => 0x4D,
+            Key::N => 0x4E,
+            Key::O => 0x4F,
+            Key::P => 0x50,
+            Key::Q => 0x51,
+            Key::R => 0x52,
+            Key::S => 0x53,
+            Key::T => 0x54,
+            Key::U => 0x55,
+            Key::V => 0x56,
+            Key::W => 0x57,
+            Key::X => 0x58,
+            Key::Y => 0x59,
+            Key::Z => 0x5A,
+            Key::Escape => VK_ESCAPE,
+            Key::Backspace => VK_BACK,
+            Key::CapsLock => VK_CAPITAL,
+            Key::Enter => VK_RETURN,
+            Key::Spacebar => VK_SPACE,
+            Key::Tab => VK_TAB,
+            Key::Alt => VK_MENU,
+            Key::Ctrl => VK_CONTROL,
+            Key::LeftCtrl => VK_LCONTROL,
+            Key::RightCtrl => VK_RCONTROL,
+            Key::Shift => VK_SHIFT,
+            Key::LeftShift => VK_LSHIFT,
+            Key::RightShift => VK_RSHIFT,
+            Key::LeftAlt => VK_LMENU,
+            Key::RightAlt => VK_RMENU,
+            Key::Applications => VK_APPS,
+            Key::LeftWindows => VK_LWIN,
+            Key::RightWindows => VK_RWIN,
+            Key::End => VK_END,
+            Key::Delete => VK_DELETE,
+            Key::Home => VK_HOME,
+            Key::Insert => VK_INSERT,
+            Key::PageUp => VK_PRIOR,
+            Key::PageDown => VK_NEXT,
+            Key::PrintScreen => VK_SNAPSHOT,
+            Key::NumLock => VK_NUMLOCK,
+            Key::ScrollLock => VK_SCROLL,
+            Key::ControlBreak => VK_CANCEL,
+            Key::ComputerSleep => VK_SLEEP,
+            Key::Clear => VK_CLEAR,
+            Key::Pause => VK_PAUSE,
+            Key::Select => VK_SELECT,
+            Key::Print => VK_PRINT,
+            Key::Execute => VK_EXECUTE,
+            Key::Help => VK_HELP,
+            Key::Packet => VK_PACKET,
+            Key::Attn => VK_ATTN,
+            Key::CrSel => VK_CRSEL,
+            Key::ExSel => VK_EXSEL,
+            Key::EraseEof => VK_EREOF,
+            Key::Play => VK_PLAY,
+            Key::Zoom => VK_ZOOM,
+            Key::Noname => VK_NONAME,
+            Key::PA1 => VK_PA1,
+            // Browser.
+            Key::Browser(Browser::Back) => VK_BROWSER_BACK,
+            Key::Browser(Browser::Forward) => VK_BROWSER_FORWARD,
+            Key::Browser(Browser::Refresh) => VK_BROWSER_REFRESH,
+            Key::Browser(Browser::Stop) => VK_BROWSER_STOP,
+            Key::Browser(Browser::Search) => VK_BROWSER_SEARCH,
+            Key::Browser(Browser::Favorites) => VK_BROWSER_FAVORITES,
+            Key::Browser(Browser::Home) => VK_BROWSER_HOME,
+            // Cursor control.
+            Key::CursorControl(CursorControl::LeftArrow) => VK_LEFT,
+            Key::CursorControl(CursorControl::UpArrow) => VK_UP,
+            Key::CursorControl(CursorControl::RightArrow) => VK_RIGHT,
+            Key::CursorControl(CursorControl::DownArrow) => VK_DOWN,
+            // Function.
+            Key::Function(Function::F1) => VK_F1,
+            Key::Function(Function::F2) => VK_F2,
+            Key::Function(Function::F3) => VK_F3,
+            Key::Function(Function::F4) => VK_F4,
+            Key::Function(Function::F5) => VK_F5,
+            Key::Function(Function::F6) => VK_F6,
+            Key::Function(Function::F7) => VK_F7,
+            Key::Function(Function::F8) => VK_F8,
+            Key::Function(Function::F9) => VK_F9,
+            Key::Function(Function::F10) => VK_F10,
+            Key::Function(Function::F11) => VK_F11,
+            Key::Function(Function::F12) => VK_F12,
+            Key::Function(Function::F13) => VK_F13,
+            Key::Function(Function::F14) => VK_F14,
+            Key::Function(Function::F15) => VK_F15,
+            Key::Function(Function::F16) => VK_F16,
+            Key::Function(Function::F17) => VK_F17,
+            Key::Function(Function::F18) => VK_F18,
+            Key::Function(Function::F19) => VK_F19,
+            Key::Function(Function::F20) => VK_F20,
+            Key::Function(Function::F21) => VK_F21,
+            Key::Function(Function::F22) => VK_F22,
+            Key::Function(Function::F23) => VK_F23,
+            Key::Function(Function::F24) => VK_F24,
+            // Launch.
+            Key::Launch(Launch::Mail) => VK_LAUNCH_MAIL,
+            Key::Launch(Launch::MediaSelect) => VK_LAUNCH_MEDIA_SELECT,
+            Key::Launch(Launch::Application1) => VK_LAUNCH_APP1,
+            Key::Launch(Launch::Application2) => VK_LAUNCH_APP2,
+            // Media.
+            Key::Media(Media::NextTrack) => VK_MEDIA_NEXT_TRACK,
+            Key::Media(Media::PreviousTrack) => VK_MEDIA_PREV_TRACK,
+            Key::Media(Media::Stop) => VK_MEDIA_STOP,
+            Key::Media(Media::PlayPause) => VK_MEDIA_PLAY_PAUSE,
+            // Mouse.
+            Key::Mouse(Mouse::LeftButton) => VK_LBUTTON,
+            Key::Mouse(Mouse::RightButton) => VK_RBUTTON,
+            Key::Mouse(Mouse::MiddleButton) => VK_MBUTTON,
+            Key::Mouse(Mouse::X1Button) => VK_XBUTTON1,
+            Key::Mouse(Mouse::X2Button) => VK_XBUTTON2,
+            // Numeric keypad.
+            Key::NumPad(NumericKeypad::Add) => VK_ADD,
+            Key::NumPad(NumericKeypad::Decimal) => VK_DECIMAL,
+            Key::NumPad(NumericKeypad::Divide) => VK_DIVIDE,
+            Key::NumPad(NumericKeypad::Multiply) => VK_MULTIPLY,
+            Key::NumPad(NumericKeypad::Separator) => VK_SEPARATOR,
+            Key::NumPad(NumericKeypad::Subtract) => VK_SUBTRACT,
+            Key::NumPad(NumericKeypad::Zero) => VK_NUMPAD0,
+            Key::NumPad(NumericKeypad::One) => VK_NUMPAD1,
+            Key::NumPad(NumericKeypad::Two) => VK_NUMPAD2,
+            Key::NumPad(NumericKeypad::Three) => VK_NUMPAD3,
+            Key::NumPad(NumericKeypad::Four) => VK_NUMPAD4,
+            Key::NumPad(NumericKeypad::Five) => VK_NUMPAD5,
+            Key::NumPad(NumericKeypad::Six) => VK_NUMPAD6,
+            Key::NumPad(NumericKeypad::Seven) => VK_NUMPAD7,
+            Key::NumPad(NumericKeypad::Eight) => VK_NUMPAD8,
+            Key::NumPad(NumericKeypad::Nine) => VK_NUMPAD9,
+            // Oem.
+            Key::Oem(Oem::_1) => VK_OEM_1,
+            Key::Oem(Oem::_2) => VK_OEM_2,
+            Key::Oem(Oem::_3) => VK_OEM_3,
+            Key::Oem(Oem::_4) => VK_OEM_4,
+            Key::Oem(Oem::_5) => VK_OEM_5,
+            Key::Oem(Oem::_6) => VK_OEM_6,
+            Key::Oem(Oem::_7) => VK_OEM_7,
+            Key::Oem(Oem::_8) => VK_OEM_8,
+            Key::Oem(Oem::_102) => VK_OEM_102,
+            Key::Oem(Oem::Clear) => VK_OEM_CLEAR,
+            Key::Oem(Oem::Comma) => VK_OEM_COMMA,
+            Key::Oem(Oem::Minus) => VK_OEM_MINUS,
+            Key::Oem(Oem::Period) => VK_OEM_PERIOD,
+            Key::Oem(Oem::Plus) => VK_OEM_PLUS,
+            // Volume.
+            Key::Volume(Volume::Mute) => VK_VOLUME_MUTE,
+            Key::Volume(Volume::Down) => VK_VOLUME_DOWN,
+            Key::Volume(Volume::Up) => VK_VOLUME_UP,
+        }
+    }
+}
+
+impl TryFrom<i32> for Key {
+    type Error = Error;
+
+    fn try_from(from: i32) -> Result<Self> {
+        Ok(match from {
+            0x30 => Key::Zero,
+            0x31 => Key::One,
+            0x32 => Key::Two,
+            0x33 => Key::Three,
+            0x34 => Key::Four,
+            0x35 => Key::Five,
+            0x36 => Key::Six,
+            0x37 => Key::Seven,
+            0x38 => Key::Eight,
+            0x39 => Key::Nine,
+            0x41 => Key::A,
+            0x42 => Key::B,
+            0x43 => Key::C,
+            0x44 => Key::D,
+            0x45 => Key::E,
+            0x46 => Key::F,
+            0x47 => Key::G,
+            0x48 => Key::H,
+            0x49 => Key::I,
+            0x4A => Key::J,
+            0x4B => Key::K,
+            0x4C => Key::L,
+            0x4D => Key::M,
+            0x4E => Key::N,
+            0x4F => Key::O,
+            0x50 => Key::P,
+            0x51 => Key::Q,
+            0x52 => Key::R,
+            0x53 => Key::S,
+            0x54 => Key::T,
+            0x55 => Key::U,
+            0x56 => Key::V,
+            0x57 => Key::W,
+            0x58 => Key::X,
+            0x59 => Key::Y,
+            0x5A => Key::Z,
+            VK_ESCAPE => Key::Escape,
+            VK_BACK => Key::Backspace,
+            VK_CAPITAL => Key::CapsLock,
+            VK_RETURN => Key::Enter,
+            VK_SPACE => Key::Spacebar,
+            VK_TAB => Key::Tab,
+            VK_MENU => Key::Alt,
+            VK_CONTROL => Key::Ctrl,
+            VK_LCONTROL => Key::LeftCtrl,
+            VK_RCONTROL => Key::RightCtrl,
+            VK_SHIFT => Key::Shift,
+            VK_LSHIFT => Key::LeftShift,
+            VK_RSHIFT => Key::RightShift,
+            VK_LMENU => Key::LeftAlt,
+            VK_RMENU => Key::RightAlt,
+            VK_APPS => Key::Applications,
+            VK_LWIN => Key::LeftWindows,
+            VK_RWIN => Key::RightWindows,
+            VK_END => Key::End,
+            VK_DELETE => Key::Delete,
+            VK_HOME => Key::Home,
+            VK_INSERT => Key::Insert,
+            VK_PRIOR => Key::PageUp,
+            VK_NEXT => Key::PageDown,
+            VK_SNAPSHOT => Key::PrintScreen,
+            VK_NUMLOCK => Key::NumLock,
+            VK_SCROLL => Key::ScrollLock,
+            VK_CANCEL => Key::ControlBreak,
+            VK_SLEEP => Key::ComputerSleep,
+            VK_CLEAR => Key::Clear,
+            VK_PAUSE => Key::Pause,
+            VK_SELECT => Key::Select,
+            VK_PRINT => Key::Print,
+            VK_EXECUTE => Key::Execute,
+            VK_HELP => Key::Help,
+            VK_PACKET => Key::Packet,
+            VK_ATTN => Key::Attn,
+            VK_CRSEL => Key::CrSel,
+            VK_EXSEL => Key::ExSel,
+            VK_EREOF => Key::EraseEof,
+            VK_PLAY => Key::Play,
+            VK_ZOOM => Key::Zoom,
+            VK_NONAME => Key::Noname,
+            VK_PA1 => Key::PA1,
+            // Browser.
+            VK_BROWSER_BACK => Key::Browser(Browser::Back),
+            VK_BROWSER_FORWARD => Key::Browser(Browser::Forward),
+            VK_BROWSER_REFRESH => Key::Browser(Browser::Refresh),
+            VK_BROWSER_STOP => Key::Browser(Browser::Stop),
+            VK_BROWSER_SEARCH => Key::Browser(Browser::Search),
+            VK_BROWSER_FAVORITES => Key::Browser(Browser::Favorites),
+            VK_BROWSER_HOME => Key::Browser(Browser::Home),
+            // Cursor control.
+            VK_LEFT => Key::CursorControl(CursorControl::LeftArrow),
+            VK_UP => Key::CursorControl(CursorControl::UpArrow),
+            VK_RIGHT => Key::CursorControl(CursorControl::RightArrow),
+            VK_DOWN => Key::CursorControl(CursorControl::DownArrow),
+            // Function.
+            VK_F1 => Key::Function(Function::F1),
+            VK_F2 => Key::Function(Function::F2),
+            VK_F3 => Key::Function(Function::F3),
+            VK_F4 => Key::Function(Function::F4),
+            VK_F5 => Key::Function(Function::F5),
+            VK_F6 => Key::Function(Function::F6),
+            VK_F7 => Key::Function(Function::F7),
+            VK_F8 => Key::Function(Function::F8),
+            VK_F9 => Key::Function(Function::F9),
+            VK_F10 => Key::Function(Function::F10),
+            VK_F11 => Key::Function(Function::F11),
+            VK_F12 => Key::Function(Function::F12),
+            VK_F13 => Key::Function(Function::F13),
+            VK_F14 => Key::Function(Function::F14),
+            VK_F15 => Key::Function(Function::F15),
+            VK_F16 => Key::Function(Function::F16),
+            VK_F17 => Key::Function(Function::F17),
+            VK_F18 => Key::Function(Function::F18),
+            VK_F19 => Key::Function(Function::F19),
+            VK_F20 => Key::Function(Function::F20),
+            VK_F21 => Key::Function(Function::F21),
+            VK_F22 => Key::Function(Function::F22),
+            VK_F23 => Key::Function(Function::F23),
+            VK_F24 => Key::Function(Function::F24),
+            // Launch.
+            VK_LAUNCH_MAIL => Key::Launch(Launch::Mail),
+            VK_LAUNCH_MEDIA_SELECT => Key::Launch(Launch::MediaSelect),
+            VK_LAUNCH_APP1 => Key::Launch(Launch::Application1),
+            VK_LAUNCH_APP2 => Key::Launch(Launch::Application2),
+            // Media.
+            VK_MEDIA_NEXT_TRACK => Key::Media(Media::NextTrack),
+            VK_MEDIA_PREV_TRACK => Key::Media(Media::PreviousTrack),
+            VK_MEDIA_STOP => Key::Media(Media::Stop),
+            VK_MEDIA_PLAY_PAUSE => Key::Media(Media::PlayPause),
+            // Mouse.
+            VK_LBUTTON => Key::Mouse(Mouse::LeftButton),
+            VK_RBUTTON => Key::Mouse(Mouse::RightButton),
+            VK_MBUTTON => Key::Mouse(Mouse::MiddleButton),
+            VK_XBUTTON1 => Key::Mouse(Mouse::X1Button),
+            VK_XBUTTON2 => Key::Mouse(Mouse::X2Button),
+            // Numeric keypad.
+            VK_ADD => Key::NumPad(NumericKeypad::Add),
+            VK_DECIMAL => Key::NumPad(NumericKeypad::Decimal),
+            VK_DIVIDE => Key::NumPad(NumericKeypad::Divide),
+            VK_MULTIPLY => Key::NumPad(NumericKeypad::Multiply),
+            VK_SEPARATOR => Key::NumPad(NumericKeypad::Separator),
+            VK_SUBTRACT => Key::NumPad(NumericKeypad::Subtract),
+            VK_NUMPAD0 => Key::NumPad(NumericKeypad::Zero),
+            VK_NUMPAD1 => Key::NumPad(NumericKeypad::One),
+            VK_NUMPAD2 => Key::NumPad(NumericKeypad::Two),
+            VK_NUMPAD3 => Key::NumPad(NumericKeypad::Three),
+            VK_NUMPAD4 => Key::NumPad(NumericKeypad::Four),
+            VK_NUMPAD5 => Key::NumPad(NumericKeypad::Five),
+            VK_NUMPAD6 => Key::NumPad(NumericKeypad::Six),
+            VK_NUMPAD7 => Key::NumPad(NumericKeypad::Seven),
+            VK_NUMPAD8 => Key::NumPad(NumericKeypad::Eight),
+            VK_NUMPAD9 => Key::NumPad(NumericKeypad::Nine),
+            // Oem.
+            VK_OEM_1 => Key::Oem(Oem::_1),
+            VK_OEM_2 => Key::Oem(Oem::_2),
+            VK_OEM_3 => Key::Oem(Oem::_3),
+            VK_OEM_4 => Key::Oem(Oem::_4),
+            VK_OEM_5 => Key::Oem(Oem::_5),
+            VK_OEM_6 => Key::Oem(Oem::_6),
+            VK_OEM_7 => Key::Oem(Oem::_7),
+            VK_OEM_8 => Key::Oem(Oem::_8),
+            VK_OEM_102 => Key::Oem(Oem::_102),
+            VK_OEM_CLEAR => Key::Oem(Oem::Clear),
+            VK_OEM_COMMA => Key::Oem(Oem::Comma),
+            VK_OEM_MINUS => Key::Oem(Oem::Minus),
+            VK_OEM_PERIOD => Key::Oem(Oem::Period),
+            VK_OEM_PLUS => Key::Oem(Oem::Plus),
+            // Volume.
+            VK_VOLUME_MUTE => Key::Volume(Volume::Mute),
+            VK_VOLUME_DOWN => Key::Volume(Volume::Down),
+            VK_VOLUME_UP => Key::Volume(Volume::Up),
+            _ => return Err(Error::unexpected_value("virtual key code", from)),
+        })
+    }
+}
+
+impl Display for Key {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+impl FromStr for Key {
+    type Err = Error;
+
+    /// Parses the canonical name or a common alias, ignoring the case.
+    fn from_str(s: &str) -> Result<Self> {
+        let alias = match &*s.to_ascii_lowercase() {
+            "control" => Some(Key::Ctrl),
+            "del" => Some(Key::Delete),
+            "esc" => Some(Key::Escape),
+            "ins" => Some(Key::Insert),
+            "pgdn" => Some(Key::PageDown),
+            "pgup" => Some(Key::PageUp),
+            "return" => Some(Key::Enter),
+            "spacebar" => Some(Key::Spacebar),
+            _ => None,
+        };
+        alias
+            .or_else(|| {
+                (0..=0xFF)
+                    .filter_map(|code| Key::try_from(code).ok())
+                    .find(|key| key.name().eq_ignore_ascii_case(s))
+            })
+            .ok_or_else(|| Error::unknown_name("key name", s))
+    }
+}
+
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Browser {
+    /// Browser Back key.
+    Back,
+    /// Browser Forward key.
+    Forward,
+    /// Browser Refresh key.
+    Refresh,
+    /// Browser Stop key.
+    Stop,
+    /// Browser Search key.
+    Search,
+    /// Browser Favorites key.
+    Favorites,
+    /// Browser Start and Home key.
+    Home,
+}
+
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum CursorControl {
+    /// LEFT ARROW key.
+    LeftArrow,
+    /// UP ARROW key.
+    UpArrow,
+    /// RIGHT ARROW key.
+    RightArrow,
+    /// DOWN ARROW key.
+    DownArrow,
+}
+
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Function {
+    /// F1 key.
+    F1,
+    /// F2 key.
+    F2,
+    /// F3 key.
+    F3,
+    /// F4 key.
+    F4,
+    /// F5 key.
+    F5,
+    /// F6 key.
+    F6,
+    /// F7 key.
+    F7,
+    /// F8 key.
+    F8,
+    /// F9 key.
+    F9,
+    /// F10 key.
+    F10,
+    /// F11 key.
+    F11,
+    /// F12 key.
+    F12,
+    /// F13 key.
+    F13,
+    /// F14 key.
+    F14,
+    /// F15 key.
+    F15,
+    /// F16 key.
+    F16,
+    /// F17 key.
+    F17,
+    /// F18 key.
+    F18,
+    /// F19 key.
+    F19,
+    /// F20 key.
+    F20,
+    /// F21 key.
+    F21,
+    /// F22 key.
+    F22,
+    /// F23 key.
+    F23,
+    /// F24 key.
+    F24,
+}
+
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Ime {
+    /// IME convert.
+    Convert,
+    /// IME nonconvert.
+    NonConvert,
+    /// IME accept.
+    Accept,
+    /// IME mode change request.
+    ModeChangeRequest,
+    /// IME Kana mode.
+    KanaMode,
+    /// IME Hanguel mode (maintained for compatibility; use VK_HANGUL).
+    HanguelMode,
+    /// IME Hangul mode.
+    HangulMode,
+    /// IME Junja mode.
+    JunjaMode,
+    /// IME final mode.
+    FinalMode,
+    /// IME Hanja mode.
+    HanjaMode,
+    /// IME Kanji mode.
+    KanjiMode,
+    /// IME PROCESS key.
+    Process,
+}
+
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Launch {
+    /// Start Mail key.
+    Mail,
+    /// Select Media key.
+    MediaSelect,
+    /// Start Application 1 key.
+    Application1,
+    /// Start Application 2 key.
+    Application2,
+}
+
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Media {
+    /// Next Track key.
+    NextTrack,
+    /// Previous Track key.
+    PreviousTrack,
+    /// Stop Media key.
+    Stop,
+    /// Play/Pause Media key.
+    PlayPause,
+}
+
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Mouse {
+    /// Left mouse button.
+    LeftButton,
+    /// Right mouse button.
+    RightButton,
+    /// Middle mouse button (three-button mouse).
+    MiddleButton,
+    /// X1 mouse button.
+    X1Button,
+    /// X2 mouse button.
+    X2Button,
+}
+
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum NumericKeypad {
+    /// Add key (+).
+    Add,
+    /// Decimal key (.).
+    Decimal,
+    /// Divide key (/).
+    Divide,
+    /// Multiply key (*).
+    Multiply,
+    /// Separator key (\).
+    Separator,
+    /// Subtract key (-).
+    Subtract,
+
+    /// Numeric keypad 0 key.
+    Zero,
+    /// Numeric keypad 1 key.
+    One,
+    /// Numeric keypad 2 key.
+    Two,
+    /// Numeric keypad 3 key.
+    Three,
+    /// Numeric keypad 4 key.
+    Four,
+    /// Numeric keypad 5 key.
+    Five,
+    /// Numeric keypad 6 key.
+    Six,
+    /// Numeric keypad 7 key.
+    Seven,
+    /// Numeric keypad 8 key.
+    Eight,
+    /// Numeric keypad 9 key.
+    Nine,
+}
+
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Oem {
+    /// Used for miscellaneous characters; it can vary by keyboard. For the US standard keyboard, the ';:' key.
+    _1,
+    /// Used for miscellaneous characters; it can vary by keyboard. For the US standard keyboard, the '/?' key.
+    _2,
+    /// Used for miscellaneous characters; it can vary by keyboard. For the US standard keyboard, the '`~' key.
+    _3,
+    /// Used for miscellaneous characters; it can vary by keyboard. For the US standard keyboard, the '[{' key.
+    _4,
+    /// Used for miscellaneous characters; it can vary by keyboard. For the US standard keyboard, the '\|' key.
+    _5,
+    /// Used for miscellaneous characters; it can vary by keyboard. For the US standard keyboard, the ']}' key.
+    _6,
+    /// Used for miscellaneous characters; it can vary by keyboard. For the US standard keyboard, the 'single-quote/double-quote' key.
+    _7,
+    /// Used for miscellaneous characters; it can vary by keyboard..
+    _8,
+    /// Either the angle bracket key or the backslash key on the RT 102-key keyboard.
+    _102,
+
+    /// Clear key.
+    Clear,
+    /// For any country/region, the ',' key.
+    Comma,
+    /// For any country/region, the '-' key.
+    Minus,
+    /// For any country/region, the '.' key.
+    Period,
+    /// For any country/region, the '+' key.
+    Plus,
+}
+
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Volume {
+    /// Volume Mute key.
+    Mute,
+    /// Volume Down key.
+    Down,
+    /// Volume Up key.
+    Up,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Browser, Function, Key, NumericKeypad};
+    use std::convert::TryFrom;
+
+    #[test]
+    fn round_trip() {
+        let keys = (0..=0xFF)
+            .filter_map(|code| Key::try_from(code).ok().map(|key| (code, key)))
+            .collect::<Vec<_>>();
+        // Every variant has its own code.
+        assert_eq!(161, keys.len());
+        for (code, key) in keys {
+            assert_eq!(code, i32::from(key), "{}", key);
+            assert_eq!(key, key.to_string().parse().unwrap());
+            assert_eq!(key, key.name().to_uppercase().parse().unwrap());
+        }
+        assert!(Key::try_from(0x07).is_err());
+        assert!(Key::try_from(0x100).is_err());
+    }
+
+    #[test]
+    fn names() {
+        assert_eq!("Ctrl", Key::Ctrl.to_string());
+        assert_eq!("F12", Key::Function(Function::F12).to_string());
+        assert_eq!("NumPad5", Key::NumPad(NumericKeypad::Five).to_string());
+        assert_eq!("NumPad+", Key::NumPad(NumericKeypad::Add).to_string());
+        assert_eq!(Key::Ctrl, "control".parse().unwrap());
+        assert_eq!(Key::Escape, "Esc".parse().unwrap());
+        assert_eq!(Key::Spacebar, "space".parse().unwrap());
+        assert!("Ctrl+".parse::<Key>().is_err());
+        assert!("".parse::<Key>().is_err());
+    }
+
+    #[test]
+    fn from() {
+        assert_eq!(Key::Browser(Browser::Back), Key::from(Browser::Back));
+        assert_eq!(Key::Function(Function::F1), Function::F1.into());
+    }
+}
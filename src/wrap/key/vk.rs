@@ -0,0 +1,266 @@
+//! Virtual-key codes.
+//!
+//! The codes of the keys, as `winapi` only has them on Windows.
+
+pub const VK_LBUTTON: i32 = 0x01;
+pub const VK_RBUTTON: i32 = 0x02;
+pub const VK_CANCEL: i32 = 0x03;
+pub const VK_MBUTTON: i32 = 0x04;
+pub const VK_XBUTTON1: i32 = 0x05;
+pub const VK_XBUTTON2: i32 = 0x06;
+pub const VK_BACK: i32 = 0x08;
+pub const VK_TAB: i32 = 0x09;
+pub const VK_CLEAR: i32 = 0x0C;
+pub const VK_RETURN: i32 = 0x0D;
+pub const VK_SHIFT: i32 = 0x10;
+pub const VK_CONTROL: i32 = 0x11;
+pub const VK_MENU: i32 = 0x12;
+pub const VK_PAUSE: i32 = 0x13;
+pub const VK_CAPITAL: i32 = 0x14;
+pub const VK_ESCAPE: i32 = 0x1B;
+pub const VK_SPACE: i32 = 0x20;
+pub const VK_PRIOR: i32 = 0x21;
+pub const VK_NEXT: i32 = 0x22;
+pub const VK_END: i32 = 0x23;
+pub const VK_HOME: i32 = 0x24;
+pub const VK_LEFT: i32 = 0x25;
+pub const VK_UP: i32 = 0x26;
+pub const VK_RIGHT: i32 = 0x27;
+pub const VK_DOWN: i32 = 0x28;
+pub const VK_SELECT: i32 = 0x29;
+pub const VK_PRINT: i32 = 0x2A;
+pub const VK_EXECUTE: i32 = 0x2B;
+pub const VK_SNAPSHOT: i32 = 0x2C;
+pub const VK_INSERT: i32 = 0x2D;
+pub const VK_DELETE: i32 = 0x2E;
+pub const VK_HELP: i32 = 0x2F;
+pub const VK_LWIN: i32 = 0x5B;
+pub const VK_RWIN: i32 = 0x5C;
+pub const VK_APPS: i32 = 0x5D;
+pub const VK_SLEEP: i32 = 0x5F;
+pub const VK_NUMPAD0: i32 = 0x60;
+pub const VK_NUMPAD1: i32 = 0x61;
+pub const VK_NUMPAD2: i32 = 0x62;
+pub const VK_NUMPAD3: i32 = 0x63;
+pub const VK_NUMPAD4: i32 = 0x64;
+pub const VK_NUMPAD5: i32 = 0x65;
+pub const VK_NUMPAD6: i32 = 0x66;
+pub const VK_NUMPAD7: i32 = 0x67;
+pub const VK_NUMPAD8: i32 = 0x68;
+pub const VK_NUMPAD9: i32 = 0x69;
+pub const VK_MULTIPLY: i32 = 0x6A;
+pub const VK_ADD: i32 = 0x6B;
+pub const VK_SEPARATOR: i32 = 0x6C;
+pub const VK_SUBTRACT: i32 = 0x6D;
+pub const VK_DECIMAL: i32 = 0x6E;
+pub const VK_DIVIDE: i32 = 0x6F;
+pub const VK_F1: i32 = 0x70;
+pub const VK_F2: i32 = 0x71;
+pub const VK_F3: i32 = 0x72;
+pub const VK_F4: i32 = 0x73;
+pub const VK_F5: i32 = 0x74;
+pub const VK_F6: i32 = 0x75;
+pub const VK_F7: i32 = 0x76;
+pub const VK_F8: i32 = 0x77;
+pub const VK_F9: i32 = 0x78;
+pub const VK_F10: i32 = 0x79;
+pub const VK_F11: i32 = 0x7A;
+pub const VK_F12: i32 = 0x7B;
+pub const VK_F13: i32 = 0x7C;
+pub const VK_F14: i32 = 0x7D;
+pub const VK_F15: i32 = 0x7E;
+pub const VK_F16: i32 = 0x7F;
+pub const VK_F17: i32 = 0x80;
+pub const VK_F18: i32 = 0x81;
+pub const VK_F19: i32 = 0x82;
+pub const VK_F20: i32 = 0x83;
+pub const VK_F21: i32 = 0x84;
+pub const VK_F22: i32 = 0x85;
+pub const VK_F23: i32 = 0x86;
+pub const VK_F24: i32 = 0x87;
+pub const VK_NUMLOCK: i32 = 0x90;
+pub const VK_SCROLL: i32 = 0x91;
+pub const VK_LSHIFT: i32 = 0xA0;
+pub const VK_RSHIFT: i32 = 0xA1;
+pub const VK_LCONTROL: i32 = 0xA2;
+pub const VK_RCONTROL: i32 = 0xA3;
+pub const VK_LMENU: i32 = 0xA4;
+pub const VK_RMENU: i32 = 0xA5;
+pub const VK_BROWSER_BACK: i32 = 0xA6;
+pub const VK_BROWSER_FORWARD: i32 = 0xA7;
+pub const VK_BROWSER_REFRESH: i32 = 0xA8;
+pub const VK_BROWSER_STOP: i32 = 0xA9;
+pub const VK_BROWSER_SEARCH: i32 = 0xAA;
+pub const VK_BROWSER_FAVORITES: i32 = 0xAB;
+pub const VK_BROWSER_HOME: i32 = 0xAC;
+pub const VK_VOLUME_MUTE: i32 = 0xAD;
+pub const VK_VOLUME_DOWN: i32 = 0xAE;
+pub const VK_VOLUME_UP: i32 = 0xAF;
+pub const VK_MEDIA_NEXT_TRACK: i32 = 0xB0;
+pub const VK_MEDIA_PREV_TRACK: i32 = 0xB1;
+pub const VK_MEDIA_STOP: i32 = 0xB2;
+pub const VK_MEDIA_PLAY_PAUSE: i32 = 0xB3;
+pub const VK_LAUNCH_MAIL: i32 = 0xB4;
+pub const VK_LAUNCH_MEDIA_SELECT: i32 = 0xB5;
+pub const VK_LAUNCH_APP1: i32 = 0xB6;
+pub const VK_LAUNCH_APP2: i32 = 0xB7;
+pub const VK_OEM_1: i32 = 0xBA;
+pub const VK_OEM_PLUS: i32 = 0xBB;
+pub const VK_OEM_COMMA: i32 = 0xBC;
+pub const VK_OEM_MINUS: i32 = 0xBD;
+pub const VK_OEM_PERIOD: i32 = 0xBE;
+pub const VK_OEM_2: i32 = 0xBF;
+pub const VK_OEM_3: i32 = 0xC0;
+pub const VK_OEM_4: i32 = 0xDB;
+pub const VK_OEM_5: i32 = 0xDC;
+pub const VK_OEM_6: i32 = 0xDD;
+pub const VK_OEM_7: i32 = 0xDE;
+pub const VK_OEM_8: i32 = 0xDF;
+pub const VK_OEM_102: i32 = 0xE2;
+pub const VK_PACKET: i32 = 0xE7;
+pub const VK_ATTN: i32 = 0xF6;
+pub const VK_CRSEL: i32 = 0xF7;
+pub const VK_EXSEL: i32 = 0xF8;
+pub const VK_EREOF: i32 = 0xF9;
+pub const VK_PLAY: i32 = 0xFA;
+pub const VK_ZOOM: i32 = 0xFB;
+pub const VK_NONAME: i32 = 0xFC;
+pub const VK_PA1: i32 = 0xFD;
+pub const VK_OEM_CLEAR: i32 = 0xFE;
+
+#[cfg(all(test, windows))]
+mod tests {
+    use winapi::um::winuser;
+
+    #[test]
+    fn winapi() {
+        assert_eq!(winuser::VK_LBUTTON, super::VK_LBUTTON);
+        assert_eq!(winuser::VK_RBUTTON, super::VK_RBUTTON);
+        assert_eq!(winuser::VK_CANCEL, super::VK_CANCEL);
+        assert_eq!(winuser::VK_MBUTTON, super::VK_MBUTTON);
+        assert_eq!(winuser::VK_XBUTTON1, super::VK_XBUTTON1);
+        assert_eq!(winuser::VK_XBUTTON2, super::VK_XBUTTON2);
+        assert_eq!(winuser::VK_BACK, super::VK_BACK);
+        assert_eq!(winuser::VK_TAB, super::VK_TAB);
+        assert_eq!(winuser::VK_CLEAR, super::VK_CLEAR);
+        assert_eq!(winuser::VK_RETURN, super::VK_RETURN);
+        assert_eq!(winuser::VK_SHIFT, super::VK_SHIFT);
+        assert_eq!(winuser::VK_CONTROL, super::VK_CONTROL);
+        assert_eq!(winuser::VK_MENU, super::VK_MENU);
+        assert_eq!(winuser::VK_PAUSE, super::VK_PAUSE);
+        assert_eq!(winuser::VK_CAPITAL, super::VK_CAPITAL);
+        assert_eq!(winuser::VK_ESCAPE, super::VK_ESCAPE);
+        assert_eq!(winuser::VK_SPACE, super::VK_SPACE);
+        assert_eq!(winuser::VK_PRIOR, super::VK_PRIOR);
+        assert_eq!(winuser::VK_NEXT, super::VK_NEXT);
+        assert_eq!(winuser::VK_END, super::VK_END);
+        assert_eq!(winuser::VK_HOME, super::VK_HOME);
+        assert_eq!(winuser::VK_LEFT, super::VK_LEFT);
+        assert_eq!(winuser::VK_UP, super::VK_UP);
+        assert_eq!(winuser::VK_RIGHT, super::VK_RIGHT);
+        assert_eq!(winuser::VK_DOWN, super::VK_DOWN);
+        assert_eq!(winuser::VK_SELECT, super::VK_SELECT);
+        assert_eq!(winuser::VK_PRINT, super::VK_PRINT);
+        assert_eq!(winuser::VK_EXECUTE, super::VK_EXECUTE);
+        assert_eq!(winuser::VK_SNAPSHOT, super::VK_SNAPSHOT);
+        assert_eq!(winuser::VK_INSERT, super::VK_INSERT);
+        assert_eq!(winuser::VK_DELETE, super::VK_DELETE);
+        assert_eq!(winuser::VK_HELP, super::VK_HELP);
+        assert_eq!(winuser::VK_LWIN, super::VK_LWIN);
+        assert_eq!(winuser::VK_RWIN, super::VK_RWIN);
+        assert_eq!(winuser::VK_APPS, super::VK_APPS);
+        assert_eq!(winuser::VK_SLEEP, super::VK_SLEEP);
+        assert_eq!(winuser::VK_NUMPAD0, super::VK_NUMPAD0);
+        assert_eq!(winuser::VK_NUMPAD1, super::VK_NUMPAD1);
+        assert_eq!(winuser::VK_NUMPAD2, super::VK_NUMPAD2);
+        assert_eq!(winuser::VK_NUMPAD3, super::VK_NUMPAD3);
+        assert_eq!(winuser::VK_NUMPAD4, super::VK_NUMPAD4);
+        assert_eq!(winuser::VK_NUMPAD5, super::VK_NUMPAD5);
+        assert_eq!(winuser::VK_NUMPAD6, super::VK_NUMPAD6);
+        assert_eq!(winuser::VK_NUMPAD7, super::VK_NUMPAD7);
+        assert_eq!(winuser::VK_NUMPAD8, super::VK_NUMPAD8);
+        assert_eq!(winuser::VK_NUMPAD9, super::VK_NUMPAD9);
+        assert_eq!(winuser::VK_MULTIPLY, super::VK_MULTIPLY);
+        assert_eq!(winuser::VK_ADD, super::VK_ADD);
+        assert_eq!(winuser::VK_SEPARATOR, super::VK_SEPARATOR);
+        assert_eq!(winuser::VK_SUBTRACT, super::VK_SUBTRACT);
+        assert_eq!(winuser::VK_DECIMAL, super::VK_DECIMAL);
+        assert_eq!(winuser::VK_DIVIDE, super::VK_DIVIDE);
+        assert_eq!(winuser::VK_F1, super::VK_F1);
+        assert_eq!(winuser::VK_F2, super::VK_F2);
+        assert_eq!(winuser::VK_F3, super::VK_F3);
+        assert_eq!(winuser::VK_F4, super::VK_F4);
+        assert_eq!(winuser::VK_F5, super::VK_F5);
+        assert_eq!(winuser::VK_F6, super::VK_F6);
+        assert_eq!(winuser::VK_F7, super::VK_F7);
+        assert_eq!(winuser::VK_F8, super::VK_F8);
+        assert_eq!(winuser::VK_F9, super::VK_F9);
+        assert_eq!(winuser::VK_F10, super::VK_F10);
+        assert_eq!(winuser::VK_F11, super::VK_F11);
+        assert_eq!(winuser::VK_F12, super::VK_F12);
+        assert_eq!(winuser::VK_F13, super::VK_F13);
+        assert_eq!(winuser::VK_F14, super::VK_F14);
+        assert_eq!(winuser::VK_F15, super::VK_F15);
+        assert_eq!(winuser::VK_F16, super::VK_F16);
+        assert_eq!(winuser::VK_F17, super::VK_F17);
+        assert_eq!(winuser::VK_F18, super::VK_F18);
+        assert_eq!(winuser::VK_F19, super::VK_F19);
+        assert_eq!(winuser::VK_F20, super::VK_F20);
+        assert_eq!(winuser::VK_F21, super::VK_F21);
+        assert_eq!(winuser::VK_F22, super::VK_F22);
+        assert_eq!(winuser::VK_F23, super::VK_F23);
+        assert_eq!(winuser::VK_F24, super::VK_F24);
+        assert_eq!(winuser::VK_NUMLOCK, super::VK_NUMLOCK);
+        assert_eq!(winuser::VK_SCROLL, super::VK_SCROLL);
+        assert_eq!(winuser::VK_LSHIFT, super::VK_LSHIFT);
+        assert_eq!(winuser::VK_RSHIFT, super::VK_RSHIFT);
+        assert_eq!(winuser::VK_LCONTROL, super::VK_LCONTROL);
+        assert_eq!(winuser::VK_RCONTROL, super::VK_RCONTROL);
+        assert_eq!(winuser::VK_LMENU, super::VK_LMENU);
+        assert_eq!(winuser::VK_RMENU, super::VK_RMENU);
+        assert_eq!(winuser::VK_BROWSER_BACK, super::VK_BROWSER_BACK);
+        assert_eq!(winuser::VK_BROWSER_FORWARD, super::VK_BROWSER_FORWARD);
+        assert_eq!(winuser::VK_BROWSER_REFRESH, super::VK_BROWSER_REFRESH);
+        assert_eq!(winuser::VK_BROWSER_STOP, super::VK_BROWSER_STOP);
+        assert_eq!(winuser::VK_BROWSER_SEARCH, super::VK_BROWSER_SEARCH);
+        assert_eq!(winuser::VK_BROWSER_FAVORITES, super::VK_BROWSER_FAVORITES);
+        assert_eq!(winuser::VK_BROWSER_HOME, super::VK_BROWSER_HOME);
+        assert_eq!(winuser::VK_VOLUME_MUTE, super::VK_VOLUME_MUTE);
+        assert_eq!(winuser::VK_VOLUME_DOWN, super::VK_VOLUME_DOWN);
+        assert_eq!(winuser::VK_VOLUME_UP, super::VK_VOLUME_UP);
+        assert_eq!(winuser::VK_MEDIA_NEXT_TRACK, super::VK_MEDIA_NEXT_TRACK);
+        assert_eq!(winuser::VK_MEDIA_PREV_TRACK, super::VK_MEDIA_PREV_TRACK);
+        assert_eq!(winuser::VK_MEDIA_STOP, super::VK_MEDIA_STOP);
+        assert_eq!(winuser::VK_MEDIA_PLAY_PAUSE, super::VK_MEDIA_PLAY_PAUSE);
+        assert_eq!(winuser::VK_LAUNCH_MAIL, super::VK_LAUNCH_MAIL);
+        assert_eq!(
+            winuser::VK_LAUNCH_MEDIA_SELECT,
+            super::VK_LAUNCH_MEDIA_SELECT
+        );
+        assert_eq!(winuser::VK_LAUNCH_APP1, super::VK_LAUNCH_APP1);
+        assert_eq!(winuser::VK_LAUNCH_APP2, super::VK_LAUNCH_APP2);
+        assert_eq!(winuser::VK_OEM_1, super::VK_OEM_1);
+        assert_eq!(winuser::VK_OEM_PLUS, super::VK_OEM_PLUS);
+        assert_eq!(winuser::VK_OEM_COMMA, super::VK_OEM_COMMA);
+        assert_eq!(winuser::VK_OEM_MINUS, super::VK_OEM_MINUS);
+        assert_eq!(winuser::VK_OEM_PERIOD, super::VK_OEM_PERIOD);
+        assert_eq!(winuser::VK_OEM_2, super::VK_OEM_2);
+        assert_eq!(winuser::VK_OEM_3, super::VK_OEM_3);
+        assert_eq!(winuser::VK_OEM_4, super::VK_OEM_4);
+        assert_eq!(winuser::VK_OEM_5, super::VK_OEM_5);
+        assert_eq!(winuser::VK_OEM_6, super::VK_OEM_6);
+        assert_eq!(winuser::VK_OEM_7, super::VK_OEM_7);
+        assert_eq!(winuser::VK_OEM_8, super::VK_OEM_8);
+        assert_eq!(winuser::VK_OEM_102, super::VK_OEM_102);
+        assert_eq!(winuser::VK_PACKET, super::VK_PACKET);
+        assert_eq!(winuser::VK_ATTN, super::VK_ATTN);
+        assert_eq!(winuser::VK_CRSEL, super::VK_CRSEL);
+        assert_eq!(winuser::VK_EXSEL, super::VK_EXSEL);
+        assert_eq!(winuser::VK_EREOF, super::VK_EREOF);
+        assert_eq!(winuser::VK_PLAY, super::VK_PLAY);
+        assert_eq!(winuser::VK_ZOOM, super::VK_ZOOM);
+        assert_eq!(winuser::VK_NONAME, super::VK_NONAME);
+        assert_eq!(winuser::VK_PA1, super::VK_PA1);
+        assert_eq!(winuser::VK_OEM_CLEAR, super::VK_OEM_CLEAR);
+    }
+}
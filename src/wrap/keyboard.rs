@@ -0,0 +1,139 @@
+use super::key::Key;
+#[cfg(windows)]
+use crate::{um::winuser::get_keyboard_state, Result};
+use derive_more::{Deref, DerefMut, From, Into};
+use std::{
+    convert::TryFrom,
+    fmt::{self, Debug, Formatter},
+};
+
+const DOWN: u8 = 0x80;
+const TOGGLED: u8 = 0x01;
+
+/// Keyboard state.
+///
+/// A snapshot of the 256 virtual keys, as returned by `get_keyboard_state`.
+#[derive(Clone, Copy, Deref, DerefMut, Eq, From, Into, PartialEq)]
+#[repr(transparent)]
+pub struct KeyboardState([u8; 256]);
+
+impl KeyboardState {
+    #[cfg(windows)]
+    pub fn get() -> Result<Self> {
+        get_keyboard_state().call().map(Self)
+    }
+
+    #[inline]
+    pub fn is_down(&self, key: Key) -> bool {
+        self.0[i32::from(key) as usize] & DOWN != 0
+    }
+
+    #[inline]
+    pub fn is_toggled(&self, key: Key) -> bool {
+        self.0[i32::from(key) as usize] & TOGGLED != 0
+    }
+
+    /// The keys down, in virtual key code order.
+    pub fn pressed(&self) -> impl Iterator<Item = Key> + '_ {
+        keys(move |code| self.0[code] & DOWN != 0)
+    }
+
+    /// The keys pressed and released since the `previous` state, in virtual
+    /// key code order.
+    pub fn diff<'a>(&'a self, previous: &'a KeyboardState) -> impl Iterator<Item = KeyEvent> + 'a {
+        (0..256).filter_map(move |code| {
+            let key = Key::try_from(code as i32).ok()?;
+            match (previous.0[code] & DOWN != 0, self.0[code] & DOWN != 0) {
+                (false, true) => Some(KeyEvent::Pressed(key)),
+                (true, false) => Some(KeyEvent::Released(key)),
+                _ => None,
+            }
+        })
+    }
+}
+
+impl Debug for KeyboardState {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.debug_struct("KeyboardState")
+            .field("pressed", &self.pressed().collect::<Vec<_>>())
+            .field(
+                "toggled",
+                &keys(|code| self.0[code] & TOGGLED != 0).collect::<Vec<_>>(),
+            )
+            .finish()
+    }
+}
+
+impl Default for KeyboardState {
+    fn default() -> Self {
+        Self([0; 256])
+    }
+}
+
+/// Key event.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum KeyEvent {
+    Pressed(Key),
+    Released(Key),
+}
+
+impl KeyEvent {
+    #[inline]
+    pub fn key(self) -> Key {
+        match self {
+            KeyEvent::Pressed(key) | KeyEvent::Released(key) => key,
+        }
+    }
+}
+
+fn keys(filter: impl Fn(usize) -> bool) -> impl Iterator<Item = Key> {
+    (0..256)
+        .filter(move |&code| filter(code))
+        .filter_map(|code| Key::try_from(code as i32).ok())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{KeyEvent, KeyboardState};
+    use crate::wrap::key::Key;
+
+    fn state(down: &[Key], toggled: &[Key]) -> KeyboardState {
+        let mut state = KeyboardState::default();
+        for &key in down {
+            state[i32::from(key) as usize] |= 0x80;
+        }
+        for &key in toggled {
+            state[i32::from(key) as usize] |= 0x01;
+        }
+        state
+    }
+
+    #[test]
+    fn keyboard_state() {
+        let state = state(&[Key::Shift, Key::A], &[Key::CapsLock, Key::A]);
+        assert!(state.is_down(Key::A));
+        assert!(state.is_down(Key::Shift));
+        assert!(!state.is_down(Key::CapsLock));
+        assert!(state.is_toggled(Key::CapsLock));
+        assert!(!state.is_toggled(Key::Shift));
+        assert_eq!(
+            vec![Key::Shift, Key::A],
+            state.pressed().collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn diff() {
+        let previous = state(&[Key::Ctrl, Key::A], &[]);
+        let current = state(&[Key::Ctrl, Key::B], &[Key::A]);
+        assert_eq!(
+            vec![KeyEvent::Released(Key::A), KeyEvent::Pressed(Key::B)],
+            current.diff(&previous).collect::<Vec<_>>(),
+        );
+        assert_eq!(
+            vec![KeyEvent::Pressed(Key::A), KeyEvent::Released(Key::B)],
+            previous.diff(&current).collect::<Vec<_>>(),
+        );
+        assert_eq!(0, current.diff(&current).count());
+    }
+}
@@ -0,0 +1,264 @@
+//! Keyboard layouts.
+
+use super::{chord::Modifiers, key::Key, keyboard::KeyboardState};
+use crate::{
+    shared::minwindef::KeyboardLayoutHandle,
+    um::winuser::{get_key_name_text, get_keyboard_layout, map_virtual_key_ex, to_unicode_ex},
+    utils::AsStrictRawHandle,
+    Error, Result,
+};
+use derive_more::{From, Into};
+use std::convert::TryFrom;
+use winapi::um::winuser::{MAPVK_VK_TO_VSC_EX, MAPVK_VSC_TO_VK_EX};
+
+/// Keeps `ToUnicodeEx` from changing the keyboard state, on Windows 10 1607
+/// and later.
+const DONT_CHANGE_STATE: u32 = 0x4;
+
+/// Keyboard layout.
+#[derive(Clone, Copy, Debug, Eq, From, Into, PartialEq)]
+#[repr(transparent)]
+pub struct KeyboardLayout(KeyboardLayoutHandle);
+
+impl KeyboardLayout {
+    /// The layout of the current thread.
+    pub fn current() -> Result<Self> {
+        Self::of_thread(0)
+    }
+
+    pub fn of_thread(thread_id: u32) -> Result<Self> {
+        let keyboard_layout = get_keyboard_layout().thread_id(thread_id).call();
+        keyboard_layout
+            .map(Self)
+            .ok_or_else(|| Error::unexpected_value("thread id", thread_id))
+    }
+
+    #[inline]
+    pub fn language_id(&self) -> u16 {
+        self.0.as_strict_raw_handle() as usize as u16
+    }
+
+    /// The scan code of the key, with the `0xE0` or `0xE1` prefix in the high
+    /// byte for extended keys.
+    pub fn scan_code(&self, key: Key) -> Option<u32> {
+        map_virtual_key_ex()
+            .code(i32::from(key) as _)
+            .map_type(MAPVK_VK_TO_VSC_EX)
+            .keyboard_layout(self.0)
+            .call()
+    }
+
+    pub fn key(&self, scan_code: u32) -> Option<Key> {
+        let code = map_virtual_key_ex()
+            .code(scan_code)
+            .map_type(MAPVK_VSC_TO_VK_EX)
+            .keyboard_layout(self.0)
+            .call()?;
+        Key::try_from(code as i32).ok()
+    }
+
+    /// The name of the key, such as `"Right Alt"` or `"Ö"`.
+    ///
+    /// Names are given by the layout of the current thread; only the scan code
+    /// comes from this one.
+    pub fn key_name(&self, key: Key) -> Result<String> {
+        let scan_code = self
+            .scan_code(key)
+            .ok_or_else(|| Error::invalid_value("key", key.to_string()))?;
+        get_key_name_text()
+            .l_param(key_name_l_param(scan_code))
+            .call()
+    }
+
+    /// Translates the key pressed with the modifiers, leaving the dead key
+    /// state of the thread untouched.
+    pub fn translate(&self, key: Key, modifiers: Modifiers) -> Translation {
+        self.to_unicode(key, modifiers, DONT_CHANGE_STATE)
+    }
+
+    fn to_unicode(self, key: Key, modifiers: Modifiers, flags: u32) -> Translation {
+        let key_state = key_state(modifiers);
+        let (r#return, buffer) = to_unicode_ex()
+            .virtual_key(i32::from(key) as _)
+            .scan_code(self.scan_code(key).unwrap_or_default())
+            .key_state(&key_state)
+            .flags(flags)
+            .keyboard_layout(self.0)
+            .call();
+        Translation::new(r#return, &buffer)
+    }
+}
+
+/// Translation.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Translation {
+    /// The key does not produce text.
+    None,
+    /// The key produces text, possibly several characters.
+    Text(String),
+    /// The key is a dead key, combining with the next key. Holds its spacing
+    /// character, such as `^`.
+    Dead(char),
+}
+
+impl Translation {
+    fn new(r#return: i32, buffer: &[u16]) -> Self {
+        match r#return {
+            0 => Translation::None,
+            _ if r#return < 0 => std::char::decode_utf16(buffer.iter().copied())
+                .next()
+                .and_then(Result::ok)
+                .map_or(Translation::None, Translation::Dead),
+            _ => Translation::Text(String::from_utf16_lossy(buffer)),
+        }
+    }
+}
+
+/// Dead key.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct DeadKey {
+    pub key: Key,
+    pub modifiers: Modifiers,
+    pub character: char,
+}
+
+/// Composer.
+///
+/// Composes characters from dead keys. The pending dead key is kept here
+/// rather than in the keyboard state of the thread, and replayed with the next
+/// key, so the thread is left as it was.
+#[derive(Clone, Copy, Debug)]
+pub struct Composer {
+    layout: KeyboardLayout,
+    pending: Option<DeadKey>,
+}
+
+impl Composer {
+    pub fn new(layout: KeyboardLayout) -> Self {
+        Self {
+            layout,
+            pending: None,
+        }
+    }
+
+    #[inline]
+    pub fn layout(&self) -> KeyboardLayout {
+        self.layout
+    }
+
+    #[inline]
+    pub fn pending(&self) -> Option<DeadKey> {
+        self.pending
+    }
+
+    pub fn reset(&mut self) {
+        self.pending = None;
+    }
+
+    /// Feeds a key press. Modifier keys neither produce text nor cancel the
+    /// pending dead key.
+    pub fn feed(&mut self, key: Key, modifiers: Modifiers) -> Translation {
+        if Modifiers::from_key(key).is_some() {
+            return Translation::None;
+        }
+        match self.pending.take() {
+            None => {
+                let translation = self.layout.translate(key, modifiers);
+                if let Translation::Dead(character) = translation {
+                    self.pending = Some(DeadKey {
+                        key,
+                        modifiers,
+                        character,
+                    });
+                }
+                translation
+            }
+            Some(dead_key) => {
+                self.layout.to_unicode(dead_key.key, dead_key.modifiers, 0);
+                let translation = self.layout.to_unicode(key, modifiers, 0);
+                if let Translation::Dead(character) = translation {
+                    // A chained dead key is left pending in the thread, so
+                    // clear it and keep it here instead.
+                    self.layout.to_unicode(Key::Spacebar, Modifiers::empty(), 0);
+                    self.pending = Some(DeadKey {
+                        key,
+                        modifiers,
+                        character,
+                    });
+                }
+                translation
+            }
+        }
+    }
+}
+
+impl Key {
+    /// Translates the key pressed with the modifiers on the layout, e.g.
+    /// `Oem::_1` is `;` on US layouts and `ж` on Russian ones.
+    pub fn to_char(self, layout: &KeyboardLayout, modifiers: Modifiers) -> Translation {
+        layout.translate(self, modifiers)
+    }
+}
+
+/// The keyboard state with the modifiers down. Sideless modifiers press the
+/// left key, and sided ones the sideless key as well.
+fn key_state(modifiers: Modifiers) -> KeyboardState {
+    let mut state = KeyboardState::default();
+    for key in modifiers.keys() {
+        let (sideless, key) = match key {
+            Key::Ctrl | Key::LeftCtrl => (Some(Key::Ctrl), Key::LeftCtrl),
+            Key::RightCtrl => (Some(Key::Ctrl), Key::RightCtrl),
+            Key::Shift | Key::LeftShift => (Some(Key::Shift), Key::LeftShift),
+            Key::RightShift => (Some(Key::Shift), Key::RightShift),
+            Key::Alt | Key::LeftAlt => (Some(Key::Alt), Key::LeftAlt),
+            Key::RightAlt => (Some(Key::Alt), Key::RightAlt),
+            key => (None, key),
+        };
+        for key in sideless.into_iter().chain(Some(key)) {
+            state[i32::from(key) as usize] |= 0x80;
+        }
+    }
+    state
+}
+
+fn key_name_l_param(scan_code: u32) -> i32 {
+    let extended = matches!(scan_code >> 8, 0xE0 | 0xE1);
+    ((scan_code & 0xFF) << 16 | (extended as u32) << 24) as i32
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{key_name_l_param, key_state, Translation};
+    use crate::wrap::{chord::Modifiers, key::Key};
+
+    #[test]
+    fn translation() {
+        assert_eq!(Translation::None, Translation::new(0, &[]));
+        assert_eq!(
+            Translation::Text("ж".to_owned()),
+            Translation::new(1, &[0x0436])
+        );
+        assert_eq!(
+            Translation::Text("´a".to_owned()),
+            Translation::new(2, &[0x00B4, 0x61])
+        );
+        assert_eq!(Translation::Dead('^'), Translation::new(-1, &[0x5E]));
+        assert_eq!(Translation::None, Translation::new(-1, &[0xD800]));
+    }
+
+    #[test]
+    fn modifiers_key_state() {
+        let state = key_state(Modifiers::CTRL | Modifiers::RIGHT_ALT);
+        assert_eq!(
+            vec![Key::Ctrl, Key::Alt, Key::LeftCtrl, Key::RightAlt],
+            state.pressed().collect::<Vec<_>>(),
+        );
+        assert_eq!(0, key_state(Modifiers::empty()).pressed().count());
+    }
+
+    #[test]
+    fn key_name() {
+        assert_eq!(0x001E_0000, key_name_l_param(0x1E));
+        assert_eq!(0x0138_0000, key_name_l_param(0xE038));
+    }
+}
@@ -0,0 +1,199 @@
+//! Window messages.
+
+use super::key::{Key, Mouse};
+use std::convert::TryFrom;
+use winapi::{
+    shared::minwindef::{LPARAM, WPARAM},
+    um::winuser::{
+        WM_CHAR, WM_CLOSE, WM_CREATE, WM_DESTROY, WM_KEYDOWN, WM_KEYUP, WM_KILLFOCUS,
+        WM_LBUTTONDOWN, WM_LBUTTONUP, WM_MBUTTONDOWN, WM_MBUTTONUP, WM_MOUSEMOVE, WM_MOUSEWHEEL,
+        WM_PAINT, WM_RBUTTONDOWN, WM_RBUTTONUP, WM_SETFOCUS, WM_SIZE, WM_SYSKEYDOWN, WM_SYSKEYUP,
+        WM_XBUTTONDOWN, WM_XBUTTONUP, XBUTTON1, XBUTTON2,
+    },
+};
+
+/// Message.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Message {
+    Create,
+    Close,
+    Destroy,
+    Paint,
+    /// The new client size.
+    Size {
+        width: u16,
+        height: u16,
+    },
+    /// A key, or a system key such as `F10` or one pressed with `Alt`, went
+    /// down.
+    KeyDown(Key),
+    KeyUp(Key),
+    /// A UTF-16 code unit, translated from key messages.
+    Char(u16),
+    /// The cursor moved, in client coordinates.
+    MouseMove {
+        x: i16,
+        y: i16,
+    },
+    /// A mouse button, in client coordinates.
+    MouseButton {
+        button: Mouse,
+        up: bool,
+        x: i16,
+        y: i16,
+    },
+    /// The wheel rotated, in screen coordinates.
+    MouseWheel {
+        delta: i16,
+        x: i16,
+        y: i16,
+    },
+    Focus(bool),
+    /// Any other message, left undecoded.
+    Other {
+        message: u32,
+        w_param: WPARAM,
+        l_param: LPARAM,
+    },
+}
+
+impl Message {
+    pub fn new(message: u32, w_param: WPARAM, l_param: LPARAM) -> Self {
+        let other = Message::Other {
+            message,
+            w_param,
+            l_param,
+        };
+        let key = || Key::try_from(w_param as i32).ok();
+        let (x, y) = (low(l_param as _) as i16, high(l_param as _) as i16);
+        let button = |button, up| Message::MouseButton { button, up, x, y };
+        match message {
+            WM_CREATE => Message::Create,
+            WM_CLOSE => Message::Close,
+            WM_DESTROY => Message::Destroy,
+            WM_PAINT => Message::Paint,
+            WM_SIZE => Message::Size {
+                width: low(l_param as _),
+                height: high(l_param as _),
+            },
+            WM_KEYDOWN | WM_SYSKEYDOWN => key().map_or(other, Message::KeyDown),
+            WM_KEYUP | WM_SYSKEYUP => key().map_or(other, Message::KeyUp),
+            WM_CHAR => Message::Char(w_param as _),
+            WM_MOUSEMOVE => Message::MouseMove { x, y },
+            WM_LBUTTONDOWN => button(Mouse::LeftButton, false),
+            WM_LBUTTONUP => button(Mouse::LeftButton, true),
+            WM_RBUTTONDOWN => button(Mouse::RightButton, false),
+            WM_RBUTTONUP => button(Mouse::RightButton, true),
+            WM_MBUTTONDOWN => button(Mouse::MiddleButton, false),
+            WM_MBUTTONUP => button(Mouse::MiddleButton, true),
+            WM_XBUTTONDOWN | WM_XBUTTONUP => {
+                let up = message == WM_XBUTTONUP;
+                match high(w_param) {
+                    XBUTTON1 => button(Mouse::X1Button, up),
+                    XBUTTON2 => button(Mouse::X2Button, up),
+                    _ => other,
+                }
+            }
+            WM_MOUSEWHEEL => Message::MouseWheel {
+                delta: high(w_param) as i16,
+                x,
+                y,
+            },
+            WM_SETFOCUS => Message::Focus(true),
+            WM_KILLFOCUS => Message::Focus(false),
+            _ => other,
+        }
+    }
+}
+
+#[inline]
+fn low(value: usize) -> u16 {
+    value as u16
+}
+
+#[inline]
+fn high(value: usize) -> u16 {
+    (value >> 16) as u16
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Message;
+    use crate::wrap::key::{Function, Key, Mouse};
+    use winapi::um::winuser::{
+        VK_F10, WM_CHAR, WM_CLOSE, WM_KEYDOWN, WM_KEYUP, WM_MOUSEMOVE, WM_MOUSEWHEEL, WM_NULL,
+        WM_SIZE, WM_SYSKEYDOWN, WM_XBUTTONUP,
+    };
+
+    /// Packs the coordinates as `MAKELPARAM` does.
+    fn point(x: i16, y: i16) -> isize {
+        (x as u16 as u32 | (y as u16 as u32) << 16) as i32 as isize
+    }
+
+    #[test]
+    fn window() {
+        assert_eq!(Message::Close, Message::new(WM_CLOSE, 0, 0));
+        assert_eq!(
+            Message::Size {
+                width: 1920,
+                height: 1080
+            },
+            Message::new(WM_SIZE, 0, point(1920, 1080)),
+        );
+        assert_eq!(
+            Message::Other {
+                message: WM_NULL,
+                w_param: 1,
+                l_param: 2
+            },
+            Message::new(WM_NULL, 1, 2),
+        );
+    }
+
+    #[test]
+    fn keyboard() {
+        assert_eq!(
+            Message::KeyDown(Key::A),
+            Message::new(WM_KEYDOWN, 0x41, 0x001E_0001)
+        );
+        assert_eq!(
+            Message::KeyDown(Key::Function(Function::F10)),
+            Message::new(WM_SYSKEYDOWN, VK_F10 as _, 0)
+        );
+        assert_eq!(Message::KeyUp(Key::A), Message::new(WM_KEYUP, 0x41, 0));
+        assert_eq!(
+            Message::Other {
+                message: WM_KEYUP,
+                w_param: 0xFF,
+                l_param: 0
+            },
+            Message::new(WM_KEYUP, 0xFF, 0),
+        );
+        assert_eq!(Message::Char(0x0436), Message::new(WM_CHAR, 0x0436, 0));
+    }
+
+    #[test]
+    fn mouse() {
+        assert_eq!(
+            Message::MouseMove { x: -5, y: 300 },
+            Message::new(WM_MOUSEMOVE, 0, point(-5, 300)),
+        );
+        assert_eq!(
+            Message::MouseButton {
+                button: Mouse::X1Button,
+                up: true,
+                x: 1,
+                y: 2
+            },
+            Message::new(WM_XBUTTONUP, 0x0001_0000, point(1, 2)),
+        );
+        assert_eq!(
+            Message::MouseWheel {
+                delta: -120,
+                x: 100,
+                y: -1
+            },
+            Message::new(WM_MOUSEWHEEL, 0xFF88_0000, point(100, -1)),
+        );
+    }
+}
@@ -1,2 +1,20 @@
+pub mod chord;
+#[cfg(windows)]
+pub mod console;
+#[cfg(windows)]
+pub mod hook;
+pub mod input;
 pub mod key;
+pub mod keyboard;
+#[cfg(windows)]
+pub mod layout;
+#[cfg(windows)]
+pub mod message;
+#[cfg(windows)]
 pub mod module;
+#[cfg(windows)]
+pub mod pseudo_console;
+pub mod service;
+pub mod vt;
+#[cfg(windows)]
+pub mod window;
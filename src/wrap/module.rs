@@ -1,8 +1,8 @@
 use crate::{
     shared::minwindef::ModuleHandle,
     um::libloaderapi::{disable_thread_library_calls, get_module_handle, GetProcAddress},
+    Result,
 };
-use anyhow::Result;
 use derive_more::{From, Into};
 use winapi::shared::minwindef::HMODULE;
 
@@ -17,17 +17,17 @@ impl Module {
     }
 
     pub fn with_name(name: &str) -> Result<Self> {
-        get_module_handle().name(name)().map(Self)
+        get_module_handle().name(name).call().map(Self)
     }
 
     pub fn disable_thread_library_calls(&self) -> Result<()> {
-        disable_thread_library_calls().handle(self.0)()
+        disable_thread_library_calls().handle(self.0).call()
     }
 
     pub fn function_address(&self, function_name: &str) -> Result<usize> {
         GetProcAddress::builder()
             .handle(&self.0)
             .name(function_name)
-            .build()()
+            .call()
     }
 }
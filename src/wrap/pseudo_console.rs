@@ -0,0 +1,134 @@
+//! Pseudo console session.
+
+use crate::{
+    shared::ntdef::OwnedHandle,
+    um::{
+        consoleapi::{close_pseudo_console, create_pseudo_console, resize_pseudo_console},
+        namedpipeapi::CreatePipe,
+        processthreadsapi::{
+            create_process, ProcThreadAttributeList, ProcessInformation,
+            PROC_THREAD_ATTRIBUTE_PSEUDOCONSOLE,
+        },
+        wincontypes::{Coordinates, PseudoConsoleHandle},
+    },
+    utils::{AsStrictRawHandle, Call},
+    Result,
+};
+use std::{
+    fs::File,
+    io::{self, Read, Write},
+    mem::size_of,
+    os::windows::io::{FromRawHandle, IntoRawHandle},
+};
+use winapi::um::wincontypes::HPCON;
+
+/// Pseudo console.
+///
+/// A pseudo console with a child process attached to it. Reading returns the
+/// output of the terminal and writing sends the input to it.
+///
+/// Note: Closing the pseudo console may block until its pending output is
+/// read, so the output should be drained on another thread.
+#[derive(Debug)]
+pub struct PseudoConsole {
+    handle: PseudoConsoleHandle,
+    input: File,
+    output: File,
+    process_information: ProcessInformation,
+}
+
+impl PseudoConsole {
+    /// Creates a pseudo console of the `size` and launches the `command_line`
+    /// attached to it.
+    pub fn spawn(command_line: &str, size: Coordinates) -> Result<Self> {
+        let (input_read, input_write) = CreatePipe::default().call()?;
+        let (output_read, output_write) = CreatePipe::default().call()?;
+        let handle = create_pseudo_console()
+            .size(size)
+            .input(input_read)
+            .output(output_write)
+            .call()?;
+        let input = unsafe { File::from_raw_handle(input_write.into_raw_handle()) };
+        let output = unsafe { File::from_raw_handle(output_read.into_raw_handle()) };
+        let process_information = attach(handle, command_line).inspect_err(|_| {
+            close_pseudo_console().handle(handle).call();
+        })?;
+        Ok(Self {
+            handle,
+            input,
+            output,
+            process_information,
+        })
+    }
+
+    #[inline]
+    pub fn handle(&self) -> PseudoConsoleHandle {
+        self.handle
+    }
+
+    #[inline]
+    pub fn process(&self) -> &OwnedHandle {
+        &self.process_information.process
+    }
+
+    #[inline]
+    pub fn process_id(&self) -> u32 {
+        self.process_information.process_id
+    }
+
+    /// Returns the terminal input stream.
+    #[inline]
+    pub fn input(&self) -> &File {
+        &self.input
+    }
+
+    /// Returns the terminal output stream.
+    #[inline]
+    pub fn output(&self) -> &File {
+        &self.output
+    }
+
+    pub fn resize(&self, size: Coordinates) -> Result<()> {
+        resize_pseudo_console()
+            .handle(self.handle)
+            .size(size)
+            .call()
+    }
+}
+
+impl Drop for PseudoConsole {
+    fn drop(&mut self) {
+        close_pseudo_console().handle(self.handle).call();
+    }
+}
+
+impl Read for PseudoConsole {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        self.output.read(buf)
+    }
+}
+
+impl Write for PseudoConsole {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.input.write(buf)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.input.flush()
+    }
+}
+
+fn attach(handle: PseudoConsoleHandle, command_line: &str) -> Result<ProcessInformation> {
+    let mut attribute_list = ProcThreadAttributeList::new(1)?;
+    unsafe {
+        attribute_list.update(
+            PROC_THREAD_ATTRIBUTE_PSEUDOCONSOLE,
+            handle.as_strict_raw_handle() as _,
+            size_of::<HPCON>(),
+        )?;
+    }
+    create_process()
+        .command_line(command_line)
+        .attribute_list(&mut attribute_list)
+        .call()
+}
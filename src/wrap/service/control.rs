@@ -0,0 +1,320 @@
+use crate::um::winsvc::ControlAccept;
+use std::sync::mpsc::Sender;
+use winapi::{
+    shared::{
+        minwindef::{DWORD, LPVOID},
+        winerror::{ERROR_CALL_NOT_IMPLEMENTED, NO_ERROR},
+    },
+    um::{
+        winsvc::{
+            SERVICE_CONTROL_CONTINUE, SERVICE_CONTROL_HARDWAREPROFILECHANGE,
+            SERVICE_CONTROL_INTERROGATE, SERVICE_CONTROL_NETBINDADD,
+            SERVICE_CONTROL_NETBINDDISABLE, SERVICE_CONTROL_NETBINDENABLE,
+            SERVICE_CONTROL_NETBINDREMOVE, SERVICE_CONTROL_PARAMCHANGE, SERVICE_CONTROL_PAUSE,
+            SERVICE_CONTROL_POWEREVENT, SERVICE_CONTROL_PRESHUTDOWN, SERVICE_CONTROL_SESSIONCHANGE,
+            SERVICE_CONTROL_SHUTDOWN, SERVICE_CONTROL_STOP, SERVICE_CONTROL_TIMECHANGE,
+            SERVICE_CONTROL_TRIGGEREVENT,
+        },
+        winuser::WTSSESSION_NOTIFICATION,
+    },
+};
+
+const SERVICE_CONTROL_USERMODEREBOOT: DWORD = 0x00000040;
+
+const PBT_APMPOWERSTATUSCHANGE: DWORD = 0x000A;
+const PBT_APMRESUMEAUTOMATIC: DWORD = 0x0012;
+const PBT_APMRESUMESUSPEND: DWORD = 0x0007;
+const PBT_APMSUSPEND: DWORD = 0x0004;
+const PBT_POWERSETTINGCHANGE: DWORD = 0x8013;
+
+const WTS_CONSOLE_CONNECT: DWORD = 0x1;
+const WTS_CONSOLE_DISCONNECT: DWORD = 0x2;
+const WTS_REMOTE_CONNECT: DWORD = 0x3;
+const WTS_REMOTE_DISCONNECT: DWORD = 0x4;
+const WTS_SESSION_LOGON: DWORD = 0x5;
+const WTS_SESSION_LOGOFF: DWORD = 0x6;
+const WTS_SESSION_LOCK: DWORD = 0x7;
+const WTS_SESSION_UNLOCK: DWORD = 0x8;
+const WTS_SESSION_REMOTE_CONTROL: DWORD = 0x9;
+const WTS_SESSION_CREATE: DWORD = 0xA;
+const WTS_SESSION_TERMINATE: DWORD = 0xB;
+
+/// Control.
+///
+/// A typed control request sent by the service control manager.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Control {
+    Continue,
+    HardwareProfileChange,
+    Interrogate,
+    NetBindAdd,
+    NetBindDisable,
+    NetBindEnable,
+    NetBindRemove,
+    ParamChange,
+    Pause,
+    PowerEvent(PowerEvent),
+    Preshutdown,
+    SessionChange(SessionChange),
+    Shutdown,
+    Stop,
+    TimeChange,
+    TriggerEvent,
+    UserModeReboot,
+}
+
+impl Control {
+    /// Decodes a control request received by a `HandlerEx` callback.
+    ///
+    /// Returns `None` for the controls that have no typed representation.
+    ///
+    /// # Safety
+    ///
+    /// The `event_data` must be the pointer passed with the request.
+    pub unsafe fn from_raw(control: u32, event_type: u32, event_data: LPVOID) -> Option<Self> {
+        Some(match control {
+            SERVICE_CONTROL_CONTINUE => Control::Continue,
+            SERVICE_CONTROL_HARDWAREPROFILECHANGE => Control::HardwareProfileChange,
+            SERVICE_CONTROL_INTERROGATE => Control::Interrogate,
+            SERVICE_CONTROL_NETBINDADD => Control::NetBindAdd,
+            SERVICE_CONTROL_NETBINDDISABLE => Control::NetBindDisable,
+            SERVICE_CONTROL_NETBINDENABLE => Control::NetBindEnable,
+            SERVICE_CONTROL_NETBINDREMOVE => Control::NetBindRemove,
+            SERVICE_CONTROL_PARAMCHANGE => Control::ParamChange,
+            SERVICE_CONTROL_PAUSE => Control::Pause,
+            SERVICE_CONTROL_POWEREVENT => Control::PowerEvent(PowerEvent::from(event_type)),
+            SERVICE_CONTROL_PRESHUTDOWN => Control::Preshutdown,
+            SERVICE_CONTROL_SESSIONCHANGE => {
+                if event_data.is_null() {
+                    return None;
+                }
+                let notification = &*(event_data as *const WTSSESSION_NOTIFICATION);
+                Control::SessionChange(SessionChange {
+                    reason: SessionChangeReason::from(event_type),
+                    session_id: notification.dwSessionId,
+                })
+            }
+            SERVICE_CONTROL_SHUTDOWN => Control::Shutdown,
+            SERVICE_CONTROL_STOP => Control::Stop,
+            SERVICE_CONTROL_TIMECHANGE => Control::TimeChange,
+            SERVICE_CONTROL_TRIGGEREVENT => Control::TriggerEvent,
+            SERVICE_CONTROL_USERMODEREBOOT => Control::UserModeReboot,
+            _ => return None,
+        })
+    }
+
+    /// Returns the flag a service must accept to receive this control.
+    pub fn accept(&self) -> ControlAccept {
+        match self {
+            Control::Continue | Control::Pause => ControlAccept::PAUSE_CONTINUE,
+            Control::HardwareProfileChange => ControlAccept::HARDWARE_PROFILE_CHANGE,
+            Control::Interrogate => ControlAccept::empty(),
+            Control::NetBindAdd
+            | Control::NetBindDisable
+            | Control::NetBindEnable
+            | Control::NetBindRemove => ControlAccept::NETBIND_CHANGE,
+            Control::ParamChange => ControlAccept::PARAM_CHANGE,
+            Control::PowerEvent(_) => ControlAccept::POWER_EVENT,
+            Control::Preshutdown => ControlAccept::PRESHUTDOWN,
+            Control::SessionChange(_) => ControlAccept::SESSION_CHANGE,
+            Control::Shutdown => ControlAccept::SHUTDOWN,
+            Control::Stop => ControlAccept::STOP,
+            Control::TimeChange => ControlAccept::TIME_CHANGE,
+            Control::TriggerEvent => ControlAccept::TRIGGER_EVENT,
+            Control::UserModeReboot => ControlAccept::USERMODE_REBOOT,
+        }
+    }
+}
+
+/// Power event.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum PowerEvent {
+    PowerStatusChange,
+    PowerSettingChange,
+    ResumeAutomatic,
+    ResumeSuspend,
+    Suspend,
+    Other(u32),
+}
+
+impl From<u32> for PowerEvent {
+    fn from(from: u32) -> Self {
+        match from {
+            PBT_APMPOWERSTATUSCHANGE => PowerEvent::PowerStatusChange,
+            PBT_POWERSETTINGCHANGE => PowerEvent::PowerSettingChange,
+            PBT_APMRESUMEAUTOMATIC => PowerEvent::ResumeAutomatic,
+            PBT_APMRESUMESUSPEND => PowerEvent::ResumeSuspend,
+            PBT_APMSUSPEND => PowerEvent::Suspend,
+            other => PowerEvent::Other(other),
+        }
+    }
+}
+
+/// Session change.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct SessionChange {
+    pub reason: SessionChangeReason,
+    pub session_id: u32,
+}
+
+/// Session change reason.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum SessionChangeReason {
+    ConsoleConnect,
+    ConsoleDisconnect,
+    RemoteConnect,
+    RemoteDisconnect,
+    SessionLogon,
+    SessionLogoff,
+    SessionLock,
+    SessionUnlock,
+    SessionRemoteControl,
+    SessionCreate,
+    SessionTerminate,
+    Other(u32),
+}
+
+impl From<u32> for SessionChangeReason {
+    fn from(from: u32) -> Self {
+        match from {
+            WTS_CONSOLE_CONNECT => SessionChangeReason::ConsoleConnect,
+            WTS_CONSOLE_DISCONNECT => SessionChangeReason::ConsoleDisconnect,
+            WTS_REMOTE_CONNECT => SessionChangeReason::RemoteConnect,
+            WTS_REMOTE_DISCONNECT => SessionChangeReason::RemoteDisconnect,
+            WTS_SESSION_LOGON => SessionChangeReason::SessionLogon,
+            WTS_SESSION_LOGOFF => SessionChangeReason::SessionLogoff,
+            WTS_SESSION_LOCK => SessionChangeReason::SessionLock,
+            WTS_SESSION_UNLOCK => SessionChangeReason::SessionUnlock,
+            WTS_SESSION_REMOTE_CONTROL => SessionChangeReason::SessionRemoteControl,
+            WTS_SESSION_CREATE => SessionChangeReason::SessionCreate,
+            WTS_SESSION_TERMINATE => SessionChangeReason::SessionTerminate,
+            other => SessionChangeReason::Other(other),
+        }
+    }
+}
+
+/// Control handler.
+///
+/// Translates raw control requests and delivers the accepted ones to a
+/// channel.
+#[derive(Debug)]
+pub struct ControlHandler {
+    controls_accepted: ControlAccept,
+    sender: Sender<Control>,
+}
+
+impl ControlHandler {
+    pub fn new(controls_accepted: ControlAccept, sender: Sender<Control>) -> Self {
+        Self {
+            controls_accepted,
+            sender,
+        }
+    }
+
+    /// Handles a control request and returns the code expected by the
+    /// service control manager.
+    ///
+    /// # Safety
+    ///
+    /// The `event_data` must be the pointer passed with the request.
+    pub unsafe fn handle(&self, control: u32, event_type: u32, event_data: LPVOID) -> u32 {
+        match Control::from_raw(control, event_type, event_data) {
+            Some(Control::Interrogate) => NO_ERROR,
+            Some(control) if self.controls_accepted.contains(control.accept()) => {
+                match self.sender.send(control) {
+                    Ok(()) => NO_ERROR,
+                    Err(_) => ERROR_CALL_NOT_IMPLEMENTED,
+                }
+            }
+            _ => ERROR_CALL_NOT_IMPLEMENTED,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::{mem::size_of, ptr::null_mut, sync::mpsc::channel};
+
+    #[test]
+    fn translate() {
+        unsafe {
+            assert_eq!(
+                Some(Control::Stop),
+                Control::from_raw(SERVICE_CONTROL_STOP, 0, null_mut()),
+            );
+            assert_eq!(
+                Some(Control::PowerEvent(PowerEvent::Suspend)),
+                Control::from_raw(SERVICE_CONTROL_POWEREVENT, PBT_APMSUSPEND, null_mut()),
+            );
+            assert_eq!(
+                Some(Control::PowerEvent(PowerEvent::Other(0xFF))),
+                Control::from_raw(SERVICE_CONTROL_POWEREVENT, 0xFF, null_mut()),
+            );
+            assert_eq!(None, Control::from_raw(0xFF, 0, null_mut()));
+        }
+    }
+
+    #[test]
+    fn translate_session_change() {
+        let mut notification = WTSSESSION_NOTIFICATION {
+            cbSize: size_of::<WTSSESSION_NOTIFICATION>() as _,
+            dwSessionId: 9,
+        };
+        let control = unsafe {
+            Control::from_raw(
+                SERVICE_CONTROL_SESSIONCHANGE,
+                WTS_SESSION_LOCK,
+                &mut notification as *mut _ as _,
+            )
+        };
+        assert_eq!(
+            Some(Control::SessionChange(SessionChange {
+                reason: SessionChangeReason::SessionLock,
+                session_id: 9,
+            })),
+            control,
+        );
+        assert_eq!(None, unsafe {
+            Control::from_raw(SERVICE_CONTROL_SESSIONCHANGE, WTS_SESSION_LOCK, null_mut())
+        });
+    }
+
+    #[test]
+    fn handle() {
+        let (sender, receiver) = channel();
+        let handler = ControlHandler::new(ControlAccept::STOP | ControlAccept::PRESHUTDOWN, sender);
+        unsafe {
+            assert_eq!(
+                NO_ERROR,
+                handler.handle(SERVICE_CONTROL_STOP, 0, null_mut())
+            );
+            assert_eq!(
+                NO_ERROR,
+                handler.handle(SERVICE_CONTROL_PRESHUTDOWN, 0, null_mut()),
+            );
+            assert_eq!(
+                NO_ERROR,
+                handler.handle(SERVICE_CONTROL_INTERROGATE, 0, null_mut()),
+            );
+            assert_eq!(
+                ERROR_CALL_NOT_IMPLEMENTED,
+                handler.handle(SERVICE_CONTROL_PAUSE, 0, null_mut()),
+            );
+        }
+        assert_eq!(
+            vec![Control::Stop, Control::Preshutdown],
+            receiver.try_iter().collect::<Vec<_>>(),
+        );
+    }
+
+    #[test]
+    fn handle_disconnected() {
+        let (sender, receiver) = channel();
+        let handler = ControlHandler::new(ControlAccept::STOP, sender);
+        drop(receiver);
+        assert_eq!(ERROR_CALL_NOT_IMPLEMENTED, unsafe {
+            handler.handle(SERVICE_CONTROL_STOP, 0, null_mut())
+        });
+    }
+}
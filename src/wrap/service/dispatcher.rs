@@ -0,0 +1,82 @@
+use super::control::{Control, ControlHandler};
+use crate::{
+    um::winsvc::{
+        register_service_ctrl_handler_ex, start_service_ctrl_dispatcher, ControlAccept,
+        ServiceStatusHandle,
+    },
+    Result,
+};
+use lazy_static::lazy_static;
+use std::{
+    ffi::OsString,
+    sync::{
+        mpsc::{channel, Receiver},
+        Mutex,
+    },
+};
+use widestring::WideCStr;
+use winapi::{
+    shared::minwindef::{DWORD, LPVOID},
+    um::winnt::LPWSTR,
+};
+
+type ServiceMain = Box<dyn FnOnce(Vec<OsString>) + Send>;
+
+lazy_static! {
+    static ref SERVICE_MAIN: Mutex<Option<ServiceMain>> = Mutex::new(None);
+}
+
+/// Runs the service control dispatcher for a single service process.
+///
+/// The `service_main` is called on a service thread with the start arguments.
+/// It blocks until the service has stopped.
+pub fn dispatch<F>(service_name: &str, service_main: F) -> Result<()>
+where
+    F: FnOnce(Vec<OsString>) + Send + 'static,
+{
+    *SERVICE_MAIN.lock().unwrap() = Some(Box::new(service_main));
+    start_service_ctrl_dispatcher()
+        .service_name(service_name)
+        .service_main(Some(service_main_proc))
+        .call()
+}
+
+/// Registers the control handler of the service.
+///
+/// Returns the status handle and the receiver of the accepted control
+/// requests. The handler lives until the process exits, as the service control
+/// manager may call it at any time.
+pub fn register(
+    service_name: &str,
+    controls_accepted: ControlAccept,
+) -> Result<(ServiceStatusHandle, Receiver<Control>)> {
+    let (sender, receiver) = channel();
+    let handler = Box::into_raw(Box::new(ControlHandler::new(controls_accepted, sender)));
+    let handle = register_service_ctrl_handler_ex()
+        .service_name(service_name)
+        .handler_proc(Some(handler_proc))
+        .context(handler as usize)
+        .call()
+        .inspect_err(|_| drop(unsafe { Box::from_raw(handler) }))?;
+    Ok((handle, receiver))
+}
+
+unsafe extern "system" fn service_main_proc(argc: DWORD, argv: *mut LPWSTR) {
+    let arguments = (0..argc as usize)
+        .map(|index| WideCStr::from_ptr_str(*argv.add(index)).to_os_string())
+        .collect();
+    let service_main = SERVICE_MAIN.lock().unwrap().take();
+    if let Some(service_main) = service_main {
+        service_main(arguments);
+    }
+}
+
+unsafe extern "system" fn handler_proc(
+    control: DWORD,
+    event_type: DWORD,
+    event_data: LPVOID,
+    context: LPVOID,
+) -> DWORD {
+    let handler = &*(context as *const ControlHandler);
+    handler.handle(control, event_type, event_data)
+}
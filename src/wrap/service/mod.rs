@@ -0,0 +1,21 @@
+//! Service host runtime.
+
+#[cfg(windows)]
+pub use self::{
+    control::{Control, ControlHandler, PowerEvent, SessionChange, SessionChangeReason},
+    dispatcher::{dispatch, register},
+    reporter::StatusReporter,
+};
+pub use self::{
+    state::{ControlAccept, CurrentState},
+    validator::{transition, StatusValidator, TransitionError},
+};
+
+#[cfg(windows)]
+mod control;
+#[cfg(windows)]
+mod dispatcher;
+#[cfg(windows)]
+mod reporter;
+mod state;
+mod validator;
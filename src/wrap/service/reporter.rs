@@ -0,0 +1,279 @@
+use super::validator::StatusValidator;
+use crate::{
+    backend::{ServiceApi, ServiceStatus, Win32},
+    um::winsvc::{ControlAccept, CurrentState, ServiceStatusHandle, Status, Type},
+    Result,
+};
+use winapi::{shared::winerror::NO_ERROR, um::winsvc::SERVICE_STATUS};
+
+/// Status reporter.
+///
+/// Builds the status transitions of a service and increments the check point
+/// while the service stays in a pending state. Each status is validated before
+/// it is reported through the backend, `Win32` by default.
+#[derive(Debug)]
+pub struct StatusReporter<B: ServiceApi = Win32> {
+    backend: B,
+    handle: B::Service,
+    service_type: Type,
+    controls_accepted: ControlAccept,
+    current_state: CurrentState,
+    check_point: u32,
+    validator: StatusValidator,
+}
+
+impl StatusReporter {
+    pub fn new(
+        handle: ServiceStatusHandle,
+        service_type: Type,
+        controls_accepted: ControlAccept,
+    ) -> Self {
+        Self::with_backend(Win32, handle, service_type, controls_accepted)
+    }
+}
+
+impl<B: ServiceApi> StatusReporter<B> {
+    pub fn with_backend(
+        backend: B,
+        handle: B::Service,
+        service_type: Type,
+        controls_accepted: ControlAccept,
+    ) -> Self {
+        Self {
+            backend,
+            handle,
+            service_type,
+            controls_accepted,
+            current_state: CurrentState::Stopped,
+            check_point: 0,
+            validator: StatusValidator::new(),
+        }
+    }
+
+    #[inline]
+    pub fn current_state(&self) -> CurrentState {
+        self.current_state
+    }
+
+    #[inline]
+    pub fn check_point(&self) -> u32 {
+        self.check_point
+    }
+
+    /// Builds and validates the status of a transition to the `current_state`,
+    /// then makes it the current one without reporting it.
+    ///
+    /// The reporter is left unchanged when the status is rejected.
+    pub fn status(&mut self, current_state: CurrentState, wait_hint: u32) -> Result<Status> {
+        let status = self.transition(current_state, wait_hint, NO_ERROR, 0);
+        let validator = self.validated(status)?;
+        self.commit(status, validator);
+        Ok(status)
+    }
+
+    /// Builds and validates the status of a transition to the stopped state
+    /// with exit codes, then makes it the current one without reporting it.
+    pub fn stopped(
+        &mut self,
+        win32_exit_code: u32,
+        service_specific_exit_code: u32,
+    ) -> Result<Status> {
+        let status = self.transition(
+            CurrentState::Stopped,
+            0,
+            win32_exit_code,
+            service_specific_exit_code,
+        );
+        let validator = self.validated(status)?;
+        self.commit(status, validator);
+        Ok(status)
+    }
+
+    /// Reports a transition to the `current_state` to the service control
+    /// manager.
+    ///
+    /// The reporter is left unchanged when the status is rejected.
+    pub fn report(&mut self, current_state: CurrentState, wait_hint: u32) -> Result<()> {
+        let status = self.transition(current_state, wait_hint, NO_ERROR, 0);
+        self.send(status)
+    }
+
+    /// Reports a transition to the stopped state with exit codes to the
+    /// service control manager.
+    pub fn report_stopped(
+        &mut self,
+        win32_exit_code: u32,
+        service_specific_exit_code: u32,
+    ) -> Result<()> {
+        let status = self.transition(
+            CurrentState::Stopped,
+            0,
+            win32_exit_code,
+            service_specific_exit_code,
+        );
+        self.send(status)
+    }
+
+    /// Validates and reports the status, then makes it the current one.
+    fn send(&mut self, status: Status) -> Result<()> {
+        let validator = self.validated(status)?;
+        self.backend
+            .set_service_status(&self.handle, ServiceStatus::from(status))?;
+        self.commit(status, validator);
+        Ok(())
+    }
+
+    /// The validator advanced past the status, the current one is untouched.
+    fn validated(&self, status: Status) -> Result<StatusValidator> {
+        let mut validator = self.validator.clone();
+        validator.validate(&ServiceStatus::from(status))?;
+        Ok(validator)
+    }
+
+    fn commit(&mut self, status: Status, validator: StatusValidator) {
+        self.current_state = status.current_state();
+        self.check_point = status.check_point();
+        self.validator = validator;
+    }
+
+    /// Builds the status of a transition from the current state, without
+    /// changing it.
+    fn transition(
+        &self,
+        current_state: CurrentState,
+        wait_hint: u32,
+        win32_exit_code: u32,
+        service_specific_exit_code: u32,
+    ) -> Status {
+        let check_point = if !current_state.is_pending() {
+            0
+        } else if current_state == self.current_state {
+            self.check_point + 1
+        } else {
+            1
+        };
+        let controls_accepted = match current_state {
+            CurrentState::StartPending | CurrentState::StopPending | CurrentState::Stopped => {
+                ControlAccept::empty()
+            }
+            _ => self.controls_accepted,
+        };
+        Status::from(SERVICE_STATUS {
+            dwServiceType: self.service_type.into(),
+            dwCurrentState: current_state.into(),
+            dwControlsAccepted: controls_accepted.bits(),
+            dwWin32ExitCode: win32_exit_code,
+            dwServiceSpecificExitCode: service_specific_exit_code,
+            dwCheckPoint: check_point,
+            dwWaitHint: if current_state.is_pending() {
+                wait_hint
+            } else {
+                0
+            },
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::StatusReporter;
+    use crate::{
+        backend::Fake,
+        um::winsvc::{ControlAccept, CurrentState, ServiceStatusHandle, Type},
+        utils::FromStrictRawHandle,
+    };
+    use std::ptr::null_mut;
+
+    fn reporter() -> StatusReporter {
+        let handle = unsafe { ServiceStatusHandle::from_strict_raw_handle(null_mut()) };
+        StatusReporter::new(
+            handle,
+            Type::Win32OwnProcess,
+            ControlAccept::STOP | ControlAccept::PAUSE_CONTINUE,
+        )
+    }
+
+    #[test]
+    fn check_point() {
+        let mut reporter = reporter();
+        let status = reporter.status(CurrentState::StartPending, 3000).unwrap();
+        assert_eq!(1, status.check_point());
+        assert_eq!(3000, status.wait_hint());
+        assert_eq!(ControlAccept::empty(), status.controls_accepted());
+        assert_eq!(
+            2,
+            reporter
+                .status(CurrentState::StartPending, 3000)
+                .unwrap()
+                .check_point()
+        );
+        let status = reporter.status(CurrentState::Running, 3000).unwrap();
+        assert_eq!(0, status.check_point());
+        assert_eq!(0, status.wait_hint());
+        assert_eq!(
+            ControlAccept::STOP | ControlAccept::PAUSE_CONTINUE,
+            status.controls_accepted(),
+        );
+        assert_eq!(
+            1,
+            reporter
+                .status(CurrentState::StopPending, 1000)
+                .unwrap()
+                .check_point()
+        );
+        assert!(reporter.status(CurrentState::Running, 0).is_err());
+        assert_eq!(CurrentState::StopPending, reporter.current_state());
+        assert_eq!(1, reporter.check_point());
+    }
+
+    #[test]
+    fn stopped() {
+        let mut reporter = reporter();
+        reporter.status(CurrentState::Running, 0).unwrap();
+        let status = reporter.stopped(1066, 9).unwrap();
+        assert_eq!(CurrentState::Stopped, status.current_state());
+        assert_eq!(Type::Win32OwnProcess, status.service_type());
+        assert_eq!(1066, status.win32_exit_code());
+        assert_eq!(9, status.service_specific_exit_code());
+        assert_eq!(ControlAccept::empty(), status.controls_accepted());
+        assert_eq!(CurrentState::Stopped, reporter.current_state());
+    }
+
+    #[test]
+    fn report() {
+        let fake = Fake::new();
+        let mut reporter = StatusReporter::with_backend(
+            &fake,
+            fake.service(),
+            Type::Win32OwnProcess,
+            ControlAccept::STOP,
+        );
+        reporter.report(CurrentState::StartPending, 3000).unwrap();
+        reporter.report(CurrentState::Running, 0).unwrap();
+        assert!(reporter.report(CurrentState::StartPending, 0).is_err());
+        assert_eq!(CurrentState::Running, reporter.current_state());
+        assert_eq!(0, reporter.check_point());
+        assert!(reporter.report(CurrentState::StopPending, 0).is_err());
+        assert_eq!(CurrentState::Running, reporter.current_state());
+        assert_eq!(0, reporter.check_point());
+        reporter.report(CurrentState::StopPending, 1000).unwrap();
+        reporter.report_stopped(0, 0).unwrap();
+        let statuses = fake.statuses(reporter.handle);
+        assert_eq!(
+            vec![
+                CurrentState::StartPending,
+                CurrentState::Running,
+                CurrentState::StopPending,
+                CurrentState::Stopped,
+            ],
+            statuses
+                .iter()
+                .map(|status| CurrentState::from(status.current_state))
+                .collect::<Vec<_>>(),
+        );
+        assert_eq!(
+            Some(CurrentState::Stopped.into()),
+            fake.current_state(reporter.handle)
+        );
+    }
+}
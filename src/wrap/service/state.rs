@@ -0,0 +1,99 @@
+//! Service states and accepted controls.
+
+use crate::backend::consts::{
+    SERVICE_ACCEPT_HARDWAREPROFILECHANGE, SERVICE_ACCEPT_NETBINDCHANGE, SERVICE_ACCEPT_PARAMCHANGE,
+    SERVICE_ACCEPT_PAUSE_CONTINUE, SERVICE_ACCEPT_POWEREVENT, SERVICE_ACCEPT_PRESHUTDOWN,
+    SERVICE_ACCEPT_SESSIONCHANGE, SERVICE_ACCEPT_SHUTDOWN, SERVICE_ACCEPT_STOP,
+    SERVICE_ACCEPT_TIMECHANGE, SERVICE_ACCEPT_TRIGGEREVENT, SERVICE_ACCEPT_USERMODEREBOOT,
+    SERVICE_CONTINUE_PENDING, SERVICE_PAUSED, SERVICE_PAUSE_PENDING, SERVICE_RUNNING,
+    SERVICE_START_PENDING, SERVICE_STOPPED, SERVICE_STOP_PENDING,
+};
+use bitflags::bitflags;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
+pub enum CurrentState {
+    ContinuePending,
+    PausePending,
+    Paused,
+    Running,
+    StartPending,
+    StopPending,
+    Stopped,
+}
+
+impl CurrentState {
+    /// Whether a start, stop, pause or continue operation is pending.
+    pub fn is_pending(self) -> bool {
+        match self {
+            CurrentState::ContinuePending
+            | CurrentState::PausePending
+            | CurrentState::StartPending
+            | CurrentState::StopPending => true,
+            CurrentState::Paused | CurrentState::Running | CurrentState::Stopped => false,
+        }
+    }
+}
+
+impl From<CurrentState> for u32 {
+    fn from(from: CurrentState) -> Self {
+        match from {
+            CurrentState::ContinuePending => SERVICE_CONTINUE_PENDING,
+            CurrentState::PausePending => SERVICE_PAUSE_PENDING,
+            CurrentState::Paused => SERVICE_PAUSED,
+            CurrentState::Running => SERVICE_RUNNING,
+            CurrentState::StartPending => SERVICE_START_PENDING,
+            CurrentState::StopPending => SERVICE_STOP_PENDING,
+            CurrentState::Stopped => SERVICE_STOPPED,
+        }
+    }
+}
+
+impl From<u32> for CurrentState {
+    fn from(from: u32) -> Self {
+        match from {
+            SERVICE_CONTINUE_PENDING => CurrentState::ContinuePending,
+            SERVICE_PAUSE_PENDING => CurrentState::PausePending,
+            SERVICE_PAUSED => CurrentState::Paused,
+            SERVICE_RUNNING => CurrentState::Running,
+            SERVICE_START_PENDING => CurrentState::StartPending,
+            SERVICE_STOP_PENDING => CurrentState::StopPending,
+            SERVICE_STOPPED => CurrentState::Stopped,
+            _ => unimplemented!(),
+        }
+    }
+}
+
+bitflags! {
+    /// Control accept.
+    pub struct ControlAccept: u32 {
+        const NETBIND_CHANGE = SERVICE_ACCEPT_NETBINDCHANGE;
+        const PARAM_CHANGE = SERVICE_ACCEPT_PARAMCHANGE;
+        const PAUSE_CONTINUE = SERVICE_ACCEPT_PAUSE_CONTINUE;
+        const PRESHUTDOWN = SERVICE_ACCEPT_PRESHUTDOWN;
+        const SHUTDOWN = SERVICE_ACCEPT_SHUTDOWN;
+        const STOP = SERVICE_ACCEPT_STOP;
+        const HARDWARE_PROFILE_CHANGE = SERVICE_ACCEPT_HARDWAREPROFILECHANGE;
+        const POWER_EVENT = SERVICE_ACCEPT_POWEREVENT;
+        const SESSION_CHANGE = SERVICE_ACCEPT_SESSIONCHANGE;
+        const TIME_CHANGE = SERVICE_ACCEPT_TIMECHANGE;
+        const TRIGGER_EVENT = SERVICE_ACCEPT_TRIGGEREVENT;
+        const USERMODE_REBOOT = SERVICE_ACCEPT_USERMODEREBOOT;
+    }
+}
+
+#[cfg(feature = "serde")]
+impl Serialize for ControlAccept {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.bits().serialize(serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> Deserialize<'de> for ControlAccept {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        u32::deserialize(deserializer).map(Self::from_bits_truncate)
+    }
+}
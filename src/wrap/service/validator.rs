@@ -0,0 +1,418 @@
+#[cfg(windows)]
+use super::control::Control;
+use super::state::{ControlAccept, CurrentState};
+use crate::backend::ServiceStatus;
+use derive_more::Display;
+use std::error::Error;
+
+/// Transition error.
+#[derive(Clone, Copy, Debug, Display, Eq, PartialEq)]
+pub enum TransitionError {
+    /// The state can't follow the previous one.
+    #[display(fmt = "Illegal transition from {:?} to {:?}.", from, to)]
+    Illegal {
+        from: Option<CurrentState>,
+        to: CurrentState,
+    },
+    /// The transition is caused by a control the previous status doesn't
+    /// accept.
+    #[display(
+        fmt = "Transition from {:?} to {:?} requires {:?} to be accepted.",
+        from,
+        to,
+        required
+    )]
+    ControlNotAccepted {
+        from: CurrentState,
+        to: CurrentState,
+        required: ControlAccept,
+    },
+    /// The pending state has a zero wait hint.
+    #[display(fmt = "Pending state {:?} requires a non-zero wait hint.", state)]
+    MissingWaitHint { state: CurrentState },
+    /// The check point of a pending state hasn't been incremented.
+    #[display(
+        fmt = "Check point regression in {:?} (previous: {}, current: {}).",
+        state,
+        previous,
+        current
+    )]
+    CheckPointRegression {
+        state: CurrentState,
+        previous: u32,
+        current: u32,
+    },
+    /// The non-pending state has a non-zero check point.
+    #[display(
+        fmt = "Non-pending state {:?} has a check point {}.",
+        state,
+        check_point
+    )]
+    UnexpectedCheckPoint {
+        state: CurrentState,
+        check_point: u32,
+    },
+}
+
+impl Error for TransitionError {}
+
+/// Status validator.
+///
+/// Validates each reported status against the previous one.
+#[derive(Clone, Debug, Default)]
+pub struct StatusValidator {
+    previous: Option<(CurrentState, ControlAccept, u32)>,
+}
+
+impl StatusValidator {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    #[inline]
+    pub fn current_state(&self) -> Option<CurrentState> {
+        self.previous.map(|(current_state, ..)| current_state)
+    }
+
+    /// Whether the last validated status accepts the control.
+    #[cfg(windows)]
+    pub fn accepts(&self, control: Control) -> bool {
+        let controls_accepted = self
+            .previous
+            .map_or(ControlAccept::empty(), |(_, controls_accepted, _)| {
+                controls_accepted
+            });
+        controls_accepted.contains(control.accept())
+    }
+
+    /// Validates the status and makes it the previous one on success.
+    pub fn validate(&mut self, status: &ServiceStatus) -> Result<(), TransitionError> {
+        let to = CurrentState::from(status.current_state);
+        let check_point = status.check_point;
+        if to.is_pending() {
+            if status.wait_hint == 0 {
+                return Err(TransitionError::MissingWaitHint { state: to });
+            }
+        } else if check_point != 0 {
+            return Err(TransitionError::UnexpectedCheckPoint {
+                state: to,
+                check_point,
+            });
+        }
+        let from = self.current_state();
+        let required = transition(from, to).ok_or(TransitionError::Illegal { from, to })?;
+        if let Some((from, controls_accepted, previous)) = self.previous {
+            if !required.is_empty() && !controls_accepted.intersects(required) {
+                return Err(TransitionError::ControlNotAccepted { from, to, required });
+            }
+            if from == to && to.is_pending() && check_point <= previous {
+                return Err(TransitionError::CheckPointRegression {
+                    state: to,
+                    previous,
+                    current: check_point,
+                });
+            }
+        }
+        let controls_accepted = ControlAccept::from_bits_truncate(status.controls_accepted);
+        self.previous = Some((to, controls_accepted, check_point));
+        Ok(())
+    }
+}
+
+/// Transition table.
+///
+/// Returns the controls one of which the previous status must accept for the
+/// transition, or `None` if the transition is illegal.
+pub fn transition(from: Option<CurrentState>, to: CurrentState) -> Option<ControlAccept> {
+    use CurrentState::*;
+
+    let none = ControlAccept::empty();
+    let pause_continue = ControlAccept::PAUSE_CONTINUE;
+    let stop = ControlAccept::STOP | ControlAccept::SHUTDOWN | ControlAccept::PRESHUTDOWN;
+    match (from, to) {
+        // The first report.
+        (None, StartPending) | (None, Running) | (None, Stopped) => Some(none),
+        (None, _) => None,
+        // A stopped service can only be started again.
+        (Some(Stopped), StartPending) => Some(none),
+        (Some(Stopped), _) => None,
+        // A stopping service can only stop.
+        (Some(StopPending), StopPending) | (Some(StopPending), Stopped) => Some(none),
+        (Some(StopPending), _) => None,
+        // A service may stop on its own, but is stopped by a control once it
+        // has started.
+        (Some(StartPending), StopPending) => Some(none),
+        (Some(_), StopPending) => Some(stop),
+        (Some(_), Stopped) => Some(none),
+        // Start.
+        (Some(StartPending), StartPending) | (Some(StartPending), Running) => Some(none),
+        // Pause.
+        (Some(Running), PausePending) | (Some(Running), Paused) => Some(pause_continue),
+        (Some(PausePending), PausePending)
+        | (Some(PausePending), Paused)
+        | (Some(PausePending), Running) => Some(none),
+        // Continue.
+        (Some(Paused), ContinuePending) | (Some(Paused), Running) => Some(pause_continue),
+        (Some(ContinuePending), ContinuePending)
+        | (Some(ContinuePending), Running)
+        | (Some(ContinuePending), Paused) => Some(none),
+        // Status refresh.
+        (Some(Running), Running) | (Some(Paused), Paused) => Some(none),
+        (Some(_), _) => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{transition, StatusValidator, TransitionError};
+    #[cfg(windows)]
+    use crate::wrap::service::Control;
+    use crate::{
+        backend::ServiceStatus,
+        wrap::service::{ControlAccept, CurrentState},
+    };
+    use std::iter::once;
+
+    const STATES: [CurrentState; 7] = [
+        CurrentState::ContinuePending,
+        CurrentState::PausePending,
+        CurrentState::Paused,
+        CurrentState::Running,
+        CurrentState::StartPending,
+        CurrentState::StopPending,
+        CurrentState::Stopped,
+    ];
+
+    fn status(
+        current_state: CurrentState,
+        controls_accepted: ControlAccept,
+        check_point: u32,
+        wait_hint: u32,
+    ) -> ServiceStatus {
+        ServiceStatus {
+            current_state: current_state.into(),
+            controls_accepted: controls_accepted.bits(),
+            check_point,
+            wait_hint,
+            ..ServiceStatus::default()
+        }
+    }
+
+    fn valid(current_state: CurrentState, controls_accepted: ControlAccept) -> ServiceStatus {
+        if current_state.is_pending() {
+            status(current_state, controls_accepted, 2, 1000)
+        } else {
+            status(current_state, controls_accepted, 0, 0)
+        }
+    }
+
+    fn validator(current_state: CurrentState, controls_accepted: ControlAccept) -> StatusValidator {
+        StatusValidator {
+            previous: Some((current_state, controls_accepted, 1)),
+        }
+    }
+
+    #[test]
+    fn table() {
+        use CurrentState::*;
+
+        let none = ControlAccept::empty();
+        let pause_continue = ControlAccept::PAUSE_CONTINUE;
+        let stop = ControlAccept::STOP | ControlAccept::SHUTDOWN | ControlAccept::PRESHUTDOWN;
+        let legal = [
+            (None, StartPending, none),
+            (None, Running, none),
+            (None, Stopped, none),
+            (Some(ContinuePending), ContinuePending, none),
+            (Some(ContinuePending), Paused, none),
+            (Some(ContinuePending), Running, none),
+            (Some(ContinuePending), StopPending, stop),
+            (Some(ContinuePending), Stopped, none),
+            (Some(PausePending), PausePending, none),
+            (Some(PausePending), Paused, none),
+            (Some(PausePending), Running, none),
+            (Some(PausePending), StopPending, stop),
+            (Some(PausePending), Stopped, none),
+            (Some(Paused), ContinuePending, pause_continue),
+            (Some(Paused), Paused, none),
+            (Some(Paused), Running, pause_continue),
+            (Some(Paused), StopPending, stop),
+            (Some(Paused), Stopped, none),
+            (Some(Running), PausePending, pause_continue),
+            (Some(Running), Paused, pause_continue),
+            (Some(Running), Running, none),
+            (Some(Running), StopPending, stop),
+            (Some(Running), Stopped, none),
+            (Some(StartPending), Running, none),
+            (Some(StartPending), StartPending, none),
+            (Some(StartPending), StopPending, none),
+            (Some(StartPending), Stopped, none),
+            (Some(StopPending), StopPending, none),
+            (Some(StopPending), Stopped, none),
+            (Some(Stopped), StartPending, none),
+        ];
+        for from in once(None).chain(STATES.iter().copied().map(Some)) {
+            for &to in &STATES {
+                let expected = legal
+                    .iter()
+                    .find(|&&(f, t, _)| f == from && t == to)
+                    .map(|&(.., required)| required);
+                assert_eq!(expected, transition(from, to), "{:?} -> {:?}", from, to);
+            }
+        }
+    }
+
+    #[test]
+    fn first() {
+        for &to in &STATES {
+            let mut validator = StatusValidator::new();
+            let result = validator.validate(&valid(to, ControlAccept::all()));
+            match transition(None, to) {
+                Some(_) => {
+                    assert_eq!(Ok(()), result);
+                    assert_eq!(Some(to), validator.current_state());
+                }
+                None => {
+                    assert_eq!(Err(TransitionError::Illegal { from: None, to }), result);
+                    assert_eq!(None, validator.current_state());
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn all_accepted() {
+        for &from in &STATES {
+            for &to in &STATES {
+                let mut validator = validator(from, ControlAccept::all());
+                let result = validator.validate(&valid(to, ControlAccept::all()));
+                match transition(Some(from), to) {
+                    Some(_) => assert_eq!(Ok(()), result, "{:?} -> {:?}", from, to),
+                    None => assert_eq!(
+                        Err(TransitionError::Illegal {
+                            from: Some(from),
+                            to,
+                        }),
+                        result,
+                    ),
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn none_accepted() {
+        for &from in &STATES {
+            for &to in &STATES {
+                let mut validator = validator(from, ControlAccept::empty());
+                let result = validator.validate(&valid(to, ControlAccept::empty()));
+                match transition(Some(from), to) {
+                    Some(required) if required.is_empty() => {
+                        assert_eq!(Ok(()), result, "{:?} -> {:?}", from, to)
+                    }
+                    Some(required) => {
+                        assert_eq!(
+                            Err(TransitionError::ControlNotAccepted { from, to, required }),
+                            result,
+                        );
+                        assert_eq!(Some(from), validator.current_state());
+                    }
+                    None => assert!(result.is_err()),
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn paused_to_stop_pending() {
+        let mut validator = StatusValidator::new();
+        let accepted = ControlAccept::PAUSE_CONTINUE;
+        assert_eq!(
+            Ok(()),
+            validator.validate(&valid(CurrentState::Running, accepted))
+        );
+        assert_eq!(
+            Ok(()),
+            validator.validate(&valid(CurrentState::Paused, accepted))
+        );
+        assert!(matches!(
+            validator.validate(&valid(CurrentState::StopPending, accepted)),
+            Err(TransitionError::ControlNotAccepted { .. })
+        ));
+        let accepted = accepted | ControlAccept::STOP;
+        assert_eq!(
+            Ok(()),
+            validator.validate(&valid(CurrentState::Paused, accepted))
+        );
+        assert_eq!(
+            Ok(()),
+            validator.validate(&valid(CurrentState::StopPending, accepted)),
+        );
+    }
+
+    #[test]
+    fn missing_wait_hint() {
+        for &state in STATES.iter().filter(|state| state.is_pending()) {
+            let mut validator = validator(state, ControlAccept::all());
+            assert_eq!(
+                Err(TransitionError::MissingWaitHint { state }),
+                validator.validate(&status(state, ControlAccept::all(), 2, 0)),
+            );
+        }
+    }
+
+    #[test]
+    fn check_point_regression() {
+        for &state in STATES.iter().filter(|state| state.is_pending()) {
+            let mut validator = validator(state, ControlAccept::all());
+            for &check_point in &[0, 1] {
+                assert_eq!(
+                    Err(TransitionError::CheckPointRegression {
+                        state,
+                        previous: 1,
+                        current: check_point,
+                    }),
+                    validator.validate(&status(state, ControlAccept::all(), check_point, 1000)),
+                );
+            }
+            for &check_point in &[2, 3, 9] {
+                assert_eq!(
+                    Ok(()),
+                    validator.validate(&status(state, ControlAccept::all(), check_point, 1000)),
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn unexpected_check_point() {
+        for &state in STATES.iter().filter(|state| !state.is_pending()) {
+            let mut validator = StatusValidator::new();
+            assert_eq!(
+                Err(TransitionError::UnexpectedCheckPoint {
+                    state,
+                    check_point: 1,
+                }),
+                validator.validate(&status(state, ControlAccept::all(), 1, 0)),
+            );
+        }
+    }
+
+    #[cfg(windows)]
+    #[test]
+    fn accepts() {
+        let mut validator = StatusValidator::new();
+        assert!(!validator.accepts(Control::Stop));
+        assert!(validator.accepts(Control::Interrogate));
+        let accepted = ControlAccept::STOP | ControlAccept::SESSION_CHANGE;
+        assert_eq!(
+            Ok(()),
+            validator.validate(&valid(CurrentState::Running, accepted))
+        );
+        assert!(validator.accepts(Control::Stop));
+        assert!(validator.accepts(Control::Interrogate));
+        assert!(!validator.accepts(Control::Pause));
+        assert!(!validator.accepts(Control::Continue));
+        assert!(!validator.accepts(Control::Preshutdown));
+    }
+}
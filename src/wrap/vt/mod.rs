@@ -0,0 +1,16 @@
+//! Virtual terminal sequences.
+//!
+//! The parser of the sequences written by a pseudo console and the screen
+//! buffer they drive.
+
+#[cfg(windows)]
+pub use self::terminal::Terminal;
+pub use self::{
+    parser::{Params, Parser, Perform},
+    screen::{Attributes, Cell, Color, Row, Screen, Style},
+};
+
+mod parser;
+mod screen;
+#[cfg(windows)]
+mod terminal;
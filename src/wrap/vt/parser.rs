@@ -0,0 +1,644 @@
+//! VT parser.
+//!
+//! A state machine after the DEC ANSI parser by Paul Williams, extended with
+//! UTF-8 input and colon separated subparameters.
+//!
+//! As in xterm, the parameters past the first 30 values are dropped and a value
+//! saturates at 65535, so a sequence is still dispatched. The bytes of an OSC
+//! string past the first 4096 are dropped.
+
+const MAX_INTERMEDIATES: usize = 2;
+const MAX_OSC_LENGTH: usize = 4096;
+const MAX_OSC_PARAMS: usize = 16;
+const MAX_PARAMS: usize = 30;
+
+const REPLACEMENT_CHARACTER: char = '\u{FFFD}';
+
+/// Perform.
+///
+/// Receives the actions of the parser.
+pub trait Perform {
+    /// Draws a character.
+    fn print(&mut self, _character: char) {}
+
+    /// Executes a C0 control function.
+    fn execute(&mut self, _byte: u8) {}
+
+    /// Dispatches a control sequence (CSI).
+    fn csi_dispatch(
+        &mut self,
+        _params: &Params,
+        _intermediates: &[u8],
+        _ignore: bool,
+        _action: char,
+    ) {
+    }
+
+    /// Dispatches an escape sequence.
+    fn esc_dispatch(&mut self, _intermediates: &[u8], _ignore: bool, _byte: u8) {}
+
+    /// Dispatches an operating system command (OSC).
+    fn osc_dispatch(&mut self, _params: &[&[u8]], _bell_terminated: bool) {}
+
+    /// Starts a device control string (DCS).
+    fn hook(&mut self, _params: &Params, _intermediates: &[u8], _ignore: bool, _action: char) {}
+
+    /// Passes a byte of a device control string.
+    fn put(&mut self, _byte: u8) {}
+
+    /// Ends a device control string.
+    fn unhook(&mut self) {}
+}
+
+/// Params.
+///
+/// The numeric parameters of a sequence. Each parameter is a non-empty slice of
+/// its colon separated subparameters.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Params {
+    values: Vec<u16>,
+    ends: Vec<usize>,
+}
+
+impl Params {
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.ends.len()
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.ends.is_empty()
+    }
+
+    pub fn get(&self, index: usize) -> Option<&[u16]> {
+        let end = *self.ends.get(index)?;
+        let start = index.checked_sub(1).map_or(0, |index| self.ends[index]);
+        Some(&self.values[start..end])
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &[u16]> {
+        (0..self.len()).map(move |index| self.get(index).unwrap())
+    }
+
+    fn clear(&mut self) {
+        self.values.clear();
+        self.ends.clear();
+    }
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum State {
+    Ground,
+    Escape,
+    EscapeIntermediate,
+    CsiEntry,
+    CsiParam,
+    CsiIntermediate,
+    CsiIgnore,
+    DcsEntry,
+    DcsParam,
+    DcsIntermediate,
+    DcsPassthrough,
+    DcsIgnore,
+    OscString,
+    SosPmApcString,
+}
+
+/// Parser.
+#[derive(Clone, Debug)]
+pub struct Parser {
+    state: State,
+    params: Params,
+    param: Option<u16>,
+    subparam: bool,
+    intermediates: Vec<u8>,
+    ignore: bool,
+    osc: Vec<u8>,
+    utf8: Vec<u8>,
+    utf8_length: usize,
+}
+
+impl Default for Parser {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Parser {
+    pub fn new() -> Self {
+        Self {
+            state: State::Ground,
+            params: Params::default(),
+            param: None,
+            subparam: false,
+            intermediates: Vec::with_capacity(MAX_INTERMEDIATES),
+            ignore: false,
+            osc: Vec::new(),
+            utf8: Vec::with_capacity(4),
+            utf8_length: 0,
+        }
+    }
+
+    /// Parses the bytes.
+    pub fn feed<P: Perform>(&mut self, performer: &mut P, bytes: &[u8]) {
+        for &byte in bytes {
+            self.advance(performer, byte);
+        }
+    }
+
+    /// Parses a byte.
+    pub fn advance<P: Perform>(&mut self, performer: &mut P, byte: u8) {
+        if self.utf8_length != 0 {
+            if let 0x80..=0xBF = byte {
+                self.utf8.push(byte);
+                if self.utf8.len() == self.utf8_length {
+                    let character = std::str::from_utf8(&self.utf8)
+                        .ok()
+                        .and_then(|string| string.chars().next())
+                        .unwrap_or(REPLACEMENT_CHARACTER);
+                    self.utf8.clear();
+                    self.utf8_length = 0;
+                    performer.print(character);
+                }
+                return;
+            }
+            self.utf8.clear();
+            self.utf8_length = 0;
+            performer.print(REPLACEMENT_CHARACTER);
+        }
+        match byte {
+            0x18 | 0x1A => {
+                self.exit(performer, false);
+                performer.execute(byte);
+                self.state = State::Ground;
+                return;
+            }
+            0x1B => {
+                self.exit(performer, true);
+                self.clear();
+                self.state = State::Escape;
+                return;
+            }
+            _ => {}
+        }
+        match self.state {
+            State::Ground => match byte {
+                0x00..=0x1F => performer.execute(byte),
+                0x20..=0x7E => performer.print(byte as char),
+                0x7F => {}
+                0xC2..=0xDF => self.start_utf8(byte, 2),
+                0xE0..=0xEF => self.start_utf8(byte, 3),
+                0xF0..=0xF4 => self.start_utf8(byte, 4),
+                _ => performer.print(REPLACEMENT_CHARACTER),
+            },
+            State::Escape => match byte {
+                0x00..=0x1F => performer.execute(byte),
+                0x20..=0x2F => {
+                    self.collect(byte);
+                    self.state = State::EscapeIntermediate;
+                }
+                b'[' => self.state = State::CsiEntry,
+                b']' => self.state = State::OscString,
+                b'P' => self.state = State::DcsEntry,
+                b'X' | b'^' | b'_' => self.state = State::SosPmApcString,
+                0x30..=0x7E => {
+                    performer.esc_dispatch(&self.intermediates, self.ignore, byte);
+                    self.state = State::Ground;
+                }
+                _ => {}
+            },
+            State::EscapeIntermediate => match byte {
+                0x00..=0x1F => performer.execute(byte),
+                0x20..=0x2F => self.collect(byte),
+                0x30..=0x7E => {
+                    performer.esc_dispatch(&self.intermediates, self.ignore, byte);
+                    self.state = State::Ground;
+                }
+                _ => {}
+            },
+            State::CsiEntry | State::CsiParam => match byte {
+                0x00..=0x1F => performer.execute(byte),
+                0x20..=0x2F => {
+                    self.collect(byte);
+                    self.state = State::CsiIntermediate;
+                }
+                b'0'..=b'9' | b':' | b';' => {
+                    self.param(byte);
+                    self.state = State::CsiParam;
+                }
+                0x3C..=0x3F if self.state == State::CsiEntry => {
+                    self.collect(byte);
+                    self.state = State::CsiParam;
+                }
+                0x3C..=0x3F => self.state = State::CsiIgnore,
+                0x40..=0x7E => {
+                    self.finish_params();
+                    performer.csi_dispatch(
+                        &self.params,
+                        &self.intermediates,
+                        self.ignore,
+                        byte as char,
+                    );
+                    self.state = State::Ground;
+                }
+                _ => {}
+            },
+            State::CsiIntermediate => match byte {
+                0x00..=0x1F => performer.execute(byte),
+                0x20..=0x2F => self.collect(byte),
+                0x30..=0x3F => self.state = State::CsiIgnore,
+                0x40..=0x7E => {
+                    self.finish_params();
+                    performer.csi_dispatch(
+                        &self.params,
+                        &self.intermediates,
+                        self.ignore,
+                        byte as char,
+                    );
+                    self.state = State::Ground;
+                }
+                _ => {}
+            },
+            State::CsiIgnore => match byte {
+                0x00..=0x1F => performer.execute(byte),
+                0x40..=0x7E => self.state = State::Ground,
+                _ => {}
+            },
+            State::DcsEntry | State::DcsParam => match byte {
+                0x20..=0x2F => {
+                    self.collect(byte);
+                    self.state = State::DcsIntermediate;
+                }
+                b'0'..=b'9' | b':' | b';' => {
+                    self.param(byte);
+                    self.state = State::DcsParam;
+                }
+                0x3C..=0x3F if self.state == State::DcsEntry => {
+                    self.collect(byte);
+                    self.state = State::DcsParam;
+                }
+                0x3C..=0x3F => self.state = State::DcsIgnore,
+                0x40..=0x7E => self.hook(performer, byte),
+                _ => {}
+            },
+            State::DcsIntermediate => match byte {
+                0x20..=0x2F => self.collect(byte),
+                0x30..=0x3F => self.state = State::DcsIgnore,
+                0x40..=0x7E => self.hook(performer, byte),
+                _ => {}
+            },
+            State::DcsPassthrough => match byte {
+                0x7F => {}
+                _ => performer.put(byte),
+            },
+            State::DcsIgnore | State::SosPmApcString => {}
+            State::OscString => match byte {
+                0x07 => {
+                    self.osc_dispatch(performer, true);
+                    self.state = State::Ground;
+                }
+                0x00..=0x1F => {}
+                _ if self.osc.len() < MAX_OSC_LENGTH => self.osc.push(byte),
+                _ => {}
+            },
+        }
+    }
+
+    fn start_utf8(&mut self, byte: u8, length: usize) {
+        self.utf8.push(byte);
+        self.utf8_length = length;
+    }
+
+    fn clear(&mut self) {
+        self.params.clear();
+        self.param = None;
+        self.subparam = false;
+        self.intermediates.clear();
+        self.ignore = false;
+        self.osc.clear();
+    }
+
+    fn collect(&mut self, byte: u8) {
+        if self.intermediates.len() < MAX_INTERMEDIATES {
+            self.intermediates.push(byte);
+        } else {
+            self.ignore = true;
+        }
+    }
+
+    fn param(&mut self, byte: u8) {
+        match byte {
+            b':' => {
+                self.push_param();
+                self.subparam = true;
+            }
+            b';' => {
+                self.push_param();
+                self.end_param();
+                self.subparam = false;
+            }
+            _ => {
+                let digit = (byte - b'0') as u16;
+                let param = self.param.unwrap_or_default();
+                self.param = Some(param.saturating_mul(10).saturating_add(digit));
+            }
+        }
+    }
+
+    fn finish_params(&mut self) {
+        if self.param.is_some() || self.subparam || !self.params.is_empty() {
+            self.push_param();
+            self.end_param();
+            self.subparam = false;
+        }
+    }
+
+    /// Pushes the current value, unless there are too many of them.
+    fn push_param(&mut self) {
+        let value = self.param.take().unwrap_or_default();
+        if self.params.values.len() < MAX_PARAMS {
+            self.params.values.push(value);
+        }
+    }
+
+    /// Ends the parameter of the values pushed since the previous one.
+    fn end_param(&mut self) {
+        let start = self.params.ends.last().copied().unwrap_or_default();
+        if self.params.values.len() > start {
+            self.params.ends.push(self.params.values.len());
+        }
+    }
+
+    fn hook<P: Perform>(&mut self, performer: &mut P, byte: u8) {
+        self.finish_params();
+        performer.hook(&self.params, &self.intermediates, self.ignore, byte as char);
+        self.state = State::DcsPassthrough;
+    }
+
+    fn osc_dispatch<P: Perform>(&mut self, performer: &mut P, bell_terminated: bool) {
+        let params = self
+            .osc
+            .splitn(MAX_OSC_PARAMS, |&byte| byte == b';')
+            .collect::<Vec<_>>();
+        performer.osc_dispatch(&params, bell_terminated);
+    }
+
+    /// Runs the exit action of the current state, which terminates a string
+    /// on escape.
+    fn exit<P: Perform>(&mut self, performer: &mut P, escape: bool) {
+        match self.state {
+            State::DcsPassthrough => performer.unhook(),
+            State::OscString if escape => self.osc_dispatch(performer, false),
+            _ => {}
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Params, Parser, Perform};
+
+    #[derive(Debug, Eq, PartialEq)]
+    enum Action {
+        Print(char),
+        Execute(u8),
+        Csi(Vec<Vec<u16>>, Vec<u8>, bool, char),
+        Esc(Vec<u8>, u8),
+        Osc(Vec<Vec<u8>>, bool),
+        Hook(Vec<Vec<u16>>, Vec<u8>, char),
+        Put(u8),
+        Unhook,
+    }
+
+    #[derive(Default)]
+    struct Recorder(Vec<Action>);
+
+    impl Perform for Recorder {
+        fn print(&mut self, character: char) {
+            self.0.push(Action::Print(character));
+        }
+
+        fn execute(&mut self, byte: u8) {
+            self.0.push(Action::Execute(byte));
+        }
+
+        fn csi_dispatch(
+            &mut self,
+            params: &Params,
+            intermediates: &[u8],
+            ignore: bool,
+            action: char,
+        ) {
+            let params = params.iter().map(<[u16]>::to_vec).collect();
+            self.0
+                .push(Action::Csi(params, intermediates.to_vec(), ignore, action));
+        }
+
+        fn esc_dispatch(&mut self, intermediates: &[u8], _ignore: bool, byte: u8) {
+            self.0.push(Action::Esc(intermediates.to_vec(), byte));
+        }
+
+        fn osc_dispatch(&mut self, params: &[&[u8]], bell_terminated: bool) {
+            let params = params.iter().map(|param| param.to_vec()).collect();
+            self.0.push(Action::Osc(params, bell_terminated));
+        }
+
+        fn hook(&mut self, params: &Params, intermediates: &[u8], _ignore: bool, action: char) {
+            let params = params.iter().map(<[u16]>::to_vec).collect();
+            self.0
+                .push(Action::Hook(params, intermediates.to_vec(), action));
+        }
+
+        fn put(&mut self, byte: u8) {
+            self.0.push(Action::Put(byte));
+        }
+
+        fn unhook(&mut self) {
+            self.0.push(Action::Unhook);
+        }
+    }
+
+    fn parse(bytes: &[u8]) -> Vec<Action> {
+        let mut recorder = Recorder::default();
+        Parser::new().feed(&mut recorder, bytes);
+        recorder.0
+    }
+
+    #[test]
+    fn print_and_execute() {
+        assert_eq!(
+            vec![
+                Action::Print('a'),
+                Action::Execute(b'\r'),
+                Action::Execute(b'\n'),
+                Action::Print('b'),
+            ],
+            parse(b"a\r\nb"),
+        );
+    }
+
+    #[test]
+    fn utf8() {
+        assert_eq!(
+            vec![Action::Print('ж'), Action::Print('€'), Action::Print('🦀')],
+            parse("ж€🦀".as_bytes()),
+        );
+        assert_eq!(
+            vec![Action::Print('\u{FFFD}'), Action::Print('a')],
+            parse(b"\xD0a"),
+        );
+        assert_eq!(vec![Action::Print('\u{FFFD}')], parse(b"\xFF"));
+    }
+
+    #[test]
+    fn csi() {
+        assert_eq!(
+            vec![Action::Csi(vec![], vec![], false, 'm')],
+            parse(b"\x1b[m"),
+        );
+        assert_eq!(
+            vec![Action::Csi(vec![vec![12], vec![40]], vec![], false, 'H')],
+            parse(b"\x1b[12;40H"),
+        );
+        assert_eq!(
+            vec![Action::Csi(vec![vec![0], vec![5]], vec![], false, 'H')],
+            parse(b"\x1b[;5H"),
+        );
+        assert_eq!(
+            vec![Action::Csi(vec![vec![1], vec![0]], vec![], false, 'H')],
+            parse(b"\x1b[1;H"),
+        );
+        assert_eq!(
+            vec![Action::Csi(vec![vec![25]], vec![b'?'], false, 'l')],
+            parse(b"\x1b[?25l"),
+        );
+        assert_eq!(
+            vec![Action::Csi(vec![vec![2]], vec![b' '], false, 'q')],
+            parse(b"\x1b[2 q"),
+        );
+    }
+
+    #[test]
+    fn csi_subparams() {
+        assert_eq!(
+            vec![Action::Csi(
+                vec![vec![38, 2, 0, 10, 20, 30], vec![1]],
+                vec![],
+                false,
+                'm',
+            )],
+            parse(b"\x1b[38:2:0:10:20:30;1m"),
+        );
+        assert_eq!(
+            vec![Action::Csi(vec![vec![38, 5, 0]], vec![], false, 'm')],
+            parse(b"\x1b[38:5:m"),
+        );
+    }
+
+    #[test]
+    fn csi_overflow() {
+        assert_eq!(
+            vec![Action::Csi(vec![vec![u16::MAX]], vec![], false, 'A')],
+            parse(b"\x1b[99999999A"),
+        );
+        let mut bytes = b"\x1b[".to_vec();
+        bytes.extend(b"1;".repeat(40));
+        bytes.push(b'm');
+        match &parse(&bytes)[..] {
+            [Action::Csi(params, _, false, 'm')] => assert_eq!(30, params.len()),
+            actions => panic!("{:?}", actions),
+        }
+        let mut bytes = b"\x1b[1;38".to_vec();
+        bytes.extend(b":2".repeat(40));
+        bytes.extend(b";4m");
+        match &parse(&bytes)[..] {
+            [Action::Csi(params, _, false, 'm')] => {
+                assert_eq!(2, params.len());
+                assert_eq!(29, params[1].len());
+            }
+            actions => panic!("{:?}", actions),
+        }
+    }
+
+    #[test]
+    fn csi_ignore() {
+        assert_eq!(vec![Action::Print('x')], parse(b"\x1b[1?2hx"));
+    }
+
+    #[test]
+    fn csi_execute_inside() {
+        assert_eq!(
+            vec![
+                Action::Execute(b'\n'),
+                Action::Csi(vec![vec![2]], vec![], false, 'J'),
+            ],
+            parse(b"\x1b[2\nJ"),
+        );
+    }
+
+    #[test]
+    fn esc() {
+        assert_eq!(
+            vec![Action::Esc(vec![], b'7'), Action::Esc(vec![b'('], b'B')],
+            parse(b"\x1b7\x1b(B"),
+        );
+    }
+
+    #[test]
+    fn osc() {
+        assert_eq!(
+            vec![Action::Osc(
+                vec![b"0".to_vec(), b"C:\\WINDOWS\\system32\\cmd.exe".to_vec()],
+                true,
+            )],
+            parse(b"\x1b]0;C:\\WINDOWS\\system32\\cmd.exe\x07"),
+        );
+        assert_eq!(
+            vec![
+                Action::Osc(vec![b"2".to_vec(), "заголовок".as_bytes().to_vec()], false),
+                Action::Esc(vec![], b'\\'),
+            ],
+            parse("\x1b]2;заголовок\x1b\\".as_bytes()),
+        );
+    }
+
+    #[test]
+    fn osc_overflow() {
+        let mut bytes = b"\x1b]0;".to_vec();
+        bytes.extend(b"x".repeat(10_000));
+        bytes.push(0x07);
+        match &parse(&bytes)[..] {
+            [Action::Osc(params, true)] => {
+                assert_eq!(b"0", &params[0][..]);
+                assert_eq!(4094, params[1].len());
+            }
+            actions => panic!("{:?}", actions),
+        }
+    }
+
+    #[test]
+    fn dcs() {
+        assert_eq!(
+            vec![
+                Action::Hook(vec![vec![1]], vec![b'$'], 'r'),
+                Action::Put(b'm'),
+                Action::Unhook,
+                Action::Esc(vec![], b'\\'),
+            ],
+            parse(b"\x1bP1$rm\x1b\\"),
+        );
+    }
+
+    #[test]
+    fn cancel() {
+        assert_eq!(
+            vec![Action::Execute(0x18), Action::Print('a')],
+            parse(b"\x1b[12\x18a"),
+        );
+        assert_eq!(
+            vec![Action::Esc(vec![], b'\\'), Action::Print('b')],
+            parse(b"\x1b_apc\x1b\\b"),
+        );
+    }
+}
@@ -0,0 +1,884 @@
+//! Screen buffer.
+
+use super::parser::{Params, Perform};
+#[cfg(windows)]
+use crate::um::wincontypes::Coordinates;
+use bitflags::bitflags;
+use std::{collections::VecDeque, convert::TryFrom, mem};
+
+const DEFAULT_SCROLLBACK_LIMIT: usize = 1000;
+const TAB_WIDTH: usize = 8;
+
+/// Color.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum Color {
+    #[default]
+    Default,
+    Indexed(u8),
+    Rgb(u8, u8, u8),
+}
+
+bitflags! {
+    /// Attributes.
+    #[derive(Default)]
+    pub struct Attributes: u16 {
+        const BOLD = 0x0001;
+        const DIM = 0x0002;
+        const ITALIC = 0x0004;
+        const UNDERLINE = 0x0008;
+        const BLINK = 0x0010;
+        const INVERSE = 0x0020;
+        const HIDDEN = 0x0040;
+        const STRIKETHROUGH = 0x0080;
+    }
+}
+
+/// Style.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct Style {
+    pub foreground: Color,
+    pub background: Color,
+    pub attributes: Attributes,
+}
+
+/// Cell.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Cell {
+    pub character: char,
+    pub style: Style,
+}
+
+impl Default for Cell {
+    fn default() -> Self {
+        Self {
+            character: ' ',
+            style: Style::default(),
+        }
+    }
+}
+
+/// Row.
+pub type Row = Vec<Cell>;
+
+/// Screen.
+///
+/// The screen buffer driven by the parser. Rows scrolled off the top of the
+/// main screen are kept in the scrollback.
+#[derive(Clone, Debug)]
+pub struct Screen {
+    width: usize,
+    height: usize,
+    rows: Vec<Row>,
+    scrollback: VecDeque<Row>,
+    scrollback_limit: usize,
+    alternate: Option<(Vec<Row>, (usize, usize))>,
+    cursor: (usize, usize),
+    saved_cursor: Option<((usize, usize), Style)>,
+    cursor_visible: bool,
+    pending_wrap: bool,
+    scroll_region: (usize, usize),
+    style: Style,
+    title: String,
+}
+
+impl Screen {
+    /// Creates a screen of the `width` and `height` in cells.
+    pub fn new(width: usize, height: usize) -> Self {
+        let width = width.max(1);
+        let height = height.max(1);
+        Self {
+            width,
+            height,
+            rows: vec![vec![Cell::default(); width]; height],
+            scrollback: VecDeque::new(),
+            scrollback_limit: DEFAULT_SCROLLBACK_LIMIT,
+            alternate: None,
+            cursor: (0, 0),
+            saved_cursor: None,
+            cursor_visible: true,
+            pending_wrap: false,
+            scroll_region: (0, height - 1),
+            style: Style::default(),
+            title: String::new(),
+        }
+    }
+
+    /// Creates a screen of the console `size`.
+    #[cfg(windows)]
+    pub fn with_size(size: Coordinates) -> Self {
+        Self::new(size.X.max(0) as _, size.Y.max(0) as _)
+    }
+
+    #[cfg(windows)]
+    pub fn size(&self) -> Coordinates {
+        Coordinates::new(to_i16(self.width), to_i16(self.height))
+    }
+
+    #[inline]
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    #[inline]
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// The zero based cursor position.
+    #[cfg(windows)]
+    pub fn cursor(&self) -> Coordinates {
+        Coordinates::new(to_i16(self.cursor.0), to_i16(self.cursor.1))
+    }
+
+    #[inline]
+    pub fn cursor_visible(&self) -> bool {
+        self.cursor_visible
+    }
+
+    #[inline]
+    pub fn is_alternate(&self) -> bool {
+        self.alternate.is_some()
+    }
+
+    #[inline]
+    pub fn style(&self) -> Style {
+        self.style
+    }
+
+    #[inline]
+    pub fn title(&self) -> &str {
+        &self.title
+    }
+
+    #[inline]
+    pub fn rows(&self) -> &[Row] {
+        &self.rows
+    }
+
+    #[inline]
+    pub fn row(&self, y: usize) -> Option<&Row> {
+        self.rows.get(y)
+    }
+
+    #[inline]
+    pub fn cell(&self, x: usize, y: usize) -> Option<&Cell> {
+        self.rows.get(y)?.get(x)
+    }
+
+    /// The rows scrolled off the top, the oldest first.
+    #[inline]
+    pub fn scrollback(&self) -> &VecDeque<Row> {
+        &self.scrollback
+    }
+
+    pub fn set_scrollback_limit(&mut self, limit: usize) {
+        self.scrollback_limit = limit;
+        while self.scrollback.len() > limit {
+            self.scrollback.pop_front();
+        }
+    }
+
+    /// The text of the row `y` without trailing spaces.
+    pub fn line(&self, y: usize) -> String {
+        self.rows.get(y).map(|row| text(row)).unwrap_or_default()
+    }
+
+    /// The text of the screen without trailing spaces and empty lines.
+    pub fn contents(&self) -> String {
+        let lines = self.rows.iter().map(|row| text(row)).collect::<Vec<_>>();
+        lines.join("\n").trim_end().to_owned()
+    }
+
+    /// Resizes the screen to the console `size`.
+    #[cfg(windows)]
+    pub fn resize(&mut self, size: Coordinates) {
+        self.set_size(size.X.max(0) as _, size.Y.max(0) as _);
+    }
+
+    /// Resizes the screen to `width` columns and `height` rows.
+    ///
+    /// Rows are removed from the bottom while they are below the cursor,
+    /// otherwise from the top into the scrollback.
+    pub fn set_size(&mut self, width: usize, height: usize) {
+        let width = width.max(1);
+        let height = height.max(1);
+        for row in &mut self.rows {
+            row.resize(width, Cell::default());
+        }
+        while self.rows.len() > height {
+            if self.cursor.1 + 1 < self.rows.len() {
+                self.rows.pop();
+            } else {
+                let row = self.rows.remove(0);
+                self.push_scrollback(row);
+                self.cursor.1 -= 1;
+            }
+        }
+        self.rows.resize(height, vec![Cell::default(); width]);
+        if let Some((rows, cursor)) = &mut self.alternate {
+            for row in rows.iter_mut() {
+                row.resize(width, Cell::default());
+            }
+            rows.resize(height, vec![Cell::default(); width]);
+            cursor.0 = cursor.0.min(width - 1);
+            cursor.1 = cursor.1.min(height - 1);
+        }
+        self.width = width;
+        self.height = height;
+        self.cursor.0 = self.cursor.0.min(width - 1);
+        self.scroll_region = (0, height - 1);
+        self.pending_wrap = false;
+    }
+
+    /// Resets the screen to the initial state keeping the size.
+    pub fn reset(&mut self) {
+        let scrollback_limit = self.scrollback_limit;
+        *self = Self::new(self.width, self.height);
+        self.scrollback_limit = scrollback_limit;
+    }
+
+    fn blank(&self) -> Cell {
+        Cell {
+            character: ' ',
+            style: Style {
+                background: self.style.background,
+                ..Style::default()
+            },
+        }
+    }
+
+    fn blank_row(&self) -> Row {
+        vec![self.blank(); self.width]
+    }
+
+    fn push_scrollback(&mut self, row: Row) {
+        if self.alternate.is_some() || self.scrollback_limit == 0 {
+            return;
+        }
+        if self.scrollback.len() == self.scrollback_limit {
+            self.scrollback.pop_front();
+        }
+        self.scrollback.push_back(row);
+    }
+
+    fn move_to(&mut self, x: usize, y: usize) {
+        self.cursor = (x.min(self.width - 1), y.min(self.height - 1));
+        self.pending_wrap = false;
+    }
+
+    fn move_up(&mut self, count: usize) {
+        let top = if self.cursor.1 >= self.scroll_region.0 {
+            self.scroll_region.0
+        } else {
+            0
+        };
+        let y = self.cursor.1.saturating_sub(count).max(top);
+        self.move_to(self.cursor.0, y);
+    }
+
+    fn move_down(&mut self, count: usize) {
+        let bottom = if self.cursor.1 <= self.scroll_region.1 {
+            self.scroll_region.1
+        } else {
+            self.height - 1
+        };
+        let y = self.cursor.1.saturating_add(count).min(bottom);
+        self.move_to(self.cursor.0, y);
+    }
+
+    fn scroll_up(&mut self, count: usize) {
+        let (top, bottom) = self.scroll_region;
+        for _ in 0..count.min(bottom - top + 1) {
+            let row = self.rows.remove(top);
+            if top == 0 {
+                self.push_scrollback(row);
+            }
+            let blank_row = self.blank_row();
+            self.rows.insert(bottom, blank_row);
+        }
+    }
+
+    fn scroll_down(&mut self, count: usize) {
+        let (top, bottom) = self.scroll_region;
+        for _ in 0..count.min(bottom - top + 1) {
+            self.rows.remove(bottom);
+            let blank_row = self.blank_row();
+            self.rows.insert(top, blank_row);
+        }
+    }
+
+    fn line_feed(&mut self) {
+        if self.cursor.1 == self.scroll_region.1 {
+            self.scroll_up(1);
+        } else if self.cursor.1 + 1 < self.height {
+            self.cursor.1 += 1;
+        }
+        self.pending_wrap = false;
+    }
+
+    fn reverse_index(&mut self) {
+        if self.cursor.1 == self.scroll_region.0 {
+            self.scroll_down(1);
+        } else if self.cursor.1 > 0 {
+            self.cursor.1 -= 1;
+        }
+        self.pending_wrap = false;
+    }
+
+    fn insert_lines(&mut self, count: usize) {
+        let (top, bottom) = self.scroll_region;
+        let y = self.cursor.1;
+        if y < top || y > bottom {
+            return;
+        }
+        for _ in 0..count.min(bottom - y + 1) {
+            self.rows.remove(bottom);
+            let blank_row = self.blank_row();
+            self.rows.insert(y, blank_row);
+        }
+        self.move_to(0, y);
+    }
+
+    fn delete_lines(&mut self, count: usize) {
+        let (top, bottom) = self.scroll_region;
+        let y = self.cursor.1;
+        if y < top || y > bottom {
+            return;
+        }
+        for _ in 0..count.min(bottom - y + 1) {
+            self.rows.remove(y);
+            let blank_row = self.blank_row();
+            self.rows.insert(bottom, blank_row);
+        }
+        self.move_to(0, y);
+    }
+
+    fn erase(&mut self, y: usize, start: usize, end: usize) {
+        let blank = self.blank();
+        let row = &mut self.rows[y];
+        let end = end.min(row.len());
+        for cell in &mut row[start.min(end)..end] {
+            *cell = blank;
+        }
+    }
+
+    fn erase_in_display(&mut self, mode: u16) {
+        let (x, y) = self.cursor;
+        match mode {
+            0 => {
+                self.erase(y, x, self.width);
+                for y in y + 1..self.height {
+                    self.erase(y, 0, self.width);
+                }
+            }
+            1 => {
+                for y in 0..y {
+                    self.erase(y, 0, self.width);
+                }
+                self.erase(y, 0, x + 1);
+            }
+            2 | 3 => {
+                for y in 0..self.height {
+                    self.erase(y, 0, self.width);
+                }
+                if mode == 3 {
+                    self.scrollback.clear();
+                }
+            }
+            _ => {}
+        }
+        self.pending_wrap = false;
+    }
+
+    fn erase_in_line(&mut self, mode: u16) {
+        let (x, y) = self.cursor;
+        match mode {
+            0 => self.erase(y, x, self.width),
+            1 => self.erase(y, 0, x + 1),
+            2 => self.erase(y, 0, self.width),
+            _ => {}
+        }
+        self.pending_wrap = false;
+    }
+
+    fn insert_characters(&mut self, count: usize) {
+        let (x, y) = self.cursor;
+        let blank = self.blank();
+        let row = &mut self.rows[y];
+        for _ in 0..count.min(self.width - x) {
+            row.pop();
+            row.insert(x, blank);
+        }
+        self.pending_wrap = false;
+    }
+
+    fn delete_characters(&mut self, count: usize) {
+        let (x, y) = self.cursor;
+        let blank = self.blank();
+        let row = &mut self.rows[y];
+        for _ in 0..count.min(self.width - x) {
+            row.remove(x);
+            row.push(blank);
+        }
+        self.pending_wrap = false;
+    }
+
+    fn save_cursor(&mut self) {
+        self.saved_cursor = Some((self.cursor, self.style));
+    }
+
+    fn restore_cursor(&mut self) {
+        let ((x, y), style) = self.saved_cursor.unwrap_or_default();
+        self.style = style;
+        self.move_to(x, y);
+    }
+
+    fn set_scroll_region(&mut self, top: usize, bottom: usize) {
+        let top = top.max(1) - 1;
+        let bottom = if bottom == 0 {
+            self.height
+        } else {
+            bottom.min(self.height)
+        } - 1;
+        if top < bottom {
+            self.scroll_region = (top, bottom);
+            self.move_to(0, 0);
+        }
+    }
+
+    fn set_mode(&mut self, mode: u16, enable: bool) {
+        match mode {
+            25 => self.cursor_visible = enable,
+            47 | 1047 | 1049 if enable && self.alternate.is_none() => {
+                let rows = vec![vec![Cell::default(); self.width]; self.height];
+                let rows = mem::replace(&mut self.rows, rows);
+                self.alternate = Some((rows, self.cursor));
+            }
+            47 | 1047 | 1049 if !enable => {
+                if let Some((rows, (x, y))) = self.alternate.take() {
+                    self.rows = rows;
+                    self.move_to(x, y);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn select_graphic_rendition(&mut self, params: &Params) {
+        if params.is_empty() {
+            self.style = Style::default();
+            return;
+        }
+        let mut params = params.iter();
+        while let Some(param) = params.next() {
+            let style = &mut self.style;
+            match param[0] {
+                0 => *style = Style::default(),
+                1 => style.attributes.insert(Attributes::BOLD),
+                2 => style.attributes.insert(Attributes::DIM),
+                3 => style.attributes.insert(Attributes::ITALIC),
+                4 | 21 => style.attributes.insert(Attributes::UNDERLINE),
+                5 | 6 => style.attributes.insert(Attributes::BLINK),
+                7 => style.attributes.insert(Attributes::INVERSE),
+                8 => style.attributes.insert(Attributes::HIDDEN),
+                9 => style.attributes.insert(Attributes::STRIKETHROUGH),
+                22 => style.attributes.remove(Attributes::BOLD | Attributes::DIM),
+                23 => style.attributes.remove(Attributes::ITALIC),
+                24 => style.attributes.remove(Attributes::UNDERLINE),
+                25 => style.attributes.remove(Attributes::BLINK),
+                27 => style.attributes.remove(Attributes::INVERSE),
+                28 => style.attributes.remove(Attributes::HIDDEN),
+                29 => style.attributes.remove(Attributes::STRIKETHROUGH),
+                value @ 30..=37 => style.foreground = Color::Indexed(value as u8 - 30),
+                38 => {
+                    if let Some(color) = extended_color(param, &mut params) {
+                        style.foreground = color;
+                    }
+                }
+                39 => style.foreground = Color::Default,
+                value @ 40..=47 => style.background = Color::Indexed(value as u8 - 40),
+                48 => {
+                    if let Some(color) = extended_color(param, &mut params) {
+                        style.background = color;
+                    }
+                }
+                49 => style.background = Color::Default,
+                value @ 90..=97 => style.foreground = Color::Indexed(value as u8 - 90 + 8),
+                value @ 100..=107 => style.background = Color::Indexed(value as u8 - 100 + 8),
+                _ => {}
+            }
+        }
+    }
+}
+
+impl Perform for Screen {
+    fn print(&mut self, character: char) {
+        if self.pending_wrap {
+            self.cursor.0 = 0;
+            self.line_feed();
+        }
+        let (x, y) = self.cursor;
+        self.rows[y][x] = Cell {
+            character,
+            style: self.style,
+        };
+        if x + 1 < self.width {
+            self.cursor.0 += 1;
+        } else {
+            self.pending_wrap = true;
+        }
+    }
+
+    fn execute(&mut self, byte: u8) {
+        match byte {
+            0x08 => {
+                let (x, y) = self.cursor;
+                self.move_to(x.saturating_sub(1), y);
+            }
+            0x09 => {
+                let (x, y) = self.cursor;
+                self.move_to((x / TAB_WIDTH + 1) * TAB_WIDTH, y);
+            }
+            0x0A..=0x0C => self.line_feed(),
+            0x0D => {
+                let y = self.cursor.1;
+                self.move_to(0, y);
+            }
+            _ => {}
+        }
+    }
+
+    fn csi_dispatch(&mut self, params: &Params, intermediates: &[u8], ignore: bool, action: char) {
+        if ignore {
+            return;
+        }
+        let param = |index: usize, default: usize| match params.get(index) {
+            Some(&[value, ..]) if value != 0 => value as usize,
+            _ => default,
+        };
+        let (x, y) = self.cursor;
+        match (intermediates, action) {
+            ([], 'A') => self.move_up(param(0, 1)),
+            ([], 'B') | ([], 'e') => self.move_down(param(0, 1)),
+            ([], 'C') | ([], 'a') => self.move_to(x.saturating_add(param(0, 1)), y),
+            ([], 'D') => self.move_to(x.saturating_sub(param(0, 1)), y),
+            ([], 'E') => {
+                self.move_down(param(0, 1));
+                self.cursor.0 = 0;
+            }
+            ([], 'F') => {
+                self.move_up(param(0, 1));
+                self.cursor.0 = 0;
+            }
+            ([], 'G') | ([], '`') => self.move_to(param(0, 1) - 1, y),
+            ([], 'H') | ([], 'f') => self.move_to(param(1, 1) - 1, param(0, 1) - 1),
+            ([], 'd') => self.move_to(x, param(0, 1) - 1),
+            ([], 'J') => self.erase_in_display(param(0, 0) as _),
+            ([], 'K') => self.erase_in_line(param(0, 0) as _),
+            ([], 'S') => self.scroll_up(param(0, 1)),
+            ([], 'T') => self.scroll_down(param(0, 1)),
+            ([], 'L') => self.insert_lines(param(0, 1)),
+            ([], 'M') => self.delete_lines(param(0, 1)),
+            ([], '@') => self.insert_characters(param(0, 1)),
+            ([], 'P') => self.delete_characters(param(0, 1)),
+            ([], 'X') => {
+                let count = param(0, 1);
+                self.erase(y, x, x.saturating_add(count));
+                self.pending_wrap = false;
+            }
+            ([], 'm') => self.select_graphic_rendition(params),
+            ([], 'r') => self.set_scroll_region(param(0, 1), param(1, 0)),
+            ([], 's') => self.save_cursor(),
+            ([], 'u') => self.restore_cursor(),
+            ([b'?'], 'h') | ([b'?'], 'l') => {
+                for param in params.iter() {
+                    self.set_mode(param[0], action == 'h');
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn esc_dispatch(&mut self, intermediates: &[u8], ignore: bool, byte: u8) {
+        if ignore || !intermediates.is_empty() {
+            return;
+        }
+        match byte {
+            b'7' => self.save_cursor(),
+            b'8' => self.restore_cursor(),
+            b'D' => self.line_feed(),
+            b'E' => {
+                self.line_feed();
+                self.cursor.0 = 0;
+            }
+            b'M' => self.reverse_index(),
+            b'c' => self.reset(),
+            _ => {}
+        }
+    }
+
+    fn osc_dispatch(&mut self, params: &[&[u8]], _bell_terminated: bool) {
+        if let [b"0", title] | [b"2", title] = params {
+            self.title = String::from_utf8_lossy(title).into_owned();
+        }
+    }
+}
+
+#[cfg(windows)]
+fn to_i16(value: usize) -> i16 {
+    i16::try_from(value).unwrap_or(i16::MAX)
+}
+
+/// Saturates a color component, as `38;5;300` would wrap around otherwise.
+fn to_u8(value: u16) -> u8 {
+    u8::try_from(value).unwrap_or(u8::MAX)
+}
+
+fn text(row: &[Cell]) -> String {
+    let text = row.iter().map(|cell| cell.character).collect::<String>();
+    text.trim_end().to_owned()
+}
+
+/// Parses the color of the SGR 38 or 48 `param`, both the colon form
+/// `38:5:n`, `38:2::r:g:b` and the semicolon form `38;5;n`, `38;2;r;g;b`.
+fn extended_color<'a>(
+    param: &[u16],
+    params: &mut impl Iterator<Item = &'a [u16]>,
+) -> Option<Color> {
+    if param.len() > 1 {
+        return match param[1] {
+            5 => Some(Color::Indexed(to_u8(*param.get(2)?))),
+            2 => {
+                let rgb = &param[2..];
+                let rgb = if rgb.len() > 3 { &rgb[1..] } else { rgb };
+                match rgb {
+                    [r, g, b, ..] => Some(Color::Rgb(to_u8(*r), to_u8(*g), to_u8(*b))),
+                    _ => None,
+                }
+            }
+            _ => None,
+        };
+    }
+    match params.next()?[0] {
+        5 => Some(Color::Indexed(to_u8(params.next()?[0]))),
+        2 => {
+            let r = params.next()?[0];
+            let g = params.next()?[0];
+            let b = params.next()?[0];
+            Some(Color::Rgb(to_u8(r), to_u8(g), to_u8(b)))
+        }
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{super::parser::Parser, Attributes, Color, Screen, Style};
+    #[cfg(windows)]
+    use crate::um::wincontypes::Coordinates;
+
+    // The start of `cmd.exe` in a 40x10 pseudo console.
+    const CMD: &[u8] = b"\x1b[?25l\x1b[2J\x1b[m\x1b[HMicrosoft Windows [Version 10.0.19042.630]\
+        \r\n(c) 2020 Microsoft Corporation. All rights reserved.\r\n\r\nC:\\>\
+        \x1b]0;C:\\WINDOWS\\system32\\cmd.exe\x07\x1b[?25h";
+
+    // A listing repainted from the top of a 40x10 pseudo console.
+    const DIR: &[u8] = b"\x1b[?25l\x1b[Hline 1\x1b[K\r\nline 2\x1b[K\r\nline 3\x1b[K\r\n\
+        line 4\x1b[K\r\nline 5\x1b[K\r\nline 6\x1b[K\r\nline 7\x1b[K\r\nline 8\x1b[K\r\n\
+        line 9\x1b[K\r\nline 10\x1b[K\r\nline 11\x1b[K\r\nline 12\x1b[K\x1b[?25h";
+
+    // Colored `Write-Host` output of `powershell.exe`.
+    const COLORS: &[u8] = b"\x1b[1;31mred\x1b[m \x1b[38;5;208morange\x1b[39m \
+        \x1b[48;2;10;20;30mrgb\x1b[49m \x1b[38:2::1:2:3mcolon\x1b[0m \x1b[94;4mbright\x1b[24;22m!";
+
+    fn screen(width: usize, height: usize, bytes: &[u8]) -> Screen {
+        let mut screen = Screen::new(width, height);
+        Parser::new().feed(&mut screen, bytes);
+        screen
+    }
+
+    #[test]
+    fn cmd() {
+        let screen = screen(40, 10, CMD);
+        assert_eq!("Microsoft Windows [Version 10.0.19042.63", screen.line(0));
+        assert_eq!("0]", screen.line(1));
+        assert_eq!("(c) 2020 Microsoft Corporation. All righ", screen.line(2));
+        assert_eq!("ts reserved.", screen.line(3));
+        assert_eq!("C:\\>", screen.line(5));
+        assert_eq!((4, 5), screen.cursor);
+        assert_eq!("C:\\WINDOWS\\system32\\cmd.exe", screen.title());
+        assert!(screen.cursor_visible());
+        assert!(screen.scrollback().is_empty());
+    }
+
+    #[test]
+    fn scrollback() {
+        let mut screen = screen(40, 10, DIR);
+        assert_eq!("line 3", screen.line(0));
+        assert_eq!("line 12", screen.line(9));
+        assert_eq!(2, screen.scrollback().len());
+        assert_eq!(
+            "line 1",
+            screen.scrollback()[0]
+                .iter()
+                .map(|cell| cell.character)
+                .collect::<String>()
+                .trim_end()
+        );
+        screen.set_scrollback_limit(1);
+        assert_eq!(1, screen.scrollback().len());
+        assert_eq!(' ', screen.scrollback()[0][7].character);
+        assert_eq!('2', screen.scrollback()[0][5].character);
+    }
+
+    #[test]
+    fn colors() {
+        let screen = screen(80, 1, COLORS);
+        let style = |x| screen.cell(x, 0).unwrap().style;
+        assert_eq!(
+            Style {
+                foreground: Color::Indexed(1),
+                background: Color::Default,
+                attributes: Attributes::BOLD,
+            },
+            style(0),
+        );
+        assert_eq!(Style::default(), style(3));
+        assert_eq!(Color::Indexed(208), style(4).foreground);
+        assert_eq!(Color::Default, style(10).foreground);
+        assert_eq!(Color::Rgb(10, 20, 30), style(11).background);
+        assert_eq!(Color::Default, style(14).background);
+        assert_eq!(Color::Rgb(1, 2, 3), style(15).foreground);
+        assert_eq!(Color::Indexed(12), style(21).foreground);
+        assert_eq!(Attributes::UNDERLINE, style(21).attributes);
+        assert_eq!(Style::default().attributes, style(27).attributes);
+        assert_eq!("red orange rgb colon bright!", screen.line(0));
+    }
+
+    #[test]
+    fn colors_overflow() {
+        let screen = screen(10, 1, b"\x1b[38;5;300ma\x1b[48:2::999:20:70000mb");
+        assert_eq!(
+            Color::Indexed(255),
+            screen.cell(0, 0).unwrap().style.foreground
+        );
+        assert_eq!(
+            Color::Rgb(255, 20, 255),
+            screen.cell(1, 0).unwrap().style.background
+        );
+    }
+
+    #[test]
+    fn cursor() {
+        let mut screen = screen(10, 5, b"\x1b[3;4H");
+        assert_eq!((3, 2), screen.cursor);
+        Parser::new().feed(&mut screen, b"\x1b[2A\x1b[5C\x1b[B\x1b[2D");
+        assert_eq!((6, 1), screen.cursor);
+        Parser::new().feed(&mut screen, b"\x1b[99;99H");
+        assert_eq!((9, 4), screen.cursor);
+        Parser::new().feed(&mut screen, b"\x1b[2G\x1b[3d\x1b7\x1b[H\x1b8");
+        assert_eq!((1, 2), screen.cursor);
+        Parser::new().feed(&mut screen, b"\t\x08\x1b[E");
+        assert_eq!((0, 3), screen.cursor);
+    }
+
+    #[test]
+    fn erase() {
+        let mut screen = screen(5, 3, b"abcde\r\nfghij\r\nklmno\x1b[2;3H\x1b[K");
+        assert_eq!(
+            ["abcde", "fg", "klmno"],
+            [screen.line(0), screen.line(1), screen.line(2)]
+        );
+        Parser::new().feed(&mut screen, b"\x1b[1K");
+        assert_eq!("", screen.line(1));
+        Parser::new().feed(&mut screen, b"\x1b[1;2H\x1b[2X\x1b[3;4H\x1b[1J");
+        assert_eq!("", screen.contents().lines().next().unwrap());
+        assert_eq!("    o", screen.line(2));
+        Parser::new().feed(&mut screen, b"\x1b[2J");
+        assert_eq!("", screen.contents());
+    }
+
+    #[test]
+    fn characters() {
+        let mut screen = screen(6, 1, b"abcdef\r\x1b[2C\x1b[2@");
+        assert_eq!("ab  cd", screen.line(0));
+        Parser::new().feed(&mut screen, b"\x1b[3P");
+        assert_eq!("abd", screen.line(0));
+    }
+
+    #[test]
+    fn wrap() {
+        let screen = screen(3, 2, b"abc");
+        assert_eq!((2, 0), screen.cursor);
+        assert!(screen.pending_wrap);
+        let screen = self::screen(3, 2, b"abc\r\n");
+        assert_eq!((0, 1), screen.cursor);
+        let screen = self::screen(3, 2, b"abcd");
+        assert_eq!(["abc", "d"], [screen.line(0), screen.line(1)]);
+    }
+
+    #[test]
+    fn scroll_region() {
+        let mut screen = screen(3, 5, b"1\r\n2\r\n3\r\n4\r\n5\x1b[2;4r");
+        assert_eq!((0, 0), screen.cursor);
+        Parser::new().feed(&mut screen, b"\x1b[4Hx\r\ny");
+        assert_eq!("1\n3\nx\ny\n5", screen.contents());
+        assert!(screen.scrollback().is_empty());
+        Parser::new().feed(&mut screen, b"\x1b[2H\x1bM");
+        assert_eq!("1\n\n3\nx\n5", screen.contents());
+        Parser::new().feed(&mut screen, b"\x1b[3H\x1b[L");
+        assert_eq!("1\n\n\n3\n5", screen.contents());
+        Parser::new().feed(&mut screen, b"\x1b[2M");
+        assert_eq!("1\n\n\n\n5", screen.contents());
+        Parser::new().feed(&mut screen, b"\x1b[r\x1b[S");
+        assert_eq!(1, screen.scrollback().len());
+        Parser::new().feed(&mut screen, b"\x1b[T");
+        assert_eq!("\n\n\n\n5", screen.contents());
+    }
+
+    #[test]
+    fn alternate() {
+        let mut screen = screen(5, 2, b"main\x1b[?1049h");
+        assert!(screen.is_alternate());
+        assert_eq!("", screen.contents());
+        Parser::new().feed(&mut screen, b"\x1b[Halt\r\n\r\n\r\n");
+        assert!(screen.scrollback().is_empty());
+        Parser::new().feed(&mut screen, b"\x1b[?1049l");
+        assert!(!screen.is_alternate());
+        assert_eq!("main", screen.contents());
+        assert_eq!((4, 0), screen.cursor);
+    }
+
+    #[test]
+    fn resize() {
+        let mut screen = screen(5, 4, b"1\r\n2\r\n3");
+        screen.set_size(3, 2);
+        assert_eq!("2\n3", screen.contents());
+        assert_eq!(1, screen.scrollback().len());
+        assert_eq!((1, 1), screen.cursor);
+        screen.set_size(6, 3);
+        assert_eq!(6, screen.row(2).unwrap().len());
+        assert_eq!((6, 3), (screen.width(), screen.height()));
+    }
+
+    #[cfg(windows)]
+    #[test]
+    fn coordinates() {
+        let mut screen = Screen::with_size(Coordinates::new(5, 4));
+        Parser::new().feed(&mut screen, b"1\r\n2\r\n3");
+        let cursor = screen.cursor();
+        assert_eq!((1, 2), (cursor.X, cursor.Y));
+        screen.resize(Coordinates::new(3, 2));
+        assert_eq!("2\n3", screen.contents());
+        let size = screen.size();
+        assert_eq!((3, 2), (size.X, size.Y));
+    }
+
+    #[test]
+    fn reset() {
+        let screen = screen(5, 2, b"\x1b[31mabc\x1b]2;title\x1b\\\x1bc");
+        assert_eq!("", screen.contents());
+        assert_eq!("", screen.title());
+        assert_eq!(Style::default(), screen.style());
+    }
+}
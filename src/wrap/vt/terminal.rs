@@ -0,0 +1,47 @@
+use super::{parser::Parser, screen::Screen};
+use crate::um::wincontypes::Coordinates;
+use std::io::{self, Write};
+
+/// Terminal.
+///
+/// The screen fed through the parser. Writing parses the bytes, so the output
+/// of a pseudo console can be copied into it.
+#[derive(Clone, Debug)]
+pub struct Terminal {
+    parser: Parser,
+    screen: Screen,
+}
+
+impl Terminal {
+    pub fn new(size: Coordinates) -> Self {
+        Self {
+            parser: Parser::new(),
+            screen: Screen::with_size(size),
+        }
+    }
+
+    pub fn feed(&mut self, bytes: &[u8]) {
+        self.parser.feed(&mut self.screen, bytes);
+    }
+
+    #[inline]
+    pub fn screen(&self) -> &Screen {
+        &self.screen
+    }
+
+    #[inline]
+    pub fn screen_mut(&mut self) -> &mut Screen {
+        &mut self.screen
+    }
+}
+
+impl Write for Terminal {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.feed(buf);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
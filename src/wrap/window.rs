@@ -0,0 +1,312 @@
+//! Windows.
+
+use super::message::Message;
+use crate::{
+    shared::windef::{Rect, WindowHandle},
+    um::winuser::{
+        create_window_ex, def_window_proc, destroy_window, dispatch_message, enum_child_windows,
+        enum_windows, find_window_ex, get_class_name, get_client_rect, get_message, get_parent,
+        get_window_long_ptr, get_window_rect, get_window_text, get_window_thread_process_id,
+        is_window, is_window_visible, peek_message, post_message, post_quit_message,
+        register_class_ex, set_window_long_ptr, show_window, translate_message, unregister_class,
+        ClassStyle, ShowCommand, WindowExStyle, WindowStyle,
+    },
+    utils::FromStrictRawHandle,
+    Result,
+};
+use std::{cell::RefCell, vec::IntoIter};
+use winapi::{
+    shared::{
+        minwindef::{BOOL, LPARAM, LRESULT, TRUE, UINT, WPARAM},
+        windef::HWND,
+    },
+    um::winuser::{
+        CREATESTRUCTW, CW_USEDEFAULT, GWLP_USERDATA, PM_REMOVE, WM_CLOSE, WM_NCCREATE,
+        WM_NCDESTROY, WM_QUIT,
+    },
+};
+
+/// Window procedure.
+pub trait WindowProcedure {
+    /// Handles the message, or returns `None` for the default processing.
+    fn handle(&mut self, window: WindowHandle, message: Message) -> Option<isize>;
+}
+
+impl<F: FnMut(WindowHandle, Message) -> Option<isize>> WindowProcedure for F {
+    fn handle(&mut self, window: WindowHandle, message: Message) -> Option<isize> {
+        self(window, message)
+    }
+}
+
+type Procedure = RefCell<Box<dyn WindowProcedure>>;
+
+/// Window class.
+///
+/// Registered with a window procedure dispatching to the `WindowProcedure` of
+/// each window, and unregistered on drop.
+#[derive(Debug)]
+pub struct WindowClass {
+    name: String,
+}
+
+impl WindowClass {
+    pub fn register(name: &str, style: ClassStyle) -> Result<Self> {
+        register_class_ex()
+            .style(style)
+            .window_procedure(Some(trampoline))
+            .class_name(name)
+            .call()?;
+        Ok(Self {
+            name: name.to_owned(),
+        })
+    }
+
+    #[inline]
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+}
+
+impl Drop for WindowClass {
+    fn drop(&mut self) {
+        let _ = unregister_class().class_name(&self.name).call();
+    }
+}
+
+/// Window.
+///
+/// Owns the window procedure, and destroys the window on drop.
+#[derive(Debug)]
+pub struct Window {
+    handle: WindowHandle,
+    procedure: *mut Procedure,
+}
+
+impl Window {
+    /// Creates the window, of the outer `size` or the default one.
+    pub fn new(
+        class: &WindowClass,
+        title: &str,
+        style: WindowStyle,
+        ex_style: WindowExStyle,
+        size: Option<(i32, i32)>,
+        procedure: impl WindowProcedure + 'static,
+    ) -> Result<Self> {
+        let procedure: Box<Procedure> = Box::new(RefCell::new(Box::new(procedure)));
+        let procedure = Box::into_raw(procedure);
+        let (width, height) = size.unwrap_or((CW_USEDEFAULT, CW_USEDEFAULT));
+        let handle = create_window_ex()
+            .ex_style(ex_style)
+            .class_name(class.name())
+            .window_name(title)
+            .style(style)
+            .width(width)
+            .height(height)
+            .param(procedure as _)
+            .call();
+        match handle {
+            Ok(handle) => Ok(Self { handle, procedure }),
+            Err(error) => {
+                drop(unsafe { Box::from_raw(procedure) });
+                Err(error)
+            }
+        }
+    }
+
+    #[inline]
+    pub fn handle(&self) -> WindowHandle {
+        self.handle
+    }
+}
+
+impl Drop for Window {
+    fn drop(&mut self) {
+        if is_window().window_handle(self.handle).call() {
+            let _ = destroy_window().window_handle(self.handle).call();
+        }
+        drop(unsafe { Box::from_raw(self.procedure) });
+    }
+}
+
+/// Runs the message loop of the thread until `WM_QUIT`.
+pub fn run() -> Result<()> {
+    while let Some(message) = get_message().call()? {
+        translate_message().message(&message).call();
+        dispatch_message().message(&message).call();
+    }
+    Ok(())
+}
+
+/// Dispatches the pending messages of the thread, returning `false` on
+/// `WM_QUIT`.
+pub fn pump() -> bool {
+    while let Some(message) = peek_message().remove_message(PM_REMOVE).call() {
+        if message.message == WM_QUIT {
+            return false;
+        }
+        translate_message().message(&message).call();
+        dispatch_message().message(&message).call();
+    }
+    true
+}
+
+/// Posts `WM_QUIT`, ending the message loop of the thread.
+pub fn quit(exit_code: i32) {
+    post_quit_message().exit_code(exit_code).call()
+}
+
+/// The top level windows, in Z order.
+pub fn windows() -> Result<IntoIter<WindowHandle>> {
+    let mut windows = Vec::new();
+    enum_windows()
+        .enum_function(Some(push))
+        .l_param(&mut windows as *mut Vec<WindowHandle> as _)
+        .call()?;
+    Ok(windows.into_iter())
+}
+
+/// Finds the first top level window with the class name and the title, any
+/// of them matching when `None`.
+pub fn find_window(class_name: Option<&str>, title: Option<&str>) -> Result<Option<WindowHandle>> {
+    find(None, None, class_name, title)
+}
+
+impl WindowHandle {
+    /// The child windows, recursively.
+    pub fn children(&self) -> IntoIter<WindowHandle> {
+        let mut windows = Vec::new();
+        enum_child_windows()
+            .window_handle(*self)
+            .enum_function(Some(push))
+            .l_param(&mut windows as *mut Vec<WindowHandle> as _)
+            .call();
+        windows.into_iter()
+    }
+
+    /// Finds the next direct child window after `after`, or the first one,
+    /// with the class name and the title.
+    pub fn find_child(
+        &self,
+        after: Option<WindowHandle>,
+        class_name: Option<&str>,
+        title: Option<&str>,
+    ) -> Result<Option<WindowHandle>> {
+        find(Some(*self), after, class_name, title)
+    }
+
+    pub fn title(&self) -> Result<String> {
+        get_window_text().window_handle(*self).call()
+    }
+
+    pub fn class_name(&self) -> Result<String> {
+        get_class_name().window_handle(*self).call()
+    }
+
+    /// The window rectangle, in screen coordinates.
+    pub fn rect(&self) -> Result<Rect> {
+        get_window_rect().window_handle(*self).call()
+    }
+
+    /// The client rectangle, whose left and top are 0.
+    pub fn client_rect(&self) -> Result<Rect> {
+        get_client_rect().window_handle(*self).call()
+    }
+
+    pub fn process_id(&self) -> Result<u32> {
+        Ok(get_window_thread_process_id()
+            .window_handle(*self)
+            .call()?
+            .1)
+    }
+
+    pub fn thread_id(&self) -> Result<u32> {
+        Ok(get_window_thread_process_id()
+            .window_handle(*self)
+            .call()?
+            .0)
+    }
+
+    pub fn is_visible(&self) -> bool {
+        is_window_visible().window_handle(*self).call()
+    }
+
+    /// The parent of a child window, or the owner of a top level one.
+    pub fn parent(&self) -> Option<WindowHandle> {
+        get_parent().window_handle(*self).call()
+    }
+
+    /// Returns whether the window was previously visible.
+    pub fn show(&self, show_command: ShowCommand) -> bool {
+        show_window()
+            .window_handle(*self)
+            .show_command(show_command)
+            .call()
+    }
+
+    /// Asks the window to close, as its close button does.
+    pub fn close(&self) -> Result<()> {
+        post_message().window_handle(*self).message(WM_CLOSE).call()
+    }
+}
+
+fn find(
+    parent: Option<WindowHandle>,
+    after: Option<WindowHandle>,
+    class_name: Option<&str>,
+    title: Option<&str>,
+) -> Result<Option<WindowHandle>> {
+    find_window_ex()
+        .parent(parent)
+        .child_after(after)
+        .class_name(class_name)
+        .window_name(title)
+        .call()
+}
+
+unsafe extern "system" fn push(handle: HWND, l_param: LPARAM) -> BOOL {
+    let windows = &mut *(l_param as *mut Vec<WindowHandle>);
+    windows.push(WindowHandle::from_strict_raw_handle(handle));
+    TRUE
+}
+
+unsafe extern "system" fn trampoline(
+    handle: HWND,
+    message: UINT,
+    w_param: WPARAM,
+    l_param: LPARAM,
+) -> LRESULT {
+    let window = WindowHandle::from_strict_raw_handle(handle);
+    let user_data = |new_long: isize| {
+        set_window_long_ptr()
+            .window_handle(window)
+            .index(GWLP_USERDATA)
+            .new_long(new_long)
+            .call()
+    };
+    if message == WM_NCCREATE {
+        let create_struct = &*(l_param as *const CREATESTRUCTW);
+        let _ = user_data(create_struct.lpCreateParams as _);
+    }
+    let procedure = get_window_long_ptr()
+        .window_handle(window)
+        .index(GWLP_USERDATA)
+        .call() as *const Procedure;
+    // A message sent while handling another one gets the default processing.
+    let result = procedure
+        .as_ref()
+        .and_then(|procedure| procedure.try_borrow_mut().ok())
+        .and_then(|mut procedure| {
+            procedure.handle(window, Message::new(message, w_param, l_param))
+        });
+    if message == WM_NCDESTROY {
+        let _ = user_data(0);
+    }
+    result.unwrap_or_else(|| {
+        def_window_proc()
+            .window_handle(window)
+            .message(message)
+            .w_param(w_param)
+            .l_param(l_param)
+            .call()
+    })
+}
@@ -1,3 +1,5 @@
+#![cfg(windows)]
+
 use anyhow::Result;
 use rustapi::um::d3dcompiler::d3d_compile;
 
@@ -19,7 +21,7 @@ fn test() -> Result<()> {
         .source_name("shader")
         .entrypoint("main")
         .target("ps_4_0")
-        .build()()?;
+        .call()?;
     Ok(())
 }
 